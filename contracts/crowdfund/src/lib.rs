@@ -1,7 +1,10 @@
 #![no_std]
 #![allow(missing_docs)]
 
-use soroban_sdk::{contract, contractimpl, contracttype, token, Address, Env, String, Symbol, Vec};
+use soroban_sdk::{
+    contract, contractclient, contractimpl, contracttype, panic_with_error, token, xdr::ToXdr,
+    Address, Bytes, BytesN, Env, Map, String, Symbol, Vec,
+};
 
 #[cfg(test)]
 mod test;
@@ -13,12 +16,12 @@ mod test;
 /// This constant must be manually incremented with every contract upgrade
 /// (see Issue #38). External tools use this to detect logic changes at a
 /// given contract address.
-const CONTRACT_VERSION: u32 = 1;
+const CONTRACT_VERSION: u32 = 2;
 
 // ── Data Types ──────────────────────────────────────────────────────────────
 
 /// Represents the campaign status.
-#[derive(Clone, PartialEq)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 #[contracttype]
 pub enum Status {
     /// The campaign is currently active and accepting contributions.
@@ -31,12 +34,124 @@ pub enum Status {
     Cancelled,
 }
 
-/// Campaign statistics for the get_stats view.
+/// What a campaign is about, for discovery.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum CampaignCategory {
+    Technology,
+    Art,
+    Music,
+    Social,
+    Environment,
+    Health,
+    Education,
+    Other,
+}
+
+/// When a campaign stops accepting contributions.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum Deadline {
+    /// The last ledger timestamp, in seconds, at which contributions count.
+    Timestamp(u64),
+    /// The last ledger sequence number at which contributions count.
+    Sequence(u32),
+}
+
+impl Deadline {
+    /// Returns true once the ledger has moved past the deadline.
+    fn has_passed(&self, env: &Env) -> bool {
+        match *self {
+            Deadline::Timestamp(timestamp) => env.ledger().timestamp() > timestamp,
+            Deadline::Sequence(sequence) => env.ledger().sequence() > sequence,
+        }
+    }
+
+    /// Returns the seconds left until the deadline, estimated from
+    /// `LEDGER_SECONDS` for sequence deadlines.
+    fn seconds_remaining(&self, env: &Env) -> u64 {
+        match *self {
+            Deadline::Timestamp(timestamp) => timestamp.saturating_sub(env.ledger().timestamp()),
+            Deadline::Sequence(sequence) => {
+                sequence.saturating_sub(env.ledger().sequence()) as u64 * LEDGER_SECONDS
+            }
+        }
+    }
+
+    /// Returns the timestamp or sequence number the deadline is set at.
+    fn value(&self) -> u64 {
+        match *self {
+            Deadline::Timestamp(timestamp) => timestamp,
+            Deadline::Sequence(sequence) => sequence as u64,
+        }
+    }
+}
+
+/// The campaign deadline and whether it has passed, from `deadline_info`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct DeadlineInfo {
+    pub deadline: Deadline,
+    pub expired: bool,
+}
+
+/// A dated entry on the campaign roadmap.
 #[derive(Clone)]
 #[contracttype]
 pub struct RoadmapItem {
     pub date: u64,
+    /// Ledger timestamp by which the milestone must be complete.
+    pub deadline: u64,
     pub description: String,
+    /// Evidence submitted with `submit_milestone_proof`; `None` while the
+    /// milestone is outstanding.
+    pub proof: Option<String>,
+}
+
+/// Links to the campaign's presence elsewhere. Empty fields are unset.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct CampaignLinks {
+    pub website: String,
+    pub twitter: String,
+    pub github: String,
+}
+
+/// Progress of a roadmap milestone.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum MilestoneStatus {
+    /// Not yet complete, and its deadline has not passed.
+    Pending,
+    /// Proof was submitted on or before the deadline.
+    Complete,
+    /// The deadline passed without proof.
+    Missed,
+}
+
+/// When the platform fee is collected.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum FeeTiming {
+    /// The whole fee is taken from `total_raised` at withdraw (default).
+    OnWithdraw,
+    /// The fee is skimmed from each contribution as it arrives. Only the net
+    /// amount is credited to the campaign, so refunds also return the net
+    /// amount, and withdraw takes no further fee.
+    OnContribute,
+}
+
+/// Who receives the fraction of a stroop truncated from a platform fee.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum DustPolicy {
+    /// The fee is rounded down, leaving the dust with the creator.
+    Creator,
+    /// The fee is rounded up, giving the dust to the platform.
+    Platform,
+    /// The fee is rounded down and the fraction is carried into the next
+    /// fee charged in the same token.
+    CarryForward,
 }
 
 /// Platform configuration for fee handling.
@@ -45,6 +160,159 @@ pub struct RoadmapItem {
 pub struct PlatformConfig {
     pub address: Address,
     pub fee_bps: u32,
+    pub fee_timing: FeeTiming,
+    /// Rounding policy for fee dust.
+    pub dust_policy: DustPolicy,
+    /// Optional `(upper_bound, fee_bps)` brackets, in strictly ascending
+    /// order of `upper_bound`, that replace the flat `fee_bps`. The first
+    /// bracket whose bound covers the total raised applies; totals above
+    /// every bound use the last bracket's rate.
+    pub tiered_fee: Option<Vec<(i128, u32)>>,
+    /// Shortest campaign, in seconds from initialization to deadline, the
+    /// platform will host. `0` leaves only the contract-wide `MIN_DURATION`.
+    pub min_campaign_duration_seconds: u64,
+    /// Longest campaign the platform will host; `0` leaves only the
+    /// contract-wide `MAX_DURATION`.
+    pub max_campaign_duration_seconds: u64,
+}
+
+impl PlatformConfig {
+    /// Returns the fee rate that applies when `total_raised` has been raised.
+    fn fee_bps_for(&self, total_raised: i128) -> u32 {
+        let Some(ref tiers) = self.tiered_fee else {
+            return self.fee_bps;
+        };
+        let mut fee_bps = self.fee_bps;
+        for (upper_bound, tier_bps) in tiers.iter() {
+            fee_bps = tier_bps;
+            if upper_bound >= total_raised {
+                break;
+            }
+        }
+        fee_bps
+    }
+}
+
+/// Optional per-campaign settings passed to `initialize`.
+///
+/// Every field defaults to "off", so `None` (or `CampaignOptions::default()`)
+/// keeps the plain crowdfund behaviour.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[contracttype]
+pub struct CampaignOptions {
+    /// Share of each referred contribution, in basis points, owed to the
+    /// referrer out of the creator's payout.
+    pub referral_fee_bps: u32,
+    /// Cooling-off period after the deadline during which contributors may
+    /// still reclaim their funds with `refund_single`, even if the goal was
+    /// met. The creator cannot withdraw until it closes.
+    pub refund_window_seconds: u64,
+    /// Early-bird bonus windows, ordered by ascending `end_timestamp`.
+    /// Contributions made before a window ends are weighted by its bonus.
+    pub early_bird_windows: Option<Vec<EarlyBirdWindow>>,
+    /// Return anything raised above the goal to contributors, pro rata,
+    /// instead of paying it to the creator. Claimed with `claim_excess`.
+    pub return_excess: bool,
+    /// Companion receipt-token contract (`contracts/receipt`) that mints
+    /// receipts to contributors and burns them on refund. The campaign must
+    /// be set as its minter.
+    pub receipt_contract: Option<Address>,
+    /// Goal in USD cents. When set (together with `price_oracle`), the token
+    /// goal is recomputed from the oracle price on every read. With a
+    /// `price_asset`, it is in the SEP-40 feed's quote units instead.
+    pub usd_goal: Option<i128>,
+    /// Price oracle quoting the campaign token in tokens per USD cent, or a
+    /// SEP-40 (Reflector-compatible) feed when `price_asset` is set.
+    pub price_oracle: Option<Address>,
+    /// Symbol of the campaign token on a SEP-40 `price_oracle`.
+    pub price_asset: Option<Symbol>,
+    /// Oldest acceptable SEP-40 price age in seconds; older prices fail
+    /// closed with `StalePrice`.
+    pub max_price_staleness: u64,
+    /// Extra tokens `contribute` accepts besides the primary campaign token,
+    /// registered as `accepted_tokens` without a swap pool. Each is tallied
+    /// separately, without conversion, and does not count toward the goal.
+    pub approved_tokens: Option<Vec<Address>>,
+    /// The most a single contributor may contribute within one rate-limit
+    /// window; 0 disables rate limiting.
+    pub rate_limit_amount: i128,
+    /// Length of a rate-limit window. A window opens with the contributor's
+    /// first contribution and resets once this many seconds have passed.
+    pub rate_limit_window_seconds: u64,
+    /// How long after a batch `refund` failed refunds stay claimable before
+    /// `sweep_unclaimed` may collect them; 0 disables sweeping.
+    pub refund_claim_period: u64,
+    /// Share of `total_raised`, in basis points, whose "yes" votes pass a
+    /// governance proposal; 0 disables governance.
+    pub quorum_bps: u32,
+    /// Longest a `creator_pause` may last before it lapses on its own;
+    /// 0 uses `DEFAULT_MAX_CREATOR_PAUSE`.
+    pub max_creator_pause_seconds: u64,
+    /// Co-signer approvals needed to pay out, via `initiate_withdraw` and
+    /// `sign_withdraw`; 0 keeps the single-key `withdraw`.
+    pub required_withdraw_signatures: u32,
+    /// Addresses allowed to initiate and sign a multi-sig withdraw.
+    pub withdraw_signers: Option<Vec<Address>>,
+    /// Contract implementing `CampaignHookInterface`, notified of every
+    /// contribution and of the campaign's close. A failing hook is reported
+    /// with a `hook_failed` event and otherwise ignored.
+    pub hook: Option<Address>,
+    /// Close the campaign as soon as it reaches its goal: the contribution
+    /// that reaches it is trimmed to the remaining room, later contributions
+    /// and pledge withdrawals fail with `GoalAlreadyReached`, and the creator
+    /// may `withdraw` before the deadline (a refund window still applies).
+    pub auto_close_at_goal: bool,
+    /// Accept contributions only from addresses the creator has added with
+    /// `add_to_allowlist`; others fail with `NotAllowlisted`.
+    pub allowlist_enabled: bool,
+    /// How long after `initialize` the creator must wait before paying out
+    /// ahead of the deadline, with `early_withdraw` or an auto-closed
+    /// `withdraw`; 0 imposes no wait.
+    pub min_funding_duration_seconds: u64,
+    /// Allow one contribution per address: no top-ups or recurring
+    /// schedules. Withdrawing the whole pledge with `partial_refund` frees
+    /// the address to contribute afresh.
+    pub single_contribution: bool,
+    /// Distinct contributors needed, on top of the goal, for the campaign to
+    /// succeed; 0 requires none. A campaign short of it refunds even if the
+    /// goal was met.
+    pub min_contributors: u32,
+    /// Seconds after the deadline before `refund` (and the refund claims
+    /// that follow it) may run, while `withdraw` is open from the deadline;
+    /// 0 opens refunds at the deadline. Needs a timestamp deadline.
+    pub grace_period_secs: u64,
+    /// Close the campaign as Successful once `total_raised` reaches the hard
+    /// cap: later contributions and pledge withdrawals panic, and the creator
+    /// may `withdraw` before the deadline. Cannot be combined with
+    /// `min_contributors` or a refund window.
+    pub auto_close_on_cap: bool,
+    /// Seconds after the deadline before any of the creator's vesting share
+    /// can be claimed; at most `vesting_duration_secs`.
+    pub vesting_cliff_secs: u64,
+    /// Seconds after the deadline over which the creator's share vests
+    /// linearly, released through `claim_vested` instead of in one payout;
+    /// 0 pays out in full. Needs a timestamp deadline.
+    pub vesting_duration_secs: u64,
+}
+
+/// A bonus applied to contributions made up to `end_timestamp`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct EarlyBirdWindow {
+    pub end_timestamp: u64,
+    /// Bonus in basis points added on top of the raw amount (2500 = +25%).
+    pub bonus_bps: u32,
+}
+
+/// A sponsor's escrowed matching pool.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct MatchingPool {
+    pub sponsor: Address,
+    /// Funds still available for matching.
+    pub remaining: i128,
+    /// Share of each contribution matched, in basis points (10000 = 1:1).
+    pub match_bps: u32,
 }
 
 /// A reward tier with a name and minimum contribution amount to qualify.
@@ -53,10 +321,106 @@ pub struct PlatformConfig {
 pub struct RewardTier {
     pub name: String,
     pub min_amount: i128,
+    /// Maximum number of contributors who can hold this tier; `None` is
+    /// unlimited.
+    pub max_claims: Option<u32>,
+    /// Number of contributors currently holding this tier.
+    pub claimed: u32,
 }
 
-/// Represents all storage keys used by the crowdfund contract.
-#[derive(Clone)]
+/// A contributor proposal to change a campaign parameter.
+///
+/// `proposed_value` is the big-endian encoding of the new value: 8 bytes
+/// for `deadline`, 16 bytes for `goal` and `min_contribution`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Proposal {
+    pub parameter: String,
+    pub proposed_value: Bytes,
+    pub created_at: u64,
+    /// Total contribution when the proposal was created; the quorum is a
+    /// share of it.
+    pub eligible_weight: i128,
+    /// Contribution-weighted votes in favour.
+    pub yes_weight: i128,
+    /// Contribution-weighted votes against.
+    pub no_weight: i128,
+    /// Set once the "yes" weight reaches the quorum.
+    pub passed: bool,
+    /// Set once the creator has applied the change.
+    pub applied: bool,
+}
+
+/// A recurring contribution registered with `schedule_recurring`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct RecurringSchedule {
+    pub amount_per_interval: i128,
+    pub interval_seconds: u64,
+    /// Pulls still to be made.
+    pub remaining_intervals: u32,
+    /// Ledger timestamp from which the next pull may be processed.
+    pub next_due: u64,
+}
+
+/// Campaign token metadata, read and cached at initialization.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct TokenMetadata {
+    pub decimals: u32,
+    pub symbol: String,
+}
+
+/// Proof of participation for other contracts, from `contribution_certificate`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ContributionCertificate {
+    pub contributor: Address,
+    pub campaign_contract: Address,
+    /// The contributor's current contribution.
+    pub total_contributed: i128,
+    pub campaign_goal: i128,
+    /// Ledger timestamp at which the certificate was issued.
+    pub timestamp: u64,
+    pub campaign_status: Status,
+}
+
+/// A multi-sig withdraw awaiting co-signatures.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct PendingWithdraw {
+    pub initiator: Address,
+    /// Ledger timestamp of `initiate_withdraw`; the request lapses
+    /// `WITHDRAW_SIGNING_WINDOW` seconds later.
+    pub initiated_at: u64,
+    /// Signers so far, the initiator included.
+    pub signatures: Vec<Address>,
+}
+
+/// Aggregate figures kept once a closed campaign is archived.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct CampaignArchive {
+    pub total_raised_at_close: i128,
+    pub contributor_count_at_close: u32,
+    pub status: Status,
+    pub goal: i128,
+}
+
+/// A permanent, non-transferable backer badge claimed after success.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Badge {
+    /// The backer's contribution when the badge was claimed.
+    pub amount: i128,
+    /// Ledger timestamp of the claim.
+    pub timestamp: u64,
+    /// Index of the reward tier held, if any.
+    pub tier: Option<u32>,
+}
+
+/// Campaign statistics for the `campaign_stats` view.
+#[derive(Clone, Debug, Eq, PartialEq)]
 #[contracttype]
 pub struct CampaignStats {
     /// Total amount raised so far.
@@ -71,6 +435,137 @@ pub struct CampaignStats {
     pub average_contribution: i128,
     /// Largest contribution amount.
     pub largest_contribution: i128,
+    /// The deadline's ledger timestamp, or its sequence number for
+    /// sequence deadlines.
+    pub deadline: u64,
+    /// Seconds left until the deadline (0 once it has passed). Estimated for
+    /// sequence deadlines.
+    pub time_remaining: u64,
+    /// Current campaign status.
+    pub status: Status,
+    /// Platform fee in basis points (0 when no platform is configured).
+    pub platform_fee_bps: u32,
+    /// Number of roadmap items.
+    pub roadmap_item_count: u32,
+}
+
+/// Descriptive contribution statistics for the `stats` view.
+///
+/// While the campaign is Active these follow every contribution, top-up and
+/// pledge withdrawal. Once it closes they freeze at their closing values: a
+/// batch refund or cancel does not reset them.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ContributionStats {
+    /// Number of contributions made, counting top-ups separately. Withdrawn
+    /// pledges are not subtracted.
+    pub count: u32,
+    /// Number of addresses with a contribution outstanding.
+    pub unique_contributors: u32,
+    /// Largest cumulative contribution of a single address.
+    pub largest: i128,
+    /// The address holding `largest`; `None` before any contribution.
+    pub largest_from: Option<Address>,
+    /// Raised total divided by `unique_contributors`.
+    pub average: i128,
+}
+
+/// Contribution state captured by `snapshot` under a creator-chosen name.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Snapshot {
+    pub total_raised: i128,
+    pub contributor_count: u32,
+    pub ledger_sequence: u32,
+}
+
+// ── Events ──────────────────────────────────────────────────────────────────
+//
+// Every state change publishes one of the structs below under the topic
+// `("campaign", <action>)` so indexers can decode payloads by name.
+
+/// Published as `("campaign", "contributed")` after each contribution.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ContributionEvent {
+    pub contributor: Address,
+    pub amount: i128,
+    pub total_raised_after: i128,
+}
+
+/// Published as `("campaign", "withdrawn")` when the creator is paid out.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct WithdrawEvent {
+    pub creator: Address,
+    /// Where the payout was sent: the creator, or the address given to
+    /// `withdraw_to`.
+    pub destination: Address,
+    /// Amount sent to `destination`, net of the platform fee.
+    pub amount: i128,
+    pub platform_fee: i128,
+}
+
+/// Published as `("campaign", "refunded")` once all contributors are refunded.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct RefundEvent {
+    pub total_refunded: i128,
+}
+
+/// Published as `("campaign", "cancelled")` when the creator cancels.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct CancelEvent {
+    pub creator: Address,
+    pub total_refunded: i128,
+}
+
+/// Published as `("campaign", "cancel_reason")` by `cancel_with_reason`, after
+/// the usual `CancelEvent`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct CancelledEvent {
+    pub reason: String,
+    pub timestamp: u64,
+}
+
+/// Published as `("campaign", "status_changed")` on every status transition.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct StatusChangedEvent {
+    pub old_status: Status,
+    pub new_status: Status,
+}
+
+/// Published as `("campaign", "roadmap_item_added")`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct RoadmapItemAddedEvent {
+    /// Position of the new item in the roadmap.
+    pub index: u32,
+    pub date: u64,
+    pub deadline: u64,
+    pub description: String,
+}
+
+/// Published once, as `("goal_reached",)`, by the contribution that first
+/// takes the campaign to its goal. The bare topic lets notification services
+/// subscribe to it without filtering every campaign event.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct GoalReachedEvent {
+    pub total_raised: i128,
+    pub timestamp: u64,
+    /// The contributor whose contribution crossed the goal.
+    pub contributor: Address,
+}
+
+/// Published as `("campaign", "links_updated")` by `set_links`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct LinksUpdatedEvent {
+    pub links: CampaignLinks,
 }
 
 /// Represents all storage keys used by the crowdfund contract.
@@ -81,7 +576,7 @@ pub enum DataKey {
     Paused,
     /// The hard cap for the campaign.
     HardCap,
-    /// The campaign category.
+    /// The campaign's `CampaignCategory`.
     Category,
     /// The campaign tags.
     Tags,
@@ -91,7 +586,7 @@ pub enum DataKey {
     Token,
     /// The funding goal in the token's smallest unit.
     Goal,
-    /// The deadline as a ledger timestamp.
+    /// The campaign `Deadline`.
     Deadline,
     /// Total amount raised so far.
     TotalRaised,
@@ -129,12 +624,360 @@ pub enum DataKey {
     StretchGoals,
     /// Total amount referred by each referrer address.
     ReferralTally(Address),
+    /// Optional campaign settings (`CampaignOptions`).
+    Options,
+    /// Referral fees owed to each referrer address.
+    ReferralEarnings(Address),
+    /// Sum of all referral fees owed, held back from the creator's payout.
+    TotalReferralFees,
+    /// The sponsor matching pool (`MatchingPool`).
+    MatchingPool,
+    /// Index of the reward tier claimed by each contributor.
+    ContributorTier(Address),
+    /// Bonus-weighted contribution of each contributor.
+    WeightedContribution(Address),
+    /// Sum of all weighted contributions.
+    TotalWeighted,
+    /// Over-goal excess each contributor can claim back.
+    ExcessClaim(Address),
+    /// Backer badge claimed by each contributor (`Badge`).
+    Badge(Address),
+    /// Non-base tokens the campaign accepts (`AcceptedToken`).
+    AcceptedTokens,
+    /// Metadata reported by the campaign token at initialization.
+    TokenMetadata,
+    /// Contribution of an address in a tallied accepted token.
+    TokenContribution(Address, Address),
+    /// Total contributed in a tallied accepted token.
+    TokenTotal(Address),
+    /// Contributors of a tallied accepted token.
+    TokenContributors(Address),
+    /// Number of contributions made by each address.
+    ContributionCount(Address),
+    /// Memo attached to an address's nth contribution.
+    ContributionMemo(Address, u32),
+    /// Whether the platform has suspended the campaign.
+    Suspended,
+    /// Whether an address is a verified endorser (set by the admin).
+    VerifiedEndorser(Address),
+    /// Verified endorsers currently endorsing the campaign.
+    Endorsements,
+    /// `(window_start, window_total)` for a contributor's rate-limit window.
+    RateWindow(Address),
+    /// A contributor's recurring contribution schedule.
+    Recurring(Address),
+}
+
+/// Storage keys for governance proposals and code upgrades.
+#[derive(Clone)]
+#[contracttype]
+pub enum GovernanceKey {
+    /// Number of proposals created; also the next proposal id.
+    ProposalCount,
+    /// A proposal by id.
+    Proposal(u32),
+    /// Whether an address has voted on a proposal.
+    Voted(u32, Address),
+    /// Each contributor's contribution when a proposal was created, which
+    /// caps their vote on it.
+    VoteWeights(u32),
+    /// WASM hash installed by the last `upgrade`.
+    WasmHash,
+    /// Storage layout version the instance was last initialized or migrated
+    /// to. Absent on instances deployed before version 2.
+    StorageVersion,
+    /// Address that deployed the contract; the only one allowed to initialize it.
+    Deployer,
+    /// Ledger timestamp at which the campaign was initialized.
+    CreatedAt,
+}
+
+/// Storage keys from earlier layouts, read only by `migrate`.
+#[derive(Clone)]
+#[contracttype]
+pub enum LegacyKey {
+    /// Version 1: every contribution in one instance-storage map.
+    Contributions,
+}
+
+/// Storage keys for gates on who may contribute, and when.
+#[derive(Clone)]
+#[contracttype]
+pub enum ComplianceKey {
+    /// IPFS hash of the campaign's terms and conditions.
+    TermsHash,
+    /// Whether a contributor has accepted the campaign terms.
+    TermsAccepted(Address),
+    /// Whether contributors must acknowledge the campaign risks.
+    RequiresAcknowledgment,
+    /// Whether a contributor has acknowledged the campaign risks.
+    RiskAcknowledged(Address),
+    /// Whether the platform has paused contributions.
+    PlatformPaused,
+    /// Ledger timestamp at which the creator's pause lapses.
+    CreatorPausedUntil,
+    /// Whether the creator has barred an address from contributing.
+    Blacklisted(Address),
+    /// Whether an address is on the creator's contributor allowlist.
+    Allowlisted(Address),
+    /// Merkle root of an allowlist too large to store per address.
+    AllowlistRoot,
+}
+
+/// Storage keys for refund and fee settlement.
+///
+/// Kept apart from `DataKey`, which is at the contract type variant limit.
+#[derive(Clone)]
+#[contracttype]
+pub enum SettlementKey {
+    /// Contributors whose batch refund transfer failed, awaiting `claim_refund`.
+    FailedRefunds,
+    /// Ledger timestamp at which the batch `refund` ran.
+    RefundedAt,
+    /// Set once unclaimed refunds have been swept.
+    RefundsSwept,
+    /// Fee dust carried forward per token under `DustPolicy::CarryForward`.
+    FeeDust(Address),
+    /// Total raised when the campaign left the Active state.
+    RaisedAtClose,
+    /// The `CampaignArchive` left behind by `archive`.
+    Archive,
+    /// The multi-sig withdraw awaiting co-signatures, if any.
+    PendingWithdraw,
+    /// Why the creator cancelled, from `cancel_with_reason`.
+    CancellationReason,
+    /// The creator's share, net of fees, held for `withdraw_amount` draws or
+    /// vested claims.
+    Drawdown,
+    /// How much of `Drawdown` the creator has drawn or claimed so far.
+    WithdrawnSoFar,
+    /// The campaign `cancel_and_migrate` pointed contributors to.
+    MigrationTarget,
+    /// The refunded amount a contributor may still move to the migration
+    /// target.
+    Migratable(Address),
+}
+
+/// Storage keys for contributor rewards issued alongside contributions.
+#[derive(Clone)]
+#[contracttype]
+pub enum RewardKey {
+    /// `(token, ratio)`: one reward token minted per `ratio` contributed.
+    RewardToken,
+    /// `(cliff_seconds, vesting_seconds)` for locked reward tokens.
+    Vesting,
+    /// Ledger timestamp at which the campaign paid out and vesting began.
+    VestingStart,
+    /// Reward tokens held by the contract for a contributor.
+    Locked(Address),
+    /// Reward tokens minted straight to a contributor for the credit they
+    /// still hold.
+    Issued(Address),
+    /// Locked reward tokens a contributor has already claimed.
+    Claimed(Address),
+    /// Cumulative profit the creator has distributed to contributors.
+    ProfitDistributed,
+    /// Profit a contributor has already claimed.
+    ProfitClaimed(Address),
+    /// Profit claimed by all contributors together.
+    TotalProfitClaimed,
+    /// Referral fees accrued on a contributor's contribution, by referrer.
+    ReferralFees(Address),
+}
+
+/// Storage keys for the creator's milestone collateral bond.
+#[derive(Clone)]
+#[contracttype]
+pub enum CollateralKey {
+    /// `(amount, slash_bps)`: the bond as posted, and the share of it each
+    /// missed milestone forfeits.
+    Bond,
+    /// Collateral still held for the creator.
+    Remaining,
+    /// Whether a missed milestone has already been slashed.
+    Slashed(u32),
+}
+
+/// Storage keys for descriptive campaign metadata.
+#[derive(Clone)]
+#[contracttype]
+pub enum MetadataKey {
+    /// The creator's `CampaignLinks`.
+    Links,
+    /// IPFS CID of the campaign's banner or video.
+    MediaHash,
+    /// Ledger timestamp until which the platform features the campaign.
+    FeaturedUntil,
+}
+
+/// Storage keys for aggregate views kept up to date on every contribution.
+#[derive(Clone)]
+#[contracttype]
+pub enum StatsKey {
+    /// `(contributor, amount)` for the largest contributors, largest first.
+    TopContributors,
+    /// Number of contributions made to the campaign.
+    ContributionCount,
+    /// Ledger timestamp at which `total_raised` first reached the goal.
+    GoalReachedAt,
+    /// A named `Snapshot`.
+    Snapshot(String),
 }
 
+// ── Media ──────────────────────────────────────────────────────────────────
+/// Shortest media hash accepted: a CIDv0 is 46 characters.
+const MIN_MEDIA_HASH_LEN: u32 = 46;
+/// Longest media hash accepted: a base32 CIDv1 is 59 characters.
+const MAX_MEDIA_HASH_LEN: u32 = 59;
+
+// ── Leaderboard ────────────────────────────────────────────────────────────
+/// Number of contributors kept on the `top_contributors` leaderboard.
+const TOP_CONTRIBUTORS: u32 = 10;
+
 // ── Rate Limiting ──────────────────────────────────────────────────────────
 /// Minimum seconds required between contributions from the same address.
 const CONTRIBUTION_COOLDOWN: u64 = 5;
 
+// ── Campaign Duration ───────────────────────────────────────────────────────
+/// Shortest allowed campaign: the deadline must be at least this many
+/// seconds after initialization.
+const MIN_DURATION: u64 = 60 * 60;
+
+/// Longest allowed campaign: a deadline may never be set more than this many
+/// seconds ahead of the current ledger time.
+const MAX_DURATION: u64 = 365 * 24 * 60 * 60;
+
+/// Approximate ledger close time, used to bound and report sequence-based
+/// deadlines in seconds.
+const LEDGER_SECONDS: u64 = 5;
+
+/// How long a multi-sig withdraw stays open for co-signatures before it has
+/// to be initiated again.
+const WITHDRAW_SIGNING_WINDOW: u64 = 3 * 24 * 60 * 60;
+
+/// How long a `creator_pause` lasts unless the campaign configures
+/// `max_creator_pause_seconds`.
+const DEFAULT_MAX_CREATOR_PAUSE: u64 = 7 * 24 * 60 * 60;
+
+// ── Receipt Token ───────────────────────────────────────────────────────────
+
+/// Interface of the companion receipt-token contract.
+#[contractclient(name = "ReceiptClient")]
+pub trait ReceiptInterface {
+    fn mint(env: Env, to: Address, amount: i128);
+    fn burn(env: Env, from: Address, amount: i128);
+    fn balance(env: Env, id: Address) -> i128;
+}
+
+// ── Campaign Registry ───────────────────────────────────────────────────────
+
+/// Callback a deploying factory exposes to aggregate platform-wide stats.
+#[contractclient(name = "RegistryClient")]
+pub trait RegistryInterface {
+    fn on_campaign_finalized(
+        env: Env,
+        campaign: Address,
+        raised_amount: i128,
+        contributor_count: u64,
+    );
+}
+
+// ── Fee Vault ───────────────────────────────────────────────────────────────
+
+/// Accounting hook of a fee-vault contract used as the platform address.
+#[contractclient(name = "FeeVaultClient")]
+pub trait FeeVaultInterface {
+    fn record_fee(env: Env, campaign: Address, token: Address, amount: i128);
+}
+
+/// Close notification a platform contract may implement to learn of
+/// finalized campaigns without polling them.
+#[contractclient(name = "PlatformListenerClient")]
+pub trait PlatformListenerInterface {
+    fn campaign_finalized(
+        env: Env,
+        campaign: Address,
+        status: Status,
+        total_raised: i128,
+        fee_paid: i128,
+    );
+}
+
+/// Callbacks a campaign hook contract implements; see `CampaignOptions::hook`.
+#[contractclient(name = "CampaignHookClient")]
+pub trait CampaignHookInterface {
+    fn on_contribute(env: Env, contributor: Address, amount: i128, total: i128);
+    fn on_finalize(env: Env, status: Status, total: i128);
+}
+
+/// Token allowlist, fee waiver and fee cap views of a platform contract,
+/// such as the fee vault.
+#[contractclient(name = "PlatformRegistryClient")]
+pub trait PlatformRegistryInterface {
+    fn is_token_allowed(env: Env, token: Address) -> bool;
+    fn is_fee_waived(env: Env, creator: Address) -> bool;
+    fn is_campaign_fee_waived(env: Env, campaign: Address) -> bool;
+    fn fee_cap_bps(env: Env) -> u32;
+}
+
+// ── Price Oracle ────────────────────────────────────────────────────────────
+
+/// Interface of the price oracle used for USD-denominated goals.
+#[contractclient(name = "PriceOracleClient")]
+pub trait PriceOracleInterface {
+    /// Returns the price of `asset` in token units per USD cent.
+    fn price(env: Env, asset: Address) -> i128;
+}
+
+// ── SEP-40 Oracle ───────────────────────────────────────────────────────────
+
+/// Asset identifier of the SEP-40 price feed interface.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum Asset {
+    Stellar(Address),
+    Other(Symbol),
+}
+
+/// A price quote of the SEP-40 price feed interface.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct PriceData {
+    pub price: i128,
+    pub timestamp: u64,
+}
+
+/// The subset of the SEP-40 price feed interface used for goal evaluation.
+#[contractclient(name = "PriceFeedClient")]
+pub trait PriceFeedInterface {
+    /// Returns the number of decimals in quoted prices.
+    fn decimals(env: Env) -> u32;
+    /// Returns the most recent price of `asset`, if any.
+    fn lastprice(env: Env, asset: Asset) -> Option<PriceData>;
+}
+
+// ── Swap Pool ───────────────────────────────────────────────────────────────
+
+/// A non-base token the campaign accepts.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct AcceptedToken {
+    pub token: Address,
+    /// Pool that converts the token to the base token, which is then
+    /// credited like a normal contribution. Without one, the token is
+    /// tallied separately, without conversion, and does not count toward
+    /// the goal.
+    pub swap_pool: Option<Address>,
+}
+
+/// Interface of a DEX pool that converts accepted tokens to the base token.
+#[contractclient(name = "SwapPoolClient")]
+pub trait SwapPoolInterface {
+    /// Swaps `amount_in` of `token_in`, already sent to the pool, for the
+    /// base token paid to `to`. Returns the amount paid out.
+    fn swap(env: Env, token_in: Address, amount_in: i128, to: Address) -> i128;
+}
+
 // ── Contract Error ──────────────────────────────────────────────────────────
 
 use soroban_sdk::contracterror;
@@ -154,886 +997,6212 @@ pub enum ContractError {
     RateLimitExceeded = 9,
     ContractPaused = 10,
     InvalidLimit = 11,
+    CampaignNotSuccessful = 12,
+    NothingToClaim = 13,
+    SelfReferral = 14,
+    RefundWindowOpen = 15,
+    RefundWindowClosed = 16,
+    BadgeAlreadyClaimed = 17,
+    NotAContributor = 18,
+    TokenNotAccepted = 19,
+    InvalidToken = 20,
+    StalePrice = 21,
+    NothingReceived = 22,
+    RefundExpired = 23,
+    ClaimPeriodActive = 24,
+    InvalidAmount = 25,
+    InvalidGoal = 26,
+    InvalidMinContribution = 27,
+    InvalidDeadline = 28,
+    InvalidDuration = 29,
+    CampaignPaused = 30,
+    GoalAlreadyReached = 31,
+    NotAllowlisted = 32,
+    InvalidProof = 33,
+    AddressBlocked = 34,
+    AlreadyContributed = 35,
+    NotEnoughContributors = 36,
+    GracePeriodActive = 37,
+    WithdrawalExceedsBalance = 38,
 }
 
-// ── Contract ────────────────────────────────────────────────────────────────
+// ── Helpers ─────────────────────────────────────────────────────────────────
 
-/// The main crowdfund contract implementation.
-#[contract]
-pub struct CrowdfundContract;
+/// Returns the stored campaign options, or the defaults if none were set.
+fn campaign_options(env: &Env) -> CampaignOptions {
+    env.storage()
+        .instance()
+        .get(&DataKey::Options)
+        .unwrap_or_default()
+}
 
-#[contractimpl]
-impl CrowdfundContract {
-    /// Initializes a new crowdfunding campaign.
-    ///
-    /// # Arguments
-    /// * `creator`            – The campaign creator's address.
-    /// * `token`              – The token contract address used for contributions.
-    /// * `goal`               – The funding goal (in the token's smallest unit).
-    /// * `hard_cap`           – Maximum total amount that can be raised (must be >= goal).
-    /// * `deadline`           – The campaign deadline as a ledger timestamp.
-    /// * `min_contribution`   – The minimum contribution amount.
-    /// * `platform_config`    – Optional platform configuration (address and fee in basis points).
-    ///
-    /// # Panics
-    /// * If already initialized.
-    /// * If platform fee exceeds 10,000 (100%).
-    #[allow(clippy::too_many_arguments)]
-    pub fn initialize(
-        env: Env,
-        creator: Address,
-        token: Address,
-        goal: i128,
-        _hard_cap: i128,
-        deadline: u64,
-        min_contribution: i128,
-        platform_config: Option<PlatformConfig>,
-    ) -> Result<(), ContractError> {
-        // Prevent re-initialization.
-        if env.storage().instance().has(&DataKey::Creator) {
-            return Err(ContractError::AlreadyInitialized);
-        }
+/// Returns the campaign deadline.
+fn campaign_deadline(env: &Env) -> Deadline {
+    env.storage().instance().get(&DataKey::Deadline).unwrap()
+}
 
-        creator.require_auth();
+/// Returns the deadline of a timestamp-based campaign.
+///
+/// # Panics
+/// * If the campaign has a sequence deadline.
+fn deadline_timestamp(env: &Env) -> u64 {
+    match campaign_deadline(env) {
+        Deadline::Timestamp(timestamp) => timestamp,
+        Deadline::Sequence(_) => panic!("deadline is not a timestamp"),
+    }
+}
 
-        // Validate platform fee if provided.
-        if let Some(ref config) = platform_config {
-            if config.fee_bps > 10_000 {
-                panic!("platform fee cannot exceed 100%");
-            }
-        }
+/// Returns the funding goal in tokens. For USD-denominated campaigns this is
+/// `usd_goal * price` from the oracle, so it moves with the price.
+///
+/// # Panics
+/// * If the oracle call fails or quotes a price of zero or less, or a
+///   SEP-40 feed has no fresh price.
+fn current_goal(env: &Env) -> i128 {
+    oracle_goal(env).unwrap_or_else(|_| panic!("oracle unavailable"))
+}
 
-        env.storage().instance().set(&DataKey::Creator, &creator);
-        env.storage().instance().set(&DataKey::Token, &token);
+/// Returns the funding goal in tokens, as `current_goal` does. With a
+/// `price_asset`, `price_oracle` is read as a SEP-40 feed quoting the token
+/// in `usd_goal`'s units, and the goal is `usd_goal / price`, rounded up.
+///
+/// # Errors
+/// * `StalePrice` if the SEP-40 feed has no price or its price is older
+///   than `max_price_staleness`.
+///
+/// # Panics
+/// * If the oracle call fails or quotes a price of zero or less.
+fn oracle_goal(env: &Env) -> Result<i128, ContractError> {
+    let options = campaign_options(env);
+    let (Some(usd_goal), Some(oracle)) = (options.usd_goal, options.price_oracle) else {
+        return Ok(env.storage().instance().get(&DataKey::Goal).unwrap());
+    };
+
+    let Some(asset) = options.price_asset else {
+        let token_address: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let price = match PriceOracleClient::new(env, &oracle).try_price(&token_address) {
+            Ok(Ok(price)) if price > 0 => price,
+            _ => panic!("oracle unavailable"),
+        };
+        return usd_goal.checked_mul(price).ok_or(ContractError::Overflow);
+    };
+
+    let feed = PriceFeedClient::new(env, &oracle);
+    let quote = feed
+        .lastprice(&Asset::Other(asset))
+        .ok_or(ContractError::StalePrice)?;
+    if env.ledger().timestamp() > quote.timestamp.saturating_add(options.max_price_staleness) {
+        return Err(ContractError::StalePrice);
+    }
+    if quote.price <= 0 {
+        panic!("oracle unavailable");
+    }
+    let scaled = usd_goal
+        .checked_mul(10i128.pow(feed.decimals()))
+        .ok_or(ContractError::Overflow)?;
+    Ok((scaled + quote.price - 1) / quote.price)
+}
 
-        env.storage().instance().set(&DataKey::Goal, &goal);
-        env.storage().instance().set(&DataKey::Deadline, &deadline);
-        env.storage()
-            .instance()
-            .set(&DataKey::MinContribution, &min_contribution);
-        env.storage().instance().set(&DataKey::TotalRaised, &0i128);
-        env.storage()
-            .instance()
-            .set(&DataKey::Status, &Status::Active);
-        env.storage().instance().set(&DataKey::Paused, &false);
+/// Returns whether an `auto_close_at_goal` campaign has reached its goal and
+/// closed.
+fn closed_at_goal(env: &Env) -> bool {
+    campaign_options(env).auto_close_at_goal
+        && env.storage().instance().has(&StatsKey::GoalReachedAt)
+}
 
-        let empty_contributors: Vec<Address> = Vec::new(&env);
-        env.storage()
-            .persistent()
-            .set(&DataKey::Contributors, &empty_contributors);
+/// Returns whether an `auto_close_on_cap` campaign has reached its hard cap
+/// and closed.
+fn closed_at_cap(env: &Env) -> bool {
+    if !campaign_options(env).auto_close_on_cap {
+        return false;
+    }
+    let total: i128 = env.storage().instance().get(&DataKey::TotalRaised).unwrap();
+    let hard_cap: i128 = env.storage().instance().get(&DataKey::HardCap).unwrap();
+    total >= hard_cap
+}
 
-        let empty_roadmap: Vec<RoadmapItem> = Vec::new(&env);
-        env.storage()
-            .instance()
-            .set(&DataKey::Roadmap, &empty_roadmap);
+/// Returns whether an `auto_close_on_cap` campaign turned Successful at its
+/// hard cap and the creator has not withdrawn yet.
+fn awaiting_payout(env: &Env) -> bool {
+    closed_at_cap(env) && CrowdfundContract::status(env.clone()) == Status::Successful
+}
 
-        let empty_reward_tiers: Vec<RewardTier> = Vec::new(&env);
-        env.storage()
-            .instance()
-            .set(&DataKey::RewardTiers, &empty_reward_tiers);
+/// Returns the most `total_raised` may reach: the hard cap, or for an
+/// `auto_close_at_goal` campaign the goal if lower. While a SEP-40 feed's
+/// price is stale the goal is unknown, and only the hard cap applies.
+fn contribution_ceiling(env: &Env, hard_cap: i128) -> i128 {
+    if !campaign_options(env).auto_close_at_goal {
+        return hard_cap;
+    }
+    oracle_goal(env).map_or(hard_cap, |goal| hard_cap.min(goal))
+}
 
-        Ok(())
+/// Returns whether the campaign has enough distinct contributors for
+/// `min_contributors`.
+fn enough_contributors(env: &Env) -> bool {
+    CrowdfundContract::contributor_count(env.clone()) >= campaign_options(env).min_contributors
+}
+
+/// Returns whether a campaign raising `total` succeeds: the goal is met and
+/// there are enough distinct contributors.
+fn campaign_succeeded(env: &Env, total: i128) -> Result<bool, ContractError> {
+    Ok(goal_reached(env, total)? && enough_contributors(env))
+}
+
+/// Returns `Ok` if a campaign raising `total` may pay out.
+///
+/// # Errors
+/// * `GoalNotReached` if `total` is below the goal.
+/// * `NotEnoughContributors` if the goal is met by too few contributors.
+fn ensure_succeeded(env: &Env, total: i128) -> Result<(), ContractError> {
+    if !goal_reached(env, total)? {
+        return Err(ContractError::GoalNotReached);
+    }
+    if !enough_contributors(env) {
+        return Err(ContractError::NotEnoughContributors);
     }
+    Ok(())
+}
 
-    /// Contribute tokens to the campaign.
-    ///
-    /// The contributor must authorize the call. Contributions are rejected
-    /// after the deadline has passed.
-    pub fn contribute(env: Env, contributor: Address, amount: i128, referral: Option<Address>) -> Result<(), ContractError> {
-        // ── Rate limiting: enforce cooldown between contributions ──
-        let now = env.ledger().timestamp();
-        let last_time_key = DataKey::LastContributionTime(contributor.clone());
-        if let Some(last_time) = env.storage().persistent().get::<_, u64>(&last_time_key) {
-            if now < last_time + CONTRIBUTION_COOLDOWN {
-                return Err(ContractError::RateLimitExceeded);
-            }
-        }
+/// Returns whether `total` tokens meet the goal.
+///
+/// # Errors
+/// * `StalePrice` if the goal is priced by a SEP-40 feed whose price is
+///   missing or stale.
+fn goal_reached(env: &Env, total: i128) -> Result<bool, ContractError> {
+    Ok(total >= oracle_goal(env)?)
+}
+
+/// Returns `amount * fee_bps / 10_000`, truncated.
+///
+/// # Errors
+/// * `Overflow` if `amount * fee_bps` does not fit in an `i128`.
+fn calculate_fee(amount: i128, fee_bps: u32) -> Result<i128, ContractError> {
+    // Checked arithmetic to prevent overflow.
+    Ok(amount
+        .checked_mul(fee_bps as i128)
+        .ok_or(ContractError::Overflow)?
+        / 10_000)
+}
+
+/// Splits a fee of `fee_bps` out of `amount` under `policy`.
+///
+/// `carried` is the fraction (in 1/10_000 stroops) carried forward from
+/// earlier fees; it only applies under `DustPolicy::CarryForward`. Returns
+/// `(fee, carry)`, where `carry` is the fraction to carry into the next fee.
+/// The payee's share is always `amount - fee`.
+///
+/// # Errors
+/// * `Overflow` if the exact fee does not fit in an `i128`.
+fn split_fee(
+    amount: i128,
+    fee_bps: u32,
+    policy: DustPolicy,
+    carried: i128,
+) -> Result<(i128, i128), ContractError> {
+    let exact = amount
+        .checked_mul(fee_bps as i128)
+        .ok_or(ContractError::Overflow)?;
+    Ok(match policy {
+        DustPolicy::Creator => (exact / 10_000, 0),
+        DustPolicy::Platform => (
+            exact.checked_add(9_999).ok_or(ContractError::Overflow)? / 10_000,
+            0,
+        ),
+        DustPolicy::CarryForward => {
+            let exact = exact.checked_add(carried).ok_or(ContractError::Overflow)?;
+            (exact / 10_000, exact % 10_000)
+        }
+    })
+}
+
+/// Returns the platform fee on `amount` of `token` at `fee_bps`, applying
+/// the platform's dust policy and updating any carried-forward dust.
+fn charge_platform_fee(
+    env: &Env,
+    config: &PlatformConfig,
+    token: &Address,
+    amount: i128,
+    fee_bps: u32,
+) -> Result<i128, ContractError> {
+    let dust_key = SettlementKey::FeeDust(token.clone());
+    let carried: i128 = env.storage().instance().get(&dust_key).unwrap_or(0);
+    let (fee, carry) = split_fee(amount, fee_bps, config.dust_policy, carried)?;
+    if config.dust_policy == DustPolicy::CarryForward {
+        env.storage().instance().set(&dust_key, &carry);
+    }
+    Ok(fee)
+}
+
+/// Moves the campaign to `new_status` and publishes a `StatusChangedEvent`.
+fn set_status(env: &Env, new_status: Status) {
+    let old_status: Status = env.storage().instance().get(&DataKey::Status).unwrap();
+    env.storage().instance().set(&DataKey::Status, &new_status);
+    env.events().publish(
+        ("campaign", "status_changed"),
+        StatusChangedEvent {
+            old_status,
+            new_status,
+        },
+    );
+}
+
+/// Records the total raised as the campaign closes, before settlement
+/// resets `TotalRaised`.
+fn record_raised_at_close(env: &Env) {
+    let total: i128 = env.storage().instance().get(&DataKey::TotalRaised).unwrap();
+    env.storage()
+        .instance()
+        .set(&SettlementKey::RaisedAtClose, &total);
+}
+
+/// Adds `amount` to `contributor`'s running total and tracks the address in
+/// the contributor list if it is new.
+fn record_contribution(
+    env: &Env,
+    contributor: &Address,
+    amount: i128,
+) -> Result<(), ContractError> {
+    // Update the contributor's running total with overflow protection.
+    let contribution_key = DataKey::Contribution(contributor.clone());
+    let prev: i128 = env
+        .storage()
+        .persistent()
+        .get(&contribution_key)
+        .unwrap_or(0);
+
+    let new_contribution = prev.checked_add(amount).ok_or(ContractError::Overflow)?;
+
+    env.storage()
+        .persistent()
+        .set(&contribution_key, &new_contribution);
+    env.storage()
+        .persistent()
+        .extend_ttl(&contribution_key, 100, 100);
+
+    // Track contributor address if new.
+    let mut contributors: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Contributors)
+        .unwrap();
+    if !contributors.contains(contributor) {
+        contributors.push_back(contributor.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKey::Contributors, &contributors);
+        env.storage()
+            .persistent()
+            .extend_ttl(&DataKey::Contributors, 100, 100);
+    }
+
+    update_leaderboard(env, contributor, prev, new_contribution);
+    mint_receipt(env, contributor, amount);
+
+    Ok(())
+}
+
+/// Moves `contributor` on the top-contributors leaderboard after their
+/// contribution changed from `prev` to `amount`. When a listed contributor
+/// drops, the board is rebuilt from the contributor list so that whoever
+/// now belongs in the top N takes their place.
+fn update_leaderboard(env: &Env, contributor: &Address, prev: i128, amount: i128) {
+    let mut board = top_contributors(env);
+    let listed = board
+        .iter()
+        .position(|(address, _)| address == *contributor);
+    if let Some(index) = listed {
+        if amount < prev {
+            rebuild_leaderboard(env);
+            return;
+        }
+        board.remove(index as u32);
+    }
+    insert_ranked(&mut board, contributor, amount);
+    env.storage()
+        .instance()
+        .set(&StatsKey::TopContributors, &board);
+}
+
+/// Recomputes the leaderboard from every current contribution.
+fn rebuild_leaderboard(env: &Env) {
+    let contributors: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Contributors)
+        .unwrap_or_else(|| Vec::new(env));
+    let mut board = Vec::new(env);
+    for contributor in contributors.iter() {
+        let amount: i128 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Contribution(contributor.clone()))
+            .unwrap_or(0);
+        insert_ranked(&mut board, &contributor, amount);
+    }
+    env.storage()
+        .instance()
+        .set(&StatsKey::TopContributors, &board);
+}
+
+/// Inserts `(contributor, amount)` into a board sorted largest first, behind
+/// any equal amounts, and trims it to `TOP_CONTRIBUTORS`. Zero amounts are
+/// not listed.
+fn insert_ranked(board: &mut Vec<(Address, i128)>, contributor: &Address, amount: i128) {
+    if amount <= 0 {
+        return;
+    }
+    let position = board
+        .iter()
+        .position(|(_, listed)| listed < amount)
+        .map_or(board.len(), |index| index as u32);
+    if position >= TOP_CONTRIBUTORS {
+        return;
+    }
+    board.insert(position, (contributor.clone(), amount));
+    if board.len() > TOP_CONTRIBUTORS {
+        board.pop_back();
+    }
+}
+
+/// Returns the current top-contributors leaderboard.
+fn top_contributors(env: &Env) -> Vec<(Address, i128)> {
+    env.storage()
+        .instance()
+        .get(&StatsKey::TopContributors)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Version 1 → 2: moves the legacy instance-storage contributions map into
+/// per-contributor persistent entries and the contributor list.
+fn migrate_contributions_map(env: &Env) {
+    let legacy: Option<Map<Address, i128>> =
+        env.storage().instance().get(&LegacyKey::Contributions);
+    let Some(legacy) = legacy else {
+        return;
+    };
+
+    let mut contributors: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Contributors)
+        .unwrap_or_else(|| Vec::new(env));
+    for (contributor, amount) in legacy.iter() {
+        let key = DataKey::Contribution(contributor.clone());
+        env.storage().persistent().set(&key, &amount);
+        env.storage().persistent().extend_ttl(&key, 100, 100);
+        if !contributors.contains(&contributor) {
+            contributors.push_back(contributor);
+        }
+    }
+    env.storage()
+        .persistent()
+        .set(&DataKey::Contributors, &contributors);
+    env.storage()
+        .persistent()
+        .extend_ttl(&DataKey::Contributors, 100, 100);
+
+    env.storage().instance().remove(&LegacyKey::Contributions);
+}
+
+/// Mints `amount` receipts to `to` if a receipt contract is configured.
+fn mint_receipt(env: &Env, to: &Address, amount: i128) {
+    if let Some(receipt) = campaign_options(env).receipt_contract {
+        ReceiptClient::new(env, &receipt).mint(to, &amount);
+    }
+}
+
+/// Tells the campaign hook, if any, that the campaign closed with `status`.
+/// Must run after `record_raised_at_close`.
+fn notify_hook_finalized(env: &Env, status: Status) {
+    let Some(hook) = campaign_options(env).hook else {
+        return;
+    };
+    let raised: i128 = env
+        .storage()
+        .instance()
+        .get(&SettlementKey::RaisedAtClose)
+        .unwrap_or(0);
+    if CampaignHookClient::new(env, &hook)
+        .try_on_finalize(&status, &raised)
+        .is_err()
+    {
+        env.events().publish(
+            ("campaign", "hook_failed"),
+            (hook, Symbol::new(env, "on_finalize")),
+        );
+    }
+}
+
+/// Tells the deployer the campaign succeeded, so a factory can keep
+/// platform-wide totals. Deployers that are not factories (or reject the
+/// report) are ignored; their state changes roll back and the payout
+/// proceeds.
+fn report_to_deployer(env: &Env) {
+    let deployer: Address = env
+        .storage()
+        .instance()
+        .get(&GovernanceKey::Deployer)
+        .unwrap();
+    let raised: i128 = env
+        .storage()
+        .instance()
+        .get(&SettlementKey::RaisedAtClose)
+        .unwrap_or(0);
+    let contributors: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Contributors)
+        .unwrap_or_else(|| Vec::new(env));
+    let _ = RegistryClient::new(env, &deployer).try_on_campaign_finalized(
+        &env.current_contract_address(),
+        &raised,
+        &(contributors.len() as u64),
+    );
+}
+
+/// Mints `amount / ratio` reward tokens to `to` if the creator has set a
+/// reward token. Amounts that round down to zero mint nothing. Under a
+/// vesting schedule the tokens are minted to the contract and locked for `to`.
+fn mint_reward(env: &Env, to: &Address, amount: i128) {
+    let reward: Option<(Address, i128)> = env.storage().instance().get(&RewardKey::RewardToken);
+    let Some((token_address, ratio)) = reward else {
+        return;
+    };
+    let minted = amount / ratio;
+    if minted <= 0 {
+        return;
+    }
+
+    let token_admin = token::StellarAssetClient::new(env, &token_address);
+    let key = if env.storage().instance().has(&RewardKey::Vesting) {
+        token_admin.mint(&env.current_contract_address(), &minted);
+        RewardKey::Locked(to.clone())
+    } else {
+        token_admin.mint(to, &minted);
+        RewardKey::Issued(to.clone())
+    };
+    let held: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+    env.storage().persistent().set(&key, &(held + minted));
+    env.storage().persistent().extend_ttl(&key, 100, 100);
+}
+
+/// Scales the reward tokens recorded against `contributor`'s contribution
+/// down as it shrinks from `prev` to `remaining`. Returns the `(issued,
+/// locked)` amounts removed: tokens the contributor holds, and tokens the
+/// contract holds for them under vesting.
+fn reduce_reward(
+    env: &Env,
+    contributor: &Address,
+    prev: i128,
+    remaining: i128,
+) -> Result<(i128, i128), ContractError> {
+    let mut removed = [0i128; 2];
+    let keys = [
+        RewardKey::Issued(contributor.clone()),
+        RewardKey::Locked(contributor.clone()),
+    ];
+    for (key, removed) in keys.iter().zip(removed.iter_mut()) {
+        let held: i128 = env.storage().persistent().get(key).unwrap_or(0);
+        if held == 0 {
+            continue;
+        }
+        let kept = held.checked_mul(remaining).ok_or(ContractError::Overflow)? / prev;
+        env.storage().persistent().set(key, &kept);
+        *removed = held - kept;
+    }
+    Ok((removed[0], removed[1]))
+}
+
+/// Moves the reward tokens tied to the part of `from`'s contribution that
+/// went to `to` (`prev` → `remaining`). Tokens `from` holds are transferred
+/// under their authorization; locked tokens only change owner.
+fn move_reward(
+    env: &Env,
+    from: &Address,
+    to: &Address,
+    prev: i128,
+    remaining: i128,
+) -> Result<(), ContractError> {
+    let (issued, locked) = reduce_reward(env, from, prev, remaining)?;
+    if issued > 0 {
+        let (token_address, _ratio): (Address, i128) = env
+            .storage()
+            .instance()
+            .get(&RewardKey::RewardToken)
+            .unwrap();
+        token::Client::new(env, &token_address).transfer(from, to, &issued);
+    }
+    for (key, amount) in [
+        (RewardKey::Issued(to.clone()), issued),
+        (RewardKey::Locked(to.clone()), locked),
+    ] {
+        if amount > 0 {
+            let held: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+            env.storage().persistent().set(&key, &(held + amount));
+            env.storage().persistent().extend_ttl(&key, 100, 100);
+        }
+    }
+    Ok(())
+}
+
+/// Burns the reward tokens tied to the refunded part of `contributor`'s
+/// contribution (`prev` → `remaining`). Locked tokens are burned from the
+/// contract. Tokens the contributor holds are clawed back, which needs the
+/// reward asset to have clawback enabled; a clawback that fails is reported
+/// with a `reward_clawback_failed` event rather than blocking the refund.
+fn burn_reward(
+    env: &Env,
+    contributor: &Address,
+    prev: i128,
+    remaining: i128,
+) -> Result<(), ContractError> {
+    let (issued, locked) = reduce_reward(env, contributor, prev, remaining)?;
+    if issued == 0 && locked == 0 {
+        return Ok(());
+    }
+
+    let (token_address, _ratio): (Address, i128) = env
+        .storage()
+        .instance()
+        .get(&RewardKey::RewardToken)
+        .unwrap();
+    if locked > 0 {
+        token::Client::new(env, &token_address).burn(&env.current_contract_address(), &locked);
+    }
+    if issued > 0
+        && token::StellarAssetClient::new(env, &token_address)
+            .try_clawback(contributor, &issued)
+            .is_err()
+    {
+        env.events().publish(
+            ("campaign", "reward_clawback_failed"),
+            (contributor.clone(), issued),
+        );
+    }
+    Ok(())
+}
+
+/// Starts the reward vesting clock when the campaign pays out, if vesting is
+/// configured.
+fn start_reward_vesting(env: &Env) {
+    if env.storage().instance().has(&RewardKey::Vesting) {
+        env.storage()
+            .instance()
+            .set(&RewardKey::VestingStart, &env.ledger().timestamp());
+    }
+}
+
+/// Returns how much of `contributor`'s locked reward has vested: nothing
+/// until the cliff has passed, then linearly over `vesting_seconds`.
+fn vested_reward(env: &Env, contributor: &Address) -> i128 {
+    let Some(start) = env
+        .storage()
+        .instance()
+        .get::<_, u64>(&RewardKey::VestingStart)
+    else {
+        return 0;
+    };
+    let (cliff, vesting): (u64, u64) = env.storage().instance().get(&RewardKey::Vesting).unwrap();
+    let locked: i128 = env
+        .storage()
+        .persistent()
+        .get(&RewardKey::Locked(contributor.clone()))
+        .unwrap_or(0);
+
+    let elapsed = env.ledger().timestamp().saturating_sub(start);
+    if elapsed <= cliff {
+        return 0;
+    }
+    let vested_time = (elapsed - cliff).min(vesting);
+    locked * vested_time as i128 / vesting as i128
+}
+
+/// Reports a fee just sent to the platform, for platforms that use a fee
+/// vault. Plain account addresses (or a vault that rejects the report) are
+/// ignored so the fee transfer itself always stands.
+fn record_platform_fee(env: &Env, platform: &Address, token: &Address, fee: i128) {
+    let _ = FeeVaultClient::new(env, platform).try_record_fee(
+        &env.current_contract_address(),
+        token,
+        &fee,
+    );
+}
+
+/// Tells the platform, when one is configured, that the campaign closed with
+/// `status` after taking `fee_paid` in the campaign token at close. Platforms
+/// that are plain accounts, or whose callback fails, are ignored. Must run
+/// after `record_raised_at_close`.
+fn notify_platform_finalized(env: &Env, status: Status, fee_paid: i128) {
+    let Some(config) = env
+        .storage()
+        .instance()
+        .get::<_, PlatformConfig>(&DataKey::PlatformConfig)
+    else {
+        return;
+    };
+    let raised: i128 = env
+        .storage()
+        .instance()
+        .get(&SettlementKey::RaisedAtClose)
+        .unwrap_or(0);
+    let _ = PlatformListenerClient::new(env, &config.address).try_campaign_finalized(
+        &env.current_contract_address(),
+        &status,
+        &raised,
+        &fee_paid,
+    );
+}
+
+/// Panics if `platform` keeps a token allowlist that excludes `token`.
+/// Platforms that are plain accounts, or contracts without an allowlist, do
+/// not restrict tokens.
+fn ensure_token_allowed(env: &Env, platform: &Address, token: &Address) {
+    if let Ok(Ok(false)) = PlatformRegistryClient::new(env, platform).try_is_token_allowed(token) {
+        panic!("token not allowed by platform");
+    }
+}
+
+/// Panics if `platform` keeps a global fee cap below `config`'s flat fee or
+/// any of its tiers. Platforms without a cap do not limit fees below 100%.
+fn ensure_fee_within_cap(env: &Env, config: &PlatformConfig) {
+    let Ok(Ok(cap_bps)) = PlatformRegistryClient::new(env, &config.address).try_fee_cap_bps()
+    else {
+        return;
+    };
+    let over_cap = config.fee_bps > cap_bps
+        || config
+            .tiered_fee
+            .as_ref()
+            .is_some_and(|tiers| tiers.iter().any(|(_, tier_bps)| tier_bps > cap_bps));
+    if over_cap {
+        panic!("platform fee exceeds global cap");
+    }
+}
+
+/// Returns the status of a roadmap milestone at the current ledger time.
+fn milestone_status(env: &Env, item: &RoadmapItem) -> MilestoneStatus {
+    if item.proof.is_some() {
+        MilestoneStatus::Complete
+    } else if env.ledger().timestamp() > item.deadline {
+        MilestoneStatus::Missed
+    } else {
+        MilestoneStatus::Pending
+    }
+}
+
+/// Adds `amount` to the profit contributors can claim pro rata.
+fn add_to_profit_pool(env: &Env, amount: i128) -> Result<(), ContractError> {
+    let distributed: i128 = env
+        .storage()
+        .instance()
+        .get(&RewardKey::ProfitDistributed)
+        .unwrap_or(0);
+    let distributed = distributed
+        .checked_add(amount)
+        .ok_or(ContractError::Overflow)?;
+    env.storage()
+        .instance()
+        .set(&RewardKey::ProfitDistributed, &distributed);
+    Ok(())
+}
+
+/// Returns true if `platform` waives its fee for `creator` or for this
+/// campaign. Platforms that are plain accounts, or contracts without a
+/// waiver list, waive nothing.
+fn fee_waived(env: &Env, platform: &Address, creator: &Address) -> bool {
+    let registry = PlatformRegistryClient::new(env, platform);
+    matches!(registry.try_is_fee_waived(creator), Ok(Ok(true)))
+        || matches!(
+            registry.try_is_campaign_fee_waived(&env.current_contract_address()),
+            Ok(Ok(true))
+        )
+}
+
+/// Burns `amount` receipts from `from` if a receipt contract is configured.
+fn burn_receipt(env: &Env, from: &Address, amount: i128) {
+    if let Some(receipt) = campaign_options(env).receipt_contract {
+        ReceiptClient::new(env, &receipt).burn(from, &amount);
+    }
+}
+
+/// Panics if the platform has suspended the campaign.
+fn ensure_not_suspended(env: &Env) {
+    let suspended: bool = env
+        .storage()
+        .instance()
+        .get(&DataKey::Suspended)
+        .unwrap_or(false);
+    if suspended {
+        panic!("campaign is suspended");
+    }
+}
+
+/// Returns whether the creator's share vests through `claim_vested`.
+fn payout_vests(env: &Env) -> bool {
+    campaign_options(env).vesting_duration_secs > 0
+}
+
+/// Rejects payout paths that would bypass the creator's payout vesting.
+fn ensure_payout_not_vesting(env: &Env) {
+    if payout_vests(env) {
+        panic!("creator payout vests; use claim_vested");
+    }
+}
+
+/// Rejects contributions while the platform or the creator has paused the
+/// campaign, or once an `auto_close_at_goal` or `auto_close_on_cap`
+/// campaign has closed.
+fn ensure_contributions_open(env: &Env) -> Result<(), ContractError> {
+    if CrowdfundContract::is_paused(env.clone())
+        || CrowdfundContract::is_creator_paused(env.clone())
+    {
+        return Err(ContractError::CampaignPaused);
+    }
+    if closed_at_cap(env) {
+        panic!("campaign is fully funded");
+    }
+    if closed_at_goal(env) {
+        return Err(ContractError::GoalAlreadyReached);
+    }
+    Ok(())
+}
+
+/// Sets the suspension flag after checking the platform's authorization.
+fn set_suspended(env: &Env, suspended: bool) {
+    let Some(config) = env
+        .storage()
+        .instance()
+        .get::<_, PlatformConfig>(&DataKey::PlatformConfig)
+    else {
+        panic!("no platform configured");
+    };
+    config.address.require_auth();
+
+    env.storage()
+        .instance()
+        .set(&DataKey::Suspended, &suspended);
+
+    let action = if suspended {
+        "suspended"
+    } else {
+        "unsuspended"
+    };
+    env.events().publish(("campaign", action), config.address);
+}
+
+/// Sets the platform pause flag after checking the platform's authorization.
+fn set_platform_paused(env: &Env, paused: bool) {
+    let Some(config) = env
+        .storage()
+        .instance()
+        .get::<_, PlatformConfig>(&DataKey::PlatformConfig)
+    else {
+        panic!("no platform configured");
+    };
+    config.address.require_auth();
+
+    env.storage()
+        .instance()
+        .set(&ComplianceKey::PlatformPaused, &paused);
+
+    let action = if paused {
+        "platform_paused"
+    } else {
+        "platform_unpaused"
+    };
+    env.events().publish(("campaign", action), config.address);
+}
+
+/// Maximum memo length, in bytes, for `contribute_with_memo`.
+const MAX_MEMO_LEN: u32 = 256;
+
+/// Bumps `contributor`'s contribution counter and returns the index of the
+/// contribution just made.
+fn next_contribution_index(env: &Env, contributor: &Address) -> u32 {
+    let count_key = DataKey::ContributionCount(contributor.clone());
+    let count: u32 = env.storage().persistent().get(&count_key).unwrap_or(0);
+    env.storage().persistent().set(&count_key, &(count + 1));
+    env.storage().persistent().extend_ttl(&count_key, 100, 100);
+    count
+}
+
+/// Rejects a contribution made within `CONTRIBUTION_COOLDOWN` seconds of the
+/// contributor's previous one.
+fn check_cooldown(env: &Env, contributor: &Address) -> Result<(), ContractError> {
+    let last_time_key = DataKey::LastContributionTime(contributor.clone());
+    if let Some(last_time) = env.storage().persistent().get::<_, u64>(&last_time_key) {
+        if env.ledger().timestamp() < last_time + CONTRIBUTION_COOLDOWN {
+            return Err(ContractError::RateLimitExceeded);
+        }
+    }
+    Ok(())
+}
+
+/// Adds `amount` to `contributor`'s current rate-limit window, if the
+/// campaign is rate limited.
+///
+/// # Panics
+/// * If the window total would exceed `rate_limit_amount`.
+fn apply_rate_limit(env: &Env, contributor: &Address, amount: i128) {
+    let options = campaign_options(env);
+    let (max_amount, window_seconds) =
+        (options.rate_limit_amount, options.rate_limit_window_seconds);
+    if max_amount == 0 {
+        return;
+    }
+
+    let now = env.ledger().timestamp();
+    let window_key = DataKey::RateWindow(contributor.clone());
+    let (window_start, window_total) = env
+        .storage()
+        .persistent()
+        .get::<_, (u64, i128)>(&window_key)
+        .filter(|(start, _)| now <= start.saturating_add(window_seconds))
+        .unwrap_or((now, 0));
+
+    let window_total = window_total.saturating_add(amount);
+    if window_total > max_amount {
+        panic!("rate limit exceeded");
+    }
+
+    env.storage()
+        .persistent()
+        .set(&window_key, &(window_start, window_total));
+    env.storage().persistent().extend_ttl(&window_key, 100, 100);
+}
+
+/// Rejects contributors who have not accepted the campaign terms, if the
+/// creator has published any.
+fn ensure_terms_accepted(env: &Env, contributor: &Address) {
+    if !env.storage().instance().has(&ComplianceKey::TermsHash) {
+        return;
+    }
+    let accepted = env
+        .storage()
+        .persistent()
+        .get(&ComplianceKey::TermsAccepted(contributor.clone()))
+        .unwrap_or(false);
+    if !accepted {
+        panic!("terms not accepted");
+    }
+}
+
+/// Rejects contributors the creator has blacklisted.
+fn ensure_not_blacklisted(env: &Env, contributor: &Address) -> Result<(), ContractError> {
+    if CrowdfundContract::is_blacklisted(env.clone(), contributor.clone()) {
+        return Err(ContractError::AddressBlocked);
+    }
+    Ok(())
+}
+
+/// Rejects a contributor with a contribution outstanding, if the campaign
+/// has `single_contribution`.
+fn ensure_first_contribution(env: &Env, contributor: &Address) -> Result<(), ContractError> {
+    if campaign_options(env).single_contribution
+        && CrowdfundContract::contribution(env.clone(), contributor.clone()) > 0
+    {
+        return Err(ContractError::AlreadyContributed);
+    }
+    Ok(())
+}
+
+/// Rejects contributors missing from the allowlist, if the campaign has
+/// `allowlist_enabled` or a Merkle allowlist root. Addresses covered only by
+/// the root must contribute with `contribute_with_proof`.
+fn ensure_allowlisted(env: &Env, contributor: &Address) -> Result<(), ContractError> {
+    let gated = campaign_options(env).allowlist_enabled
+        || env.storage().instance().has(&ComplianceKey::AllowlistRoot);
+    if gated && !CrowdfundContract::is_allowlisted(env.clone(), contributor.clone()) {
+        return Err(ContractError::NotAllowlisted);
+    }
+    Ok(())
+}
+
+/// Runs the per-address checks a new contribution credit must pass: the
+/// blacklist, `single_contribution`, the allowlist (skipped
+/// when `proven`), the campaign terms and the risk acknowledgment.
+fn ensure_eligible(env: &Env, contributor: &Address, proven: bool) -> Result<(), ContractError> {
+    ensure_not_blacklisted(env, contributor)?;
+    ensure_first_contribution(env, contributor)?;
+    if !proven {
+        ensure_allowlisted(env, contributor)?;
+    }
+    ensure_terms_accepted(env, contributor);
+    ensure_risk_acknowledged(env, contributor);
+    Ok(())
+}
+
+/// Returns whether `proof` links `contributor`'s leaf to `root`.
+///
+/// The leaf is the SHA-256 of the address's XDR encoding; each step hashes
+/// the running node with the next sibling, smaller of the two first.
+fn verify_allowlist_proof(
+    env: &Env,
+    contributor: &Address,
+    proof: &Vec<BytesN<32>>,
+    root: &BytesN<32>,
+) -> bool {
+    let mut node: BytesN<32> = env.crypto().sha256(&contributor.clone().to_xdr(env)).into();
+    for sibling in proof.iter() {
+        let (left, right) = if node <= sibling {
+            (node, sibling)
+        } else {
+            (sibling, node)
+        };
+        let mut pair = Bytes::from_array(env, &left.to_array());
+        pair.append(&Bytes::from_array(env, &right.to_array()));
+        node = env.crypto().sha256(&pair).into();
+    }
+    node == *root
+}
+
+/// Rejects contributors who have not acknowledged the campaign risks, if the
+/// creator requires it.
+fn ensure_risk_acknowledged(env: &Env, contributor: &Address) {
+    let required = env
+        .storage()
+        .instance()
+        .get(&ComplianceKey::RequiresAcknowledgment)
+        .unwrap_or(false);
+    if required
+        && !env
+            .storage()
+            .persistent()
+            .get(&ComplianceKey::RiskAcknowledged(contributor.clone()))
+            .unwrap_or(false)
+    {
+        panic!("risk not acknowledged");
+    }
+}
+
+/// Decodes a proposal value for `parameter`, or `None` if the parameter is
+/// not governable or the value has the wrong length.
+fn decode_proposal_value(env: &Env, parameter: &String, value: &Bytes) -> Option<i128> {
+    if *parameter == String::from_str(env, "deadline") {
+        let mut buf = [0u8; 8];
+        if value.len() != 8 {
+            return None;
+        }
+        value.copy_into_slice(&mut buf);
+        return Some(u64::from_be_bytes(buf) as i128);
+    }
+    if *parameter == String::from_str(env, "goal")
+        || *parameter == String::from_str(env, "min_contribution")
+    {
+        let mut buf = [0u8; 16];
+        if value.len() != 16 {
+            return None;
+        }
+        value.copy_into_slice(&mut buf);
+        return Some(i128::from_be_bytes(buf));
+    }
+    None
+}
+
+/// Checks the amounts a campaign runs on: a positive `goal` no higher than
+/// `hard_cap`, and a `min_contribution` between zero and the goal.
+fn validate_amounts(
+    goal: i128,
+    hard_cap: i128,
+    min_contribution: i128,
+) -> Result<(), ContractError> {
+    if goal <= 0 {
+        return Err(ContractError::InvalidGoal);
+    }
+    if min_contribution < 0 || min_contribution > goal {
+        return Err(ContractError::InvalidMinContribution);
+    }
+    if hard_cap < goal {
+        return Err(ContractError::InvalidHardCap);
+    }
+    Ok(())
+}
+
+/// Checks that a deadline `duration` seconds away is in the future and
+/// within `MIN_DURATION..=MAX_DURATION`.
+fn validate_duration(duration: u64) -> Result<(), ContractError> {
+    if duration == 0 {
+        return Err(ContractError::InvalidDeadline);
+    }
+    if !(MIN_DURATION..=MAX_DURATION).contains(&duration) {
+        return Err(ContractError::InvalidDuration);
+    }
+    Ok(())
+}
+
+/// Loads proposal `proposal_id`, panicking if it does not exist.
+fn load_proposal(env: &Env, proposal_id: u32) -> Proposal {
+    env.storage()
+        .persistent()
+        .get(&GovernanceKey::Proposal(proposal_id))
+        .unwrap_or_else(|| panic!("proposal not found"))
+}
+
+/// Stores `proposal` under `proposal_id`.
+fn save_proposal(env: &Env, proposal_id: u32, proposal: &Proposal) {
+    let key = GovernanceKey::Proposal(proposal_id);
+    env.storage().persistent().set(&key, proposal);
+    env.storage().persistent().extend_ttl(&key, 100, 100);
+}
+
+/// Deletes `contributor`'s recurring schedule and publishes why.
+fn cancel_recurring_schedule(env: &Env, contributor: &Address, reason: &str) {
+    let key = DataKey::Recurring(contributor.clone());
+    let Some(schedule) = env.storage().persistent().get::<_, RecurringSchedule>(&key) else {
+        return;
+    };
+    env.storage().persistent().remove(&key);
+
+    env.events().publish(
+        ("campaign", "recurring_cancelled"),
+        (
+            contributor.clone(),
+            schedule.remaining_intervals,
+            Symbol::new(env, reason),
+        ),
+    );
+}
+
+/// Records the contributor's latest contribution time for rate limiting.
+fn record_contribution_time(env: &Env, contributor: &Address) {
+    let last_time_key = DataKey::LastContributionTime(contributor.clone());
+    env.storage()
+        .persistent()
+        .set(&last_time_key, &env.ledger().timestamp());
+    env.storage()
+        .persistent()
+        .extend_ttl(&last_time_key, 100, 100);
+}
+
+/// Credits `amount`, already held by the contract, to `contributor`: updates
+/// their balance, reward tier and weighted credit, adds it (plus any sponsor
+/// match) to `total_raised` and emits the contribution events. `total` is the
+/// total raised before this contribution.
+fn credit_contribution(
+    env: &Env,
+    contributor: &Address,
+    amount: i128,
+    total: i128,
+    hard_cap: i128,
+) -> Result<(), ContractError> {
+    record_contribution(env, contributor, amount)?;
+    mint_reward(env, contributor, amount);
+    next_contribution_index(env, contributor);
+    let count: u32 = env
+        .storage()
+        .instance()
+        .get(&StatsKey::ContributionCount)
+        .unwrap_or(0);
+    env.storage()
+        .instance()
+        .set(&StatsKey::ContributionCount, &(count + 1));
+    claim_reward_tier(env, contributor);
+    record_weighted(env, contributor, amount)?;
+
+    // Update the global total raised with overflow protection.
+    let mut new_total = total.checked_add(amount).ok_or(ContractError::Overflow)?;
+
+    // Emit contribution event
+    env.events().publish(
+        ("campaign", "contributed"),
+        ContributionEvent {
+            contributor: contributor.clone(),
+            amount,
+            total_raised_after: new_total,
+        },
+    );
+
+    // Draw sponsor matching funds, if a pool is active.
+    new_total = new_total
+        .checked_add(draw_match(
+            env,
+            amount,
+            contribution_ceiling(env, hard_cap) - new_total,
+        )?)
+        .ok_or(ContractError::Overflow)?;
+
+    env.storage()
+        .instance()
+        .set(&DataKey::TotalRaised, &new_total);
+
+    if new_total >= hard_cap {
+        env.events()
+            .publish(("campaign", "hard_cap_reached"), hard_cap);
+        if campaign_options(env).auto_close_on_cap {
+            env.events()
+                .publish(("campaign", "closed_at_cap"), hard_cap);
+            set_status(env, Status::Successful);
+        }
+    }
+
+    record_goal_reached(env, contributor, new_total);
+
+    if let Some(hook) = campaign_options(env).hook {
+        let result =
+            CampaignHookClient::new(env, &hook).try_on_contribute(contributor, &amount, &new_total);
+        if result.is_err() {
+            env.events().publish(
+                ("campaign", "hook_failed"),
+                (hook, Symbol::new(env, "on_contribute")),
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Stores the ledger timestamp and emits `goal_reached` the first time
+/// `total` meets the goal. Later crossings, after withdrawals drop the total
+/// back under the goal, keep the first timestamp.
+fn record_goal_reached(env: &Env, contributor: &Address, total: i128) {
+    if env.storage().instance().has(&StatsKey::GoalReachedAt) {
+        return;
+    }
+    // A stale goal oracle must not block the contribution; the crossing is
+    // picked up by the next contribution made with a fresh price.
+    if !matches!(goal_reached(env, total), Ok(true)) {
+        return;
+    }
+    let now = env.ledger().timestamp();
+    env.storage().instance().set(&StatsKey::GoalReachedAt, &now);
+    env.events().publish(
+        (Symbol::new(env, "goal_reached"),),
+        GoalReachedEvent {
+            total_raised: total,
+            timestamp: now,
+            contributor: contributor.clone(),
+        },
+    );
+    if campaign_options(env).auto_close_at_goal {
+        env.events().publish(("campaign", "closed_at_goal"), total);
+    }
+}
+
+/// Matches `amount` from the sponsor pool, capped by what is left in the
+/// pool and by `headroom` under the hard cap. The match is credited to the
+/// sponsor as a contribution. Returns the matched amount.
+fn draw_match(env: &Env, amount: i128, headroom: i128) -> Result<i128, ContractError> {
+    let Some(mut pool) = env
+        .storage()
+        .instance()
+        .get::<_, MatchingPool>(&DataKey::MatchingPool)
+    else {
+        return Ok(0);
+    };
+
+    let matched = calculate_fee(amount, pool.match_bps)?
+        .min(pool.remaining)
+        .min(headroom);
+    if matched <= 0 {
+        return Ok(0);
+    }
+
+    record_contribution(env, &pool.sponsor, matched)?;
+    pool.remaining -= matched;
+    env.storage().instance().set(&DataKey::MatchingPool, &pool);
+
+    env.events()
+        .publish(("campaign", "matched"), (pool.sponsor, matched));
+
+    Ok(matched)
+}
+
+/// Returns whatever is left of the sponsor matching pool to the sponsor.
+fn release_match_pool(env: &Env) {
+    let Some(mut pool) = env
+        .storage()
+        .instance()
+        .get::<_, MatchingPool>(&DataKey::MatchingPool)
+    else {
+        return;
+    };
+    if pool.remaining <= 0 {
+        return;
+    }
+
+    let token_address: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+    let token_client = token::Client::new(env, &token_address);
+    token_client.transfer(
+        &env.current_contract_address(),
+        &pool.sponsor,
+        &pool.remaining,
+    );
+
+    env.events().publish(
+        ("campaign", "match_released"),
+        (pool.sponsor.clone(), pool.remaining),
+    );
+
+    pool.remaining = 0;
+    env.storage().instance().set(&DataKey::MatchingPool, &pool);
+}
+
+/// Credits `contributor` with `amount` weighted by the early-bird window the
+/// current ledger time falls in, if any.
+fn record_weighted(env: &Env, contributor: &Address, amount: i128) -> Result<(), ContractError> {
+    let now = env.ledger().timestamp();
+    let bonus_bps = campaign_options(env)
+        .early_bird_windows
+        .and_then(|windows| windows.iter().find(|w| now <= w.end_timestamp))
+        .map_or(0, |w| w.bonus_bps);
+
+    let weighted = amount
+        .checked_mul(10_000 + bonus_bps as i128)
+        .ok_or(ContractError::Overflow)?
+        / 10_000;
+
+    add_weighted(env, contributor, weighted)
+}
+
+/// Adds `weighted` to `contributor`'s weighted contribution and the total.
+fn add_weighted(env: &Env, contributor: &Address, weighted: i128) -> Result<(), ContractError> {
+    let weighted_key = DataKey::WeightedContribution(contributor.clone());
+    let prev: i128 = env.storage().persistent().get(&weighted_key).unwrap_or(0);
+    let new_weighted = prev.checked_add(weighted).ok_or(ContractError::Overflow)?;
+    env.storage().persistent().set(&weighted_key, &new_weighted);
+    env.storage()
+        .persistent()
+        .extend_ttl(&weighted_key, 100, 100);
+
+    let total: i128 = env
+        .storage()
+        .instance()
+        .get(&DataKey::TotalWeighted)
+        .unwrap_or(0);
+    let new_total = total.checked_add(weighted).ok_or(ContractError::Overflow)?;
+    env.storage()
+        .instance()
+        .set(&DataKey::TotalWeighted, &new_total);
+
+    Ok(())
+}
+
+/// Scales `contributor`'s weighted contribution down after they took back
+/// part of their raw contribution (`prev` → `remaining`). Returns the weight
+/// removed.
+fn reduce_weighted(
+    env: &Env,
+    contributor: &Address,
+    prev: i128,
+    remaining: i128,
+) -> Result<i128, ContractError> {
+    let weighted_key = DataKey::WeightedContribution(contributor.clone());
+    let weighted: i128 = env.storage().persistent().get(&weighted_key).unwrap_or(0);
+    if weighted == 0 {
+        return Ok(0);
+    }
+
+    let new_weighted = weighted
+        .checked_mul(remaining)
+        .ok_or(ContractError::Overflow)?
+        / prev;
+    env.storage().persistent().set(&weighted_key, &new_weighted);
+
+    let total: i128 = env
+        .storage()
+        .instance()
+        .get(&DataKey::TotalWeighted)
+        .unwrap_or(0);
+    env.storage().instance().set(
+        &DataKey::TotalWeighted,
+        &(total - (weighted - new_weighted)),
+    );
+
+    Ok(weighted - new_weighted)
+}
+
+/// Notes that `fee` of `referrer`'s earnings came from `contributor`'s
+/// contribution, so it can be taken back if that contribution is refunded.
+fn record_referral_fee(
+    env: &Env,
+    contributor: &Address,
+    referrer: &Address,
+    fee: i128,
+) -> Result<(), ContractError> {
+    let key = RewardKey::ReferralFees(contributor.clone());
+    let mut fees: Map<Address, i128> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| Map::new(env));
+    let recorded = fees.get(referrer.clone()).unwrap_or(0);
+    fees.set(
+        referrer.clone(),
+        recorded.checked_add(fee).ok_or(ContractError::Overflow)?,
+    );
+    env.storage().persistent().set(&key, &fees);
+    env.storage().persistent().extend_ttl(&key, 100, 100);
+    Ok(())
+}
+
+/// Scales the referral fees recorded against `contributor`'s contribution
+/// down as it shrinks from `prev` to `remaining`. Returns the fee removed
+/// for each referrer.
+fn reduce_referral_fees(
+    env: &Env,
+    contributor: &Address,
+    prev: i128,
+    remaining: i128,
+) -> Result<Map<Address, i128>, ContractError> {
+    let key = RewardKey::ReferralFees(contributor.clone());
+    let mut removed = Map::new(env);
+    let Some(fees) = env
+        .storage()
+        .persistent()
+        .get::<_, Map<Address, i128>>(&key)
+    else {
+        return Ok(removed);
+    };
+
+    let mut kept = Map::new(env);
+    for (referrer, fee) in fees.iter() {
+        let new_fee = fee.checked_mul(remaining).ok_or(ContractError::Overflow)? / prev;
+        if new_fee > 0 {
+            kept.set(referrer.clone(), new_fee);
+        }
+        removed.set(referrer, fee - new_fee);
+    }
+    if kept.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &kept);
+    }
+    Ok(removed)
+}
+
+/// Takes back the referral fees earned on the part of `contributor`'s
+/// contribution that was refunded (`prev` → `remaining`), from both the
+/// referrers' earnings and the total held back from the creator.
+fn claw_back_referral_fees(
+    env: &Env,
+    contributor: &Address,
+    prev: i128,
+    remaining: i128,
+) -> Result<(), ContractError> {
+    let mut clawed_back = 0i128;
+    for (referrer, fee) in reduce_referral_fees(env, contributor, prev, remaining)?.iter() {
+        let earnings_key = DataKey::ReferralEarnings(referrer);
+        let earned: i128 = env.storage().persistent().get(&earnings_key).unwrap_or(0);
+        env.storage()
+            .persistent()
+            .set(&earnings_key, &(earned - fee));
+        clawed_back += fee;
+    }
+    if clawed_back > 0 {
+        let total_fees: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::TotalReferralFees)
+            .unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&DataKey::TotalReferralFees, &(total_fees - clawed_back));
+    }
+    Ok(())
+}
+
+/// Claims the best reward tier `contributor` now qualifies for, if it is
+/// better than the one they already hold. Tiers that are sold out are
+/// skipped, so limited tiers go to contributors in first-come order. When a
+/// contributor moves up, their old tier's slot is released.
+fn claim_reward_tier(env: &Env, contributor: &Address) {
+    let mut tiers: Vec<RewardTier> = env
+        .storage()
+        .instance()
+        .get(&DataKey::RewardTiers)
+        .unwrap_or_else(|| Vec::new(env));
+    if tiers.is_empty() {
+        return;
+    }
+
+    let contribution: i128 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Contribution(contributor.clone()))
+        .unwrap_or(0);
+
+    let tier_key = DataKey::ContributorTier(contributor.clone());
+    let current: Option<u32> = env.storage().persistent().get(&tier_key);
+    let mut best_min = match current {
+        Some(index) => tiers.get(index).unwrap().min_amount,
+        None => 0,
+    };
+
+    let mut best: Option<u32> = None;
+    for (index, tier) in tiers.iter().enumerate() {
+        let sold_out = tier.max_claims.is_some_and(|max| tier.claimed >= max);
+        if contribution >= tier.min_amount && tier.min_amount > best_min && !sold_out {
+            best_min = tier.min_amount;
+            best = Some(index as u32);
+        }
+    }
+
+    let Some(new_index) = best else {
+        return;
+    };
+
+    if let Some(old_index) = current {
+        let mut old_tier = tiers.get(old_index).unwrap();
+        old_tier.claimed -= 1;
+        tiers.set(old_index, old_tier);
+    }
+    let mut new_tier = tiers.get(new_index).unwrap();
+    new_tier.claimed += 1;
+    tiers.set(new_index, new_tier);
+
+    env.storage().instance().set(&DataKey::RewardTiers, &tiers);
+    env.storage().persistent().set(&tier_key, &new_index);
+    env.storage().persistent().extend_ttl(&tier_key, 100, 100);
+
+    env.events().publish(
+        ("campaign", "tier_claimed"),
+        (contributor.clone(), new_index),
+    );
+}
+
+/// Re-evaluates `contributor`'s reward tier after their contribution went
+/// down: if it no longer covers the tier they hold, the tier is released and
+/// the best tier still available is claimed instead.
+fn downgrade_reward_tier(env: &Env, contributor: &Address) {
+    let Some(index) = env
+        .storage()
+        .persistent()
+        .get::<_, u32>(&DataKey::ContributorTier(contributor.clone()))
+    else {
+        return;
+    };
+
+    let tiers: Vec<RewardTier> = env.storage().instance().get(&DataKey::RewardTiers).unwrap();
+    let contribution: i128 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Contribution(contributor.clone()))
+        .unwrap_or(0);
+    if contribution >= tiers.get(index).unwrap().min_amount {
+        return;
+    }
+
+    release_reward_tier(env, contributor);
+    claim_reward_tier(env, contributor);
+}
+
+/// Drops `contributor` from the contributor list and releases their reward
+/// tier once their contribution is back to zero.
+fn remove_contributor(env: &Env, contributor: &Address) {
+    release_reward_tier(env, contributor);
+
+    let mut contributors: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Contributors)
+        .unwrap();
+    if let Some(index) = contributors.first_index_of(contributor) {
+        contributors.remove(index);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Contributors, &contributors);
+    }
+}
+
+/// Gives up `contributor`'s reward tier after they withdrew everything.
+fn release_reward_tier(env: &Env, contributor: &Address) {
+    let tier_key = DataKey::ContributorTier(contributor.clone());
+    let Some(index) = env.storage().persistent().get::<_, u32>(&tier_key) else {
+        return;
+    };
+
+    let mut tiers: Vec<RewardTier> = env.storage().instance().get(&DataKey::RewardTiers).unwrap();
+    let mut tier = tiers.get(index).unwrap();
+    tier.claimed -= 1;
+    tiers.set(index, tier);
+    env.storage().instance().set(&DataKey::RewardTiers, &tiers);
+    env.storage().persistent().remove(&tier_key);
+}
+
+/// If the campaign returns excess, schedules each contributor's pro-rata share
+/// of `total - goal` for `claim_excess` and returns what is left for the
+/// creator (the goal plus any rounding dust). Otherwise returns `total`.
+fn schedule_excess_returns(env: &Env, total: i128) -> Result<i128, ContractError> {
+    let goal = current_goal(env);
+    if !campaign_options(env).return_excess || total <= goal {
+        return Ok(total);
+    }
+
+    let excess = total - goal;
+    let contributors: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Contributors)
+        .unwrap();
+
+    let mut scheduled = 0i128;
+    for contributor in contributors.iter() {
+        let contribution: i128 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Contribution(contributor.clone()))
+            .unwrap_or(0);
+        let share = contribution
+            .checked_mul(excess)
+            .ok_or(ContractError::Overflow)?
+            / total;
+        if share > 0 {
+            let claim_key = DataKey::ExcessClaim(contributor);
+            env.storage().persistent().set(&claim_key, &share);
+            env.storage().persistent().extend_ttl(&claim_key, 100, 100);
+            scheduled += share;
+        }
+    }
+
+    Ok(total - scheduled)
+}
+
+/// Returns the registry entry for a non-base `token`, if it is accepted.
+fn accepted_token(env: &Env, token: &Address) -> Option<AcceptedToken> {
+    CrowdfundContract::accepted_tokens(env.clone())
+        .iter()
+        .find(|accepted| accepted.token == *token)
+}
+
+/// Returns the accepted tokens that are tallied rather than converted.
+fn tallied_tokens(env: &Env) -> Vec<Address> {
+    let mut tokens = Vec::new(env);
+    for accepted in CrowdfundContract::accepted_tokens(env.clone()).iter() {
+        if accepted.swap_pool.is_none() {
+            tokens.push_back(accepted.token);
+        }
+    }
+    tokens
+}
+
+/// Transfers `amount` of `token` from `from` to this contract and returns
+/// what actually arrived, measured as the change in the contract's balance.
+///
+/// Fee-on-transfer and deflationary tokens deliver less than `amount`, so
+/// callers must account with the returned value.
+fn receive_tokens(
+    env: &Env,
+    token: &Address,
+    from: &Address,
+    amount: i128,
+) -> Result<i128, ContractError> {
+    let token_client = token::Client::new(env, token);
+    let contract = env.current_contract_address();
+    let balance_before = token_client.balance(&contract);
+    token_client.transfer(from, &contract, &amount);
+    let received = token_client.balance(&contract) - balance_before;
+    if received <= 0 {
+        return Err(ContractError::NothingReceived);
+    }
+    Ok(received)
+}
+
+/// Takes `amount` of secondary `token` from `contributor` and adds it to the
+/// per-token tallies.
+fn record_token_contribution(
+    env: &Env,
+    contributor: &Address,
+    token: &Address,
+    amount: i128,
+) -> Result<(), ContractError> {
+    let amount = receive_tokens(env, token, contributor, amount)?;
+    next_contribution_index(env, contributor);
+
+    let contribution_key = DataKey::TokenContribution(contributor.clone(), token.clone());
+    let prev: i128 = env
+        .storage()
+        .persistent()
+        .get(&contribution_key)
+        .unwrap_or(0);
+    env.storage().persistent().set(
+        &contribution_key,
+        &prev.checked_add(amount).ok_or(ContractError::Overflow)?,
+    );
+    env.storage()
+        .persistent()
+        .extend_ttl(&contribution_key, 100, 100);
+
+    let total_key = DataKey::TokenTotal(token.clone());
+    let total: i128 = env.storage().instance().get(&total_key).unwrap_or(0);
+    env.storage().instance().set(
+        &total_key,
+        &total.checked_add(amount).ok_or(ContractError::Overflow)?,
+    );
+
+    let contributors_key = DataKey::TokenContributors(token.clone());
+    let mut contributors: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&contributors_key)
+        .unwrap_or_else(|| Vec::new(env));
+    if !contributors.contains(contributor) {
+        contributors.push_back(contributor.clone());
+        env.storage()
+            .persistent()
+            .set(&contributors_key, &contributors);
+        env.storage()
+            .persistent()
+            .extend_ttl(&contributors_key, 100, 100);
+    }
+
+    env.events().publish(
+        ("campaign", "token_contribution"),
+        (contributor.clone(), token.clone(), amount),
+    );
+
+    Ok(())
+}
+
+/// Pays each secondary token's tally to the creator, less the flat platform
+/// fee for that token.
+fn pay_out_secondary_tokens(
+    env: &Env,
+    creator: &Address,
+    destination: &Address,
+) -> Result<(), ContractError> {
+    let platform_config: Option<PlatformConfig> = env
+        .storage()
+        .instance()
+        .get(&DataKey::PlatformConfig)
+        .filter(|config: &PlatformConfig| !fee_waived(env, &config.address, creator));
+
+    for token_address in tallied_tokens(env).iter() {
+        let total_key = DataKey::TokenTotal(token_address.clone());
+        let total: i128 = env.storage().instance().get(&total_key).unwrap_or(0);
+        if total <= 0 {
+            continue;
+        }
+
+        let token_client = token::Client::new(env, &token_address);
+        let mut payout = total;
+        if let Some(ref config) = platform_config {
+            let fee = charge_platform_fee(env, config, &token_address, total, config.fee_bps)?;
+            if fee > 0 {
+                token_client.transfer(&env.current_contract_address(), &config.address, &fee);
+                record_platform_fee(env, &config.address, &token_address, fee);
+                payout -= fee;
+            }
+        }
+        token_client.transfer(&env.current_contract_address(), destination, &payout);
+        env.storage().instance().set(&total_key, &0i128);
+
+        env.events().publish(
+            ("campaign", "token_withdrawn"),
+            (token_address, payout, total - payout),
+        );
+    }
+
+    Ok(())
+}
+
+/// Returns every secondary-token contribution to its contributor.
+fn refund_secondary_tokens(env: &Env) {
+    for token_address in tallied_tokens(env).iter() {
+        let contributors: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::TokenContributors(token_address.clone()))
+            .unwrap_or_else(|| Vec::new(env));
+        let token_client = token::Client::new(env, &token_address);
+
+        for contributor in contributors.iter() {
+            let contribution_key =
+                DataKey::TokenContribution(contributor.clone(), token_address.clone());
+            let amount: i128 = env
+                .storage()
+                .persistent()
+                .get(&contribution_key)
+                .unwrap_or(0);
+            if amount > 0 {
+                token_client.transfer(&env.current_contract_address(), &contributor, &amount);
+                env.storage().persistent().set(&contribution_key, &0i128);
+            }
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::TokenTotal(token_address), &0i128);
+    }
+}
+
+/// Checks that the campaign can pay out and returns the total raised.
+fn withdrawable_total(env: &Env) -> Result<i128, ContractError> {
+    ensure_not_suspended(env);
+
+    let paused: bool = env
+        .storage()
+        .instance()
+        .get(&DataKey::Paused)
+        .unwrap_or(false);
+    if paused {
+        return Err(ContractError::ContractPaused);
+    }
+
+    let status: Status = env.storage().instance().get(&DataKey::Status).unwrap();
+    if status != Status::Active && !awaiting_payout(env) {
+        panic!("campaign is not active");
+    }
+
+    if !campaign_deadline(env).has_passed(env) {
+        if !closed_at_goal(env) && !awaiting_payout(env) {
+            return Err(ContractError::CampaignStillActive);
+        }
+        ensure_min_funding_elapsed(env);
+    }
+
+    let refund_window = campaign_options(env).refund_window_seconds;
+    if refund_window > 0
+        && env.ledger().timestamp() <= deadline_timestamp(env).saturating_add(refund_window)
+    {
+        return Err(ContractError::RefundWindowOpen);
+    }
+
+    let total: i128 = env.storage().instance().get(&DataKey::TotalRaised).unwrap();
+    ensure_succeeded(env, total)?;
+    if !CrowdfundContract::verify_accounting(env.clone()) {
+        panic!("accounting invariant violated");
+    }
+    Ok(total)
+}
+
+/// Returns `GracePeriodActive` until `grace_period_secs` have passed since
+/// the deadline.
+fn ensure_grace_period_over(env: &Env) -> Result<(), ContractError> {
+    let grace = campaign_options(env).grace_period_secs;
+    if grace > 0 && env.ledger().timestamp() <= deadline_timestamp(env).saturating_add(grace) {
+        return Err(ContractError::GracePeriodActive);
+    }
+    Ok(())
+}
+
+/// Panics unless `min_funding_duration_seconds` have passed since the
+/// campaign was initialized. Campaigns initialized before the creation time
+/// was recorded count as old enough.
+fn ensure_min_funding_elapsed(env: &Env) {
+    let created_at = CrowdfundContract::creation_timestamp(env.clone());
+    let min_duration = campaign_options(env).min_funding_duration_seconds;
+    if env.ledger().timestamp().saturating_sub(created_at) < min_duration {
+        panic!("minimum funding duration not elapsed");
+    }
+}
+
+/// Panics unless multi-sig withdraw is enabled and `signer` is one of the
+/// configured withdraw signers.
+fn ensure_withdraw_signer(env: &Env, signer: &Address) {
+    let options = campaign_options(env);
+    if options.required_withdraw_signatures == 0 {
+        panic!("multi-sig withdraw not enabled");
+    }
+    if !options
+        .withdraw_signers
+        .is_some_and(|signers| signers.contains(signer))
+    {
+        panic!("not a withdraw signer");
+    }
+}
+
+/// Stores `pending`, or pays out to the creator and clears it once it has
+/// the required number of signatures.
+fn settle_pending_withdraw(env: &Env, pending: PendingWithdraw) -> Result<(), ContractError> {
+    if pending.signatures.len() < campaign_options(env).required_withdraw_signatures {
+        env.storage()
+            .instance()
+            .set(&SettlementKey::PendingWithdraw, &pending);
+        return Ok(());
+    }
+
+    env.storage()
+        .instance()
+        .remove(&SettlementKey::PendingWithdraw);
+    let creator: Address = env.storage().instance().get(&DataKey::Creator).unwrap();
+    let total = withdrawable_total(env)?;
+    pay_out_creator(env, &creator, &creator, total)
+}
+
+/// Pays `total` out of the contract: the platform fee (unless it was already
+/// skimmed on contribute) goes to the platform, the rest to `destination` on
+/// the creator's behalf. Marks the campaign Successful. With `return_excess`,
+/// only the goal is paid out. Under payout vesting the share is held for
+/// `claim_vested` instead.
+fn pay_out_creator(
+    env: &Env,
+    creator: &Address,
+    destination: &Address,
+    total: i128,
+) -> Result<(), ContractError> {
+    let (creator_payout, platform_fee) = settle_success(env, creator, destination, total)?;
+
+    if payout_vests(env) {
+        env.storage()
+            .instance()
+            .set(&SettlementKey::Drawdown, &creator_payout);
+        env.events()
+            .publish(("campaign", "payout_vesting"), creator_payout);
+        return Ok(());
+    }
+
+    let token_address: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+    token::Client::new(env, &token_address).transfer(
+        &env.current_contract_address(),
+        destination,
+        &creator_payout,
+    );
+
+    // Emit withdrawal event
+    env.events().publish(
+        ("campaign", "withdrawn"),
+        WithdrawEvent {
+            creator: creator.clone(),
+            destination: destination.clone(),
+            amount: creator_payout,
+            platform_fee,
+        },
+    );
+
+    Ok(())
+}
+
+/// Closes a campaign that raised `total` as Successful: charges the platform
+/// fee, pays out secondary tokens to `destination` and runs the close-out
+/// notifications. Returns the creator's share of the primary token, still
+/// held by the contract, and the platform fee.
+fn settle_success(
+    env: &Env,
+    creator: &Address,
+    destination: &Address,
+    total: i128,
+) -> Result<(i128, i128), ContractError> {
+    let total = schedule_excess_returns(env, total)?;
+
+    let token_address: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+    let token_client = token::Client::new(env, &token_address);
+
+    // Calculate and transfer platform fee if configured.
+    // Fees already skimmed on contribute are not charged again.
+    let platform_config: Option<PlatformConfig> = env
+        .storage()
+        .instance()
+        .get(&DataKey::PlatformConfig)
+        .filter(|config: &PlatformConfig| config.fee_timing == FeeTiming::OnWithdraw)
+        .filter(|config: &PlatformConfig| !fee_waived(env, &config.address, creator));
+
+    let mut platform_fee = 0i128;
+    let creator_payout = if let Some(config) = platform_config {
+        let fee = charge_platform_fee(
+            env,
+            &config,
+            &token_address,
+            total,
+            config.fee_bps_for(total),
+        )?;
+
+        // Transfer fee to platform.
+        token_client.transfer(&env.current_contract_address(), &config.address, &fee);
+        record_platform_fee(env, &config.address, &token_address, fee);
+
+        // Emit event with fee details.
+        env.events()
+            .publish(("campaign", "fee_transferred"), (&config.address, fee));
+
+        // Calculate creator payout.
+        platform_fee = fee;
+        total.checked_sub(fee).ok_or(ContractError::Overflow)?
+    } else {
+        total
+    };
+
+    // Referral fees stay in the contract for referrers to claim.
+    let referral_fees: i128 = env
+        .storage()
+        .instance()
+        .get(&DataKey::TotalReferralFees)
+        .unwrap_or(0);
+    let creator_payout = creator_payout
+        .checked_sub(referral_fees)
+        .ok_or(ContractError::Overflow)?;
+
+    record_raised_at_close(env);
+    env.storage().instance().set(&DataKey::TotalRaised, &0i128);
+    if CrowdfundContract::status(env.clone()) != Status::Successful {
+        set_status(env, Status::Successful);
+    }
+    notify_hook_finalized(env, Status::Successful);
+    start_reward_vesting(env);
+    report_to_deployer(env);
+
+    // Unused matching funds go back to the sponsor.
+    release_match_pool(env);
+    pay_out_secondary_tokens(env, creator, destination)?;
+    notify_platform_finalized(env, Status::Successful, platform_fee);
+
+    Ok((creator_payout, platform_fee))
+}
+
+/// Swaps `amount` of `token` from `contributor` to the base token through
+/// `pool` and credits the proceeds like a normal contribution.
+///
+/// # Errors
+/// * `HardCapExceeded` if the converted amount does not fit under the cap.
+/// * `Overflow` if a running total would overflow.
+///
+/// # Panics
+/// * If the converted amount is below the minimum contribution.
+fn contribute_converted(
+    env: &Env,
+    contributor: &Address,
+    token: &Address,
+    pool: &Address,
+    amount: i128,
+) -> Result<(), ContractError> {
+    let base_address: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+    let base_client = token::Client::new(env, &base_address);
+    let balance_before = base_client.balance(&env.current_contract_address());
+
+    token::Client::new(env, token).transfer(contributor, pool, &amount);
+    SwapPoolClient::new(env, pool).swap(token, &amount, &env.current_contract_address());
+
+    let converted = base_client.balance(&env.current_contract_address()) - balance_before;
+    let min_contribution: i128 = env
+        .storage()
+        .instance()
+        .get(&DataKey::MinContribution)
+        .unwrap();
+    if converted < min_contribution {
+        panic!("converted amount below minimum");
+    }
+
+    let total: i128 = env.storage().instance().get(&DataKey::TotalRaised).unwrap();
+    let hard_cap: i128 = env.storage().instance().get(&DataKey::HardCap).unwrap();
+    if total
+        .checked_add(converted)
+        .ok_or(ContractError::Overflow)?
+        > contribution_ceiling(env, hard_cap)
+    {
+        return Err(ContractError::HardCapExceeded);
+    }
+
+    // In fee-on-contribute mode the platform's cut comes out of the
+    // converted amount.
+    let mut effective_amount = converted;
+    if let Some(config) = env
+        .storage()
+        .instance()
+        .get::<_, PlatformConfig>(&DataKey::PlatformConfig)
+    {
+        if config.fee_timing == FeeTiming::OnContribute {
+            let fee = charge_platform_fee(
+                env,
+                &config,
+                &base_address,
+                converted,
+                config.fee_bps_for(total),
+            )?;
+            if fee > 0 {
+                base_client.transfer(&env.current_contract_address(), &config.address, &fee);
+                record_platform_fee(env, &config.address, &base_address, fee);
+                env.events()
+                    .publish(("campaign", "fee_transferred"), (&config.address, fee));
+            }
+            effective_amount = converted - fee;
+        }
+    }
+
+    credit_contribution(env, contributor, effective_amount, total, hard_cap)?;
+
+    env.events().publish(
+        ("campaign", "token_contributed"),
+        (contributor.clone(), token, amount, converted),
+    );
+
+    Ok(())
+}
+
+/// Body of `contribute`. `proven` skips the allowlist check for a contributor
+/// whose Merkle proof `contribute_with_proof` has already verified.
+fn contribute_as(
+    env: Env,
+    contributor: Address,
+    amount: i128,
+    referral: Option<Address>,
+    token: Option<Address>,
+    proven: bool,
+) -> Result<(), ContractError> {
+    ensure_not_suspended(&env);
+
+    if amount <= 0 {
+        return Err(ContractError::InvalidAmount);
+    }
+
+    if referral.as_ref() == Some(&contributor) {
+        return Err(ContractError::SelfReferral);
+    }
+
+    check_cooldown(&env, &contributor)?;
+
+    let paused: bool = env
+        .storage()
+        .instance()
+        .get(&DataKey::Paused)
+        .unwrap_or(false);
+    if paused {
+        return Err(ContractError::ContractPaused);
+    }
+    ensure_contributions_open(&env)?;
+
+    contributor.require_auth();
+    ensure_eligible(&env, &contributor, proven)?;
+
+    let status: Status = env.storage().instance().get(&DataKey::Status).unwrap();
+    if status != Status::Active {
+        panic!("campaign is not active");
+    }
+
+    if campaign_deadline(&env).has_passed(&env) {
+        return Err(ContractError::CampaignEnded);
+    }
+
+    let token_address: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+    let token = token.filter(|token| *token != token_address);
+    let swap_pool = match &token {
+        Some(token) => match accepted_token(&env, token) {
+            Some(accepted) => accepted.swap_pool,
+            None => return Err(ContractError::TokenNotAccepted),
+        },
+        None => None,
+    };
+    if let (Some(token), Some(pool)) = (&token, swap_pool) {
+        contribute_converted(&env, &contributor, token, &pool, amount)?;
+        record_contribution_time(&env, &contributor);
+        return Ok(());
+    }
+
+    let min_contribution: i128 = env
+        .storage()
+        .instance()
+        .get(&DataKey::MinContribution)
+        .unwrap();
+    if amount < min_contribution {
+        panic!("amount below minimum");
+    }
+
+    if let Some(token) = token {
+        record_token_contribution(&env, &contributor, &token, amount)?;
+        record_contribution_time(&env, &contributor);
+        return Ok(());
+    }
+
+    let total: i128 = env.storage().instance().get(&DataKey::TotalRaised).unwrap();
+    let hard_cap: i128 = env.storage().instance().get(&DataKey::HardCap).unwrap();
+
+    if total >= hard_cap {
+        return Err(ContractError::HardCapExceeded);
+    }
+
+    let headroom = contribution_ceiling(&env, hard_cap) - total;
+    let gross_amount = if amount <= headroom { amount } else { headroom };
+    apply_rate_limit(&env, &contributor, gross_amount);
+
+    let token_client = token::Client::new(&env, &token_address);
+
+    // In fee-on-contribute mode the platform's cut goes straight to the
+    // platform and only the net amount is credited to the campaign.
+    let platform_config: Option<PlatformConfig> =
+        env.storage().instance().get(&DataKey::PlatformConfig);
+    let mut effective_amount = gross_amount;
+    if let Some(config) = platform_config {
+        if config.fee_timing == FeeTiming::OnContribute {
+            // Brackets follow the total raised before this contribution.
+            let fee = charge_platform_fee(
+                &env,
+                &config,
+                &token_address,
+                gross_amount,
+                config.fee_bps_for(total),
+            )?;
+            if fee > 0 {
+                token_client.transfer(&contributor, &config.address, &fee);
+                record_platform_fee(&env, &config.address, &token_address, fee);
+                env.events()
+                    .publish(("campaign", "fee_transferred"), (&config.address, fee));
+            }
+            effective_amount = gross_amount - fee;
+        }
+    }
+
+    // Transfer tokens from the contributor to this contract and credit
+    // only what arrived, in case the token charges a transfer fee.
+    let effective_amount = receive_tokens(&env, &token_address, &contributor, effective_amount)?;
+
+    credit_contribution(&env, &contributor, effective_amount, total, hard_cap)?;
+
+    // Update referral tally if referral provided
+    if let Some(referrer) = referral {
+        let referral_key = DataKey::ReferralTally(referrer.clone());
+        let current_tally: i128 = env.storage().persistent().get(&referral_key).unwrap_or(0);
+
+        let new_tally = current_tally
+            .checked_add(effective_amount)
+            .ok_or(ContractError::Overflow)?;
+
+        env.storage().persistent().set(&referral_key, &new_tally);
+        env.storage()
+            .persistent()
+            .extend_ttl(&referral_key, 100, 100);
+
+        // Accrue the referrer's fee, paid from the creator's portion.
+        let referral_fee =
+            calculate_fee(effective_amount, campaign_options(&env).referral_fee_bps)?;
+        if referral_fee > 0 {
+            let earnings_key = DataKey::ReferralEarnings(referrer.clone());
+            let earned: i128 = env.storage().persistent().get(&earnings_key).unwrap_or(0);
+            env.storage().persistent().set(
+                &earnings_key,
+                &earned
+                    .checked_add(referral_fee)
+                    .ok_or(ContractError::Overflow)?,
+            );
+            env.storage()
+                .persistent()
+                .extend_ttl(&earnings_key, 100, 100);
+
+            let total_fees: i128 = env
+                .storage()
+                .instance()
+                .get(&DataKey::TotalReferralFees)
+                .unwrap_or(0);
+            env.storage().instance().set(
+                &DataKey::TotalReferralFees,
+                &total_fees
+                    .checked_add(referral_fee)
+                    .ok_or(ContractError::Overflow)?,
+            );
+            record_referral_fee(&env, &contributor, &referrer, referral_fee)?;
+        }
+
+        // Emit referral event
+        env.events().publish(
+            ("campaign", "referral"),
+            (referrer, contributor.clone(), effective_amount),
+        );
+    }
+
+    record_contribution_time(&env, &contributor);
+
+    Ok(())
+}
+
+// ── Contract ────────────────────────────────────────────────────────────────
+
+/// The main crowdfund contract implementation.
+#[contract]
+pub struct CrowdfundContract;
+
+/// `initialize` sits in its own impl block so that the argument-count
+/// allowance it needs also covers the argument builder `#[contractimpl]`
+/// generates alongside it.
+mod initialize {
+    #![allow(clippy::too_many_arguments)]
+
+    use super::*;
+
+    #[contractimpl]
+    impl CrowdfundContract {
+        /// Initializes a new crowdfunding campaign — deployer-only.
+        ///
+        /// # Arguments
+        /// * `creator`            – The campaign creator's address.
+        /// * `token`              – The token contract address used for contributions.
+        /// * `goal`               – The funding goal (in the token's smallest unit).
+        /// * `hard_cap`           – Maximum total amount that can be raised (must be >= goal).
+        /// * `deadline`           – The campaign deadline, as a ledger timestamp or a
+        ///                          ledger sequence number.
+        /// * `min_contribution`   – The minimum contribution amount.
+        /// * `platform_config`    – Optional platform configuration (address, fee in basis points
+        ///                          and when the fee is collected).
+        /// * `admin`              – Optional address allowed to upgrade the contract.
+        ///                          Defaults to the creator when `None`.
+        /// * `options`            – Optional campaign settings; `None` uses the defaults.
+        ///
+        /// # Errors
+        /// * `InvalidGoal` if `goal` is not positive.
+        /// * `InvalidMinContribution` if `min_contribution` is negative or above
+        ///   `goal`.
+        /// * `InvalidDeadline` if `deadline` is not after the current ledger time
+        ///   (or sequence).
+        /// * `InvalidDuration` if `deadline` is less than `min_duration` or more
+        ///   than `max_duration` seconds away (both bounds inclusive). Sequence
+        ///   deadlines are converted at `LEDGER_SECONDS` per ledger.
+        /// * `InvalidHardCap` if `hard_cap` is below `goal`.
+        /// * `InvalidToken` if `token` does not answer `decimals` and `symbol`.
+        ///
+        /// # Panics
+        /// * If the deployer has not authorized the call.
+        /// * If already initialized.
+        /// * If platform fee exceeds 10,000 (100%).
+        /// * If the platform keeps a token allowlist that excludes `token`.
+        /// * If the platform fee exceeds the platform's global fee cap.
+        /// * If `deadline` is outside the platform's campaign duration bounds.
+        /// * If a sequence deadline is combined with a refund window.
+        pub fn initialize(
+            env: Env,
+            creator: Address,
+            token: Address,
+            goal: i128,
+            hard_cap: i128,
+            deadline: Deadline,
+            min_contribution: i128,
+            platform_config: Option<PlatformConfig>,
+            admin: Option<Address>,
+            options: Option<CampaignOptions>,
+        ) -> Result<(), ContractError> {
+            let deployer: Address = env
+                .storage()
+                .instance()
+                .get(&GovernanceKey::Deployer)
+                .unwrap();
+            deployer.require_auth();
+
+            // Prevent re-initialization.
+            if env.storage().instance().has(&DataKey::Creator) {
+                return Err(ContractError::AlreadyInitialized);
+            }
+
+            creator.require_auth();
+
+            validate_amounts(goal, hard_cap, min_contribution)?;
+            let duration = deadline.seconds_remaining(&env);
+            validate_duration(duration)?;
+
+            // Validate platform fee if provided.
+            if let Some(ref config) = platform_config {
+                if config.fee_bps > 10_000 {
+                    panic!("platform fee cannot exceed 100%");
+                }
+                if duration < config.min_campaign_duration_seconds {
+                    panic!("campaign duration too short");
+                }
+                if config.max_campaign_duration_seconds > 0
+                    && duration > config.max_campaign_duration_seconds
+                {
+                    panic!("campaign duration too long");
+                }
+                if let Some(ref tiers) = config.tiered_fee {
+                    let mut prev_bound: Option<i128> = None;
+                    for (upper_bound, tier_bps) in tiers.iter() {
+                        if tier_bps > 10_000 {
+                            panic!("platform fee cannot exceed 100%");
+                        }
+                        if prev_bound.is_some_and(|prev| upper_bound <= prev) {
+                            panic!("fee tiers must be in ascending order");
+                        }
+                        prev_bound = Some(upper_bound);
+                    }
+                }
+            }
+
+            // Make sure `token` actually implements the token interface (a SAC,
+            // including native XLM, or a custom token) before accepting it.
+            let token_client = token::Client::new(&env, &token);
+            let (Ok(Ok(decimals)), Ok(Ok(symbol))) =
+                (token_client.try_decimals(), token_client.try_symbol())
+            else {
+                return Err(ContractError::InvalidToken);
+            };
+            if let Some(ref config) = platform_config {
+                ensure_token_allowed(&env, &config.address, &token);
+                ensure_fee_within_cap(&env, config);
+            }
+
+            env.storage().instance().set(&DataKey::Creator, &creator);
+            env.storage().instance().set(&DataKey::Token, &token);
+            env.storage()
+                .instance()
+                .set(&DataKey::TokenMetadata, &TokenMetadata { decimals, symbol });
+
+            // The creator is implicitly the admin unless one was designated.
+            let admin = admin.unwrap_or_else(|| creator.clone());
+            env.storage().instance().set(&DataKey::Admin, &admin);
+            env.storage()
+                .instance()
+                .set(&GovernanceKey::StorageVersion, &CONTRACT_VERSION);
+
+            env.storage().instance().set(&DataKey::Goal, &goal);
+            env.storage().instance().set(&DataKey::HardCap, &hard_cap);
+            env.storage().instance().set(&DataKey::Deadline, &deadline);
+            env.storage()
+                .instance()
+                .set(&DataKey::MinContribution, &min_contribution);
+            env.storage().instance().set(&DataKey::TotalRaised, &0i128);
+            env.storage()
+                .instance()
+                .set(&DataKey::Status, &Status::Active);
+            env.storage().instance().set(&DataKey::Paused, &false);
+            env.storage()
+                .instance()
+                .set(&GovernanceKey::CreatedAt, &env.ledger().timestamp());
+
+            if let Some(config) = platform_config {
+                env.storage()
+                    .instance()
+                    .set(&DataKey::PlatformConfig, &config);
+            }
+
+            let options = options.unwrap_or_default();
+            if options.referral_fee_bps > 10_000 {
+                panic!("referral fee cannot exceed 100%");
+            }
+            if options.quorum_bps > 10_000 {
+                panic!("quorum cannot exceed 100%");
+            }
+            if options.required_withdraw_signatures > 0
+                && options
+                    .withdraw_signers
+                    .as_ref()
+                    .is_none_or(|signers| signers.len() < options.required_withdraw_signatures)
+            {
+                panic!("not enough withdraw signers");
+            }
+            if options.refund_window_seconds > 0 && matches!(deadline, Deadline::Sequence(_)) {
+                panic!("refund window requires a timestamp deadline");
+            }
+            if options.grace_period_secs > 0 && matches!(deadline, Deadline::Sequence(_)) {
+                panic!("grace period requires a timestamp deadline");
+            }
+            if options.vesting_cliff_secs > options.vesting_duration_secs {
+                panic!("vesting cliff exceeds duration");
+            }
+            if options.vesting_duration_secs > 0 && matches!(deadline, Deadline::Sequence(_)) {
+                panic!("payout vesting requires a timestamp deadline");
+            }
+            if options.usd_goal.is_some() != options.price_oracle.is_some() {
+                panic!("usd_goal and price_oracle must be set together");
+            }
+            if options.usd_goal.is_some_and(|usd_goal| usd_goal <= 0) {
+                panic!("usd_goal must be positive");
+            }
+            if options.price_asset.is_some() && options.usd_goal.is_none() {
+                panic!("price_asset needs usd_goal and price_oracle");
+            }
+            if options.auto_close_at_goal && options.min_contributors > 0 {
+                panic!("auto_close_at_goal cannot require min_contributors");
+            }
+            if options.auto_close_on_cap && options.min_contributors > 0 {
+                panic!("auto_close_on_cap cannot require min_contributors");
+            }
+            if options.auto_close_on_cap && options.refund_window_seconds > 0 {
+                panic!("auto_close_on_cap cannot have a refund window");
+            }
+            if options.rate_limit_amount < 0
+                || (options.rate_limit_amount > 0) != (options.rate_limit_window_seconds > 0)
+            {
+                panic!("invalid rate limit");
+            }
+            if let Some(ref windows) = options.early_bird_windows {
+                let mut prev_end = 0u64;
+                for window in windows.iter() {
+                    if window.end_timestamp <= prev_end {
+                        panic!("early-bird windows must be in ascending order");
+                    }
+                    prev_end = window.end_timestamp;
+                }
+            }
+            if let Some(ref approved) = options.approved_tokens {
+                let mut accepted = Vec::new(&env);
+                for token in approved.iter() {
+                    accepted.push_back(AcceptedToken {
+                        token,
+                        swap_pool: None,
+                    });
+                }
+                env.storage()
+                    .instance()
+                    .set(&DataKey::AcceptedTokens, &accepted);
+            }
+            env.storage().instance().set(&DataKey::Options, &options);
+
+            let empty_contributors: Vec<Address> = Vec::new(&env);
+            env.storage()
+                .persistent()
+                .set(&DataKey::Contributors, &empty_contributors);
+
+            let empty_roadmap: Vec<RoadmapItem> = Vec::new(&env);
+            env.storage()
+                .instance()
+                .set(&DataKey::Roadmap, &empty_roadmap);
+
+            let empty_reward_tiers: Vec<RewardTier> = Vec::new(&env);
+            env.storage()
+                .instance()
+                .set(&DataKey::RewardTiers, &empty_reward_tiers);
+
+            Ok(())
+        }
+    }
+}
+
+#[contractimpl]
+impl CrowdfundContract {
+    /// Runs once, atomically with deployment, and records who deployed the
+    /// contract.
+    ///
+    /// Only the deployer may then call `initialize`, so a deployed-but-not-yet
+    /// initialized contract cannot be claimed by someone else in between.
+    /// Deployers should still initialize in the same transaction where they
+    /// can (the factory does).
+    pub fn __constructor(env: Env, deployer: Address) {
+        env.storage()
+            .instance()
+            .set(&GovernanceKey::Deployer, &deployer);
+    }
+
+    /// Contribute tokens to the campaign.
+    ///
+    /// The contributor must authorize the call. Contributions are rejected
+    /// after the deadline has passed.
+    ///
+    /// If a `referral` address is given and the campaign has a
+    /// `referral_fee_bps`, that share of the contribution is owed to the
+    /// referrer out of the creator's payout. Self-referral is rejected.
+    ///
+    /// `token` selects the asset; `None` (or the primary token) is a normal
+    /// contribution. An accepted token with a swap pool is converted as for
+    /// `contribute_token`, without referral. One without a pool is only
+    /// tallied per token —
+    /// it does not count toward the goal or hard cap, and no referral,
+    /// matching, tier or receipt applies. It is paid to the creator (less the
+    /// flat platform fee) on withdraw and returned on refund or cancel.
+    ///
+    /// The amount credited is what the contract's balance actually grew by,
+    /// so fee-on-transfer tokens are accounted for correctly.
+    ///
+    /// # Errors
+    /// * `InvalidAmount` if `amount` is zero or negative.
+    /// * `Overflow` if a running total would overflow.
+    /// * `TokenNotAccepted` if `token` is neither primary nor accepted.
+    /// * `NothingReceived` if the transfer left the contract's balance unchanged.
+    /// * `CampaignPaused` while the platform or the creator has paused
+    ///   contributions.
+    /// * `NotAllowlisted` if the campaign has an allowlist without the
+    ///   contributor on it.
+    /// * `AddressBlocked` if the creator has blacklisted the contributor.
+    /// * `AlreadyContributed` if the campaign takes a single contribution
+    ///   per address and the contributor has one outstanding.
+    ///
+    /// # Panics
+    /// * If the contribution would exceed the campaign's rate limit for the
+    ///   contributor's current window.
+    /// * If the campaign has terms the contributor has not accepted.
+    /// * If the campaign requires a risk acknowledgment the contributor has
+    ///   not given.
+    /// * Once an `auto_close_on_cap` campaign has closed.
+    pub fn contribute(
+        env: Env,
+        contributor: Address,
+        amount: i128,
+        referral: Option<Address>,
+        token: Option<Address>,
+    ) -> Result<(), ContractError> {
+        contribute_as(env, contributor, amount, referral, token, false)
+    }
+
+    /// Contribute like `contribute`, proving membership of the creator's
+    /// Merkle allowlist with `proof`, the sibling hashes from the
+    /// contributor's leaf up to the root. No referral or secondary token
+    /// applies.
+    ///
+    /// # Errors
+    /// * `InvalidProof` if `proof` does not lead to the current root.
+    /// * As for `contribute`.
+    ///
+    /// # Panics
+    /// * If no allowlist root is set.
+    /// * As for `contribute`.
+    pub fn contribute_with_proof(
+        env: Env,
+        contributor: Address,
+        amount: i128,
+        proof: Vec<BytesN<32>>,
+    ) -> Result<(), ContractError> {
+        let Some(root) = Self::allowlist_root(env.clone()) else {
+            panic!("no allowlist root set");
+        };
+        if !verify_allowlist_proof(&env, &contributor, &proof, &root) {
+            return Err(ContractError::InvalidProof);
+        }
+        contribute_as(env, contributor, amount, None, None, true)
+    }
+
+    /// Contribute exactly like `contribute` and attach a public memo to the
+    /// contribution, readable with `contribution_memo`.
+    ///
+    /// # Panics
+    /// * If `memo` is longer than 256 bytes.
+    /// * Anything `contribute` panics on.
+    pub fn contribute_with_memo(
+        env: Env,
+        contributor: Address,
+        amount: i128,
+        memo: String,
+    ) -> Result<(), ContractError> {
+        if memo.len() > MAX_MEMO_LEN {
+            panic!("memo too long");
+        }
+
+        Self::contribute(env.clone(), contributor.clone(), amount, None, None)?;
+
+        let count: u32 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ContributionCount(contributor.clone()))
+            .unwrap();
+        let memo_key = DataKey::ContributionMemo(contributor.clone(), count - 1);
+        env.storage().persistent().set(&memo_key, &memo);
+        env.storage().persistent().extend_ttl(&memo_key, 100, 100);
+
+        env.events()
+            .publish(("campaign", "memo"), (contributor, count - 1, memo));
+
+        Ok(())
+    }
+
+    /// Contribute in an accepted non-base token; the same as `contribute`
+    /// with `token` and no referral.
+    ///
+    /// A token registered with a swap pool is sent to the pool, and the
+    /// base-token proceeds received by the contract are credited like a normal
+    /// contribution. The credited amount is measured from the contract's
+    /// balance, not the pool's return value.
+    ///
+    /// # Errors
+    /// * `TokenNotAccepted` if `token` was not registered.
+    /// * `HardCapExceeded` if the converted amount does not fit under the cap.
+    /// * As for `contribute`.
+    ///
+    /// # Panics
+    /// * If the converted amount is below the minimum contribution.
+    /// * As for `contribute`.
+    pub fn contribute_token(
+        env: Env,
+        contributor: Address,
+        token: Address,
+        amount: i128,
+    ) -> Result<(), ContractError> {
+        contribute_as(env, contributor, amount, None, Some(token), false)
+    }
+
+    /// Register a recurring contribution of `amount_per_interval`, pulled
+    /// `total_intervals` times, `interval_seconds` apart, starting one
+    /// interval from now.
+    ///
+    /// The contributor approves the contract to spend the whole schedule up
+    /// front; `process_recurring` then makes each pull. Replaces any existing
+    /// schedule for the contributor.
+    ///
+    /// # Errors
+    /// * `InvalidAmount` if `amount_per_interval` is zero or negative.
+    /// * `Overflow` if the total allowance would overflow.
+    ///
+    /// # Panics
+    /// * If the campaign is not Active or `amount_per_interval` is below the
+    ///   minimum contribution.
+    /// * If `interval_seconds` or `total_intervals` is zero.
+    /// * If the campaign takes a single contribution per address.
+    pub fn schedule_recurring(
+        env: Env,
+        contributor: Address,
+        amount_per_interval: i128,
+        interval_seconds: u64,
+        total_intervals: u32,
+    ) -> Result<(), ContractError> {
+        ensure_not_suspended(&env);
+        contributor.require_auth();
+        if campaign_options(&env).single_contribution {
+            panic!("recurring contributions not allowed");
+        }
+        ensure_not_blacklisted(&env, &contributor)?;
+        ensure_allowlisted(&env, &contributor)?;
+        ensure_terms_accepted(&env, &contributor);
+        ensure_risk_acknowledged(&env, &contributor);
+
+        if amount_per_interval <= 0 {
+            return Err(ContractError::InvalidAmount);
+        }
+        let status: Status = env.storage().instance().get(&DataKey::Status).unwrap();
+        if status != Status::Active {
+            panic!("campaign is not active");
+        }
+        let min_contribution: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::MinContribution)
+            .unwrap();
+        if amount_per_interval < min_contribution {
+            panic!("amount below minimum");
+        }
+        if interval_seconds == 0 || total_intervals == 0 {
+            panic!("invalid schedule");
+        }
+
+        let allowance = amount_per_interval
+            .checked_mul(total_intervals as i128)
+            .ok_or(ContractError::Overflow)?;
+        let token_address: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        token::Client::new(&env, &token_address).approve(
+            &contributor,
+            &env.current_contract_address(),
+            &allowance,
+            // The token keeps the allowance live one ledger past expiry.
+            &(env.ledger().max_live_until_ledger() - 1),
+        );
+
+        let key = DataKey::Recurring(contributor.clone());
+        env.storage().persistent().set(
+            &key,
+            &RecurringSchedule {
+                amount_per_interval,
+                interval_seconds,
+                remaining_intervals: total_intervals,
+                next_due: env.ledger().timestamp().saturating_add(interval_seconds),
+            },
+        );
+        env.storage().persistent().extend_ttl(&key, 100, 100);
+
+        env.events().publish(
+            ("campaign", "recurring_scheduled"),
+            (
+                contributor,
+                amount_per_interval,
+                interval_seconds,
+                total_intervals,
+            ),
+        );
+
+        Ok(())
+    }
+
+    /// Make `contributor`'s next recurring pull if it is due — callable by
+    /// anyone. Returns whether a contribution was made.
+    ///
+    /// The schedule is cancelled instead if the contributor can no longer
+    /// pay (insufficient balance or allowance), the campaign has ended or is
+    /// full. Pulls are credited like `contribute`, without referral.
+    ///
+    /// # Errors
+    /// * `ContractPaused` while the campaign is paused.
+    /// * `CampaignPaused` while the platform or the creator has paused
+    ///   contributions.
+    /// * `NotAllowlisted` if the campaign has an allowlist without the
+    ///   contributor on it.
+    /// * `AddressBlocked` if the creator has blacklisted the contributor.
+    pub fn process_recurring(env: Env, contributor: Address) -> Result<bool, ContractError> {
+        ensure_not_suspended(&env);
+
+        let paused: bool = env
+            .storage()
+            .instance()
+            .get(&DataKey::Paused)
+            .unwrap_or(false);
+        if paused {
+            return Err(ContractError::ContractPaused);
+        }
+        ensure_contributions_open(&env)?;
+
+        ensure_not_blacklisted(&env, &contributor)?;
+        ensure_allowlisted(&env, &contributor)?;
+
+        let key = DataKey::Recurring(contributor.clone());
+        let Some(mut schedule) = env.storage().persistent().get::<_, RecurringSchedule>(&key)
+        else {
+            return Ok(false);
+        };
+        let now = env.ledger().timestamp();
+        if now < schedule.next_due {
+            return Ok(false);
+        }
+
+        let status: Status = env.storage().instance().get(&DataKey::Status).unwrap();
+        if status != Status::Active || campaign_deadline(&env).has_passed(&env) {
+            cancel_recurring_schedule(&env, &contributor, "campaign_ended");
+            return Ok(false);
+        }
+
+        let total: i128 = env.storage().instance().get(&DataKey::TotalRaised).unwrap();
+        let hard_cap: i128 = env.storage().instance().get(&DataKey::HardCap).unwrap();
+        let amount = schedule
+            .amount_per_interval
+            .min(contribution_ceiling(&env, hard_cap) - total);
+        if amount <= 0 {
+            cancel_recurring_schedule(&env, &contributor, "hard_cap_reached");
+            return Ok(false);
+        }
+
+        // Pull through the allowance, measuring what actually arrived.
+        let token_address: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let token_client = token::Client::new(&env, &token_address);
+        let contract = env.current_contract_address();
+        let balance_before = token_client.balance(&contract);
+        if token_client
+            .try_transfer_from(&contract, &contributor, &contract, &amount)
+            .is_err()
+        {
+            cancel_recurring_schedule(&env, &contributor, "insufficient_funds");
+            return Ok(false);
+        }
+        let received = token_client.balance(&contract) - balance_before;
+        if received <= 0 {
+            return Err(ContractError::NothingReceived);
+        }
+
+        // In fee-on-contribute mode the platform's cut leaves straight away.
+        let mut effective_amount = received;
+        if let Some(config) = env
+            .storage()
+            .instance()
+            .get::<_, PlatformConfig>(&DataKey::PlatformConfig)
+        {
+            if config.fee_timing == FeeTiming::OnContribute {
+                let fee = charge_platform_fee(
+                    &env,
+                    &config,
+                    &token_address,
+                    received,
+                    config.fee_bps_for(total),
+                )?;
+                if fee > 0 {
+                    token_client.transfer(&contract, &config.address, &fee);
+                    record_platform_fee(&env, &config.address, &token_address, fee);
+                    env.events()
+                        .publish(("campaign", "fee_transferred"), (&config.address, fee));
+                }
+                effective_amount = received - fee;
+            }
+        }
+
+        credit_contribution(&env, &contributor, effective_amount, total, hard_cap)?;
+        record_contribution_time(&env, &contributor);
+
+        schedule.remaining_intervals -= 1;
+        if schedule.remaining_intervals == 0 {
+            env.storage().persistent().remove(&key);
+            env.events()
+                .publish(("campaign", "recurring_completed"), contributor.clone());
+        } else {
+            schedule.next_due = schedule.next_due.saturating_add(schedule.interval_seconds);
+            env.storage().persistent().set(&key, &schedule);
+            env.storage().persistent().extend_ttl(&key, 100, 100);
+        }
+
+        Ok(true)
+    }
+
+    /// Cancel the caller's recurring contribution schedule. Contributions
+    /// already made are unaffected.
+    pub fn cancel_recurring(env: Env, contributor: Address) {
+        contributor.require_auth();
+        cancel_recurring_schedule(&env, &contributor, "cancelled");
+    }
+
+    /// Returns how many pulls are left on `contributor`'s schedule.
+    pub fn remaining_intervals(env: Env, contributor: Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get::<_, RecurringSchedule>(&DataKey::Recurring(contributor))
+            .map_or(0, |schedule| schedule.remaining_intervals)
+    }
+
+    /// Returns `contributor`'s recurring schedule, if any.
+    pub fn recurring_schedule(env: Env, contributor: Address) -> Option<RecurringSchedule> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Recurring(contributor))
+    }
+
+    /// Replace the non-base tokens the campaign accepts — creator-only.
+    ///
+    /// # Panics
+    /// * If a tallied token that still holds contributions would be
+    ///   dropped.
+    pub fn set_accepted_tokens(env: Env, tokens: Vec<AcceptedToken>) {
+        let creator: Address = env.storage().instance().get(&DataKey::Creator).unwrap();
+        creator.require_auth();
+
+        for token in tallied_tokens(&env).iter() {
+            let kept = tokens
+                .iter()
+                .any(|accepted| accepted.token == token && accepted.swap_pool.is_none());
+            if !kept && Self::token_total(env.clone(), token) > 0 {
+                panic!("token still holds contributions");
+            }
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::AcceptedTokens, &tokens);
+
+        env.events()
+            .publish(("campaign", "accepted_tokens_set"), tokens.len());
+    }
+
+    /// Returns the non-base tokens the campaign accepts.
+    pub fn accepted_tokens(env: Env) -> Vec<AcceptedToken> {
+        env.storage()
+            .instance()
+            .get(&DataKey::AcceptedTokens)
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    /// Withdraw part (or all) of a contribution while the campaign is still
+    /// running.
+    ///
+    /// The remaining balance must either stay at or above the minimum
+    /// contribution or be exactly zero (a full opt-out, which also removes
+    /// the address from the contributor list). Referral fees earned on the
+    /// refunded part are taken back from the referrers, and its reward
+    /// tokens are burned.
+    ///
+    /// # Errors
+    /// * `CampaignEnded` if the deadline has passed.
+    /// * `GoalAlreadyReached` once an `auto_close_at_goal` campaign has closed.
+    ///
+    /// # Panics
+    /// * If the campaign is not Active.
+    /// * If `amount` is not positive or exceeds the contribution.
+    /// * If the remaining balance would be below the minimum but not zero.
+    /// * Once an `auto_close_on_cap` campaign has closed.
+    pub fn partial_refund(
+        env: Env,
+        contributor: Address,
+        amount: i128,
+    ) -> Result<(), ContractError> {
+        contributor.require_auth();
+
+        if closed_at_cap(&env) {
+            panic!("campaign is fully funded");
+        }
+        let status: Status = env.storage().instance().get(&DataKey::Status).unwrap();
+        if status != Status::Active {
+            panic!("campaign is not active");
+        }
+
+        if campaign_deadline(&env).has_passed(&env) {
+            return Err(ContractError::CampaignEnded);
+        }
+        if closed_at_goal(&env) {
+            return Err(ContractError::GoalAlreadyReached);
+        }
+
+        let contribution_key = DataKey::Contribution(contributor.clone());
+        let prev: i128 = env
+            .storage()
+            .persistent()
+            .get(&contribution_key)
+            .unwrap_or(0);
+        if amount <= 0 || amount > prev {
+            panic!("invalid refund amount");
+        }
+
+        let remaining = prev - amount;
+        let min_contribution: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::MinContribution)
+            .unwrap();
+        if remaining != 0 && remaining < min_contribution {
+            panic!("remaining contribution below minimum");
+        }
+
+        env.storage()
+            .persistent()
+            .set(&contribution_key, &remaining);
+        env.storage()
+            .persistent()
+            .extend_ttl(&contribution_key, 100, 100);
+
+        let total: i128 = env.storage().instance().get(&DataKey::TotalRaised).unwrap();
+        env.storage()
+            .instance()
+            .set(&DataKey::TotalRaised, &(total - amount));
+
+        reduce_weighted(&env, &contributor, prev, remaining)?;
+        claw_back_referral_fees(&env, &contributor, prev, remaining)?;
+        burn_reward(&env, &contributor, prev, remaining)?;
+
+        if remaining == 0 {
+            remove_contributor(&env, &contributor);
+        }
+        update_leaderboard(&env, &contributor, prev, remaining);
+
+        let token_address: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let token_client = token::Client::new(&env, &token_address);
+        token_client.transfer(&env.current_contract_address(), &contributor, &amount);
+        burn_receipt(&env, &contributor, amount);
+
+        env.events()
+            .publish(("campaign", "partial_refund"), (contributor, amount));
+
+        Ok(())
+    }
+
+    /// Transfer part or all of a contribution credit to another address.
+    ///
+    /// Only the internal ledger changes — the tokens stay in the contract.
+    /// Weighted credit, receipts, reward tokens and the referral fees accrued
+    /// on the amount move along with it, and both addresses have their reward
+    /// tier re-evaluated. Reward tokens `from` holds are transferred to `to`,
+    /// so `from` must still hold them.
+    ///
+    /// `to` must be eligible to contribute the amount directly: it goes
+    /// through the same blacklist, `single_contribution`, allowlist, terms,
+    /// risk and rate-limit checks as `contribute`. An
+    /// address covered only by the Merkle allowlist cannot receive credit.
+    ///
+    /// # Errors
+    /// * `CampaignEnded` if the deadline has passed.
+    /// * `AddressBlocked`, `AlreadyContributed` or `NotAllowlisted` if `to`
+    ///   fails the matching contribution check.
+    ///
+    /// # Panics
+    /// * If the campaign is not Active.
+    /// * If `from` and `to` are the same address.
+    /// * If `amount` is not positive or exceeds `from`'s contribution.
+    /// * If `to` has not accepted the terms or acknowledged the risks, or
+    ///   would exceed its rate limit.
+    pub fn transfer_contribution(
+        env: Env,
+        from: Address,
+        to: Address,
+        amount: i128,
+    ) -> Result<(), ContractError> {
+        from.require_auth();
+
+        let status: Status = env.storage().instance().get(&DataKey::Status).unwrap();
+        if status != Status::Active {
+            panic!("campaign is not active");
+        }
+        if from == to {
+            panic!("cannot transfer to self");
+        }
+        if campaign_deadline(&env).has_passed(&env) {
+            return Err(ContractError::CampaignEnded);
+        }
+        ensure_eligible(&env, &to, false)?;
+
+        let from_key = DataKey::Contribution(from.clone());
+        let prev: i128 = env.storage().persistent().get(&from_key).unwrap_or(0);
+        if amount <= 0 || amount > prev {
+            panic!("invalid transfer amount");
+        }
+        apply_rate_limit(&env, &to, amount);
+
+        let remaining = prev - amount;
+        env.storage().persistent().set(&from_key, &remaining);
+        env.storage().persistent().extend_ttl(&from_key, 100, 100);
+
+        let moved_weight = reduce_weighted(&env, &from, prev, remaining)?;
+        let moved_fees = reduce_referral_fees(&env, &from, prev, remaining)?;
+        move_reward(&env, &from, &to, prev, remaining)?;
+        burn_receipt(&env, &from, amount);
+
+        if remaining == 0 {
+            remove_contributor(&env, &from);
+        } else {
+            downgrade_reward_tier(&env, &from);
+        }
+        update_leaderboard(&env, &from, prev, remaining);
+
+        record_contribution(&env, &to, amount)?;
+        add_weighted(&env, &to, moved_weight)?;
+        for (referrer, fee) in moved_fees.iter() {
+            if fee > 0 {
+                record_referral_fee(&env, &to, &referrer, fee)?;
+            }
+        }
+        claim_reward_tier(&env, &to);
+
+        env.events()
+            .publish(("campaign", "contribution_transferred"), (from, to, amount));
+
+        Ok(())
+    }
+
+    /// Reclaim a full contribution during the post-deadline refund window.
+    ///
+    /// Available only when the campaign was initialized with a non-zero
+    /// `refund_window_seconds`, and only in `(deadline, deadline + window]`.
+    /// Works whether or not the goal was met.
+    ///
+    /// # Errors
+    /// * `RefundWindowClosed` outside the refund window.
+    /// * `NothingToClaim` if the address has no contribution left.
+    ///
+    /// # Panics
+    /// * If the campaign is not Active.
+    pub fn refund_single(env: Env, contributor: Address) -> Result<(), ContractError> {
+        contributor.require_auth();
+
+        let status: Status = env.storage().instance().get(&DataKey::Status).unwrap();
+        if status != Status::Active {
+            panic!("campaign is not active");
+        }
+
+        let refund_window = campaign_options(&env).refund_window_seconds;
+        if refund_window == 0 {
+            return Err(ContractError::RefundWindowClosed);
+        }
+        let deadline = deadline_timestamp(&env);
+        let now = env.ledger().timestamp();
+        if now <= deadline || now > deadline.saturating_add(refund_window) {
+            return Err(ContractError::RefundWindowClosed);
+        }
+
+        let contribution_key = DataKey::Contribution(contributor.clone());
+        let amount: i128 = env
+            .storage()
+            .persistent()
+            .get(&contribution_key)
+            .unwrap_or(0);
+        if amount <= 0 {
+            return Err(ContractError::NothingToClaim);
+        }
+
+        env.storage().persistent().set(&contribution_key, &0i128);
+        env.storage()
+            .persistent()
+            .extend_ttl(&contribution_key, 100, 100);
+
+        let total: i128 = env.storage().instance().get(&DataKey::TotalRaised).unwrap();
+        env.storage()
+            .instance()
+            .set(&DataKey::TotalRaised, &(total - amount));
+
+        reduce_weighted(&env, &contributor, amount, 0)?;
+        claw_back_referral_fees(&env, &contributor, amount, 0)?;
+        burn_reward(&env, &contributor, amount, 0)?;
+        remove_contributor(&env, &contributor);
+        update_leaderboard(&env, &contributor, amount, 0);
+
+        let token_address: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let token_client = token::Client::new(&env, &token_address);
+        token_client.transfer(&env.current_contract_address(), &contributor, &amount);
+        burn_receipt(&env, &contributor, amount);
+
+        env.events()
+            .publish(("campaign", "refund_single"), (contributor, amount));
+
+        Ok(())
+    }
+
+    /// Refund a failed campaign to whoever holds the receipts.
+    ///
+    /// The holder's whole receipt balance is burned through the receipt
+    /// contract and the same amount of underlying tokens is returned. The
+    /// holder's contribution and `total_raised` are reduced in step, so the
+    /// ledger always matches the receipt supply and a later `refund` cannot
+    /// pay the same contribution twice.
+    ///
+    /// # Errors
+    /// * `CampaignStillActive` before the deadline.
+    /// * `GracePeriodActive` during the post-deadline grace period.
+    /// * `GoalReached` if the campaign succeeded.
+    /// * `NothingToClaim` if the holder has no receipts.
+    ///
+    /// # Panics
+    /// * If the campaign is not Active or has no receipt contract.
+    /// * If the receipt balance exceeds the holder's recorded contribution.
+    pub fn claim_refund_with_receipt(env: Env, holder: Address) -> Result<(), ContractError> {
+        holder.require_auth();
+
+        let status: Status = env.storage().instance().get(&DataKey::Status).unwrap();
+        if status != Status::Active {
+            panic!("campaign is not active");
+        }
+
+        if !campaign_deadline(&env).has_passed(&env) {
+            return Err(ContractError::CampaignStillActive);
+        }
+        ensure_grace_period_over(&env)?;
+
+        let total: i128 = env.storage().instance().get(&DataKey::TotalRaised).unwrap();
+        if campaign_succeeded(&env, total)? {
+            return Err(ContractError::GoalReached);
+        }
+
+        let Some(receipt) = campaign_options(&env).receipt_contract else {
+            panic!("no receipt contract configured");
+        };
+        let receipt_client = ReceiptClient::new(&env, &receipt);
+        let amount = receipt_client.balance(&holder);
+        if amount <= 0 {
+            return Err(ContractError::NothingToClaim);
+        }
+
+        let contribution_key = DataKey::Contribution(holder.clone());
+        let prev: i128 = env
+            .storage()
+            .persistent()
+            .get(&contribution_key)
+            .unwrap_or(0);
+        if amount > prev {
+            panic!("receipt balance exceeds contribution");
+        }
+
+        receipt_client.burn(&holder, &amount);
+
+        let remaining = prev - amount;
+        env.storage()
+            .persistent()
+            .set(&contribution_key, &remaining);
+        env.storage()
+            .persistent()
+            .extend_ttl(&contribution_key, 100, 100);
+        env.storage()
+            .instance()
+            .set(&DataKey::TotalRaised, &(total - amount));
+
+        reduce_weighted(&env, &holder, prev, remaining)?;
+        claw_back_referral_fees(&env, &holder, prev, remaining)?;
+        burn_reward(&env, &holder, prev, remaining)?;
+        if remaining == 0 {
+            remove_contributor(&env, &holder);
+        }
+        update_leaderboard(&env, &holder, prev, remaining);
+
+        let token_address: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let token_client = token::Client::new(&env, &token_address);
+        token_client.transfer(&env.current_contract_address(), &holder, &amount);
+
+        env.events()
+            .publish(("campaign", "receipt_refund"), (holder, amount));
+
+        Ok(())
+    }
+
+    /// Escrow a sponsor matching pool in the contract.
+    ///
+    /// Every later contribution draws `amount * match_bps / 10_000` from the
+    /// pool (until it runs out), credited to the sponsor as a contribution.
+    /// Matched amounts are refundable to the sponsor like any contribution
+    /// and the unspent pool is returned once the campaign settles.
+    ///
+    /// # Panics
+    /// * If the campaign is not Active or the deadline has passed.
+    /// * If `pool_amount` or `match_bps` is zero, or a pool already exists.
+    pub fn fund_match(env: Env, sponsor: Address, pool_amount: i128, match_bps: u32) {
+        sponsor.require_auth();
+
+        let status: Status = env.storage().instance().get(&DataKey::Status).unwrap();
+        if status != Status::Active {
+            panic!("campaign is not active");
+        }
+
+        if campaign_deadline(&env).has_passed(&env) {
+            panic!("campaign has ended");
+        }
+
+        if pool_amount <= 0 || match_bps == 0 {
+            panic!("invalid matching pool");
+        }
+        if env.storage().instance().has(&DataKey::MatchingPool) {
+            panic!("matching pool already funded");
+        }
+
+        let token_address: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let pool_amount = receive_tokens(&env, &token_address, &sponsor, pool_amount)
+            .unwrap_or_else(|_| panic!("no tokens received"));
+
+        env.storage().instance().set(
+            &DataKey::MatchingPool,
+            &MatchingPool {
+                sponsor: sponsor.clone(),
+                remaining: pool_amount,
+                match_bps,
+            },
+        );
+
+        env.events().publish(
+            ("campaign", "match_funded"),
+            (sponsor, pool_amount, match_bps),
+        );
+    }
+
+    /// Pledge tokens to the campaign without transferring them immediately.
+    ///
+    /// The pledger must authorize the call. Pledges are recorded off-chain
+    /// and only collected if the goal is met after the deadline.
+    ///
+    /// # Errors
+    /// * `InvalidAmount` if `amount` is zero or negative.
+    /// * `Overflow` if the pledge totals would overflow.
+    pub fn pledge(env: Env, pledger: Address, amount: i128) -> Result<(), ContractError> {
+        pledger.require_auth();
+
+        if amount <= 0 {
+            return Err(ContractError::InvalidAmount);
+        }
+
+        let min_contribution: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::MinContribution)
+            .unwrap();
+        if amount < min_contribution {
+            panic!("amount below minimum");
+        }
+
+        if campaign_deadline(&env).has_passed(&env) {
+            return Err(ContractError::CampaignEnded);
+        }
+
+        // Update the pledger's running total.
+        let pledge_key = DataKey::Pledge(pledger.clone());
+        let prev: i128 = env.storage().persistent().get(&pledge_key).unwrap_or(0);
+        env.storage().persistent().set(
+            &pledge_key,
+            &prev.checked_add(amount).ok_or(ContractError::Overflow)?,
+        );
+        env.storage().persistent().extend_ttl(&pledge_key, 100, 100);
+
+        // Update the global total pledged.
+        let total_pledged: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::TotalPledged)
+            .unwrap_or(0);
+        env.storage().instance().set(
+            &DataKey::TotalPledged,
+            &total_pledged
+                .checked_add(amount)
+                .ok_or(ContractError::Overflow)?,
+        );
+
+        // Track pledger address if new.
+        let mut pledgers: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Pledgers)
+            .unwrap_or_else(|| Vec::new(&env));
+        if !pledgers.contains(&pledger) {
+            pledgers.push_back(pledger.clone());
+            env.storage()
+                .persistent()
+                .set(&DataKey::Pledgers, &pledgers);
+            env.storage()
+                .persistent()
+                .extend_ttl(&DataKey::Pledgers, 100, 100);
+        }
+
+        // Emit pledge event
+        env.events()
+            .publish(("campaign", "pledged"), (pledger, amount));
+
+        Ok(())
+    }
+
+    /// Collect all pledges after the deadline when the goal is met.
+    ///
+    /// This function transfers tokens from all pledgers to the contract.
+    /// Only callable after the deadline and when the combined total of
+    /// contributions and pledges meets or exceeds the goal. Only the amounts
+    /// actually received are added to `total_raised`.
+    pub fn collect_pledges(env: Env) -> Result<(), ContractError> {
+        let status: Status = env.storage().instance().get(&DataKey::Status).unwrap();
+        if status != Status::Active {
+            panic!("campaign is not active");
+        }
+
+        if !campaign_deadline(&env).has_passed(&env) {
+            return Err(ContractError::CampaignStillActive);
+        }
+
+        let total_raised: i128 = env.storage().instance().get(&DataKey::TotalRaised).unwrap();
+        let total_pledged: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::TotalPledged)
+            .unwrap_or(0);
+
+        // Check if combined total meets the goal
+        let combined = total_raised
+            .checked_add(total_pledged)
+            .ok_or(ContractError::Overflow)?;
+        if !goal_reached(&env, combined)? {
+            return Err(ContractError::GoalNotReached);
+        }
+
+        let token_address: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+
+        let pledgers: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Pledgers)
+            .unwrap_or_else(|| Vec::new(&env));
+
+        // Collect pledges from all pledgers, counting what actually arrived.
+        let mut collected: i128 = 0;
+        for pledger in pledgers.iter() {
+            let pledge_key = DataKey::Pledge(pledger.clone());
+            let amount: i128 = env.storage().persistent().get(&pledge_key).unwrap_or(0);
+            if amount > 0 {
+                // Transfer tokens from pledger to contract
+                collected = collected
+                    .checked_add(receive_tokens(&env, &token_address, &pledger, amount)?)
+                    .ok_or(ContractError::Overflow)?;
+
+                // Clear the pledge
+                env.storage().persistent().set(&pledge_key, &0i128);
+                env.storage().persistent().extend_ttl(&pledge_key, 100, 100);
+            }
+        }
+
+        // Update total raised to include collected pledges
+        env.storage().instance().set(
+            &DataKey::TotalRaised,
+            &total_raised
+                .checked_add(collected)
+                .ok_or(ContractError::Overflow)?,
+        );
+
+        // Reset total pledged
+        env.storage().instance().set(&DataKey::TotalPledged, &0i128);
+
+        // Emit pledges collected event
+        env.events()
+            .publish(("campaign", "pledges_collected"), collected);
+
+        Ok(())
+    }
+
+    /// Withdraw raised funds — only callable by the creator after the
+    /// deadline, and only if the goal has been met. An `auto_close_at_goal`
+    /// campaign may be withdrawn as soon as it closes.
+    ///
+    /// If a platform fee is configured, deducts the fee and transfers it to
+    /// the platform address, then sends the remainder to the creator. Under payout
+    /// vesting the remainder is held for `claim_vested` instead.
+    ///
+    /// # Errors
+    /// * `RefundWindowOpen` while the post-deadline refund window is open.
+    /// * `StalePrice` if the goal's SEP-40 price is missing or stale.
+    /// * `NotEnoughContributors` if the goal was met by fewer than
+    ///   `min_contributors` contributors.
+    ///
+    /// # Panics
+    /// * If the campaign requires multi-sig withdraw signatures.
+    /// * With "accounting invariant violated" if the contract holds less
+    ///   than `total_raised`; see `verify_accounting`.
+    pub fn withdraw(env: Env) -> Result<(), ContractError> {
+        let creator: Address = env.storage().instance().get(&DataKey::Creator).unwrap();
+        creator.require_auth();
+
+        if campaign_options(&env).required_withdraw_signatures > 0 {
+            panic!("withdraw requires co-signatures");
+        }
+
+        let total = withdrawable_total(&env)?;
+        pay_out_creator(&env, &creator, &creator, total)?;
+
+        Ok(())
+    }
+
+    /// Withdraw like `withdraw`, but send the creator's share to
+    /// `destination` — e.g. a treasury contract or an exchange deposit
+    /// address — instead of the creator. The platform fee still goes to the
+    /// platform.
+    ///
+    /// # Errors
+    /// * As for `withdraw`.
+    ///
+    /// # Panics
+    /// * If the campaign requires multi-sig withdraw signatures.
+    /// * If the creator's payout vests.
+    pub fn withdraw_to(env: Env, destination: Address) -> Result<(), ContractError> {
+        let creator: Address = env.storage().instance().get(&DataKey::Creator).unwrap();
+        creator.require_auth();
+        ensure_payout_not_vesting(&env);
+
+        if campaign_options(&env).required_withdraw_signatures > 0 {
+            panic!("withdraw requires co-signatures");
+        }
+
+        let total = withdrawable_total(&env)?;
+        pay_out_creator(&env, &creator, &destination, total)?;
+
+        Ok(())
+    }
+
+    /// Draw `amount` of the raised funds — creator-only, on the same terms as
+    /// `withdraw`. The campaign moves to Successful on the first draw, and
+    /// the creator may keep drawing until their share is used up.
+    ///
+    /// The platform fee is taken in full on the first draw, so every draw
+    /// sends exactly `amount` and the draws add up to what `withdraw` would
+    /// have paid in one go. Secondary tokens are paid out in full on the
+    /// first draw too.
+    ///
+    /// # Errors
+    /// * `InvalidAmount` if `amount` is zero or negative.
+    /// * `WithdrawalExceedsBalance` if `amount` is more than is left to draw.
+    /// * As for `withdraw`, on the first draw.
+    ///
+    /// # Panics
+    /// * If the campaign requires multi-sig withdraw signatures.
+    /// * If the campaign was paid out with `withdraw` or is otherwise no
+    ///   longer Active.
+    /// * If the creator's payout vests.
+    pub fn withdraw_amount(env: Env, amount: i128) -> Result<(), ContractError> {
+        let creator: Address = env.storage().instance().get(&DataKey::Creator).unwrap();
+        creator.require_auth();
+        ensure_payout_not_vesting(&env);
+
+        if amount <= 0 {
+            return Err(ContractError::InvalidAmount);
+        }
+        if campaign_options(&env).required_withdraw_signatures > 0 {
+            panic!("withdraw requires co-signatures");
+        }
+
+        let drawdown = match env.storage().instance().get(&SettlementKey::Drawdown) {
+            Some(drawdown) => drawdown,
+            None => {
+                let total = withdrawable_total(&env)?;
+                let (drawdown, _) = settle_success(&env, &creator, &creator, total)?;
+                env.storage()
+                    .instance()
+                    .set(&SettlementKey::Drawdown, &drawdown);
+                drawdown
+            }
+        };
+
+        let withdrawn = Self::withdrawn_so_far(env.clone());
+        if amount > drawdown - withdrawn {
+            return Err(ContractError::WithdrawalExceedsBalance);
+        }
+        let withdrawn = withdrawn + amount;
+        env.storage()
+            .instance()
+            .set(&SettlementKey::WithdrawnSoFar, &withdrawn);
+
+        let token_address: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        token::Client::new(&env, &token_address).transfer(
+            &env.current_contract_address(),
+            &creator,
+            &amount,
+        );
+
+        env.events()
+            .publish(("campaign", "partial_withdrawn"), (amount, withdrawn));
+
+        Ok(())
+    }
+
+    /// Returns how much the creator has drawn with `withdraw_amount` or
+    /// `claim_vested`.
+    pub fn withdrawn_so_far(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&SettlementKey::WithdrawnSoFar)
+            .unwrap_or(0)
+    }
+
+    /// Transfer the creator's vested, unclaimed share to them — creator-only,
+    /// for campaigns with a `vesting_duration_secs`. Returns the amount transferred,
+    /// which is zero before the cliff or once the vested share is claimed.
+    ///
+    /// The first claim settles a successful campaign as `withdraw` would,
+    /// taking the platform fee in full; `withdraw` or `finalize` may also
+    /// have settled it already.
+    ///
+    /// # Errors
+    /// * As for `withdraw`, if the campaign has not been settled yet.
+    ///
+    /// # Panics
+    /// * If the creator's payout does not vest.
+    pub fn claim_vested(env: Env) -> Result<i128, ContractError> {
+        let creator: Address = env.storage().instance().get(&DataKey::Creator).unwrap();
+        creator.require_auth();
+
+        if !payout_vests(&env) {
+            panic!("payout does not vest");
+        }
+        if !env.storage().instance().has(&SettlementKey::Drawdown) {
+            if campaign_options(&env).required_withdraw_signatures > 0 {
+                panic!("withdraw requires co-signatures");
+            }
+            let total = withdrawable_total(&env)?;
+            pay_out_creator(&env, &creator, &creator, total)?;
+        }
+
+        let claimable = Self::vested_amount(env.clone()) - Self::claimed_amount(env.clone());
+        if claimable <= 0 {
+            return Ok(0);
+        }
+        env.storage().instance().set(
+            &SettlementKey::WithdrawnSoFar,
+            &(Self::claimed_amount(env.clone()) + claimable),
+        );
+
+        let token_address: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        token::Client::new(&env, &token_address).transfer(
+            &env.current_contract_address(),
+            &creator,
+            &claimable,
+        );
+
+        env.events()
+            .publish(("campaign", "vested_claimed"), claimable);
+        Ok(claimable)
+    }
+
+    /// Returns how much of the creator's share has vested so far: nothing
+    /// before the cliff, then a linear share of the time since the deadline
+    /// over `duration_secs`. Zero until the campaign has settled.
+    pub fn vested_amount(env: Env) -> i128 {
+        let options = campaign_options(&env);
+        if options.vesting_duration_secs == 0 {
+            return 0;
+        }
+        let Some(share) = env
+            .storage()
+            .instance()
+            .get::<_, i128>(&SettlementKey::Drawdown)
+        else {
+            return 0;
+        };
+
+        let elapsed = env
+            .ledger()
+            .timestamp()
+            .saturating_sub(deadline_timestamp(&env));
+        if elapsed < options.vesting_cliff_secs {
+            return 0;
+        }
+        let vested_time = elapsed.min(options.vesting_duration_secs);
+        share * vested_time as i128 / options.vesting_duration_secs as i128
+    }
+
+    /// Returns how much of the creator's vested share has been claimed.
+    pub fn claimed_amount(env: Env) -> i128 {
+        Self::withdrawn_so_far(env)
+    }
+
+    /// Start a multi-sig withdraw — callable by any configured withdraw
+    /// signer, whose signature counts toward `required_withdraw_signatures`.
+    ///
+    /// A request that has gone `WITHDRAW_SIGNING_WINDOW` seconds without
+    /// enough signatures is replaced, discarding its signatures.
+    ///
+    /// # Errors
+    /// * As for `withdraw`, if the campaign cannot pay out yet.
+    ///
+    /// # Panics
+    /// * If multi-sig withdraw is not enabled.
+    /// * If `initiator` is not a withdraw signer.
+    /// * If a request is already pending.
+    pub fn initiate_withdraw(env: Env, initiator: Address) -> Result<(), ContractError> {
+        initiator.require_auth();
+        ensure_withdraw_signer(&env, &initiator);
+        withdrawable_total(&env)?;
+
+        if Self::pending_withdraw(env.clone()).is_some_and(|pending| {
+            env.ledger().timestamp() <= pending.initiated_at + WITHDRAW_SIGNING_WINDOW
+        }) {
+            panic!("withdraw already pending");
+        }
+
+        let pending = PendingWithdraw {
+            initiator: initiator.clone(),
+            initiated_at: env.ledger().timestamp(),
+            signatures: Vec::from_array(&env, [initiator.clone()]),
+        };
+        env.events()
+            .publish(("campaign", "withdraw_initiated"), initiator);
+
+        settle_pending_withdraw(&env, pending)
+    }
+
+    /// Co-sign the pending multi-sig withdraw — callable by any configured
+    /// withdraw signer. The payout runs as soon as the threshold is reached.
+    ///
+    /// # Errors
+    /// * As for `withdraw`, when the final signature triggers the payout.
+    ///
+    /// # Panics
+    /// * If `signer` is not a withdraw signer or has already signed.
+    /// * If no request is pending, or the pending request has lapsed.
+    pub fn sign_withdraw(env: Env, signer: Address) -> Result<(), ContractError> {
+        signer.require_auth();
+        ensure_withdraw_signer(&env, &signer);
+
+        let Some(mut pending) = Self::pending_withdraw(env.clone()) else {
+            panic!("no pending withdraw");
+        };
+        if env.ledger().timestamp() > pending.initiated_at + WITHDRAW_SIGNING_WINDOW {
+            panic!("withdraw request expired");
+        }
+        if pending.signatures.contains(&signer) {
+            panic!("already signed");
+        }
+
+        pending.signatures.push_back(signer.clone());
+        env.events()
+            .publish(("campaign", "withdraw_signed"), signer);
+
+        settle_pending_withdraw(&env, pending)
+    }
+
+    /// Returns the multi-sig withdraw awaiting signatures, if any. It may
+    /// have lapsed; see `WITHDRAW_SIGNING_WINDOW`.
+    pub fn pending_withdraw(env: Env) -> Option<PendingWithdraw> {
+        env.storage()
+            .instance()
+            .get(&SettlementKey::PendingWithdraw)
+    }
+
+    /// Withdraw before the deadline once the goal has been met — creator-only.
+    ///
+    /// Pays out exactly like `withdraw` (minus the platform fee) and moves the
+    /// campaign to Successful, so no further contributions are accepted.
+    ///
+    /// # Errors
+    /// * `ContractPaused` if the contract is paused.
+    /// * `RefundWindowOpen` if the campaign has a refund window, since paying
+    ///   out early would skip the cooling-off period.
+    /// * `GoalNotReached` if `total_raised` is still below the goal.
+    /// * `NotEnoughContributors` if the goal is met by fewer than
+    ///   `min_contributors` contributors.
+    ///
+    /// # Panics
+    /// * If the campaign is not Active.
+    /// * If the campaign requires multi-sig withdraw signatures.
+    /// * If `min_funding_duration_seconds` have not passed since
+    ///   `initialize`.
+    pub fn early_withdraw(env: Env) -> Result<(), ContractError> {
+        ensure_not_suspended(&env);
+
+        if campaign_options(&env).required_withdraw_signatures > 0 {
+            panic!("withdraw requires co-signatures");
+        }
+
+        let paused: bool = env
+            .storage()
+            .instance()
+            .get(&DataKey::Paused)
+            .unwrap_or(false);
+        if paused {
+            return Err(ContractError::ContractPaused);
+        }
+
+        let status: Status = env.storage().instance().get(&DataKey::Status).unwrap();
+        if status != Status::Active {
+            panic!("campaign is not active");
+        }
+
+        let creator: Address = env.storage().instance().get(&DataKey::Creator).unwrap();
+        creator.require_auth();
+
+        if campaign_options(&env).refund_window_seconds > 0 {
+            return Err(ContractError::RefundWindowOpen);
+        }
+        ensure_min_funding_elapsed(&env);
+
+        let total: i128 = env.storage().instance().get(&DataKey::TotalRaised).unwrap();
+        ensure_succeeded(&env, total)?;
+
+        pay_out_creator(&env, &creator, &creator, total)?;
+
+        Ok(())
+    }
+
+    /// Pay a referrer the fees they earned — only once the campaign has
+    /// succeeded and the creator has withdrawn.
+    ///
+    /// # Errors
+    /// * `CampaignNotSuccessful` if the campaign has not been paid out.
+    /// * `NothingToClaim` if the referrer has no unclaimed earnings.
+    pub fn claim_referral(env: Env, referrer: Address) -> Result<(), ContractError> {
+        referrer.require_auth();
+
+        let status: Status = env.storage().instance().get(&DataKey::Status).unwrap();
+        if status != Status::Successful || awaiting_payout(&env) {
+            return Err(ContractError::CampaignNotSuccessful);
+        }
+
+        let earnings_key = DataKey::ReferralEarnings(referrer.clone());
+        let amount: i128 = env.storage().persistent().get(&earnings_key).unwrap_or(0);
+        if amount <= 0 {
+            return Err(ContractError::NothingToClaim);
+        }
+
+        env.storage().persistent().set(&earnings_key, &0i128);
+
+        let token_address: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let token_client = token::Client::new(&env, &token_address);
+        token_client.transfer(&env.current_contract_address(), &referrer, &amount);
+
+        env.events()
+            .publish(("campaign", "referral_claimed"), (referrer, amount));
+
+        Ok(())
+    }
+
+    /// Claim the contributor's share of the over-goal excess after the
+    /// creator has withdrawn a `return_excess` campaign.
+    ///
+    /// # Errors
+    /// * `CampaignNotSuccessful` if the campaign has not been paid out.
+    /// * `NothingToClaim` if there is no excess owed to the contributor.
+    pub fn claim_excess(env: Env, contributor: Address) -> Result<(), ContractError> {
+        contributor.require_auth();
+
+        let status: Status = env.storage().instance().get(&DataKey::Status).unwrap();
+        if status != Status::Successful || awaiting_payout(&env) {
+            return Err(ContractError::CampaignNotSuccessful);
+        }
+
+        let claim_key = DataKey::ExcessClaim(contributor.clone());
+        let amount: i128 = env.storage().persistent().get(&claim_key).unwrap_or(0);
+        if amount <= 0 {
+            return Err(ContractError::NothingToClaim);
+        }
+
+        env.storage().persistent().set(&claim_key, &0i128);
+
+        let token_address: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let token_client = token::Client::new(&env, &token_address);
+        token_client.transfer(&env.current_contract_address(), &contributor, &amount);
+
+        env.events()
+            .publish(("campaign", "excess_claimed"), (contributor, amount));
+
+        Ok(())
+    }
+
+    /// Claim a soulbound backer badge once the campaign has succeeded.
+    ///
+    /// Records the contributor's amount, the claim time and their reward
+    /// tier (if any). Each contributor can claim once.
+    ///
+    /// # Errors
+    /// * `CampaignNotSuccessful` unless the campaign has been paid out.
+    /// * `NotAContributor` if the address has no contribution.
+    /// * `BadgeAlreadyClaimed` if the badge was already claimed.
+    pub fn claim_badge(env: Env, contributor: Address) -> Result<(), ContractError> {
+        contributor.require_auth();
+
+        let status: Status = env.storage().instance().get(&DataKey::Status).unwrap();
+        if status != Status::Successful || awaiting_payout(&env) {
+            return Err(ContractError::CampaignNotSuccessful);
+        }
+
+        let amount: i128 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Contribution(contributor.clone()))
+            .unwrap_or(0);
+        if amount <= 0 {
+            return Err(ContractError::NotAContributor);
+        }
+
+        let badge_key = DataKey::Badge(contributor.clone());
+        if env.storage().persistent().has(&badge_key) {
+            return Err(ContractError::BadgeAlreadyClaimed);
+        }
+
+        let badge = Badge {
+            amount,
+            timestamp: env.ledger().timestamp(),
+            tier: env
+                .storage()
+                .persistent()
+                .get(&DataKey::ContributorTier(contributor.clone())),
+        };
+        env.storage().persistent().set(&badge_key, &badge);
+        env.storage().persistent().extend_ttl(&badge_key, 100, 100);
+
+        env.events()
+            .publish(("campaign", "badge_claimed"), (contributor, badge));
+
+        Ok(())
+    }
+
+    /// Settle the campaign after the deadline — callable by anyone, so
+    /// keepers need not know which path applies.
+    ///
+    /// Pays out like `withdraw` if the goal was met, and refunds like `refund`
+    /// otherwise. Returns the resulting status; on a campaign that is no
+    /// longer Active it does nothing and returns the current status.
+    ///
+    /// # Errors
+    /// * `CampaignStillActive` before the deadline.
+    /// * As for `withdraw` or `refund`, depending on the path taken.
+    ///
+    /// # Panics
+    /// * If the goal was met but the campaign requires multi-sig withdraw
+    ///   signatures.
+    pub fn finalize(env: Env) -> Result<Status, ContractError> {
+        let status: Status = env.storage().instance().get(&DataKey::Status).unwrap();
+        if status != Status::Active {
+            return Ok(status);
+        }
+        if !campaign_deadline(&env).has_passed(&env) {
+            return Err(ContractError::CampaignStillActive);
+        }
+
+        let total: i128 = env.storage().instance().get(&DataKey::TotalRaised).unwrap();
+        if campaign_succeeded(&env, total)? {
+            if campaign_options(&env).required_withdraw_signatures > 0 {
+                panic!("withdraw requires co-signatures");
+            }
+            let creator: Address = env.storage().instance().get(&DataKey::Creator).unwrap();
+            let total = withdrawable_total(&env)?;
+            pay_out_creator(&env, &creator, &creator, total)?;
+        } else {
+            Self::refund(env.clone())?;
+        }
+
+        Ok(Self::status(env))
+    }
+
+    /// Refund all contributors — callable by anyone after the deadline
+    /// if the campaign did **not** succeed: the goal was missed, or met by
+    /// fewer than `min_contributors` contributors.
+    ///
+    /// A contributor whose transfer fails (e.g. a frozen or deauthorized
+    /// account) does not block the batch: their contribution is kept, they
+    /// are added to `failed_refunds` with a `refund_failed` event, and the
+    /// refund can be retried later with `claim_refund`.
+    ///
+    /// # Errors
+    /// * `GracePeriodActive` during the post-deadline grace period.
+    /// * `StalePrice` if the goal's SEP-40 price is missing or stale.
+    pub fn refund(env: Env) -> Result<(), ContractError> {
+        ensure_not_suspended(&env);
 
         let paused: bool = env
             .storage()
             .instance()
-            .get(&DataKey::Paused)
-            .unwrap_or(false);
-        if paused {
-            return Err(ContractError::ContractPaused);
+            .get(&DataKey::Paused)
+            .unwrap_or(false);
+        if paused {
+            return Err(ContractError::ContractPaused);
+        }
+
+        let status: Status = env.storage().instance().get(&DataKey::Status).unwrap();
+        if status != Status::Active {
+            panic!("campaign is not active");
+        }
+
+        if !campaign_deadline(&env).has_passed(&env) {
+            return Err(ContractError::CampaignStillActive);
+        }
+        ensure_grace_period_over(&env)?;
+
+        let total: i128 = env.storage().instance().get(&DataKey::TotalRaised).unwrap();
+        if campaign_succeeded(&env, total)? {
+            return Err(ContractError::GoalReached);
+        }
+
+        let token_address: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let token_client = token::Client::new(&env, &token_address);
+
+        let contributors: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Contributors)
+            .unwrap();
+
+        let mut total_refunded = 0i128;
+        let mut outstanding = 0i128;
+        let mut failed_refunds: Vec<Address> = Vec::new(&env);
+        for contributor in contributors.iter() {
+            let contribution_key = DataKey::Contribution(contributor.clone());
+            let amount: i128 = env
+                .storage()
+                .persistent()
+                .get(&contribution_key)
+                .unwrap_or(0);
+            if amount > 0 {
+                let transferred = token_client
+                    .try_transfer(&env.current_contract_address(), &contributor, &amount)
+                    .is_ok();
+                if !transferred {
+                    outstanding += amount;
+                    failed_refunds.push_back(contributor.clone());
+                    env.events()
+                        .publish(("campaign", "refund_failed"), (contributor, amount));
+                    continue;
+                }
+                burn_receipt(&env, &contributor, amount);
+                burn_reward(&env, &contributor, amount, 0)?;
+                total_refunded += amount;
+                env.storage().persistent().set(&contribution_key, &0i128);
+                env.storage()
+                    .persistent()
+                    .extend_ttl(&contribution_key, 100, 100);
+            }
+        }
+
+        record_raised_at_close(&env);
+        env.storage()
+            .instance()
+            .set(&DataKey::TotalRaised, &outstanding);
+        if !failed_refunds.is_empty() {
+            env.storage()
+                .instance()
+                .set(&SettlementKey::FailedRefunds, &failed_refunds);
+        }
+        env.storage()
+            .instance()
+            .set(&SettlementKey::RefundedAt, &env.ledger().timestamp());
+        set_status(&env, Status::Refunded);
+        notify_hook_finalized(&env, Status::Refunded);
+        release_match_pool(&env);
+        refund_secondary_tokens(&env);
+        notify_platform_finalized(&env, Status::Refunded, 0);
+
+        env.events()
+            .publish(("campaign", "refunded"), RefundEvent { total_refunded });
+
+        Ok(())
+    }
+
+    /// Retry a refund that failed during `refund` — callable by anyone.
+    ///
+    /// # Errors
+    /// * `RefundExpired` if unclaimed refunds have been swept.
+    /// * `GracePeriodActive` during the post-deadline grace period.
+    /// * `NothingToClaim` if `contributor` has no failed refund outstanding.
+    ///
+    /// # Panics
+    /// * If the transfer still fails; the claim stays recorded.
+    pub fn claim_refund(env: Env, contributor: Address) -> Result<(), ContractError> {
+        ensure_not_suspended(&env);
+
+        if env.storage().instance().has(&SettlementKey::RefundsSwept) {
+            return Err(ContractError::RefundExpired);
+        }
+        ensure_grace_period_over(&env)?;
+
+        let mut failed_refunds = Self::failed_refunds(env.clone());
+        let Some(index) = failed_refunds.first_index_of(&contributor) else {
+            return Err(ContractError::NothingToClaim);
+        };
+
+        let contribution_key = DataKey::Contribution(contributor.clone());
+        let amount: i128 = env
+            .storage()
+            .persistent()
+            .get(&contribution_key)
+            .unwrap_or(0);
+
+        failed_refunds.remove(index);
+        env.storage()
+            .instance()
+            .set(&SettlementKey::FailedRefunds, &failed_refunds);
+        env.storage().persistent().set(&contribution_key, &0i128);
+        env.storage()
+            .persistent()
+            .extend_ttl(&contribution_key, 100, 100);
+
+        let total: i128 = env.storage().instance().get(&DataKey::TotalRaised).unwrap();
+        env.storage()
+            .instance()
+            .set(&DataKey::TotalRaised, &(total - amount));
+
+        let token_address: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let token_client = token::Client::new(&env, &token_address);
+        token_client.transfer(&env.current_contract_address(), &contributor, &amount);
+        burn_receipt(&env, &contributor, amount);
+        burn_reward(&env, &contributor, amount, 0)?;
+
+        env.events()
+            .publish(("campaign", "refund_claimed"), (contributor, amount));
+
+        Ok(())
+    }
+
+    /// Collect refunds still unclaimed `refund_claim_period` seconds after
+    /// the batch `refund` and send them to `destination`.
+    ///
+    /// Callable by the platform if one is configured, otherwise by the
+    /// creator. Every outstanding claim is closed, and later `claim_refund`
+    /// calls fail with `RefundExpired`.
+    ///
+    /// # Errors
+    /// * `ClaimPeriodActive` before the claim period has elapsed.
+    /// * `RefundExpired` if unclaimed refunds were already swept.
+    ///
+    /// # Panics
+    /// * If the campaign has not been refunded or sweeping is disabled.
+    pub fn sweep_unclaimed(env: Env, destination: Address) -> Result<i128, ContractError> {
+        let authority = match env
+            .storage()
+            .instance()
+            .get::<_, PlatformConfig>(&DataKey::PlatformConfig)
+        {
+            Some(config) => config.address,
+            None => env.storage().instance().get(&DataKey::Creator).unwrap(),
+        };
+        authority.require_auth();
+
+        let status: Status = env.storage().instance().get(&DataKey::Status).unwrap();
+        if status != Status::Refunded {
+            panic!("campaign has not been refunded");
+        }
+        let claim_period = campaign_options(&env).refund_claim_period;
+        if claim_period == 0 {
+            panic!("refund sweeping is disabled");
+        }
+        if env.storage().instance().has(&SettlementKey::RefundsSwept) {
+            return Err(ContractError::RefundExpired);
+        }
+
+        let refunded_at: u64 = env
+            .storage()
+            .instance()
+            .get(&SettlementKey::RefundedAt)
+            .unwrap();
+        if env.ledger().timestamp() <= refunded_at.saturating_add(claim_period) {
+            return Err(ContractError::ClaimPeriodActive);
+        }
+
+        let mut swept = 0i128;
+        for contributor in Self::failed_refunds(env.clone()).iter() {
+            let contribution_key = DataKey::Contribution(contributor.clone());
+            let amount: i128 = env
+                .storage()
+                .persistent()
+                .get(&contribution_key)
+                .unwrap_or(0);
+            env.storage().persistent().set(&contribution_key, &0i128);
+            env.storage()
+                .persistent()
+                .extend_ttl(&contribution_key, 100, 100);
+            burn_receipt(&env, &contributor, amount);
+            swept += amount;
+        }
+
+        env.storage()
+            .instance()
+            .remove(&SettlementKey::FailedRefunds);
+        env.storage()
+            .instance()
+            .set(&SettlementKey::RefundsSwept, &true);
+        env.storage().instance().set(&DataKey::TotalRaised, &0i128);
+
+        if swept > 0 {
+            let token_address: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+            let token_client = token::Client::new(&env, &token_address);
+            token_client.transfer(&env.current_contract_address(), &destination, &swept);
+        }
+
+        env.events()
+            .publish(("campaign", "unclaimed_swept"), (destination, swept));
+
+        Ok(swept)
+    }
+
+    /// Compact a closed campaign's storage — callable by the creator or the
+    /// admin once the campaign is Successful, Refunded or Cancelled.
+    ///
+    /// Deletes every per-contributor entry (contributions, memos, tiers,
+    /// weights, schedules, acknowledgments and secondary-token tallies) and
+    /// the contributor lists, keeping only a `CampaignArchive`. Badges and
+    /// unclaimed excess or referral earnings are left in place. Afterwards
+    /// `contribution` returns 0 for every address.
+    ///
+    /// # Panics
+    /// * If `caller` is neither the creator nor the admin.
+    /// * If the campaign is still Active or already archived.
+    /// * If failed refunds are still waiting to be claimed or swept.
+    pub fn archive(env: Env, caller: Address) {
+        let creator: Address = env.storage().instance().get(&DataKey::Creator).unwrap();
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        if caller != creator && caller != admin {
+            panic!("not authorized");
+        }
+        caller.require_auth();
+
+        let status: Status = env.storage().instance().get(&DataKey::Status).unwrap();
+        if status == Status::Active || awaiting_payout(&env) {
+            panic!("campaign is not closed");
+        }
+        if Self::is_archived(env.clone()) {
+            panic!("campaign already archived");
+        }
+        if !Self::failed_refunds(env.clone()).is_empty() {
+            panic!("refunds outstanding");
+        }
+
+        let contributors: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Contributors)
+            .unwrap_or_else(|| Vec::new(&env));
+        let storage = env.storage().persistent();
+        for contributor in contributors.iter() {
+            let count_key = DataKey::ContributionCount(contributor.clone());
+            let count: u32 = storage.get(&count_key).unwrap_or(0);
+            for index in 0..count {
+                storage.remove(&DataKey::ContributionMemo(contributor.clone(), index));
+            }
+            storage.remove(&count_key);
+            storage.remove(&DataKey::Contribution(contributor.clone()));
+            storage.remove(&DataKey::LastContributionTime(contributor.clone()));
+            storage.remove(&DataKey::ContributorTier(contributor.clone()));
+            storage.remove(&DataKey::WeightedContribution(contributor.clone()));
+            storage.remove(&DataKey::RateWindow(contributor.clone()));
+            storage.remove(&DataKey::Recurring(contributor.clone()));
+            storage.remove(&ComplianceKey::TermsAccepted(contributor.clone()));
+            storage.remove(&ComplianceKey::RiskAcknowledged(contributor.clone()));
+            storage.remove(&ComplianceKey::Blacklisted(contributor.clone()));
+            storage.remove(&ComplianceKey::Allowlisted(contributor.clone()));
+        }
+        storage.remove(&DataKey::Contributors);
+
+        for token in tallied_tokens(&env).iter() {
+            let token_contributors_key = DataKey::TokenContributors(token.clone());
+            let token_contributors: Vec<Address> = storage
+                .get(&token_contributors_key)
+                .unwrap_or_else(|| Vec::new(&env));
+            for contributor in token_contributors.iter() {
+                storage.remove(&DataKey::TokenContribution(contributor, token.clone()));
+            }
+            storage.remove(&token_contributors_key);
+        }
+
+        let archive = CampaignArchive {
+            total_raised_at_close: env
+                .storage()
+                .instance()
+                .get(&SettlementKey::RaisedAtClose)
+                .unwrap_or(0),
+            contributor_count_at_close: contributors.len(),
+            status,
+            goal: current_goal(&env),
+        };
+        env.storage()
+            .instance()
+            .set(&SettlementKey::Archive, &archive);
+
+        env.events().publish(("campaign", "archived"), archive);
+    }
+
+    /// Returns the contributors whose batch refund failed and is still owed.
+    pub fn failed_refunds(env: Env) -> Vec<Address> {
+        env.storage()
+            .instance()
+            .get(&SettlementKey::FailedRefunds)
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    /// Cancel the campaign and refund all contributors — callable only by
+    /// the creator while the campaign is still Active.
+    pub fn cancel(env: Env) {
+        ensure_not_suspended(&env);
+
+        let status: Status = env.storage().instance().get(&DataKey::Status).unwrap();
+        if status != Status::Active {
+            panic!("campaign is not active");
+        }
+
+        let creator: Address = env.storage().instance().get(&DataKey::Creator).unwrap();
+        creator.require_auth();
+
+        let token_address: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let token_client = token::Client::new(&env, &token_address);
+
+        let contributors: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Contributors)
+            .unwrap();
+
+        let mut total_refunded = 0i128;
+        for contributor in contributors.iter() {
+            let contribution_key = DataKey::Contribution(contributor.clone());
+            let amount: i128 = env
+                .storage()
+                .persistent()
+                .get(&contribution_key)
+                .unwrap_or(0);
+            if amount > 0 {
+                token_client.transfer(&env.current_contract_address(), &contributor, &amount);
+                burn_receipt(&env, &contributor, amount);
+                if let Err(err) = burn_reward(&env, &contributor, amount, 0) {
+                    panic_with_error!(&env, err);
+                }
+                total_refunded += amount;
+                env.storage().persistent().set(&contribution_key, &0i128);
+                env.storage()
+                    .persistent()
+                    .extend_ttl(&contribution_key, 100, 100);
+            }
+        }
+
+        record_raised_at_close(&env);
+        env.storage().instance().set(&DataKey::TotalRaised, &0i128);
+        set_status(&env, Status::Cancelled);
+        notify_hook_finalized(&env, Status::Cancelled);
+        release_match_pool(&env);
+        refund_secondary_tokens(&env);
+        notify_platform_finalized(&env, Status::Cancelled, 0);
+
+        env.events().publish(
+            ("campaign", "cancelled"),
+            CancelEvent {
+                creator,
+                total_refunded,
+            },
+        );
+    }
+
+    /// Cancel the campaign like `cancel`, recording why — creator-only.
+    ///
+    /// The reason is kept for `cancellation_reason` and published in a
+    /// `CancelledEvent`.
+    ///
+    /// # Panics
+    /// * If `reason` is empty.
+    /// * As for `cancel`.
+    pub fn cancel_with_reason(env: Env, reason: String) {
+        if reason.is_empty() {
+            panic!("reason must not be empty");
+        }
+
+        Self::cancel(env.clone());
+
+        env.storage()
+            .instance()
+            .set(&SettlementKey::CancellationReason, &reason);
+        env.events().publish(
+            ("campaign", "cancel_reason"),
+            CancelledEvent {
+                reason,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+    }
+
+    /// Returns the reason given to `cancel_with_reason`, if any.
+    pub fn cancellation_reason(env: Env) -> Option<String> {
+        env.storage()
+            .instance()
+            .get(&SettlementKey::CancellationReason)
+    }
+
+    /// Cancel the campaign like `cancel` and point its contributors to
+    /// `new_campaign` — creator-only.
+    ///
+    /// Every contributor is refunded as for `cancel`. The refunded amount is
+    /// recorded so that each contributor can then move it to `new_campaign`
+    /// with `migrate_my_contribution`, which they sign themselves; the
+    /// creator cannot contribute on their behalf.
+    ///
+    /// # Panics
+    /// * If `new_campaign` is this campaign, is not Active, or uses a
+    ///   different token.
+    /// * As for `cancel`.
+    pub fn cancel_and_migrate(env: Env, new_campaign: Address) {
+        if new_campaign == env.current_contract_address() {
+            panic!("cannot migrate to the same campaign");
+        }
+        let target = CrowdfundContractClient::new(&env, &new_campaign);
+        if target.status() != Status::Active {
+            panic!("new campaign is not active");
+        }
+        let token_address: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        if target.token() != token_address {
+            panic!("new campaign uses a different token");
+        }
+
+        let contributors: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Contributors)
+            .unwrap_or_else(|| Vec::new(&env));
+        for contributor in contributors.iter() {
+            let amount = Self::contribution(env.clone(), contributor.clone());
+            if amount > 0 {
+                let key = SettlementKey::Migratable(contributor);
+                env.storage().persistent().set(&key, &amount);
+                env.storage().persistent().extend_ttl(&key, 100, 100);
+            }
+        }
+
+        Self::cancel(env.clone());
+
+        env.storage()
+            .instance()
+            .set(&SettlementKey::MigrationTarget, &new_campaign);
+        env.events()
+            .publish(("campaign", "migration_opened"), new_campaign);
+    }
+
+    /// Re-contribute the amount `cancel_and_migrate` refunded to
+    /// `contributor` to the migration target — contributor-only. Returns the
+    /// amount moved.
+    ///
+    /// This runs as the contributor's own `contribute` on the target, so the
+    /// target's rules (minimum, cap, deadline, ...) apply as usual.
+    ///
+    /// # Errors
+    /// * `NothingToClaim` if `contributor` has nothing left to migrate.
+    ///
+    /// # Panics
+    /// * If the campaign was not cancelled through `cancel_and_migrate`.
+    /// * If the target rejects the contribution.
+    pub fn migrate_my_contribution(env: Env, contributor: Address) -> Result<i128, ContractError> {
+        contributor.require_auth();
+
+        let Some(new_campaign) = Self::migration_target(env.clone()) else {
+            panic!("campaign is not migrating");
+        };
+        let key = SettlementKey::Migratable(contributor.clone());
+        let amount: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+        if amount <= 0 {
+            return Err(ContractError::NothingToClaim);
+        }
+        env.storage().persistent().remove(&key);
+
+        CrowdfundContractClient::new(&env, &new_campaign).contribute(
+            &contributor,
+            &amount,
+            &None,
+            &None,
+        );
+
+        env.events().publish(
+            ("campaign", "migrated"),
+            (new_campaign, contributor, amount),
+        );
+        Ok(amount)
+    }
+
+    /// Returns the campaign `cancel_and_migrate` pointed contributors to, if
+    /// any.
+    pub fn migration_target(env: Env) -> Option<Address> {
+        env.storage()
+            .instance()
+            .get(&SettlementKey::MigrationTarget)
+    }
+
+    /// Returns how much `contributor` may still move to the migration
+    /// target.
+    pub fn migratable_amount(env: Env, contributor: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&SettlementKey::Migratable(contributor))
+            .unwrap_or(0)
+    }
+
+    /// Upgrade the contract to a new WASM implementation — admin-only, and
+    /// co-signed by the platform when one is configured.
+    ///
+    /// This function allows the designated admin to upgrade the contract's WASM code
+    /// without changing the contract's address or storage. The new WASM hash must be
+    /// provided and the caller must be authorized as the admin. Requiring the
+    /// platform as well means neither party can swap the code out from under
+    /// contributors alone.
+    ///
+    /// The `upgraded` event carries the previous hash installed by `upgrade`
+    /// (`None` for the originally deployed code) and the new one.
+    ///
+    /// # Arguments
+    /// * `new_wasm_hash` – The SHA-256 hash of the new WASM binary to deploy.
+    ///
+    /// # Panics
+    /// * If the caller is not the admin.
+    /// * If a platform is configured and has not authorized the upgrade.
+    pub fn upgrade(env: Env, new_wasm_hash: soroban_sdk::BytesN<32>) {
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        if let Some(config) = env
+            .storage()
+            .instance()
+            .get::<_, PlatformConfig>(&DataKey::PlatformConfig)
+        {
+            config.address.require_auth();
+        }
+
+        let old_wasm_hash: Option<soroban_sdk::BytesN<32>> =
+            env.storage().instance().get(&GovernanceKey::WasmHash);
+        env.storage()
+            .instance()
+            .set(&GovernanceKey::WasmHash, &new_wasm_hash);
+
+        env.deployer()
+            .update_current_contract_wasm(new_wasm_hash.clone());
+
+        env.events().publish(
+            ("campaign", "upgraded"),
+            (admin, old_wasm_hash, new_wasm_hash),
+        );
+    }
+
+    /// Bring storage written by an earlier contract version up to the layout
+    /// expected by this one — creator-only.
+    ///
+    /// Run once after `upgrade`. Each step moves the layout forward by one
+    /// version, so an instance several releases behind is walked through all of
+    /// them. Version 1 kept contributions in a single instance-storage map;
+    /// version 2 stores one persistent entry per contributor.
+    ///
+    /// # Panics
+    /// * If the creator has not authorized the call.
+    /// * If storage is already at the current version.
+    pub fn migrate(env: Env) {
+        let creator: Address = env.storage().instance().get(&DataKey::Creator).unwrap();
+        creator.require_auth();
+
+        let from = Self::storage_version(env.clone());
+        if from >= CONTRACT_VERSION {
+            panic!("already migrated");
+        }
+
+        if from < 2 {
+            migrate_contributions_map(&env);
+        }
+
+        env.storage()
+            .instance()
+            .set(&GovernanceKey::StorageVersion, &CONTRACT_VERSION);
+
+        env.events()
+            .publish(("campaign", "migrated"), (from, CONTRACT_VERSION));
+    }
+
+    /// Returns the storage layout version, or 1 for instances deployed before
+    /// the version was recorded.
+    pub fn storage_version(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&GovernanceKey::StorageVersion)
+            .unwrap_or(1)
+    }
+
+    /// Transfer the admin role to a new address — admin-only.
+    ///
+    /// Used for key rotation. The new admin takes over the right to call
+    /// `upgrade` and `set_admin` immediately.
+    ///
+    /// # Arguments
+    /// * `new_admin` – The address that becomes the admin.
+    ///
+    /// # Panics
+    /// * If the caller is not the current admin.
+    pub fn set_admin(env: Env, new_admin: Address) {
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        env.storage().instance().set(&DataKey::Admin, &new_admin);
+
+        env.events()
+            .publish(("campaign", "admin_changed"), (admin, new_admin));
+    }
+
+    /// Pause or unpause the contract — creator-only.
+    ///
+    /// When paused, all contributions, withdrawals, and refunds are blocked.
+    /// This is a security mechanism to halt operations in case of detected
+    /// vulnerabilities or external threats.
+    ///
+    /// # Arguments
+    /// * `paused` – True to pause, false to unpause.
+    pub fn set_paused(env: Env, paused: bool) {
+        let creator: Address = env.storage().instance().get(&DataKey::Creator).unwrap();
+        creator.require_auth();
+
+        env.storage().instance().set(&DataKey::Paused, &paused);
+
+        let event_name = if paused { "paused" } else { "unpaused" };
+        env.events().publish(("campaign", event_name), ());
+    }
+
+    /// Suspend the campaign pending investigation — platform-only.
+    ///
+    /// While suspended, contributions, withdrawals, refunds and cancellation
+    /// all panic with "campaign is suspended". Read-only functions and
+    /// roadmap updates keep working.
+    ///
+    /// # Panics
+    /// * If no platform is configured.
+    pub fn suspend(env: Env) {
+        set_suspended(&env, true);
+    }
+
+    /// Lift a suspension — platform-only.
+    ///
+    /// # Panics
+    /// * If no platform is configured.
+    pub fn unsuspend(env: Env) {
+        set_suspended(&env, false);
+    }
+
+    /// Returns true while the platform has the campaign suspended.
+    pub fn is_suspended(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::Suspended)
+            .unwrap_or(false)
+    }
+
+    /// Pause contributions in an emergency — platform-only.
+    ///
+    /// Unlike `set_paused`, only new contributions are blocked (they fail
+    /// with `CampaignPaused`); refunds, pledge withdrawals and
+    /// `claim_refund` keep working so funds are never trapped by the pause.
+    ///
+    /// # Panics
+    /// * If no platform is configured.
+    pub fn pause(env: Env) {
+        set_platform_paused(&env, true);
+    }
+
+    /// Lift an emergency pause — platform-only.
+    ///
+    /// # Panics
+    /// * If no platform is configured.
+    pub fn unpause(env: Env) {
+        set_platform_paused(&env, false);
+    }
+
+    /// Pause contributions, e.g. pending a legal review — creator-only.
+    ///
+    /// The pause lapses on its own after `max_creator_pause_seconds` (7 days
+    /// by default) and the deadline does not move. Like the platform `pause`,
+    /// only new contributions are blocked; every contributor exit keeps
+    /// working.
+    ///
+    /// # Panics
+    /// * If the campaign is not Active.
+    /// * If the creator has already paused the campaign.
+    pub fn creator_pause(env: Env) {
+        let creator: Address = env.storage().instance().get(&DataKey::Creator).unwrap();
+        creator.require_auth();
+
+        let status: Status = env.storage().instance().get(&DataKey::Status).unwrap();
+        if status != Status::Active {
+            panic!("campaign is not active");
+        }
+        if Self::is_creator_paused(env.clone()) {
+            panic!("campaign already paused");
+        }
+
+        let max_pause = match campaign_options(&env).max_creator_pause_seconds {
+            0 => DEFAULT_MAX_CREATOR_PAUSE,
+            seconds => seconds,
+        };
+        let paused_until = env.ledger().timestamp().saturating_add(max_pause);
+        env.storage()
+            .instance()
+            .set(&ComplianceKey::CreatorPausedUntil, &paused_until);
+
+        env.events()
+            .publish(("campaign", "creator_paused"), paused_until);
+    }
+
+    /// Lift the creator's pause early — creator-only. A platform pause, if
+    /// any, stays in place.
+    ///
+    /// # Panics
+    /// * If the creator has not paused the campaign, or the pause lapsed.
+    pub fn creator_resume(env: Env) {
+        let creator: Address = env.storage().instance().get(&DataKey::Creator).unwrap();
+        creator.require_auth();
+
+        if !Self::is_creator_paused(env.clone()) {
+            panic!("campaign is not paused");
+        }
+        env.storage()
+            .instance()
+            .remove(&ComplianceKey::CreatorPausedUntil);
+
+        env.events().publish(("campaign", "creator_resumed"), ());
+    }
+
+    /// Returns true while a creator pause is in effect.
+    pub fn is_creator_paused(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get::<_, u64>(&ComplianceKey::CreatorPausedUntil)
+            .is_some_and(|paused_until| env.ledger().timestamp() < paused_until)
+    }
+
+    /// Returns true while the platform has contributions paused.
+    pub fn is_paused(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&ComplianceKey::PlatformPaused)
+            .unwrap_or(false)
+    }
+
+    /// Replace this campaign's platform fee with a negotiated rate —
+    /// platform-only.
+    ///
+    /// `new_fee_bps` becomes the flat fee for this campaign, replacing any
+    /// tiered schedule, and applies from the next fee charged. The platform
+    /// address and fee timing are unchanged.
+    ///
+    /// # Panics
+    /// * If no platform is configured.
+    /// * If `new_fee_bps` exceeds 10,000 (100%) or the platform's global fee
+    ///   cap.
+    pub fn override_platform_fee(env: Env, new_fee_bps: u32) {
+        let Some(mut config) = env
+            .storage()
+            .instance()
+            .get::<_, PlatformConfig>(&DataKey::PlatformConfig)
+        else {
+            panic!("no platform configured");
+        };
+        config.address.require_auth();
+
+        if new_fee_bps > 10_000 {
+            panic!("platform fee cannot exceed 100%");
+        }
+
+        let old_fee_bps = config.fee_bps;
+        config.fee_bps = new_fee_bps;
+        config.tiered_fee = None;
+        ensure_fee_within_cap(&env, &config);
+        env.storage()
+            .instance()
+            .set(&DataKey::PlatformConfig, &config);
+
+        env.events()
+            .publish(("campaign", "fee_overridden"), (old_fee_bps, new_fee_bps));
+    }
+
+    /// Returns true if the platform currently waives its fee for this
+    /// campaign, either for the campaign itself or for its creator.
+    pub fn is_fee_waived(env: Env) -> bool {
+        let Some(config) = Self::platform_config(env.clone()) else {
+            return false;
+        };
+        fee_waived(&env, &config.address, &Self::creator(env.clone()))
+    }
+
+    /// Returns the campaign's platform configuration, if any.
+    pub fn platform_config(env: Env) -> Option<PlatformConfig> {
+        env.storage().instance().get(&DataKey::PlatformConfig)
+    }
+
+    /// Add or remove `endorser` from the verified endorser registry —
+    /// admin-only.
+    ///
+    /// Removing an endorser does not retract endorsements already made.
+    pub fn set_verified_endorser(env: Env, endorser: Address, verified: bool) {
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        let key = DataKey::VerifiedEndorser(endorser.clone());
+        env.storage().persistent().set(&key, &verified);
+        env.storage().persistent().extend_ttl(&key, 100, 100);
+
+        env.events()
+            .publish(("campaign", "endorser_verified"), (endorser, verified));
+    }
+
+    /// Returns true if `endorser` is in the verified endorser registry.
+    pub fn is_verified_endorser(env: Env, endorser: Address) -> bool {
+        env.storage()
+            .persistent()
+            .get(&DataKey::VerifiedEndorser(endorser))
+            .unwrap_or(false)
+    }
+
+    /// Endorse the campaign as a verified endorser.
+    ///
+    /// # Panics
+    /// * If `endorser` is not a verified endorser.
+    /// * If `endorser` has already endorsed the campaign.
+    pub fn endorse_campaign(env: Env, endorser: Address) {
+        endorser.require_auth();
+
+        if !Self::is_verified_endorser(env.clone(), endorser.clone()) {
+            panic!("not a verified endorser");
+        }
+
+        let mut endorsements = Self::endorsements(env.clone());
+        if endorsements.contains(&endorser) {
+            panic!("already endorsed");
+        }
+        endorsements.push_back(endorser.clone());
+        env.storage()
+            .instance()
+            .set(&DataKey::Endorsements, &endorsements);
+
+        env.events().publish(("campaign", "endorsed"), endorser);
+    }
+
+    /// Withdraw an earlier endorsement.
+    ///
+    /// # Panics
+    /// * If `endorser` has not endorsed the campaign.
+    pub fn retract_endorsement(env: Env, endorser: Address) {
+        endorser.require_auth();
+
+        let mut endorsements = Self::endorsements(env.clone());
+        let Some(index) = endorsements.first_index_of(&endorser) else {
+            panic!("not endorsed");
+        };
+        endorsements.remove(index);
+        env.storage()
+            .instance()
+            .set(&DataKey::Endorsements, &endorsements);
+
+        env.events()
+            .publish(("campaign", "endorsement_retracted"), endorser);
+    }
+
+    /// Returns every address currently endorsing the campaign.
+    pub fn endorsements(env: Env) -> Vec<Address> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Endorsements)
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    /// Returns the number of current endorsements.
+    pub fn endorsement_count(env: Env) -> u32 {
+        Self::endorsements(env).len()
+    }
+
+    /// Publish the IPFS hash of the campaign's terms and conditions —
+    /// creator-only.
+    ///
+    /// Once set, `contribute` requires each contributor to have called
+    /// `accept_terms`.
+    pub fn set_terms_hash(env: Env, hash: String) {
+        let creator: Address = env.storage().instance().get(&DataKey::Creator).unwrap();
+        creator.require_auth();
+
+        if hash.is_empty() {
+            panic!("terms hash cannot be empty");
+        }
+        env.storage()
+            .instance()
+            .set(&ComplianceKey::TermsHash, &hash);
+
+        env.events().publish(("campaign", "terms_set"), hash);
+    }
+
+    /// Returns the IPFS hash of the campaign terms, if published.
+    pub fn terms_hash(env: Env) -> Option<String> {
+        env.storage().instance().get(&ComplianceKey::TermsHash)
+    }
+
+    /// Record that `contributor` accepts the campaign terms. Acceptance is
+    /// irrevocable.
+    pub fn accept_terms(env: Env, contributor: Address) {
+        contributor.require_auth();
+
+        let key = ComplianceKey::TermsAccepted(contributor.clone());
+        env.storage().persistent().set(&key, &true);
+        env.storage().persistent().extend_ttl(&key, 100, 100);
+
+        env.events()
+            .publish(("campaign", "terms_accepted"), contributor);
+    }
+
+    /// Returns true if `contributor` has accepted the campaign terms.
+    pub fn has_accepted_terms(env: Env, contributor: Address) -> bool {
+        env.storage()
+            .persistent()
+            .get(&ComplianceKey::TermsAccepted(contributor))
+            .unwrap_or(false)
+    }
+
+    /// Bar `address` from contributing — creator-only.
+    ///
+    /// Its attempts to contribute, schedule or process recurring
+    /// contributions, or receive transferred credit fail with
+    /// `AddressBlocked`. Contributions already made stay in the campaign (and
+    /// remain refundable); the address just cannot add to them.
+    pub fn blacklist_contributor(env: Env, address: Address) {
+        let creator: Address = env.storage().instance().get(&DataKey::Creator).unwrap();
+        creator.require_auth();
+
+        let key = ComplianceKey::Blacklisted(address.clone());
+        env.storage().persistent().set(&key, &true);
+        env.storage().persistent().extend_ttl(&key, 100, 100);
+
+        env.events().publish(("campaign", "blacklisted"), address);
+    }
+
+    /// Lift a blacklist entry — creator-only.
+    pub fn remove_from_blacklist(env: Env, address: Address) {
+        let creator: Address = env.storage().instance().get(&DataKey::Creator).unwrap();
+        creator.require_auth();
+
+        env.storage()
+            .persistent()
+            .remove(&ComplianceKey::Blacklisted(address.clone()));
+
+        env.events().publish(("campaign", "unblacklisted"), address);
+    }
+
+    /// Returns true if the creator has blacklisted `address`.
+    pub fn is_blacklisted(env: Env, address: Address) -> bool {
+        env.storage()
+            .persistent()
+            .get(&ComplianceKey::Blacklisted(address))
+            .unwrap_or(false)
+    }
+
+    /// Allow `addresses` to contribute to an `allowlist_enabled` campaign —
+    /// creator-only.
+    pub fn add_to_allowlist(env: Env, addresses: Vec<Address>) {
+        let creator: Address = env.storage().instance().get(&DataKey::Creator).unwrap();
+        creator.require_auth();
+
+        for address in addresses.iter() {
+            let key = ComplianceKey::Allowlisted(address);
+            env.storage().persistent().set(&key, &true);
+            env.storage().persistent().extend_ttl(&key, 100, 100);
+        }
+
+        env.events().publish(("campaign", "allowlisted"), addresses);
+    }
+
+    /// Take `addresses` off the allowlist — creator-only.
+    ///
+    /// Contributions already made stay in the campaign; the addresses just
+    /// cannot add to them.
+    pub fn remove_from_allowlist(env: Env, addresses: Vec<Address>) {
+        let creator: Address = env.storage().instance().get(&DataKey::Creator).unwrap();
+        creator.require_auth();
+
+        for address in addresses.iter() {
+            env.storage()
+                .persistent()
+                .remove(&ComplianceKey::Allowlisted(address));
+        }
+
+        env.events()
+            .publish(("campaign", "unallowlisted"), addresses);
+    }
+
+    /// Set the Merkle root of the contributor allowlist — creator-only, while
+    /// Active. Once a root is set, addresses not on the per-address allowlist
+    /// must contribute with `contribute_with_proof`; proofs against an
+    /// earlier root stop working.
+    ///
+    /// # Panics
+    /// * If the campaign is not Active.
+    pub fn set_allowlist_root(env: Env, root: BytesN<32>) {
+        let creator: Address = env.storage().instance().get(&DataKey::Creator).unwrap();
+        creator.require_auth();
+
+        let status: Status = env.storage().instance().get(&DataKey::Status).unwrap();
+        if status != Status::Active {
+            panic!("campaign is not active");
+        }
+
+        env.storage()
+            .instance()
+            .set(&ComplianceKey::AllowlistRoot, &root);
+        env.events()
+            .publish(("campaign", "allowlist_root_set"), root);
+    }
+
+    /// Returns the Merkle root of the contributor allowlist, if set.
+    pub fn allowlist_root(env: Env) -> Option<BytesN<32>> {
+        env.storage().instance().get(&ComplianceKey::AllowlistRoot)
+    }
+
+    /// Returns true if `address` is on the contributor allowlist.
+    pub fn is_allowlisted(env: Env, address: Address) -> bool {
+        env.storage()
+            .persistent()
+            .get(&ComplianceKey::Allowlisted(address))
+            .unwrap_or(false)
+    }
+
+    /// Require (or stop requiring) contributors to call `acknowledge_risk`
+    /// before contributing — creator-only.
+    pub fn set_requires_acknowledgment(env: Env, required: bool) {
+        let creator: Address = env.storage().instance().get(&DataKey::Creator).unwrap();
+        creator.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&ComplianceKey::RequiresAcknowledgment, &required);
+
+        env.events()
+            .publish(("campaign", "acknowledgment_required"), required);
+    }
+
+    /// Returns true if contributors must acknowledge the campaign risks.
+    pub fn requires_acknowledgment(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&ComplianceKey::RequiresAcknowledgment)
+            .unwrap_or(false)
+    }
+
+    /// Record that `contributor` acknowledges the campaign risks. The
+    /// acknowledgment covers all of their later contributions.
+    pub fn acknowledge_risk(env: Env, contributor: Address) {
+        contributor.require_auth();
+
+        let key = ComplianceKey::RiskAcknowledged(contributor.clone());
+        env.storage().persistent().set(&key, &true);
+        env.storage().persistent().extend_ttl(&key, 100, 100);
+
+        env.events()
+            .publish(("campaign", "risk_acknowledged"), contributor);
+    }
+
+    /// Returns true if `contributor` has acknowledged the campaign risks.
+    pub fn has_acknowledged(env: Env, contributor: Address) -> bool {
+        env.storage()
+            .persistent()
+            .get(&ComplianceKey::RiskAcknowledged(contributor))
+            .unwrap_or(false)
+    }
+
+    /// Propose changing `parameter` (`deadline`, `goal` or
+    /// `min_contribution`) to `proposed_value`. Returns the proposal id.
+    ///
+    /// # Errors
+    /// * `NotAContributor` if `proposer` has no contribution.
+    ///
+    /// # Panics
+    /// * If governance is disabled or the campaign is not Active.
+    /// * If the parameter is not governable or the value is malformed.
+    pub fn create_proposal(
+        env: Env,
+        proposer: Address,
+        parameter: String,
+        proposed_value: Bytes,
+    ) -> Result<u32, ContractError> {
+        proposer.require_auth();
+
+        if campaign_options(&env).quorum_bps == 0 {
+            panic!("governance is disabled");
+        }
+        let status: Status = env.storage().instance().get(&DataKey::Status).unwrap();
+        if status != Status::Active {
+            panic!("campaign is not active");
+        }
+        if Self::contribution(env.clone(), proposer.clone()) <= 0 {
+            return Err(ContractError::NotAContributor);
+        }
+        if decode_proposal_value(&env, &parameter, &proposed_value).is_none() {
+            panic!("unsupported proposal");
+        }
+
+        let proposal_id: u32 = env
+            .storage()
+            .instance()
+            .get(&GovernanceKey::ProposalCount)
+            .unwrap_or(0);
+        let contributors: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Contributors)
+            .unwrap();
+        let mut weights: Map<Address, i128> = Map::new(&env);
+        for contributor in contributors.iter() {
+            let weight = Self::contribution(env.clone(), contributor.clone());
+            if weight > 0 {
+                weights.set(contributor, weight);
+            }
         }
+        let weights_key = GovernanceKey::VoteWeights(proposal_id);
+        env.storage().persistent().set(&weights_key, &weights);
+        env.storage()
+            .persistent()
+            .extend_ttl(&weights_key, 100, 100);
+        save_proposal(
+            &env,
+            proposal_id,
+            &Proposal {
+                parameter: parameter.clone(),
+                proposed_value,
+                created_at: env.ledger().timestamp(),
+                eligible_weight: Self::total_raised(env.clone()),
+                yes_weight: 0,
+                no_weight: 0,
+                passed: false,
+                applied: false,
+            },
+        );
+        env.storage()
+            .instance()
+            .set(&GovernanceKey::ProposalCount, &(proposal_id + 1));
 
-        contributor.require_auth();
+        env.events().publish(
+            ("campaign", "proposal_created"),
+            (proposal_id, proposer, parameter),
+        );
 
-        let min_contribution: i128 = env
+        Ok(proposal_id)
+    }
+
+    /// Vote on a proposal, weighted by the voter's contribution when the
+    /// proposal was created, or their current contribution if that is lower.
+    /// Credit transferred in after the proposal was created carries no vote,
+    /// so moving it around cannot vote twice.
+    ///
+    /// The proposal passes once its "yes" weight reaches `quorum_bps` of
+    /// the total raised when it was created.
+    ///
+    /// # Errors
+    /// * `NotAContributor` if `voter` had no contribution when the proposal
+    ///   was created, or has none left.
+    ///
+    /// # Panics
+    /// * If the proposal does not exist or was already applied.
+    /// * If `voter` has already voted on it.
+    pub fn vote_proposal(
+        env: Env,
+        voter: Address,
+        proposal_id: u32,
+        approve: bool,
+    ) -> Result<(), ContractError> {
+        voter.require_auth();
+
+        let mut proposal = load_proposal(&env, proposal_id);
+        if proposal.applied {
+            panic!("proposal already applied");
+        }
+        let weights: Map<Address, i128> = env
             .storage()
-            .instance()
-            .get(&DataKey::MinContribution)
-            .unwrap();
-        if amount < min_contribution {
-            panic!("amount below minimum");
+            .persistent()
+            .get(&GovernanceKey::VoteWeights(proposal_id))
+            .unwrap_or_else(|| Map::new(&env));
+        let weight = weights
+            .get(voter.clone())
+            .unwrap_or(0)
+            .min(Self::contribution(env.clone(), voter.clone()));
+        if weight <= 0 {
+            return Err(ContractError::NotAContributor);
         }
 
-        let deadline: u64 = env.storage().instance().get(&DataKey::Deadline).unwrap();
-        if env.ledger().timestamp() > deadline {
-            return Err(ContractError::CampaignEnded);
+        let voted_key = GovernanceKey::Voted(proposal_id, voter.clone());
+        if env.storage().persistent().has(&voted_key) {
+            panic!("already voted");
+        }
+        env.storage().persistent().set(&voted_key, &approve);
+        env.storage().persistent().extend_ttl(&voted_key, 100, 100);
+
+        if approve {
+            proposal.yes_weight = proposal
+                .yes_weight
+                .checked_add(weight)
+                .ok_or(ContractError::Overflow)?;
+        } else {
+            proposal.no_weight = proposal
+                .no_weight
+                .checked_add(weight)
+                .ok_or(ContractError::Overflow)?;
         }
 
-        let total: i128 = env.storage().instance().get(&DataKey::TotalRaised).unwrap();
-        let hard_cap: i128 = env.storage().instance().get(&DataKey::HardCap).unwrap();
+        let quorum = proposal
+            .eligible_weight
+            .checked_mul(campaign_options(&env).quorum_bps as i128)
+            .ok_or(ContractError::Overflow)?;
+        let yes = proposal
+            .yes_weight
+            .checked_mul(10_000)
+            .ok_or(ContractError::Overflow)?;
+        if !proposal.passed && yes >= quorum {
+            proposal.passed = true;
+            env.events()
+                .publish(("campaign", "proposal_passed"), proposal_id);
+        }
+        save_proposal(&env, proposal_id, &proposal);
+
+        env.events().publish(
+            ("campaign", "proposal_voted"),
+            (proposal_id, voter, approve, weight),
+        );
+
+        Ok(())
+    }
+
+    /// Apply a passed proposal — creator-only.
+    ///
+    /// The new value must pass the checks `initialize` applies.
+    ///
+    /// # Errors
+    /// * `InvalidGoal` if a new goal is not positive.
+    /// * `InvalidHardCap` if a new goal is above the hard cap.
+    /// * `InvalidMinContribution` if the minimum contribution would be
+    ///   negative or above the goal.
+    /// * `InvalidDeadline` if a new deadline is not in the future.
+    /// * `InvalidDuration` if a new deadline is less than `min_duration` or
+    ///   more than `max_duration` seconds away.
+    ///
+    /// # Panics
+    /// * If the campaign is not Active.
+    /// * If the proposal does not exist, has not passed or was already applied.
+    /// * If a deadline proposal is applied to a campaign with a sequence
+    ///   deadline.
+    pub fn apply_proposal(env: Env, proposal_id: u32) -> Result<(), ContractError> {
+        let creator: Address = env.storage().instance().get(&DataKey::Creator).unwrap();
+        creator.require_auth();
 
-        if total >= hard_cap {
-            return Err(ContractError::HardCapExceeded);
+        let status: Status = env.storage().instance().get(&DataKey::Status).unwrap();
+        if status != Status::Active {
+            panic!("campaign is not active");
         }
 
-        let headroom = hard_cap - total;
-        let effective_amount = if amount <= headroom { amount } else { headroom };
+        let mut proposal = load_proposal(&env, proposal_id);
+        if !proposal.passed {
+            panic!("proposal has not passed");
+        }
+        if proposal.applied {
+            panic!("proposal already applied");
+        }
 
-        let token_address: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let token_client = token::Client::new(&env, &token_address);
+        let value = decode_proposal_value(&env, &proposal.parameter, &proposal.proposed_value)
+            .unwrap_or_else(|| panic!("unsupported proposal"));
+        if proposal.parameter == String::from_str(&env, "deadline") {
+            deadline_timestamp(&env);
+            let deadline = Deadline::Timestamp(value as u64);
+            validate_duration(deadline.seconds_remaining(&env))?;
+            env.storage().instance().set(&DataKey::Deadline, &deadline);
+        } else {
+            let hard_cap: i128 = env.storage().instance().get(&DataKey::HardCap).unwrap();
+            let mut goal: i128 = env.storage().instance().get(&DataKey::Goal).unwrap();
+            let mut min_contribution: i128 = env
+                .storage()
+                .instance()
+                .get(&DataKey::MinContribution)
+                .unwrap();
+            let key = if proposal.parameter == String::from_str(&env, "goal") {
+                goal = value;
+                DataKey::Goal
+            } else {
+                min_contribution = value;
+                DataKey::MinContribution
+            };
+            validate_amounts(goal, hard_cap, min_contribution)?;
+            env.storage().instance().set(&key, &value);
+        }
 
-        // Transfer tokens from the contributor to this contract.
-        token_client.transfer(
-            &contributor,
-            &env.current_contract_address(),
-            &effective_amount,
-        );
+        proposal.applied = true;
+        save_proposal(&env, proposal_id, &proposal);
 
-        // Update the contributor's running total with overflow protection.
-        let contribution_key = DataKey::Contribution(contributor.clone());
-        let prev: i128 = env
-            .storage()
-            .persistent()
-            .get(&contribution_key)
-            .unwrap_or(0);
+        env.events().publish(
+            ("campaign", "proposal_applied"),
+            (proposal_id, proposal.parameter),
+        );
 
-        let new_contribution = prev
-            .checked_add(effective_amount)
-            .ok_or(ContractError::Overflow)?;
+        Ok(())
+    }
 
+    /// Returns proposal `proposal_id`, if it exists.
+    pub fn proposal(env: Env, proposal_id: u32) -> Option<Proposal> {
         env.storage()
             .persistent()
-            .set(&contribution_key, &new_contribution);
-        env.storage()
-            .persistent()
-            .extend_ttl(&contribution_key, 100, 100);
+            .get(&GovernanceKey::Proposal(proposal_id))
+    }
 
-        // Update the global total raised with overflow protection.
-        let new_total = total
-            .checked_add(effective_amount)
-            .ok_or(ContractError::Overflow)?;
+    /// Replace the campaign's links — creator-only, at any time.
+    ///
+    /// Any field may be an empty string to leave that link unset.
+    pub fn set_links(env: Env, links: CampaignLinks) {
+        let creator: Address = env.storage().instance().get(&DataKey::Creator).unwrap();
+        creator.require_auth();
 
-        env.storage()
-            .instance()
-            .set(&DataKey::TotalRaised, &new_total);
+        env.storage().instance().set(&MetadataKey::Links, &links);
 
-        if new_total == hard_cap {
-            env.events()
-                .publish(("campaign", "hard_cap_reached"), hard_cap);
+        env.events()
+            .publish(("campaign", "links_updated"), LinksUpdatedEvent { links });
+    }
+
+    /// Store the IPFS CID of the campaign's media — creator-only, while the
+    /// campaign is Active.
+    ///
+    /// # Panics
+    /// * If the campaign is not Active.
+    /// * If `hash` is not 46–59 characters long.
+    pub fn set_media_hash(env: Env, hash: String) {
+        let creator: Address = env.storage().instance().get(&DataKey::Creator).unwrap();
+        creator.require_auth();
+
+        let status: Status = env.storage().instance().get(&DataKey::Status).unwrap();
+        if status != Status::Active {
+            panic!("campaign is not active");
         }
+        if hash.len() < MIN_MEDIA_HASH_LEN || hash.len() > MAX_MEDIA_HASH_LEN {
+            panic!("invalid media hash");
+        }
+        env.storage().instance().set(&MetadataKey::MediaHash, &hash);
 
-        // Track contributor address if new.
-        let mut contributors: Vec<Address> = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Contributors)
-            .unwrap();
-        if !contributors.contains(&contributor) {
-            contributors.push_back(contributor.clone());
-            env.storage()
-                .persistent()
-                .set(&DataKey::Contributors, &contributors);
+        env.events().publish(("campaign", "media_set"), hash);
+    }
+
+    /// Feature the campaign until `expires_at` — callable only by the
+    /// deployer, normally the factory acting for the platform admin.
+    ///
+    /// An `expires_at` at or before the current time clears the flag.
+    pub fn set_featured(env: Env, expires_at: u64) {
+        let deployer = Self::deployer(env.clone());
+        deployer.require_auth();
+
+        if expires_at <= env.ledger().timestamp() {
+            env.storage().instance().remove(&MetadataKey::FeaturedUntil);
+        } else {
             env.storage()
-                .persistent()
-                .extend_ttl(&DataKey::Contributors, 100, 100);
+                .instance()
+                .set(&MetadataKey::FeaturedUntil, &expires_at);
         }
+        env.events().publish(("campaign", "featured"), expires_at);
+    }
 
-        // Emit contribution event
-        env.events()
-            .publish(("campaign", "contributed"), (contributor.clone(), effective_amount));
+    /// Returns true while the campaign is featured. An expired flag is
+    /// cleared here.
+    pub fn is_featured(env: Env) -> bool {
+        let Some(featured_until) = env
+            .storage()
+            .instance()
+            .get::<_, u64>(&MetadataKey::FeaturedUntil)
+        else {
+            return false;
+        };
+        if env.ledger().timestamp() < featured_until {
+            return true;
+        }
+        env.storage().instance().remove(&MetadataKey::FeaturedUntil);
+        false
+    }
 
-        // Update referral tally if referral provided
-        if let Some(referrer) = referral {
-            if referrer != contributor {
-                let referral_key = DataKey::ReferralTally(referrer.clone());
-                let current_tally: i128 = env
-                    .storage()
-                    .persistent()
-                    .get(&referral_key)
-                    .unwrap_or(0);
-                
-                let new_tally = current_tally
-                    .checked_add(effective_amount)
-                    .ok_or(ContractError::Overflow)?;
-                
-                env.storage()
-                    .persistent()
-                    .set(&referral_key, &new_tally);
-                env.storage()
-                    .persistent()
-                    .extend_ttl(&referral_key, 100, 100);
+    /// Returns the ledger timestamp until which the campaign is featured, if
+    /// a flag is stored.
+    pub fn featured_until(env: Env) -> Option<u64> {
+        env.storage().instance().get(&MetadataKey::FeaturedUntil)
+    }
 
-                // Emit referral event
-                env.events()
-                    .publish(("campaign", "referral"), (referrer, contributor, effective_amount));
-            }
-        }
+    /// Returns the IPFS CID of the campaign's media, if set.
+    pub fn media_hash(env: Env) -> Option<String> {
+        env.storage().instance().get(&MetadataKey::MediaHash)
+    }
 
-        // Update last contribution time for rate limiting
-        env.storage().persistent().set(&last_time_key, &now);
-        env.storage()
-            .persistent()
-            .extend_ttl(&last_time_key, 100, 100);
+    /// File the campaign under `category` — creator-only, until the
+    /// campaign ends.
+    ///
+    /// # Errors
+    /// * `CampaignEnded` if the deadline has passed.
+    ///
+    /// # Panics
+    /// * If the campaign is not Active.
+    pub fn set_category(env: Env, category: CampaignCategory) -> Result<(), ContractError> {
+        let creator: Address = env.storage().instance().get(&DataKey::Creator).unwrap();
+        creator.require_auth();
+
+        let status: Status = env.storage().instance().get(&DataKey::Status).unwrap();
+        if status != Status::Active {
+            panic!("campaign is not active");
+        }
+        if campaign_deadline(&env).has_passed(&env) {
+            return Err(ContractError::CampaignEnded);
+        }
 
+        env.storage().instance().set(&DataKey::Category, &category);
+        env.events().publish(("campaign", "category_set"), category);
         Ok(())
     }
 
-    /// Pledge tokens to the campaign without transferring them immediately.
+    /// Update campaign metadata — only callable by the creator while the
+    /// campaign is still Active.
     ///
-    /// The pledger must authorize the call. Pledges are recorded off-chain
-    /// and only collected if the goal is met after the deadline.
-    pub fn pledge(env: Env, pledger: Address, amount: i128) -> Result<(), ContractError> {
-        pledger.require_auth();
-
-        let min_contribution: i128 = env
-            .storage()
-            .instance()
-            .get(&DataKey::MinContribution)
-            .unwrap();
-        if amount < min_contribution {
-            panic!("amount below minimum");
+    /// # Arguments
+    /// * `creator`     – The campaign creator's address (for authentication).
+    /// * `title`       – Optional new title (None to keep existing).
+    /// * `description` – Optional new description (None to keep existing).
+    /// * `socials`    – Optional new social links (None to keep existing).
+    pub fn update_metadata(
+        env: Env,
+        creator: Address,
+        title: Option<String>,
+        description: Option<String>,
+        socials: Option<String>,
+    ) {
+        // Check campaign is active.
+        let status: Status = env.storage().instance().get(&DataKey::Status).unwrap();
+        if status != Status::Active {
+            panic!("campaign is not active");
         }
 
-        let deadline: u64 = env.storage().instance().get(&DataKey::Deadline).unwrap();
-        if env.ledger().timestamp() > deadline {
-            return Err(ContractError::CampaignEnded);
+        // Require creator authentication and verify caller is the creator.
+        let stored_creator: Address = env.storage().instance().get(&DataKey::Creator).unwrap();
+        if creator != stored_creator {
+            panic!("not authorized");
         }
+        creator.require_auth();
 
-        // Update the pledger's running total.
-        let pledge_key = DataKey::Pledge(pledger.clone());
-        let prev: i128 = env.storage().persistent().get(&pledge_key).unwrap_or(0);
-        env.storage()
-            .persistent()
-            .set(&pledge_key, &(prev + amount));
-        env.storage().persistent().extend_ttl(&pledge_key, 100, 100);
+        // Track which fields were updated for the event.
+        let mut updated_fields: Vec<Symbol> = Vec::new(&env);
 
-        // Update the global total pledged.
-        let total_pledged: i128 = env
-            .storage()
-            .instance()
-            .get(&DataKey::TotalPledged)
-            .unwrap_or(0);
-        env.storage()
-            .instance()
-            .set(&DataKey::TotalPledged, &(total_pledged + amount));
+        // Update title if provided.
+        if let Some(new_title) = title {
+            env.storage().instance().set(&DataKey::Title, &new_title);
+            updated_fields.push_back(Symbol::new(&env, "title"));
+        }
 
-        // Track pledger address if new.
-        let mut pledgers: Vec<Address> = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Pledgers)
-            .unwrap_or_else(|| Vec::new(&env));
-        if !pledgers.contains(&pledger) {
-            pledgers.push_back(pledger.clone());
-            env.storage()
-                .persistent()
-                .set(&DataKey::Pledgers, &pledgers);
+        // Update description if provided.
+        if let Some(new_description) = description {
             env.storage()
-                .persistent()
-                .extend_ttl(&DataKey::Pledgers, 100, 100);
+                .instance()
+                .set(&DataKey::Description, &new_description);
+            updated_fields.push_back(Symbol::new(&env, "description"));
         }
 
-        // Emit pledge event
-        env.events()
-            .publish(("campaign", "pledged"), (pledger, amount));
+        // Update social links if provided.
+        if let Some(new_socials) = socials {
+            env.storage()
+                .instance()
+                .set(&DataKey::SocialLinks, &new_socials);
+            updated_fields.push_back(Symbol::new(&env, "socials"));
+        }
 
-        Ok(())
+        // Emit metadata_updated event with the list of updated field names.
+        env.events().publish(
+            (
+                Symbol::new(&env, "campaign"),
+                Symbol::new(&env, "metadata_updated"),
+            ),
+            updated_fields,
+        );
     }
 
-    /// Collect all pledges after the deadline when the goal is met.
+    /// Update the campaign deadline — only callable by the creator while the
+    /// campaign is still Active.
+    ///
+    /// # Arguments
+    /// * `new_deadline` – The new deadline as a ledger timestamp (must be greater than current deadline).
     ///
-    /// This function transfers tokens from all pledgers to the contract.
-    /// Only callable after the deadline and when the combined total of
-    /// contributions and pledges meets or exceeds the goal.
-    pub fn collect_pledges(env: Env) -> Result<(), ContractError> {
+    /// # Panics
+    /// * If the campaign is not Active.
+    /// * If new_deadline is less than or equal to the current deadline.
+    /// * If new_deadline is more than `max_duration` seconds from now.
+    /// * If the campaign has a sequence deadline.
+    pub fn update_deadline(env: Env, new_deadline: u64) {
+        // Check campaign is active.
         let status: Status = env.storage().instance().get(&DataKey::Status).unwrap();
         if status != Status::Active {
             panic!("campaign is not active");
         }
 
-        let deadline: u64 = env.storage().instance().get(&DataKey::Deadline).unwrap();
-        if env.ledger().timestamp() <= deadline {
-            return Err(ContractError::CampaignStillActive);
-        }
+        // Require creator authentication.
+        let creator: Address = env.storage().instance().get(&DataKey::Creator).unwrap();
+        creator.require_auth();
 
-        let goal: i128 = env.storage().instance().get(&DataKey::Goal).unwrap();
-        let total_raised: i128 = env.storage().instance().get(&DataKey::TotalRaised).unwrap();
-        let total_pledged: i128 = env
-            .storage()
-            .instance()
-            .get(&DataKey::TotalPledged)
-            .unwrap_or(0);
+        // Get the current deadline.
+        let current_deadline = deadline_timestamp(&env);
 
-        // Check if combined total meets the goal
-        if total_raised + total_pledged < goal {
-            return Err(ContractError::GoalNotReached);
+        // Ensure new_deadline is greater than current_deadline (only extensions allowed).
+        if new_deadline <= current_deadline {
+            panic!("new deadline must be after current deadline");
         }
-
-        let token_address: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let token_client = token::Client::new(&env, &token_address);
-
-        let pledgers: Vec<Address> = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Pledgers)
-            .unwrap_or_else(|| Vec::new(&env));
-
-        // Collect pledges from all pledgers
-        for pledger in pledgers.iter() {
-            let pledge_key = DataKey::Pledge(pledger.clone());
-            let amount: i128 = env.storage().persistent().get(&pledge_key).unwrap_or(0);
-            if amount > 0 {
-                // Transfer tokens from pledger to contract
-                token_client.transfer(&pledger, &env.current_contract_address(), &amount);
-
-                // Clear the pledge
-                env.storage().persistent().set(&pledge_key, &0i128);
-                env.storage().persistent().extend_ttl(&pledge_key, 100, 100);
-            }
+        if new_deadline > env.ledger().timestamp().saturating_add(MAX_DURATION) {
+            panic!("deadline exceeds maximum duration");
         }
 
-        // Update total raised to include collected pledges
+        // Update the deadline.
         env.storage()
             .instance()
-            .set(&DataKey::TotalRaised, &(total_raised + total_pledged));
+            .set(&DataKey::Deadline, &Deadline::Timestamp(new_deadline));
 
-        // Reset total pledged
-        env.storage().instance().set(&DataKey::TotalPledged, &0i128);
-
-        // Emit pledges collected event
-        env.events()
-            .publish(("campaign", "pledges_collected"), total_pledged);
-
-        Ok(())
+        // Emit deadline_updated event with old and new deadline values.
+        env.events().publish(
+            ("campaign", "deadline_updated"),
+            (current_deadline, new_deadline),
+        );
     }
 
-    /// Withdraw raised funds — only callable by the creator after the
-    /// deadline, and only if the goal has been met.
-    ///
-    /// If a platform fee is configured, deducts the fee and transfers it to
-    /// the platform address, then sends the remainder to the creator.
-    pub fn withdraw(env: Env) -> Result<(), ContractError> {
-        let paused: bool = env
-            .storage()
-            .instance()
-            .get(&DataKey::Paused)
-            .unwrap_or(false);
-        if paused {
-            return Err(ContractError::ContractPaused);
-        }
-
-        let status: Status = env.storage().instance().get(&DataKey::Status).unwrap();
-        if status != Status::Active {
-            panic!("campaign is not active");
-        }
+    // ── View helpers ────────────────────────────────────────────────────
 
+    /// Add a roadmap item to the campaign timeline.
+    ///
+    /// Only the creator can add roadmap items. The date must be in the future,
+    /// the completion `deadline` must not precede it, and the description must
+    /// not be empty.
+    pub fn add_roadmap_item(env: Env, date: u64, deadline: u64, description: String) {
         let creator: Address = env.storage().instance().get(&DataKey::Creator).unwrap();
         creator.require_auth();
 
-        let deadline: u64 = env.storage().instance().get(&DataKey::Deadline).unwrap();
-        if env.ledger().timestamp() <= deadline {
-            return Err(ContractError::CampaignStillActive);
+        let current_timestamp = env.ledger().timestamp();
+        if date <= current_timestamp {
+            panic!("date must be in the future");
         }
 
-        let goal: i128 = env.storage().instance().get(&DataKey::Goal).unwrap();
-        let total: i128 = env.storage().instance().get(&DataKey::TotalRaised).unwrap();
-        if total < goal {
-            return Err(ContractError::GoalNotReached);
+        if deadline < date {
+            panic!("deadline must not precede date");
         }
 
-        let token_address: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let token_client = token::Client::new(&env, &token_address);
+        if description.is_empty() {
+            panic!("description cannot be empty");
+        }
 
-        // Calculate and transfer platform fee if configured.
-        let platform_config: Option<PlatformConfig> =
-            env.storage().instance().get(&DataKey::PlatformConfig);
+        let mut roadmap: Vec<RoadmapItem> = env
+            .storage()
+            .instance()
+            .get(&DataKey::Roadmap)
+            .unwrap_or_else(|| Vec::new(&env));
 
-        let creator_payout = if let Some(config) = platform_config {
-            // Calculate fee using checked arithmetic to prevent overflow.
-            let fee = total
-                .checked_mul(config.fee_bps as i128)
-                .expect("fee calculation overflow")
-                .checked_div(10_000)
-                .expect("fee division by zero");
+        let item = RoadmapItem {
+            date,
+            deadline,
+            description: description.clone(),
+            proof: None,
+        };
 
-            // Transfer fee to platform.
-            token_client.transfer(&env.current_contract_address(), &config.address, &fee);
+        let index = roadmap.len();
+        roadmap.push_back(item);
+        env.storage().instance().set(&DataKey::Roadmap, &roadmap);
 
-            // Emit event with fee details.
-            env.events()
-                .publish(("campaign", "fee_transferred"), (&config.address, fee));
+        env.events().publish(
+            ("campaign", "roadmap_item_added"),
+            RoadmapItemAddedEvent {
+                index,
+                date,
+                deadline,
+                description,
+            },
+        );
+    }
 
-            // Calculate creator payout.
-            total.checked_sub(fee).expect("creator payout underflow")
-        } else {
-            total
-        };
+    /// Mark roadmap milestone `index` complete with a pointer to its
+    /// evidence — creator-only.
+    ///
+    /// # Panics
+    /// * If there is no milestone at `index`.
+    /// * If the milestone is already complete, or its deadline has passed.
+    /// * If `proof` is empty.
+    pub fn submit_milestone_proof(env: Env, index: u32, proof: String) {
+        let creator: Address = env.storage().instance().get(&DataKey::Creator).unwrap();
+        creator.require_auth();
 
-        // Transfer remainder to creator.
-        token_client.transfer(&env.current_contract_address(), &creator, &creator_payout);
+        let mut roadmap = Self::roadmap(env.clone());
+        let mut item = roadmap.get(index).expect("milestone not found");
+        match milestone_status(&env, &item) {
+            MilestoneStatus::Complete => panic!("milestone already complete"),
+            MilestoneStatus::Missed => panic!("milestone deadline passed"),
+            MilestoneStatus::Pending => {}
+        }
+        if proof.is_empty() {
+            panic!("proof cannot be empty");
+        }
 
-        env.storage().instance().set(&DataKey::TotalRaised, &0i128);
-        env.storage()
-            .instance()
-            .set(&DataKey::Status, &Status::Successful);
+        item.proof = Some(proof.clone());
+        roadmap.set(index, item);
+        env.storage().instance().set(&DataKey::Roadmap, &roadmap);
 
-        // Emit withdrawal event
         env.events()
-            .publish(("campaign", "withdrawn"), (creator.clone(), total));
+            .publish(("campaign", "milestone_completed"), (index, proof));
+    }
 
-        Ok(())
+    /// Returns the status of roadmap milestone `index`. A milestone counts as
+    /// missed as soon as its deadline passes without proof.
+    ///
+    /// # Panics
+    /// * If there is no milestone at `index`.
+    pub fn milestone_status(env: Env, index: u32) -> MilestoneStatus {
+        let item = Self::roadmap(env.clone())
+            .get(index)
+            .expect("milestone not found");
+        milestone_status(&env, &item)
     }
 
-    /// Refund all contributors — callable by anyone after the deadline
-    /// if the goal was **not** met.
-    pub fn refund(env: Env) -> Result<(), ContractError> {
-        let paused: bool = env
-            .storage()
-            .instance()
-            .get(&DataKey::Paused)
-            .unwrap_or(false);
-        if paused {
-            return Err(ContractError::ContractPaused);
-        }
+    /// Returns the number of roadmap milestones whose deadline passed without
+    /// proof.
+    pub fn missed_milestone_count(env: Env) -> u32 {
+        Self::roadmap(env.clone())
+            .iter()
+            .filter(|item| milestone_status(&env, item) == MilestoneStatus::Missed)
+            .count() as u32
+    }
 
-        let status: Status = env.storage().instance().get(&DataKey::Status).unwrap();
-        if status != Status::Active {
-            panic!("campaign is not active");
-        }
+    /// Lock collateral against the roadmap — creator-only.
+    ///
+    /// Pulls `amount` of the campaign token from the creator. Each missed
+    /// milestone forfeits `slash_bps` of the posted amount to contributors,
+    /// who claim it like distributed profit.
+    ///
+    /// # Panics
+    /// * If `amount` is not positive, or `slash_bps` is zero or above 10 000.
+    /// * If collateral has already been posted.
+    pub fn post_collateral(env: Env, amount: i128, slash_bps: u32) {
+        let creator: Address = env.storage().instance().get(&DataKey::Creator).unwrap();
+        creator.require_auth();
 
-        let deadline: u64 = env.storage().instance().get(&DataKey::Deadline).unwrap();
-        if env.ledger().timestamp() <= deadline {
-            return Err(ContractError::CampaignStillActive);
+        if amount <= 0 {
+            panic!("amount must be positive");
         }
-
-        let goal: i128 = env.storage().instance().get(&DataKey::Goal).unwrap();
-        let total: i128 = env.storage().instance().get(&DataKey::TotalRaised).unwrap();
-        if total >= goal {
-            return Err(ContractError::GoalReached);
+        if slash_bps == 0 || slash_bps > 10_000 {
+            panic!("slash bps must be between 1 and 10000");
+        }
+        if env.storage().instance().has(&CollateralKey::Bond) {
+            panic!("collateral already posted");
         }
 
         let token_address: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let token_client = token::Client::new(&env, &token_address);
+        token::Client::new(&env, &token_address).transfer(
+            &creator,
+            &env.current_contract_address(),
+            &amount,
+        );
+        env.storage()
+            .instance()
+            .set(&CollateralKey::Bond, &(amount, slash_bps));
+        env.storage()
+            .instance()
+            .set(&CollateralKey::Remaining, &amount);
 
-        let contributors: Vec<Address> = env
+        env.events()
+            .publish(("campaign", "collateral_posted"), (amount, slash_bps));
+    }
+
+    /// Forfeit collateral for every missed milestone not yet slashed. Anyone
+    /// may call this. Returns the amount slashed.
+    pub fn slash_missed_milestones(env: Env) -> Result<i128, ContractError> {
+        let Some((amount, slash_bps)) = env
             .storage()
-            .persistent()
-            .get(&DataKey::Contributors)
-            .unwrap();
+            .instance()
+            .get::<_, (i128, u32)>(&CollateralKey::Bond)
+        else {
+            return Ok(0);
+        };
+        let per_milestone = amount
+            .checked_mul(slash_bps as i128)
+            .ok_or(ContractError::Overflow)?
+            / 10_000;
+        let mut remaining: i128 = env
+            .storage()
+            .instance()
+            .get(&CollateralKey::Remaining)
+            .unwrap_or(0);
 
-        for contributor in contributors.iter() {
-            let contribution_key = DataKey::Contribution(contributor.clone());
-            let amount: i128 = env
-                .storage()
-                .persistent()
-                .get(&contribution_key)
-                .unwrap_or(0);
-            if amount > 0 {
-                token_client.transfer(&env.current_contract_address(), &contributor, &amount);
-                env.storage().persistent().set(&contribution_key, &0i128);
-                env.storage()
-                    .persistent()
-                    .extend_ttl(&contribution_key, 100, 100);
+        let mut slashed = 0i128;
+        for (index, item) in Self::roadmap(env.clone()).iter().enumerate() {
+            let key = CollateralKey::Slashed(index as u32);
+            if milestone_status(&env, &item) != MilestoneStatus::Missed
+                || env.storage().instance().has(&key)
+            {
+                continue;
             }
+            env.storage().instance().set(&key, &true);
+            let slash = per_milestone.min(remaining);
+            remaining -= slash;
+            slashed += slash;
+        }
+        if slashed == 0 {
+            return Ok(0);
         }
 
-        env.storage().instance().set(&DataKey::TotalRaised, &0i128);
         env.storage()
             .instance()
-            .set(&DataKey::Status, &Status::Refunded);
+            .set(&CollateralKey::Remaining, &remaining);
+        add_to_profit_pool(&env, slashed)?;
 
-        Ok(())
+        env.events()
+            .publish(("campaign", "collateral_slashed"), slashed);
+        Ok(slashed)
     }
 
-    /// Cancel the campaign and refund all contributors — callable only by
-    /// the creator while the campaign is still Active.
-    pub fn cancel(env: Env) {
-        let status: Status = env.storage().instance().get(&DataKey::Status).unwrap();
-        if status != Status::Active {
-            panic!("campaign is not active");
-        }
-
+    /// Return the unslashed collateral to the creator once no milestone is
+    /// pending. Missed milestones are slashed first. Returns the amount
+    /// released.
+    ///
+    /// # Panics
+    /// * If a roadmap milestone is still pending.
+    pub fn release_collateral(env: Env) -> Result<i128, ContractError> {
         let creator: Address = env.storage().instance().get(&DataKey::Creator).unwrap();
         creator.require_auth();
 
-        let token_address: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let token_client = token::Client::new(&env, &token_address);
-
-        let contributors: Vec<Address> = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Contributors)
-            .unwrap();
-
-        for contributor in contributors.iter() {
-            let contribution_key = DataKey::Contribution(contributor.clone());
-            let amount: i128 = env
-                .storage()
-                .persistent()
-                .get(&contribution_key)
-                .unwrap_or(0);
-            if amount > 0 {
-                token_client.transfer(&env.current_contract_address(), &contributor, &amount);
-                env.storage().persistent().set(&contribution_key, &0i128);
-                env.storage()
-                    .persistent()
-                    .extend_ttl(&contribution_key, 100, 100);
-            }
+        if Self::roadmap(env.clone())
+            .iter()
+            .any(|item| milestone_status(&env, &item) == MilestoneStatus::Pending)
+        {
+            panic!("milestones still pending");
         }
+        Self::slash_missed_milestones(env.clone())?;
 
-        env.storage().instance().set(&DataKey::TotalRaised, &0i128);
+        let remaining = Self::collateral(env.clone());
+        if remaining == 0 {
+            return Ok(0);
+        }
         env.storage()
             .instance()
-            .set(&DataKey::Status, &Status::Cancelled);
+            .set(&CollateralKey::Remaining, &0i128);
+        let token_address: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        token::Client::new(&env, &token_address).transfer(
+            &env.current_contract_address(),
+            &creator,
+            &remaining,
+        );
+
+        env.events()
+            .publish(("campaign", "collateral_released"), remaining);
+        Ok(remaining)
     }
 
-    /// Upgrade the contract to a new WASM implementation — admin-only.
-    ///
-    /// This function allows the designated admin to upgrade the contract's WASM code
-    /// without changing the contract's address or storage. The new WASM hash must be
-    /// provided and the caller must be authorized as the admin.
-    ///
-    /// # Arguments
-    /// * `new_wasm_hash` – The SHA-256 hash of the new WASM binary to deploy.
-    ///
-    /// # Panics
-    /// * If the caller is not the admin.
-    pub fn upgrade(env: Env, new_wasm_hash: soroban_sdk::BytesN<32>) {
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
+    /// Returns the collateral still held for the creator.
+    pub fn collateral(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&CollateralKey::Remaining)
+            .unwrap_or(0)
+    }
 
-        env.deployer().update_current_contract_wasm(new_wasm_hash);
+    /// Returns the full ordered list of roadmap items.
+    pub fn roadmap(env: Env) -> Vec<RoadmapItem> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Roadmap)
+            .unwrap_or_else(|| Vec::new(&env))
     }
 
-    /// Pause or unpause the contract — creator-only.
-    ///
-    /// When paused, all contributions, withdrawals, and refunds are blocked.
-    /// This is a security mechanism to halt operations in case of detected
-    /// vulnerabilities or external threats.
+    /// Add a stretch goal milestone to the campaign.
     ///
-    /// # Arguments
-    /// * `paused` – True to pause, false to unpause.
-    pub fn set_paused(env: Env, paused: bool) {
+    /// Only the creator can add stretch goals. The milestone must be greater
+    /// than the primary goal.
+    pub fn add_stretch_goal(env: Env, milestone: i128) {
         let creator: Address = env.storage().instance().get(&DataKey::Creator).unwrap();
         creator.require_auth();
 
-        env.storage().instance().set(&DataKey::Paused, &paused);
+        let goal = current_goal(&env);
+        if milestone <= goal {
+            panic!("stretch goal must be greater than primary goal");
+        }
 
-        let event_name = if paused { "paused" } else { "unpaused" };
-        env.events().publish(("campaign", event_name), ());
+        let mut stretch_goals: Vec<i128> = env
+            .storage()
+            .instance()
+            .get(&DataKey::StretchGoals)
+            .unwrap_or_else(|| Vec::new(&env));
+
+        stretch_goals.push_back(milestone);
+        env.storage()
+            .instance()
+            .set(&DataKey::StretchGoals, &stretch_goals);
     }
 
-    /// Update campaign metadata — only callable by the creator while the
-    /// campaign is still Active.
+    /// Add a reward tier (creator only). Rejects min_amount <= 0.
     ///
-    /// # Arguments
-    /// * `creator`     – The campaign creator's address (for authentication).
-    /// * `title`       – Optional new title (None to keep existing).
-    /// * `description` – Optional new description (None to keep existing).
-    /// * `socials`    – Optional new social links (None to keep existing).
-    pub fn update_metadata(
+    /// `max_claims` limits how many contributors can hold the tier; `None`
+    /// means unlimited. Tiers are claimed when contributing, so a tier added
+    /// after someone contributed only applies to their later contributions.
+    pub fn add_reward_tier(
         env: Env,
         creator: Address,
-        title: Option<String>,
-        description: Option<String>,
-        socials: Option<String>,
+        name: String,
+        min_amount: i128,
+        max_claims: Option<u32>,
     ) {
-        // Check campaign is active.
         let status: Status = env.storage().instance().get(&DataKey::Status).unwrap();
         if status != Status::Active {
             panic!("campaign is not active");
         }
 
-        // Require creator authentication and verify caller is the creator.
         let stored_creator: Address = env.storage().instance().get(&DataKey::Creator).unwrap();
         if creator != stored_creator {
             panic!("not authorized");
         }
         creator.require_auth();
 
-        // Track which fields were updated for the event.
-        let mut updated_fields: Vec<Symbol> = Vec::new(&env);
+        if min_amount <= 0 {
+            panic!("min_amount must be greater than 0");
+        }
 
-        // Update title if provided.
-        if let Some(new_title) = title {
-            env.storage().instance().set(&DataKey::Title, &new_title);
-            updated_fields.push_back(Symbol::new(&env, "title"));
+        let mut tiers: Vec<RewardTier> = env
+            .storage()
+            .instance()
+            .get(&DataKey::RewardTiers)
+            .unwrap_or_else(|| Vec::new(&env));
+
+        tiers.push_back(RewardTier {
+            name: name.clone(),
+            min_amount,
+            max_claims,
+            claimed: 0,
+        });
+        env.storage().instance().set(&DataKey::RewardTiers, &tiers);
+
+        env.events()
+            .publish(("campaign", "reward_tier_added"), (name, min_amount));
+    }
+
+    /// Returns the full ordered list of reward tiers.
+    pub fn reward_tiers(env: Env) -> Vec<RewardTier> {
+        env.storage()
+            .instance()
+            .get(&DataKey::RewardTiers)
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    /// Returns the highest tier name the user's contribution qualifies for,
+    /// or None if the user has not contributed or no tiers are defined.
+    /// Tiers are evaluated by min_amount descending (highest qualifying tier wins).
+    pub fn get_user_tier(env: Env, user: Address) -> Option<String> {
+        let contribution: i128 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Contribution(user))
+            .unwrap_or(0);
+
+        if contribution <= 0 {
+            return None;
         }
 
-        // Update description if provided.
-        if let Some(new_description) = description {
-            env.storage()
-                .instance()
-                .set(&DataKey::Description, &new_description);
-            updated_fields.push_back(Symbol::new(&env, "description"));
+        let tiers: Vec<RewardTier> = env
+            .storage()
+            .instance()
+            .get(&DataKey::RewardTiers)
+            .unwrap_or_else(|| Vec::new(&env));
+
+        if tiers.is_empty() {
+            return None;
         }
 
-        // Update social links if provided.
-        if let Some(new_socials) = socials {
-            env.storage()
-                .instance()
-                .set(&DataKey::SocialLinks, &new_socials);
-            updated_fields.push_back(Symbol::new(&env, "socials"));
+        let mut best: Option<RewardTier> = None;
+        for tier in tiers.iter() {
+            if contribution >= tier.min_amount {
+                let is_better = match &best {
+                    None => true,
+                    Some(ref b) => tier.min_amount > b.min_amount,
+                };
+                if is_better {
+                    best = Some(tier.clone());
+                }
+            }
         }
 
-        // Emit metadata_updated event with the list of updated field names.
-        env.events().publish(
-            (
-                Symbol::new(&env, "campaign"),
-                Symbol::new(&env, "metadata_updated"),
-            ),
-            updated_fields,
-        );
+        best.map(|t| t.name)
     }
 
-    /// Update the campaign deadline — only callable by the creator while the
-    /// campaign is still Active.
-    ///
-    /// # Arguments
-    /// * `new_deadline` – The new deadline as a ledger timestamp (must be greater than current deadline).
+    /// Returns the index (into `reward_tiers`) of the tier the contributor
+    /// claimed, or None if they hold no tier.
     ///
-    /// # Panics
-    /// * If the campaign is not Active.
-    /// * If new_deadline is less than or equal to the current deadline.
-    pub fn update_deadline(env: Env, new_deadline: u64) {
-        // Check campaign is active.
-        let status: Status = env.storage().instance().get(&DataKey::Status).unwrap();
-        if status != Status::Active {
-            panic!("campaign is not active");
-        }
+    /// Unlike `get_user_tier`, this respects `max_claims` and reflects the
+    /// tier recorded at contribution time.
+    pub fn contributor_tier(env: Env, contributor: Address) -> Option<u32> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ContributorTier(contributor))
+    }
 
-        // Require creator authentication.
-        let creator: Address = env.storage().instance().get(&DataKey::Creator).unwrap();
-        creator.require_auth();
+    /// Returns the next unmet stretch goal milestone.
+    ///
+    /// Returns 0 if there are no stretch goals or all have been met.
+    pub fn current_milestone(env: Env) -> i128 {
+        let total_raised: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::TotalRaised)
+            .unwrap_or(0);
 
-        // Get the current deadline.
-        let current_deadline: u64 = env.storage().instance().get(&DataKey::Deadline).unwrap();
+        let stretch_goals: Vec<i128> = env
+            .storage()
+            .instance()
+            .get(&DataKey::StretchGoals)
+            .unwrap_or_else(|| Vec::new(&env));
 
-        // Ensure new_deadline is greater than current_deadline (only extensions allowed).
-        if new_deadline <= current_deadline {
-            panic!("new deadline must be after current deadline");
+        for milestone in stretch_goals.iter() {
+            if total_raised < milestone {
+                return milestone;
+            }
         }
 
-        // Update the deadline.
+        0
+    }
+    pub fn total_raised(env: Env) -> i128 {
         env.storage()
             .instance()
-            .set(&DataKey::Deadline, &new_deadline);
+            .get(&DataKey::TotalRaised)
+            .unwrap_or(0)
+    }
 
-        // Emit deadline_updated event with old and new deadline values.
-        env.events().publish(
-            ("campaign", "deadline_updated"),
-            (current_deadline, new_deadline),
-        );
+    /// Returns the funding goal in tokens. For a USD-denominated goal this
+    /// is recomputed from the current oracle price.
+    pub fn goal(env: Env) -> i128 {
+        current_goal(&env)
     }
 
-    // ── View helpers ────────────────────────────────────────────────────
+    /// Returns the hard cap (maximum total that can be raised).
+    pub fn hard_cap(env: Env) -> i128 {
+        env.storage().instance().get(&DataKey::HardCap).unwrap()
+    }
 
-    /// Add a roadmap item to the campaign timeline.
-    ///
-    /// Only the creator can add roadmap items. The date must be in the future
-    /// and the description must not be empty.
-    pub fn add_roadmap_item(env: Env, date: u64, description: String) {
-        let creator: Address = env.storage().instance().get(&DataKey::Creator).unwrap();
-        creator.require_auth();
+    /// Returns the campaign deadline: its ledger timestamp, or its sequence
+    /// number for sequence deadlines. See `deadline_info` for the variant.
+    pub fn deadline(env: Env) -> u64 {
+        campaign_deadline(&env).value()
+    }
 
-        let current_timestamp = env.ledger().timestamp();
-        if date <= current_timestamp {
-            panic!("date must be in the future");
+    /// Returns the campaign deadline and whether it has passed.
+    pub fn deadline_info(env: Env) -> DeadlineInfo {
+        let deadline = campaign_deadline(&env);
+        DeadlineInfo {
+            expired: deadline.has_passed(&env),
+            deadline,
         }
+    }
 
-        if description.is_empty() {
-            panic!("description cannot be empty");
+    /// Returns the contribution of a specific address.
+    pub fn contribution(env: Env, contributor: Address) -> i128 {
+        let contribution_key = DataKey::Contribution(contributor);
+        env.storage()
+            .persistent()
+            .get(&contribution_key)
+            .unwrap_or(0)
+    }
+
+    /// Returns `contributor`'s share of `total_raised` in basis points, or 0
+    /// before anything is raised.
+    pub fn contribution_share_bps(env: Env, contributor: Address) -> i128 {
+        let total = Self::total_raised(env.clone());
+        if total == 0 {
+            return 0;
         }
+        Self::contribution(env, contributor) * 10_000 / total
+    }
 
-        let mut roadmap: Vec<RoadmapItem> = env
-            .storage()
-            .instance()
-            .get(&DataKey::Roadmap)
-            .unwrap_or_else(|| Vec::new(&env));
+    /// Returns up to ten `(contributor, amount)` pairs for the largest
+    /// contributions, largest first. Equal amounts keep the order in which
+    /// they were reached.
+    pub fn top_contributors(env: Env) -> Vec<(Address, i128)> {
+        top_contributors(&env)
+    }
 
-        let item = RoadmapItem {
-            date,
-            description: description.clone(),
-        };
+    /// Returns how many separate contributions `contributor` has made.
+    pub fn contribution_count(env: Env, contributor: Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ContributionCount(contributor))
+            .unwrap_or(0)
+    }
 
-        roadmap.push_back(item.clone());
-        env.storage().instance().set(&DataKey::Roadmap, &roadmap);
+    /// Returns the unclaimed referral fees owed to `referrer`.
+    pub fn referral_earnings(env: Env, referrer: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ReferralEarnings(referrer))
+            .unwrap_or(0)
+    }
 
-        env.events()
-            .publish(("campaign", "roadmap_item_added"), (date, description));
+    /// Returns a certificate of `contributor`'s participation as of now, for
+    /// reward contracts to verify without reading raw storage.
+    pub fn contribution_certificate(env: Env, contributor: Address) -> ContributionCertificate {
+        ContributionCertificate {
+            total_contributed: Self::contribution(env.clone(), contributor.clone()),
+            contributor,
+            campaign_contract: env.current_contract_address(),
+            campaign_goal: current_goal(&env),
+            timestamp: env.ledger().timestamp(),
+            campaign_status: env.storage().instance().get(&DataKey::Status).unwrap(),
+        }
     }
 
-    /// Returns the full ordered list of roadmap items.
-    pub fn roadmap(env: Env) -> Vec<RoadmapItem> {
+    /// Returns the backer badge of an address, if claimed.
+    pub fn badge_of(env: Env, contributor: Address) -> Option<Badge> {
+        env.storage().persistent().get(&DataKey::Badge(contributor))
+    }
+
+    /// Returns the over-goal excess an address can still claim.
+    pub fn excess_claim(env: Env, contributor: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ExcessClaim(contributor))
+            .unwrap_or(0)
+    }
+
+    /// Mint `token_address` to contributors as they contribute: one reward
+    /// token per `ratio` units contributed — creator-only.
+    ///
+    /// The campaign contract must be the admin of `token_address` (a Stellar
+    /// Asset Contract). Applies to contributions made from now on. Only new
+    /// contributions mint: credit moved by `transfer_contribution` takes its
+    /// reward tokens along, and refunds burn them, clawing back tokens the
+    /// contributor holds if the asset has clawback enabled.
+    ///
+    /// # Panics
+    /// * If `ratio` is not positive.
+    /// * If the campaign contract is not the admin of `token_address`.
+    pub fn set_reward_token(env: Env, token_address: Address, ratio: i128) {
+        let creator: Address = env.storage().instance().get(&DataKey::Creator).unwrap();
+        creator.require_auth();
+
+        if ratio <= 0 {
+            panic!("ratio must be positive");
+        }
+        let token_admin = token::StellarAssetClient::new(&env, &token_address).admin();
+        if token_admin != env.current_contract_address() {
+            panic!("contract is not the reward token admin");
+        }
+
         env.storage()
             .instance()
-            .get(&DataKey::Roadmap)
-            .unwrap_or_else(|| Vec::new(&env))
+            .set(&RewardKey::RewardToken, &(token_address.clone(), ratio));
+
+        env.events()
+            .publish(("campaign", "reward_token_set"), (token_address, ratio));
     }
 
-    /// Add a stretch goal milestone to the campaign.
+    /// Lock reward tokens until the campaign pays out, then release them
+    /// linearly — creator-only.
     ///
-    /// Only the creator can add stretch goals. The milestone must be greater
-    /// than the primary goal.
-    pub fn add_stretch_goal(env: Env, milestone: i128) {
+    /// Applies to rewards for contributions made from now on. Nothing vests
+    /// during the first `cliff_seconds` after payout; the rest vests evenly
+    /// over the following `vesting_seconds`.
+    ///
+    /// # Panics
+    /// * If `vesting_seconds` is zero.
+    /// * If the campaign is no longer active.
+    pub fn set_reward_vesting(env: Env, cliff_seconds: u64, vesting_seconds: u64) {
         let creator: Address = env.storage().instance().get(&DataKey::Creator).unwrap();
         creator.require_auth();
 
-        let goal: i128 = env.storage().instance().get(&DataKey::Goal).unwrap();
-        if milestone <= goal {
-            panic!("stretch goal must be greater than primary goal");
+        if vesting_seconds == 0 {
+            panic!("vesting period must be positive");
+        }
+        let status: Status = env.storage().instance().get(&DataKey::Status).unwrap();
+        if status != Status::Active {
+            panic!("campaign is not active");
+        }
+
+        env.storage()
+            .instance()
+            .set(&RewardKey::Vesting, &(cliff_seconds, vesting_seconds));
+
+        env.events().publish(
+            ("campaign", "reward_vesting_set"),
+            (cliff_seconds, vesting_seconds),
+        );
+    }
+
+    /// Transfer `contributor`'s vested, unclaimed reward tokens to them.
+    /// Returns the amount transferred.
+    pub fn claim_reward_tokens(env: Env, contributor: Address) -> i128 {
+        contributor.require_auth();
+
+        let claimable = Self::vested_reward_tokens(env.clone(), contributor.clone());
+        if claimable == 0 {
+            return 0;
         }
 
-        let mut stretch_goals: Vec<i128> = env
+        let key = RewardKey::Claimed(contributor.clone());
+        let claimed: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+        env.storage().persistent().set(&key, &(claimed + claimable));
+        env.storage().persistent().extend_ttl(&key, 100, 100);
+
+        let (token_address, _ratio): (Address, i128) = env
             .storage()
             .instance()
-            .get(&DataKey::StretchGoals)
-            .unwrap_or_else(|| Vec::new(&env));
+            .get(&RewardKey::RewardToken)
+            .unwrap();
+        token::Client::new(&env, &token_address).transfer(
+            &env.current_contract_address(),
+            &contributor,
+            &claimable,
+        );
 
-        stretch_goals.push_back(milestone);
-        env.storage()
-            .instance()
-            .set(&DataKey::StretchGoals, &stretch_goals);
+        env.events()
+            .publish(("campaign", "reward_claimed"), (contributor, claimable));
+        claimable
     }
 
-    /// Add a reward tier (creator only). Rejects min_amount <= 0.
-    pub fn add_reward_tier(env: Env, creator: Address, name: String, min_amount: i128) {
-        let status: Status = env.storage().instance().get(&DataKey::Status).unwrap();
-        if status != Status::Active {
-            panic!("campaign is not active");
-        }
+    /// Returns the reward tokens `contributor` can claim right now.
+    pub fn vested_reward_tokens(env: Env, contributor: Address) -> i128 {
+        let claimed: i128 = env
+            .storage()
+            .persistent()
+            .get(&RewardKey::Claimed(contributor.clone()))
+            .unwrap_or(0);
+        vested_reward(&env, &contributor) - claimed
+    }
 
-        let stored_creator: Address = env.storage().instance().get(&DataKey::Creator).unwrap();
-        if creator != stored_creator {
-            panic!("not authorized");
-        }
+    /// Share campaign revenue with contributors — creator-only.
+    ///
+    /// Pulls `amount` of the campaign token from the creator. Each
+    /// contributor is entitled to the fraction of it matching their share of
+    /// the raised total; rounds accumulate until claimed with
+    /// [`claim_profit`](Self::claim_profit).
+    ///
+    /// # Panics
+    /// * If `amount` is not positive.
+    /// * If the campaign has not succeeded.
+    pub fn distribute_profit(env: Env, amount: i128) -> Result<(), ContractError> {
+        let creator: Address = env.storage().instance().get(&DataKey::Creator).unwrap();
         creator.require_auth();
 
-        if min_amount <= 0 {
-            panic!("min_amount must be greater than 0");
+        if amount <= 0 {
+            panic!("amount must be positive");
+        }
+        let status: Status = env.storage().instance().get(&DataKey::Status).unwrap();
+        if status != Status::Successful || awaiting_payout(&env) {
+            panic!("campaign has not succeeded");
         }
 
-        let mut tiers: Vec<RewardTier> = env
-            .storage()
-            .instance()
-            .get(&DataKey::RewardTiers)
-            .unwrap_or_else(|| Vec::new(&env));
-
-        tiers.push_back(RewardTier {
-            name: name.clone(),
-            min_amount,
-        });
-        env.storage().instance().set(&DataKey::RewardTiers, &tiers);
+        let token_address: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        token::Client::new(&env, &token_address).transfer(
+            &creator,
+            &env.current_contract_address(),
+            &amount,
+        );
+        add_to_profit_pool(&env, amount)?;
 
         env.events()
-            .publish(("campaign", "reward_tier_added"), (name, min_amount));
+            .publish(("campaign", "profit_distributed"), amount);
+        Ok(())
     }
 
-    /// Returns the full ordered list of reward tiers.
-    pub fn reward_tiers(env: Env) -> Vec<RewardTier> {
-        env.storage()
+    /// Returns the distributed profit `contributor` has not yet claimed.
+    pub fn claimable_profit(env: Env, contributor: Address) -> i128 {
+        let distributed: i128 = env
+            .storage()
             .instance()
-            .get(&DataKey::RewardTiers)
-            .unwrap_or_else(|| Vec::new(&env))
-    }
+            .get(&RewardKey::ProfitDistributed)
+            .unwrap_or(0);
+        let raised: i128 = env
+            .storage()
+            .instance()
+            .get(&SettlementKey::RaisedAtClose)
+            .unwrap_or(0);
+        if distributed == 0 || raised == 0 {
+            return 0;
+        }
 
-    /// Returns the highest tier name the user's contribution qualifies for,
-    /// or None if the user has not contributed or no tiers are defined.
-    /// Tiers are evaluated by min_amount descending (highest qualifying tier wins).
-    pub fn get_user_tier(env: Env, user: Address) -> Option<String> {
         let contribution: i128 = env
             .storage()
             .persistent()
-            .get(&DataKey::Contribution(user))
+            .get(&DataKey::Contribution(contributor.clone()))
+            .unwrap_or(0);
+        let claimed: i128 = env
+            .storage()
+            .persistent()
+            .get(&RewardKey::ProfitClaimed(contributor))
             .unwrap_or(0);
+        contribution * distributed / raised - claimed
+    }
 
-        if contribution <= 0 {
-            return None;
+    /// Transfer `contributor`'s unclaimed profit to them. Returns the amount
+    /// transferred.
+    pub fn claim_profit(env: Env, contributor: Address) -> i128 {
+        contributor.require_auth();
+
+        let claimable = Self::claimable_profit(env.clone(), contributor.clone());
+        if claimable == 0 {
+            return 0;
         }
 
-        let tiers: Vec<RewardTier> = env
+        let key = RewardKey::ProfitClaimed(contributor.clone());
+        let claimed: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+        env.storage().persistent().set(&key, &(claimed + claimable));
+        env.storage().persistent().extend_ttl(&key, 100, 100);
+        let total_claimed: i128 = env
             .storage()
             .instance()
-            .get(&DataKey::RewardTiers)
-            .unwrap_or_else(|| Vec::new(&env));
+            .get(&RewardKey::TotalProfitClaimed)
+            .unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&RewardKey::TotalProfitClaimed, &(total_claimed + claimable));
 
-        if tiers.is_empty() {
-            return None;
-        }
+        let token_address: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        token::Client::new(&env, &token_address).transfer(
+            &env.current_contract_address(),
+            &contributor,
+            &claimable,
+        );
 
-        let mut best: Option<RewardTier> = None;
-        for tier in tiers.iter() {
-            if contribution >= tier.min_amount {
-                let is_better = match &best {
-                    None => true,
-                    Some(ref b) => tier.min_amount > b.min_amount,
-                };
-                if is_better {
-                    best = Some(tier.clone());
-                }
-            }
-        }
+        env.events()
+            .publish(("campaign", "profit_claimed"), (contributor, claimable));
+        claimable
+    }
 
-        best.map(|t| t.name)
+    /// Returns `(cliff_seconds, vesting_seconds)`, if reward vesting is set.
+    pub fn reward_vesting(env: Env) -> Option<(u64, u64)> {
+        env.storage().instance().get(&RewardKey::Vesting)
     }
 
-    /// Returns the next unmet stretch goal milestone.
-    ///
-    /// Returns 0 if there are no stretch goals or all have been met.
-    pub fn current_milestone(env: Env) -> i128 {
-        let total_raised: i128 = env
-            .storage()
-            .instance()
-            .get(&DataKey::TotalRaised)
-            .unwrap_or(0);
+    /// Returns the reward token and ratio, if the creator has set one.
+    pub fn reward_token(env: Env) -> Option<(Address, i128)> {
+        env.storage().instance().get(&RewardKey::RewardToken)
+    }
 
-        let stretch_goals: Vec<i128> = env
-            .storage()
-            .instance()
-            .get(&DataKey::StretchGoals)
-            .unwrap_or_else(|| Vec::new(&env));
+    /// Returns the receipt-token contract, if one is configured.
+    pub fn receipt_contract(env: Env) -> Option<Address> {
+        campaign_options(&env).receipt_contract
+    }
 
-        for milestone in stretch_goals.iter() {
-            if total_raised < milestone {
-                return milestone;
-            }
-        }
+    /// Returns the shortest allowed campaign duration, in seconds.
+    pub fn min_duration(_env: Env) -> u64 {
+        MIN_DURATION
+    }
 
-        0
+    /// Returns the longest allowed distance, in seconds, between the current
+    /// ledger time and a deadline.
+    pub fn max_duration(_env: Env) -> u64 {
+        MAX_DURATION
     }
-    pub fn total_raised(env: Env) -> i128 {
+
+    /// Returns the number of decimals of the campaign token, so UIs can
+    /// format amounts.
+    pub fn token_decimals(env: Env) -> u32 {
+        Self::token_metadata(env).decimals
+    }
+
+    /// Returns the campaign token's decimals and symbol, cached at
+    /// initialization.
+    pub fn token_metadata(env: Env) -> TokenMetadata {
         env.storage()
             .instance()
-            .get(&DataKey::TotalRaised)
-            .unwrap_or(0)
+            .get(&DataKey::TokenMetadata)
+            .unwrap()
     }
 
-    /// Returns the funding goal.
-    pub fn goal(env: Env) -> i128 {
-        env.storage().instance().get(&DataKey::Goal).unwrap()
+    /// Returns the memo attached to an address's `index`th contribution
+    /// (zero-based), or None if that contribution had no memo.
+    pub fn contribution_memo(env: Env, contributor: Address, index: u32) -> Option<String> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ContributionMemo(contributor, index))
     }
 
-    /// Returns the hard cap (maximum total that can be raised).
-    pub fn hard_cap(env: Env) -> i128 {
-        env.storage().instance().get(&DataKey::HardCap).unwrap()
+    /// Returns an address's contribution in a tallied accepted token.
+    pub fn token_contribution(env: Env, contributor: Address, token: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::TokenContribution(contributor, token))
+            .unwrap_or(0)
     }
 
-    /// Returns the campaign deadline.
-    pub fn deadline(env: Env) -> u64 {
-        env.storage().instance().get(&DataKey::Deadline).unwrap()
+    /// Returns the total contributed in a tallied accepted token.
+    pub fn token_total(env: Env, token: Address) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::TokenTotal(token))
+            .unwrap_or(0)
     }
 
-    /// Returns the contribution of a specific address.
-    pub fn contribution(env: Env, contributor: Address) -> i128 {
-        let contribution_key = DataKey::Contribution(contributor);
+    /// Returns the sponsor matching pool, if one was funded.
+    pub fn matching_pool(env: Env) -> Option<MatchingPool> {
+        env.storage().instance().get(&DataKey::MatchingPool)
+    }
+
+    /// Returns the early-bird weighted contribution of an address. This is
+    /// informational only — refunds and withdrawals use the raw amounts.
+    pub fn weighted_contribution(env: Env, contributor: Address) -> i128 {
         env.storage()
             .persistent()
-            .get(&contribution_key)
+            .get(&DataKey::WeightedContribution(contributor))
+            .unwrap_or(0)
+    }
+
+    /// Returns the sum of all early-bird weighted contributions.
+    pub fn total_weighted(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::TotalWeighted)
             .unwrap_or(0)
     }
 
@@ -1059,9 +7228,9 @@ impl CrowdfundContract {
             .unwrap()
     }
 
-    /// Returns the primary campaign category.
-    pub fn category(env: Env) -> soroban_sdk::String {
-        env.storage().instance().get(&DataKey::Category).unwrap()
+    /// Returns the campaign category, if the creator has set one.
+    pub fn category(env: Env) -> Option<CampaignCategory> {
+        env.storage().instance().get(&DataKey::Category)
     }
 
     /// Returns the optional descriptive tags.
@@ -1072,22 +7241,24 @@ impl CrowdfundContract {
             .unwrap_or(Vec::new(&env))
     }
 
-    /// Returns comprehensive campaign statistics.
-    pub fn get_stats(env: Env) -> CampaignStats {
+    /// Returns comprehensive campaign statistics in a single call.
+    ///
+    /// Every field is computed fresh from storage; this is a pure read.
+    pub fn campaign_stats(env: Env) -> CampaignStats {
         let total_raised: i128 = env
             .storage()
             .instance()
             .get(&DataKey::TotalRaised)
             .unwrap_or(0);
-        let goal: i128 = env.storage().instance().get(&DataKey::Goal).unwrap();
+        let goal = current_goal(&env);
         let contributors: Vec<Address> = env
             .storage()
-            .instance()
+            .persistent()
             .get(&DataKey::Contributors)
-            .unwrap();
+            .unwrap_or_else(|| Vec::new(&env));
 
         let progress_bps = if goal > 0 {
-            let raw = (total_raised * 10_000) / goal;
+            let raw = total_raised.saturating_mul(10_000) / goal;
             if raw > 10_000 {
                 10_000
             } else {
@@ -1106,7 +7277,7 @@ impl CrowdfundContract {
             for contributor in contributors.iter() {
                 let amount: i128 = env
                     .storage()
-                    .instance()
+                    .persistent()
                     .get(&DataKey::Contribution(contributor))
                     .unwrap_or(0);
                 if amount > largest {
@@ -1116,6 +7287,23 @@ impl CrowdfundContract {
             (average, largest)
         };
 
+        let campaign_deadline = campaign_deadline(&env);
+        let deadline = campaign_deadline.value();
+        let time_remaining = campaign_deadline.seconds_remaining(&env);
+        let status: Status = env.storage().instance().get(&DataKey::Status).unwrap();
+        let platform_fee_bps = env
+            .storage()
+            .instance()
+            .get::<_, PlatformConfig>(&DataKey::PlatformConfig)
+            .map(|config| config.fee_bps_for(total_raised))
+            .unwrap_or(0);
+        let roadmap_item_count = env
+            .storage()
+            .instance()
+            .get::<_, Vec<RoadmapItem>>(&DataKey::Roadmap)
+            .map(|roadmap| roadmap.len())
+            .unwrap_or(0);
+
         CampaignStats {
             total_raised,
             goal,
@@ -1123,9 +7311,108 @@ impl CrowdfundContract {
             contributor_count,
             average_contribution,
             largest_contribution,
+            deadline,
+            time_remaining,
+            status,
+            platform_fee_bps,
+            roadmap_item_count,
+        }
+    }
+
+    /// Record the current `total_raised`, contributor count and ledger
+    /// sequence under `name` — creator-only. Snapshots cannot be replaced.
+    ///
+    /// # Panics
+    /// * If a snapshot named `name` already exists.
+    pub fn snapshot(env: Env, name: String) {
+        let creator: Address = env.storage().instance().get(&DataKey::Creator).unwrap();
+        creator.require_auth();
+
+        let key = StatsKey::Snapshot(name.clone());
+        if env.storage().persistent().has(&key) {
+            panic!("snapshot name already exists");
+        }
+
+        let snapshot = Snapshot {
+            total_raised: Self::total_raised(env.clone()),
+            contributor_count: Self::contributor_count(env.clone()),
+            ledger_sequence: env.ledger().sequence(),
+        };
+        env.storage().persistent().set(&key, &snapshot);
+        env.storage().persistent().extend_ttl(&key, 100, 100);
+
+        env.events()
+            .publish(("campaign", "snapshot"), (name, snapshot));
+    }
+
+    /// Returns the snapshot recorded under `name`, if any.
+    pub fn get_snapshot(env: Env, name: String) -> Option<Snapshot> {
+        env.storage().persistent().get(&StatsKey::Snapshot(name))
+    }
+
+    /// Returns the ledger timestamp at which the campaign first reached its
+    /// goal, or `None` if it never has.
+    pub fn goal_reached_at(env: Env) -> Option<u64> {
+        env.storage().instance().get(&StatsKey::GoalReachedAt)
+    }
+
+    /// Returns contribution count, unique contributors, the largest
+    /// contribution and its contributor, and the average per contributor.
+    /// See `ContributionStats` for how they behave once the campaign closes.
+    pub fn stats(env: Env) -> ContributionStats {
+        let count: u32 = env
+            .storage()
+            .instance()
+            .get(&StatsKey::ContributionCount)
+            .unwrap_or(0);
+        let unique_contributors = env
+            .storage()
+            .persistent()
+            .get::<_, Vec<Address>>(&DataKey::Contributors)
+            .map(|contributors| contributors.len())
+            .unwrap_or(0);
+        let (largest_from, largest) = match top_contributors(&env).first() {
+            Some((address, amount)) => (Some(address), amount),
+            None => (None, 0),
+        };
+
+        let status: Status = env.storage().instance().get(&DataKey::Status).unwrap();
+        let raised: i128 = if status == Status::Active {
+            env.storage()
+                .instance()
+                .get(&DataKey::TotalRaised)
+                .unwrap_or(0)
+        } else {
+            env.storage()
+                .instance()
+                .get(&SettlementKey::RaisedAtClose)
+                .unwrap_or(0)
+        };
+        let average = if unique_contributors == 0 {
+            0
+        } else {
+            raised / unique_contributors as i128
+        };
+
+        ContributionStats {
+            count,
+            unique_contributors,
+            largest,
+            largest_from,
+            average,
         }
     }
 
+    /// Alias of `campaign_stats`, kept for existing integrations.
+    pub fn get_stats(env: Env) -> CampaignStats {
+        Self::campaign_stats(env)
+    }
+
+    /// Returns the current campaign status.
+    pub fn status(env: Env) -> Status {
+        env.storage().instance().get(&DataKey::Status).unwrap()
+    }
+
     /// Returns the campaign title.
     pub fn title(env: Env) -> String {
         let empty = String::from_str(&env, "");
@@ -1153,6 +7440,11 @@ impl CrowdfundContract {
             .unwrap_or(empty)
     }
 
+    /// Returns the campaign's website, Twitter and GitHub links, if set.
+    pub fn links(env: Env) -> Option<CampaignLinks> {
+        env.storage().instance().get(&MetadataKey::Links)
+    }
+
     /// Returns the contract version.
     ///
     /// This view function allows external tools to detect which version of the
@@ -1162,11 +7454,88 @@ impl CrowdfundContract {
         CONTRACT_VERSION
     }
 
+    /// Returns the address that deployed the contract.
+    pub fn deployer(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&GovernanceKey::Deployer)
+            .unwrap()
+    }
+
+    /// Returns the address allowed to upgrade the contract.
+    pub fn admin(env: Env) -> Address {
+        env.storage().instance().get(&DataKey::Admin).unwrap()
+    }
+
+    /// Returns the campaign creator.
+    pub fn creator(env: Env) -> Address {
+        env.storage().instance().get(&DataKey::Creator).unwrap()
+    }
+
     /// Returns the token contract address used for contributions.
     pub fn token(env: Env) -> Address {
         env.storage().instance().get(&DataKey::Token).unwrap()
     }
 
+    /// Returns the ledger timestamp at which the campaign was initialized, or
+    /// 0 for campaigns initialized before it was recorded.
+    pub fn creation_timestamp(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&GovernanceKey::CreatedAt)
+            .unwrap_or(0)
+    }
+
+    /// Returns the contribution token; the same as `token`, under the name
+    /// integrations such as `campaigns_by_token` query.
+    pub fn token_address(env: Env) -> Address {
+        Self::token(env)
+    }
+
+    /// Returns the contract's actual balance of the contribution token. It
+    /// should match `total_raised` while the campaign is Active; a gap points
+    /// to an accounting bug or a fee-on-transfer token.
+    pub fn token_balance(env: Env) -> i128 {
+        token::Client::new(&env, &Self::token(env.clone())).balance(&env.current_contract_address())
+    }
+
+    /// Returns whether the contract holds at least `total_raised` of the
+    /// contribution token, on top of the unspent matching pool, posted
+    /// collateral and unclaimed profit that sit in the same balance. Used by
+    /// monitors and as a check before paying out.
+    ///
+    /// A shortfall, from a token that charges holders or an accounting bug,
+    /// means the recorded funds are not fully backed. A surplus is not a
+    /// failure, so a stray transfer cannot block the payout.
+    pub fn verify_accounting(env: Env) -> bool {
+        let matching: i128 = Self::matching_pool(env.clone()).map_or(0, |pool| pool.remaining);
+        let distributed: i128 = env
+            .storage()
+            .instance()
+            .get(&RewardKey::ProfitDistributed)
+            .unwrap_or(0);
+        let claimed: i128 = env
+            .storage()
+            .instance()
+            .get(&RewardKey::TotalProfitClaimed)
+            .unwrap_or(0);
+        let tracked = Self::total_raised(env.clone())
+            .checked_add(matching)
+            .and_then(|sum| sum.checked_add(Self::collateral(env.clone())))
+            .and_then(|sum| sum.checked_add(distributed - claimed));
+        tracked.is_some_and(|tracked| Self::token_balance(env) >= tracked)
+    }
+
+    /// Returns whether the campaign has been archived.
+    pub fn is_archived(env: Env) -> bool {
+        env.storage().instance().has(&SettlementKey::Archive)
+    }
+
+    /// Returns the aggregates kept by `archive`, if the campaign is archived.
+    pub fn campaign_archive(env: Env) -> Option<CampaignArchive> {
+        env.storage().instance().get(&SettlementKey::Archive)
+    }
+
     /// Returns the number of unique contributors.
     pub fn contributor_count(env: Env) -> u32 {
         let contributors: Vec<Address> = env
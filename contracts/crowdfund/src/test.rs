@@ -1,11 +1,22 @@
 #![allow(unused_doc_comments)]
 
+extern crate std;
+
 use soroban_sdk::{
-    testutils::{Address as _, Ledger},
-    token, Address, Env,
+    testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, Events, Ledger},
+    token, Address, Env, IntoVal, Symbol, TryFromVal, Val,
+};
+
+use crate::{
+    AcceptedToken, Asset, CampaignCategory, CampaignLinks, CampaignOptions, CancelEvent,
+    CancelledEvent, ContributionEvent, ContributionStats, CrowdfundContract,
+    CrowdfundContractClient, DataKey, Deadline, DustPolicy, EarlyBirdWindow, FeeTiming,
+    GoalReachedEvent, GovernanceKey, LegacyKey, LinksUpdatedEvent, MilestoneStatus, PlatformConfig,
+    PriceData, RefundEvent, RoadmapItemAddedEvent, Snapshot, Status, StatusChangedEvent,
+    WithdrawEvent,
 };
 
-use crate::{CrowdfundContract, CrowdfundContractClient};
+use proptest::prelude::*;
 
 // ── Helpers ─────────────────────────────────────────────────────────────────
 
@@ -21,7 +32,8 @@ fn setup_env() -> (
     env.mock_all_auths();
 
     // Deploy the crowdfund contract.
-    let contract_id = env.register(CrowdfundContract, ());
+    let deployer = Address::generate(&env);
+    let contract_id = env.register(CrowdfundContract, (deployer,));
     let client = CrowdfundContractClient::new(&env, &contract_id);
 
     // Create a token for contributions.
@@ -39,6 +51,31 @@ fn setup_env() -> (
     (env, client, creator, token_address, token_admin.clone())
 }
 
+/// Initializes the campaign from `setup_env` with a 1,000,000 goal, a
+/// 2,000,000 hard cap, a 1,000 minimum and a deadline an hour out, under
+/// `options`. Returns the deadline.
+fn init_with_options(
+    env: &Env,
+    client: &CrowdfundContractClient,
+    creator: &Address,
+    token_address: &Address,
+    options: CampaignOptions,
+) -> u64 {
+    let deadline = env.ledger().timestamp() + 3600;
+    client.initialize(
+        creator,
+        token_address,
+        &1_000_000,
+        &2_000_000,
+        &Deadline::Timestamp(deadline),
+        &1_000,
+        &None,
+        &None,
+        &Some(options),
+    );
+    deadline
+}
+
 /// Helper to mint tokens to an arbitrary contributor.
 fn mint_to(env: &Env, token_address: &Address, admin: &Address, to: &Address, amount: i128) {
     let admin_client = token::StellarAssetClient::new(env, token_address);
@@ -61,9 +98,11 @@ fn test_initialize() {
         &token_address,
         &goal,
         &(goal * 2),
-        &deadline,
+        &Deadline::Timestamp(deadline),
         &min_contribution,
         &None,
+        &None,
+        &None,
     );
 
     assert_eq!(client.goal(), goal);
@@ -77,7 +116,81 @@ fn test_version() {
     let (_env, client, _creator, _token_address, _admin) = setup_env();
 
     // Test that version() returns the expected version number
-    assert_eq!(client.version(), 1);
+    assert_eq!(client.version(), 2);
+}
+
+#[test]
+fn test_initialize_requires_deployer_auth() {
+    let env = Env::default();
+    let deployer = Address::generate(&env);
+    let contract_id = env.register(CrowdfundContract, (deployer.clone(),));
+    let client = CrowdfundContractClient::new(&env, &contract_id);
+    let token_admin = Address::generate(&env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    let attacker = Address::generate(&env);
+    let deadline = Deadline::Timestamp(env.ledger().timestamp() + 3600);
+
+    // Someone watching the deployment tries to claim the campaign first.
+    let args = (
+        attacker.clone(),
+        token_address.clone(),
+        1_000_000i128,
+        2_000_000i128,
+        deadline.clone(),
+        1_000i128,
+        None::<PlatformConfig>,
+        None::<Address>,
+        None::<CampaignOptions>,
+    )
+        .into_val(&env);
+    let result = client
+        .mock_auths(&[soroban_sdk::testutils::MockAuth {
+            address: &attacker,
+            invoke: &soroban_sdk::testutils::MockAuthInvoke {
+                contract: &contract_id,
+                fn_name: "initialize",
+                args,
+                sub_invokes: &[],
+            },
+        }])
+        .try_initialize(
+            &attacker,
+            &token_address,
+            &1_000_000,
+            &2_000_000,
+            &deadline,
+            &1_000,
+            &None,
+            &None,
+            &None,
+        );
+    assert!(result.is_err());
+    assert_eq!(client.deployer(), deployer);
+
+    // The deployer can still configure it.
+    let creator = Address::generate(&env);
+    env.mock_all_auths();
+    client.initialize(
+        &creator,
+        &token_address,
+        &1_000_000,
+        &2_000_000,
+        &deadline,
+        &1_000,
+        &None,
+        &None,
+        &None,
+    );
+    assert_eq!(client.goal(), 1_000_000);
+}
+
+#[test]
+#[should_panic]
+fn test_deploy_without_deployer_panics() {
+    let env = Env::default();
+    env.register(CrowdfundContract, ());
 }
 
 #[test]
@@ -93,18 +206,22 @@ fn test_double_initialize_panics() {
         &token_address,
         &goal,
         &(goal * 2),
-        &deadline,
+        &Deadline::Timestamp(deadline),
         &min_contribution,
         &None,
+        &None,
+        &None,
     );
     let result = client.try_initialize(
         &creator,
         &token_address,
         &goal,
         &(goal * 2),
-        &deadline,
+        &Deadline::Timestamp(deadline),
         &min_contribution,
         &None,
+        &None,
+        &None,
     );
 
     assert!(result.is_err());
@@ -126,15 +243,17 @@ fn test_contribute() {
         &token_address,
         &goal,
         &(goal * 2),
-        &deadline,
+        &Deadline::Timestamp(deadline),
         &min_contribution,
         &None,
+        &None,
+        &None,
     );
 
     let contributor = Address::generate(&env);
     mint_to(&env, &token_address, &admin, &contributor, 500_000);
 
-    client.contribute(&contributor, &500_000, &None);
+    client.contribute(&contributor, &500_000, &None, &None);
 
     assert_eq!(client.total_raised(), 500_000);
     assert_eq!(client.contribution(&contributor), 500_000);
@@ -152,9 +271,11 @@ fn test_multiple_contributions() {
         &token_address,
         &goal,
         &(goal * 2),
-        &deadline,
+        &Deadline::Timestamp(deadline),
         &min_contribution,
         &None,
+        &None,
+        &None,
     );
 
     let alice = Address::generate(&env);
@@ -162,8 +283,8 @@ fn test_multiple_contributions() {
     mint_to(&env, &token_address, &admin, &alice, 600_000);
     mint_to(&env, &token_address, &admin, &bob, 400_000);
 
-    client.contribute(&alice, &300_000, None);
-    client.contribute(&bob, &200_000, None);
+    client.contribute(&alice, &300_000, &None, &None);
+    client.contribute(&bob, &200_000, &None, &None);
 
     assert_eq!(client.total_raised(), 500_000);
     assert_eq!(client.contribution(&alice), 300_000);
@@ -174,7 +295,7 @@ fn test_multiple_contributions() {
 fn test_contribute_after_deadline_panics() {
     let (env, client, creator, token_address, admin) = setup_env();
 
-    let deadline = env.ledger().timestamp() + 100;
+    let deadline = env.ledger().timestamp() + 3600;
     let goal: i128 = 1_000_000;
     let min_contribution: i128 = 1_000;
     client.initialize(
@@ -182,9 +303,11 @@ fn test_contribute_after_deadline_panics() {
         &token_address,
         &goal,
         &(goal * 2),
-        &deadline,
+        &Deadline::Timestamp(deadline),
         &min_contribution,
         &None,
+        &None,
+        &None,
     );
 
     // Fast-forward past the deadline.
@@ -193,7 +316,7 @@ fn test_contribute_after_deadline_panics() {
     let contributor = Address::generate(&env);
     mint_to(&env, &token_address, &admin, &contributor, 500_000);
 
-    let result = client.try_contribute(&contributor, &500_000);
+    let result = client.try_contribute(&contributor, &500_000, &None, &None);
 
     assert!(result.is_err());
     assert_eq!(
@@ -214,14 +337,16 @@ fn test_withdraw_after_goal_met() {
         &token_address,
         &goal,
         &(goal * 2),
-        &deadline,
+        &Deadline::Timestamp(deadline),
         &min_contribution,
         &None,
+        &None,
+        &None,
     );
 
     let contributor = Address::generate(&env);
     mint_to(&env, &token_address, &admin, &contributor, 1_000_000);
-    client.contribute(&contributor, &1_000_000, &None);
+    client.contribute(&contributor, &1_000_000, &None, &None);
 
     assert_eq!(client.total_raised(), goal);
 
@@ -250,14 +375,16 @@ fn test_withdraw_before_deadline_panics() {
         &token_address,
         &goal,
         &(goal * 2),
-        &deadline,
+        &Deadline::Timestamp(deadline),
         &min_contribution,
         &None,
+        &None,
+        &None,
     );
 
     let contributor = Address::generate(&env);
     mint_to(&env, &token_address, &admin, &contributor, 1_000_000);
-    client.contribute(&contributor, &1_000_000, &None);
+    client.contribute(&contributor, &1_000_000, &None, &None);
 
     let result = client.try_withdraw();
 
@@ -280,14 +407,16 @@ fn test_withdraw_goal_not_reached_panics() {
         &token_address,
         &goal,
         &(goal * 2),
-        &deadline,
+        &Deadline::Timestamp(deadline),
         &min_contribution,
         &None,
+        &None,
+        &None,
     );
 
     let contributor = Address::generate(&env);
     mint_to(&env, &token_address, &admin, &contributor, 500_000);
-    client.contribute(&contributor, &500_000, &None);
+    client.contribute(&contributor, &500_000, &None, &None);
 
     // Move past deadline, but goal not met.
     env.ledger().set_timestamp(deadline + 1);
@@ -313,9 +442,11 @@ fn test_refund_when_goal_not_met() {
         &token_address,
         &goal,
         &(goal * 2),
-        &deadline,
+        &Deadline::Timestamp(deadline),
         &min_contribution,
         &None,
+        &None,
+        &None,
     );
 
     let alice = Address::generate(&env);
@@ -323,8 +454,8 @@ fn test_refund_when_goal_not_met() {
     mint_to(&env, &token_address, &admin, &alice, 300_000);
     mint_to(&env, &token_address, &admin, &bob, 200_000);
 
-    client.contribute(&alice, &300_000, None);
-    client.contribute(&bob, &200_000, None);
+    client.contribute(&alice, &300_000, &None, &None);
+    client.contribute(&bob, &200_000, &None, &None);
 
     // Move past deadline — goal not met.
     env.ledger().set_timestamp(deadline + 1);
@@ -350,14 +481,16 @@ fn test_refund_when_goal_reached_panics() {
         &token_address,
         &goal,
         &(goal * 2),
-        &deadline,
+        &Deadline::Timestamp(deadline),
         &min_contribution,
         &None,
+        &None,
+        &None,
     );
 
     let contributor = Address::generate(&env);
     mint_to(&env, &token_address, &admin, &contributor, 1_000_000);
-    client.contribute(&contributor, &1_000_000, &None);
+    client.contribute(&contributor, &1_000_000, &None, &None);
 
     env.ledger().set_timestamp(deadline + 1);
 
@@ -401,18 +534,22 @@ fn test_bug_condition_exploration_all_error_conditions_panic() {
             &token_address,
             &goal,
             &(goal * 2),
-            &deadline,
+            &Deadline::Timestamp(deadline),
             &1_000,
             &None,
+            &None,
+            &None,
         );
         let result = client.try_initialize(
             &creator,
             &token_address,
             &goal,
             &(goal * 2),
-            &deadline,
+            &Deadline::Timestamp(deadline),
             &1_000,
             &None,
+            &None,
+            &None,
         );
 
         assert!(result.is_err());
@@ -425,23 +562,25 @@ fn test_bug_condition_exploration_all_error_conditions_panic() {
     // Test 2: Late contribution
     {
         let (env, client, creator, token_address, admin) = setup_env();
-        let deadline = env.ledger().timestamp() + 100;
+        let deadline = env.ledger().timestamp() + 3600;
         let goal: i128 = 1_000_000;
         client.initialize(
             &creator,
             &token_address,
             &goal,
             &(goal * 2),
-            &deadline,
+            &Deadline::Timestamp(deadline),
             &1_000,
             &None,
+            &None,
+            &None,
         );
 
         env.ledger().set_timestamp(deadline + 1);
 
         let contributor = Address::generate(&env);
         mint_to(&env, &token_address, &admin, &contributor, 500_000);
-        let result = client.try_contribute(&contributor, &500_000);
+        let result = client.try_contribute(&contributor, &500_000, &None, &None);
 
         assert!(result.is_err());
         assert_eq!(result.unwrap_err().unwrap(), ContractError::CampaignEnded);
@@ -457,14 +596,16 @@ fn test_bug_condition_exploration_all_error_conditions_panic() {
             &token_address,
             &goal,
             &(goal * 2),
-            &deadline,
+            &Deadline::Timestamp(deadline),
             &1_000,
             &None,
+            &None,
+            &None,
         );
 
         let contributor = Address::generate(&env);
         mint_to(&env, &token_address, &admin, &contributor, 1_000_000);
-        client.contribute(&contributor, &1_000_000, &None);
+        client.contribute(&contributor, &1_000_000, &None, &None);
 
         let result = client.try_withdraw();
 
@@ -485,14 +626,16 @@ fn test_bug_condition_exploration_all_error_conditions_panic() {
             &token_address,
             &goal,
             &(goal * 2),
-            &deadline,
+            &Deadline::Timestamp(deadline),
             &1_000,
             &None,
+            &None,
+            &None,
         );
 
         let contributor = Address::generate(&env);
         mint_to(&env, &token_address, &admin, &contributor, 500_000);
-        client.contribute(&contributor, &500_000, &None);
+        client.contribute(&contributor, &500_000, &None, &None);
 
         env.ledger().set_timestamp(deadline + 1);
         let result = client.try_withdraw();
@@ -511,14 +654,16 @@ fn test_bug_condition_exploration_all_error_conditions_panic() {
             &token_address,
             &goal,
             &(goal * 2),
-            &deadline,
+            &Deadline::Timestamp(deadline),
             &1_000,
             &None,
+            &None,
+            &None,
         );
 
         let contributor = Address::generate(&env);
         mint_to(&env, &token_address, &admin, &contributor, 500_000);
-        client.contribute(&contributor, &500_000, &None);
+        client.contribute(&contributor, &500_000, &None, &None);
 
         let result = client.try_refund();
 
@@ -539,14 +684,16 @@ fn test_bug_condition_exploration_all_error_conditions_panic() {
             &token_address,
             &goal,
             &(goal * 2),
-            &deadline,
+            &Deadline::Timestamp(deadline),
             &1_000,
             &None,
+            &None,
+            &None,
         );
 
         let contributor = Address::generate(&env);
         mint_to(&env, &token_address, &admin, &contributor, 1_000_000);
-        client.contribute(&contributor, &1_000_000, &None);
+        client.contribute(&contributor, &1_000_000, &None, &None);
 
         env.ledger().set_timestamp(deadline + 1);
         let result = client.try_refund();
@@ -570,9 +717,11 @@ fn test_cancel_with_no_contributions() {
         &token_address,
         &goal,
         &(goal * 2),
-        &deadline,
+        &Deadline::Timestamp(deadline),
         &min_contribution,
         &None,
+        &None,
+        &None,
     );
 
     client.cancel();
@@ -592,9 +741,11 @@ fn test_cancel_with_contributions() {
         &token_address,
         &goal,
         &(goal * 2),
-        &deadline,
+        &Deadline::Timestamp(deadline),
         &min_contribution,
         &None,
+        &None,
+        &None,
     );
 
     let alice = Address::generate(&env);
@@ -602,8 +753,8 @@ fn test_cancel_with_contributions() {
     mint_to(&env, &token_address, &admin, &alice, 300_000);
     mint_to(&env, &token_address, &admin, &bob, 200_000);
 
-    client.contribute(&alice, &300_000, None);
-    client.contribute(&bob, &200_000, None);
+    client.contribute(&alice, &300_000, &None, &None);
+    client.contribute(&bob, &200_000, &None, &None);
 
     client.cancel();
 
@@ -627,15 +778,17 @@ fn test_contribute_exact_minimum() {
         &token_address,
         &goal,
         &(goal * 2),
-        &deadline,
+        &Deadline::Timestamp(deadline),
         &min_contribution,
         &None,
+        &None,
+        &None,
     );
 
     let contributor = Address::generate(&env);
     mint_to(&env, &token_address, &admin, &contributor, 10_000);
 
-    client.contribute(&contributor, &10_000, None);
+    client.contribute(&contributor, &10_000, &None, &None);
 
     assert_eq!(client.total_raised(), 10_000);
     assert_eq!(client.contribution(&contributor), 10_000);
@@ -653,15 +806,17 @@ fn test_contribute_above_minimum() {
         &token_address,
         &goal,
         &(goal * 2),
-        &deadline,
+        &Deadline::Timestamp(deadline),
         &min_contribution,
         &None,
+        &None,
+        &None,
     );
 
     let contributor = Address::generate(&env);
     mint_to(&env, &token_address, &admin, &contributor, 50_000);
 
-    client.contribute(&contributor, &50_000, &None);
+    client.contribute(&contributor, &50_000, &None, &None);
 
     assert_eq!(client.total_raised(), 50_000);
     assert_eq!(client.contribution(&contributor), 50_000);
@@ -681,21 +836,23 @@ fn test_get_user_tier_bronze_level() {
         &token_address,
         &goal,
         &(goal * 2),
-        &deadline,
+        &Deadline::Timestamp(deadline),
         &min_contribution,
         &None,
+        &None,
+        &None,
     );
 
     let bronze = soroban_sdk::String::from_str(&env, "Bronze");
     let silver = soroban_sdk::String::from_str(&env, "Silver");
     let gold = soroban_sdk::String::from_str(&env, "Gold");
-    client.add_reward_tier(&creator, &bronze, &10_000);
-    client.add_reward_tier(&creator, &silver, &100_000);
-    client.add_reward_tier(&creator, &gold, &500_000);
+    client.add_reward_tier(&creator, &bronze, &10_000, &None);
+    client.add_reward_tier(&creator, &silver, &100_000, &None);
+    client.add_reward_tier(&creator, &gold, &500_000, &None);
 
     let contributor = Address::generate(&env);
     mint_to(&env, &token_address, &admin, &contributor, 50_000);
-    client.contribute(&contributor, &50_000, &None);
+    client.contribute(&contributor, &50_000, &None, &None);
 
     let tier = client.get_user_tier(&contributor);
     assert!(tier.is_some());
@@ -714,21 +871,23 @@ fn test_get_user_tier_gold_level() {
         &token_address,
         &goal,
         &(goal * 2),
-        &deadline,
+        &Deadline::Timestamp(deadline),
         &min_contribution,
         &None,
+        &None,
+        &None,
     );
 
     let bronze = soroban_sdk::String::from_str(&env, "Bronze");
     let silver = soroban_sdk::String::from_str(&env, "Silver");
     let gold = soroban_sdk::String::from_str(&env, "Gold");
-    client.add_reward_tier(&creator, &bronze, &10_000);
-    client.add_reward_tier(&creator, &silver, &100_000);
-    client.add_reward_tier(&creator, &gold, &500_000);
+    client.add_reward_tier(&creator, &bronze, &10_000, &None);
+    client.add_reward_tier(&creator, &silver, &100_000, &None);
+    client.add_reward_tier(&creator, &gold, &500_000, &None);
 
     let contributor = Address::generate(&env);
     mint_to(&env, &token_address, &admin, &contributor, 600_000);
-    client.contribute(&contributor, &600_000, &None);
+    client.contribute(&contributor, &600_000, &None, &None);
 
     let tier = client.get_user_tier(&contributor);
     assert!(tier.is_some());
@@ -747,13 +906,15 @@ fn test_get_user_tier_non_contributor_returns_none() {
         &token_address,
         &goal,
         &(goal * 2),
-        &deadline,
+        &Deadline::Timestamp(deadline),
         &min_contribution,
         &None,
+        &None,
+        &None,
     );
 
     let bronze = soroban_sdk::String::from_str(&env, "Bronze");
-    client.add_reward_tier(&creator, &bronze, &10_000);
+    client.add_reward_tier(&creator, &bronze, &10_000, &None);
 
     let non_contributor = Address::generate(&env);
     let tier = client.get_user_tier(&non_contributor);
@@ -772,14 +933,16 @@ fn test_get_user_tier_no_tiers_defined_returns_none() {
         &token_address,
         &goal,
         &(goal * 2),
-        &deadline,
+        &Deadline::Timestamp(deadline),
         &min_contribution,
         &None,
+        &None,
+        &None,
     );
 
     let contributor = Address::generate(&env);
     mint_to(&env, &token_address, &admin, &contributor, 500_000);
-    client.contribute(&contributor, &500_000, &None);
+    client.contribute(&contributor, &500_000, &None, &None);
 
     let tier = client.get_user_tier(&contributor);
     assert!(tier.is_none());
@@ -797,21 +960,23 @@ fn test_get_user_tier_highest_qualifying_tier_returned() {
         &token_address,
         &goal,
         &(goal * 2),
-        &deadline,
+        &Deadline::Timestamp(deadline),
         &min_contribution,
         &None,
+        &None,
+        &None,
     );
 
     let bronze = soroban_sdk::String::from_str(&env, "Bronze");
     let silver = soroban_sdk::String::from_str(&env, "Silver");
     let gold = soroban_sdk::String::from_str(&env, "Gold");
-    client.add_reward_tier(&creator, &bronze, &10_000);
-    client.add_reward_tier(&creator, &silver, &100_000);
-    client.add_reward_tier(&creator, &gold, &500_000);
+    client.add_reward_tier(&creator, &bronze, &10_000, &None);
+    client.add_reward_tier(&creator, &silver, &100_000, &None);
+    client.add_reward_tier(&creator, &gold, &500_000, &None);
 
     let contributor = Address::generate(&env);
     mint_to(&env, &token_address, &admin, &contributor, 1_000_000);
-    client.contribute(&contributor, &1_000_000, &None);
+    client.contribute(&contributor, &1_000_000, &None, &None);
 
     let tier = client.get_user_tier(&contributor);
     assert!(tier.is_some());
@@ -831,14 +996,16 @@ fn test_add_reward_tier_non_creator_rejected() {
         &token_address,
         &goal,
         &(goal * 2),
-        &deadline,
+        &Deadline::Timestamp(deadline),
         &min_contribution,
         &None,
+        &None,
+        &None,
     );
 
     let non_creator = Address::generate(&env);
     let bronze = soroban_sdk::String::from_str(&env, "Bronze");
-    client.add_reward_tier(&non_creator, &bronze, &10_000);
+    client.add_reward_tier(&non_creator, &bronze, &10_000, &None);
 }
 
 #[test]
@@ -854,13 +1021,15 @@ fn test_add_reward_tier_rejects_zero_min_amount() {
         &token_address,
         &goal,
         &(goal * 2),
-        &deadline,
+        &Deadline::Timestamp(deadline),
         &min_contribution,
         &None,
+        &None,
+        &None,
     );
 
     let bronze = soroban_sdk::String::from_str(&env, "Bronze");
-    client.add_reward_tier(&creator, &bronze, &0);
+    client.add_reward_tier(&creator, &bronze, &0, &None);
 }
 
 #[test]
@@ -875,17 +1044,19 @@ fn test_reward_tiers_view() {
         &token_address,
         &goal,
         &(goal * 2),
-        &deadline,
+        &Deadline::Timestamp(deadline),
         &min_contribution,
         &None,
+        &None,
+        &None,
     );
 
     assert_eq!(client.reward_tiers().len(), 0);
 
     let bronze = soroban_sdk::String::from_str(&env, "Bronze");
     let silver = soroban_sdk::String::from_str(&env, "Silver");
-    client.add_reward_tier(&creator, &bronze, &10_000);
-    client.add_reward_tier(&creator, &silver, &100_000);
+    client.add_reward_tier(&creator, &bronze, &10_000, &None);
+    client.add_reward_tier(&creator, &silver, &100_000, &None);
 
     let tiers = client.reward_tiers();
     assert_eq!(tiers.len(), 2);
@@ -895,6 +1066,94 @@ fn test_reward_tiers_view() {
     assert_eq!(tiers.get(1).unwrap().min_amount, 100_000);
 }
 
+/// Initializes a campaign with Bronze (10k), Silver (100k, max 1 claim) and
+/// Gold (500k) tiers.
+fn setup_limited_tiers(
+    env: &Env,
+    client: &CrowdfundContractClient,
+    creator: &Address,
+    token_address: &Address,
+) {
+    init_with_options(
+        env,
+        client,
+        creator,
+        token_address,
+        CampaignOptions::default(),
+    );
+
+    let bronze = soroban_sdk::String::from_str(env, "Bronze");
+    let silver = soroban_sdk::String::from_str(env, "Silver");
+    let gold = soroban_sdk::String::from_str(env, "Gold");
+    client.add_reward_tier(creator, &bronze, &10_000, &None);
+    client.add_reward_tier(creator, &silver, &100_000, &Some(1));
+    client.add_reward_tier(creator, &gold, &500_000, &None);
+}
+
+#[test]
+fn test_contributor_tier_across_thresholds() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    setup_limited_tiers(&env, &client, &creator, &token_address);
+
+    let small = Address::generate(&env);
+    let bronze = Address::generate(&env);
+    let gold = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &small, 5_000);
+    mint_to(&env, &token_address, &admin, &bronze, 50_000);
+    mint_to(&env, &token_address, &admin, &gold, 600_000);
+
+    client.contribute(&small, &5_000, &None, &None);
+    client.contribute(&bronze, &50_000, &None, &None);
+    client.contribute(&gold, &600_000, &None, &None);
+
+    assert_eq!(client.contributor_tier(&small), None);
+    assert_eq!(client.contributor_tier(&bronze), Some(0));
+    assert_eq!(client.contributor_tier(&gold), Some(2));
+}
+
+#[test]
+fn test_limited_tier_sells_out() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    setup_limited_tiers(&env, &client, &creator, &token_address);
+
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &first, 100_000);
+    mint_to(&env, &token_address, &admin, &second, 100_000);
+
+    client.contribute(&first, &100_000, &None, &None);
+    client.contribute(&second, &100_000, &None, &None);
+
+    // Silver only has one slot, so the second backer falls back to Bronze.
+    assert_eq!(client.contributor_tier(&first), Some(1));
+    assert_eq!(client.contributor_tier(&second), Some(0));
+
+    let tiers = client.reward_tiers();
+    assert_eq!(tiers.get(0).unwrap().claimed, 1);
+    assert_eq!(tiers.get(1).unwrap().claimed, 1);
+}
+
+#[test]
+fn test_contributor_tier_upgrade_by_topping_up() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    setup_limited_tiers(&env, &client, &creator, &token_address);
+
+    let contributor = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &contributor, 600_000);
+
+    client.contribute(&contributor, &50_000, &None, &None);
+    assert_eq!(client.contributor_tier(&contributor), Some(0));
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 5);
+    client.contribute(&contributor, &450_000, &None, &None);
+    assert_eq!(client.contributor_tier(&contributor), Some(2));
+
+    // The Bronze slot was released on upgrade.
+    let tiers = client.reward_tiers();
+    assert_eq!(tiers.get(0).unwrap().claimed, 0);
+    assert_eq!(tiers.get(2).unwrap().claimed, 1);
+}
+
 // ── Roadmap Tests ──────────────────────────────────────────────────────────
 
 #[test]
@@ -909,16 +1168,18 @@ fn test_add_single_roadmap_item() {
         &token_address,
         &goal,
         &(goal * 2),
-        &deadline,
+        &Deadline::Timestamp(deadline),
         &min_contribution,
         &None,
+        &None,
+        &None,
     );
 
     let current_time = env.ledger().timestamp();
     let roadmap_date = current_time + 86400; // 1 day in the future
     let description = soroban_sdk::String::from_str(&env, "Beta release");
 
-    client.add_roadmap_item(&roadmap_date, &description);
+    client.add_roadmap_item(&roadmap_date, &roadmap_date, &description);
 
     let roadmap = client.roadmap();
     assert_eq!(roadmap.len(), 1);
@@ -938,9 +1199,11 @@ fn test_add_multiple_roadmap_items_in_order() {
         &token_address,
         &goal,
         &(goal * 2),
-        &deadline,
+        &Deadline::Timestamp(deadline),
         &min_contribution,
         &None,
+        &None,
+        &None,
     );
 
     let current_time = env.ledger().timestamp();
@@ -952,9 +1215,9 @@ fn test_add_multiple_roadmap_items_in_order() {
     let desc2 = soroban_sdk::String::from_str(&env, "Beta release");
     let desc3 = soroban_sdk::String::from_str(&env, "Production launch");
 
-    client.add_roadmap_item(&date1, &desc1);
-    client.add_roadmap_item(&date2, &desc2);
-    client.add_roadmap_item(&date3, &desc3);
+    client.add_roadmap_item(&date1, &date1, &desc1);
+    client.add_roadmap_item(&date2, &date2, &desc2);
+    client.add_roadmap_item(&date3, &date3, &desc3);
 
     let roadmap = client.roadmap();
     assert_eq!(roadmap.len(), 3);
@@ -979,9 +1242,11 @@ fn test_add_roadmap_item_with_past_date_panics() {
         &token_address,
         &goal,
         &(goal * 2),
-        &deadline,
+        &Deadline::Timestamp(deadline),
         &min_contribution,
         &None,
+        &None,
+        &None,
     );
 
     let current_time = env.ledger().timestamp();
@@ -990,7 +1255,7 @@ fn test_add_roadmap_item_with_past_date_panics() {
     let past_date = current_time + 500; // Earlier than the new current time
     let description = soroban_sdk::String::from_str(&env, "Past milestone");
 
-    client.add_roadmap_item(&past_date, &description); // should panic
+    client.add_roadmap_item(&past_date, &past_date, &description); // should panic
 }
 
 #[test]
@@ -1006,15 +1271,17 @@ fn test_add_roadmap_item_with_current_date_panics() {
         &token_address,
         &goal,
         &(goal * 2),
-        &deadline,
+        &Deadline::Timestamp(deadline),
         &min_contribution,
         &None,
+        &None,
+        &None,
     );
 
     let current_time = env.ledger().timestamp();
     let description = soroban_sdk::String::from_str(&env, "Current milestone");
 
-    client.add_roadmap_item(&current_time, &description); // should panic
+    client.add_roadmap_item(&current_time, &current_time, &description); // should panic
 }
 
 #[test]
@@ -1030,23 +1297,25 @@ fn test_add_roadmap_item_with_empty_description_panics() {
         &token_address,
         &goal,
         &(goal * 2),
-        &deadline,
+        &Deadline::Timestamp(deadline),
         &min_contribution,
         &None,
+        &None,
+        &None,
     );
 
     let current_time = env.ledger().timestamp();
     let roadmap_date = current_time + 86400;
     let empty_description = soroban_sdk::String::from_str(&env, "");
 
-    client.add_roadmap_item(&roadmap_date, &empty_description); // should panic
+    client.add_roadmap_item(&roadmap_date, &roadmap_date, &empty_description); // should panic
 }
 
 #[test]
 #[should_panic]
 fn test_add_roadmap_item_by_non_creator_panics() {
     let env = Env::default();
-    let contract_id = env.register(crate::CrowdfundContract, ());
+    let contract_id = env.register(crate::CrowdfundContract, (Address::generate(&env),));
     let client = crate::CrowdfundContractClient::new(&env, &contract_id);
 
     let token_admin = Address::generate(&env);
@@ -1066,9 +1335,11 @@ fn test_add_roadmap_item_by_non_creator_panics() {
         &token_address,
         &goal,
         &(goal * 2),
-        &deadline,
+        &Deadline::Timestamp(deadline),
         &min_contribution,
         &None,
+        &None,
+        &None,
     );
 
     env.mock_all_auths_allowing_non_root_auth();
@@ -1088,7 +1359,133 @@ fn test_add_roadmap_item_by_non_creator_panics() {
         },
     }]);
 
-    client.add_roadmap_item(&roadmap_date, &description); // should panic
+    client.add_roadmap_item(&roadmap_date, &roadmap_date, &description); // should panic
+}
+
+/// Initializes a campaign with two roadmap milestones: the first due in
+/// one day, the second in two. Returns the two deadlines.
+fn setup_milestones(
+    env: &Env,
+    client: &CrowdfundContractClient,
+    creator: &Address,
+    token_address: &Address,
+) -> (u64, u64) {
+    init_with_options(
+        env,
+        client,
+        creator,
+        token_address,
+        CampaignOptions::default(),
+    );
+    let now = env.ledger().timestamp();
+    let first = now + DAY;
+    let second = now + 2 * DAY;
+    client.add_roadmap_item(
+        &(now + 100),
+        &first,
+        &soroban_sdk::String::from_str(env, "Prototype"),
+    );
+    client.add_roadmap_item(
+        &(now + 200),
+        &second,
+        &soroban_sdk::String::from_str(env, "Launch"),
+    );
+    (first, second)
+}
+
+#[test]
+fn test_milestone_completed_on_time() {
+    let (env, client, creator, token_address, _admin) = setup_env();
+    let (first, _second) = setup_milestones(&env, &client, &creator, &token_address);
+    assert_eq!(client.milestone_status(&0), MilestoneStatus::Pending);
+
+    env.ledger().set_timestamp(first);
+    let proof = soroban_sdk::String::from_str(&env, "ipfs://prototype-demo");
+    client.submit_milestone_proof(&0, &proof);
+
+    env.ledger().set_timestamp(first + DAY / 2);
+    assert_eq!(client.milestone_status(&0), MilestoneStatus::Complete);
+    assert_eq!(client.milestone_status(&1), MilestoneStatus::Pending);
+    assert_eq!(client.missed_milestone_count(), 0);
+    assert_eq!(client.roadmap().get(0).unwrap().proof, Some(proof));
+}
+
+#[test]
+fn test_milestone_marked_missed_after_deadline() {
+    let (env, client, creator, token_address, _admin) = setup_env();
+    let (first, second) = setup_milestones(&env, &client, &creator, &token_address);
+
+    env.ledger().set_timestamp(first + 1);
+    assert_eq!(client.milestone_status(&0), MilestoneStatus::Missed);
+    assert_eq!(client.milestone_status(&1), MilestoneStatus::Pending);
+    assert_eq!(client.missed_milestone_count(), 1);
+
+    env.ledger().set_timestamp(second + 1);
+    assert_eq!(client.missed_milestone_count(), 2);
+}
+
+#[test]
+#[should_panic(expected = "milestone deadline passed")]
+fn test_submit_milestone_proof_after_deadline_panics() {
+    let (env, client, creator, token_address, _admin) = setup_env();
+    let (first, _second) = setup_milestones(&env, &client, &creator, &token_address);
+
+    env.ledger().set_timestamp(first + 1);
+    client.submit_milestone_proof(&0, &soroban_sdk::String::from_str(&env, "late"));
+}
+
+#[test]
+#[should_panic(expected = "deadline must not precede date")]
+fn test_add_roadmap_item_with_deadline_before_date_panics() {
+    let (env, client, creator, token_address, _admin) = setup_env();
+    setup_milestones(&env, &client, &creator, &token_address);
+
+    let date = env.ledger().timestamp() + DAY;
+    client.add_roadmap_item(
+        &date,
+        &(date - 1),
+        &soroban_sdk::String::from_str(&env, "Audit"),
+    );
+}
+
+#[test]
+fn test_missed_milestones_slash_collateral_to_contributors() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let (first, second) = setup_milestones(&env, &client, &creator, &token_address);
+    client.post_collateral(&200_000, &2_500);
+    assert_eq!(client.collateral(), 200_000);
+
+    let contributor = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &contributor, 1_000_000);
+    client.contribute(&contributor, &1_000_000, &None, &None);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 3601);
+    client.withdraw();
+
+    // The first milestone lapses: a quarter of the bond is forfeited, once.
+    env.ledger().set_timestamp(first + 1);
+    assert_eq!(client.slash_missed_milestones(), 50_000);
+    assert_eq!(client.slash_missed_milestones(), 0);
+    assert_eq!(client.collateral(), 150_000);
+    assert_eq!(client.claimable_profit(&contributor), 50_000);
+
+    // The second is delivered, so the rest goes back to the creator.
+    client.submit_milestone_proof(&1, &soroban_sdk::String::from_str(&env, "ipfs://launch"));
+    env.ledger().set_timestamp(second + 1);
+    let token_client = token::Client::new(&env, &token_address);
+    let before = token_client.balance(&creator);
+    assert_eq!(client.release_collateral(), 150_000);
+    assert_eq!(token_client.balance(&creator) - before, 150_000);
+    assert_eq!(client.collateral(), 0);
+}
+
+#[test]
+#[should_panic(expected = "milestones still pending")]
+fn test_release_collateral_with_pending_milestone_panics() {
+    let (env, client, creator, token_address, _admin) = setup_env();
+    setup_milestones(&env, &client, &creator, &token_address);
+    client.post_collateral(&200_000, &2_500);
+
+    client.release_collateral();
 }
 
 #[test]
@@ -1103,9 +1500,11 @@ fn test_roadmap_empty_after_initialization() {
         &token_address,
         &goal,
         &(goal * 2),
-        &deadline,
+        &Deadline::Timestamp(deadline),
         &min_contribution,
         &None,
+        &None,
+        &None,
     );
 
     let roadmap = client.roadmap();
@@ -1126,9 +1525,11 @@ fn test_update_title() {
         &token_address,
         &goal,
         &(goal * 2),
-        &deadline,
+        &Deadline::Timestamp(deadline),
         &min_contribution,
         &None,
+        &None,
+        &None,
     );
 
     // Update title.
@@ -1150,9 +1551,11 @@ fn test_update_description() {
         &token_address,
         &goal,
         &(goal * 2),
-        &deadline,
+        &Deadline::Timestamp(deadline),
         &min_contribution,
         &None,
+        &None,
+        &None,
     );
 
     // Update description.
@@ -1172,9 +1575,11 @@ fn test_update_socials() {
         &token_address,
         &goal,
         &(goal * 2),
-        &deadline,
+        &Deadline::Timestamp(deadline),
         &min_contribution,
         &None,
+        &None,
+        &None,
     );
 
     // Update social links.
@@ -1194,9 +1599,11 @@ fn test_partial_update() {
         &token_address,
         &goal,
         &(goal * 2),
-        &deadline,
+        &Deadline::Timestamp(deadline),
         &min_contribution,
         &None,
+        &None,
+        &None,
     );
 
     // Update only title (description and socials should remain None).
@@ -1221,15 +1628,17 @@ fn test_update_metadata_when_not_active_panics() {
         &token_address,
         &goal,
         &(goal * 2),
-        &deadline,
+        &Deadline::Timestamp(deadline),
         &min_contribution,
         &None,
+        &None,
+        &None,
     );
 
     // Contribute to meet the goal.
     let contributor = Address::generate(&env);
     mint_to(&env, &token_address, &admin, &contributor, 1_000_000);
-    client.contribute(&contributor, &1_000_000, &None);
+    client.contribute(&contributor, &1_000_000, &None, &None);
 
     // Move past deadline and withdraw (status becomes Successful).
     env.ledger().set_timestamp(deadline + 1);
@@ -1253,9 +1662,11 @@ fn test_update_metadata_after_cancel_panics() {
         &token_address,
         &goal,
         &(goal * 2),
-        &deadline,
+        &Deadline::Timestamp(deadline),
         &min_contribution,
         &None,
+        &None,
+        &None,
     );
 
     // Cancel the campaign.
@@ -1270,785 +1681,8860 @@ fn test_update_metadata_after_cancel_panics() {
 // The authorization check is covered by require_auth() in the contract,
 // which will panic if the caller is not the creator.
 
-// ── Deadline Update Tests ──────────────────────────────────────────────────
+// ── Contribution Count Tests ───────────────────────────────────────────────
 
 #[test]
-fn test_update_deadline_extends_campaign() {
-    let (env, client, creator, token_address, _admin) = setup_env();
+fn test_contribution_count_zero_without_contributions() {
+    let (env, client, _creator) = setup_links_campaign();
 
-    let deadline = env.ledger().timestamp() + 3600;
-    let goal: i128 = 1_000_000;
-    let min_contribution: i128 = 1_000;
-    client.initialize(
-        &creator,
-        &token_address,
-        &goal,
-        &(goal * 2),
-        &deadline,
-        &min_contribution,
-        &None,
+    assert_eq!(client.contribution_count(&Address::generate(&env)), 0);
+}
+
+#[test]
+fn test_contribution_count_after_one_contribution() {
+    let (env, client, _creator) = setup_links_campaign();
+    let token_address = client.token();
+    let contributor = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token_address).mint(&contributor, &10_000);
+
+    client.contribute(&contributor, &10_000, &None, &None);
+
+    assert_eq!(client.contribution_count(&contributor), 1);
+}
+
+#[test]
+fn test_contribution_count_tracks_calls_per_address() {
+    let (env, client, _creator) = setup_links_campaign();
+    let token_address = client.token();
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token_address).mint(&alice, &30_000);
+    token::StellarAssetClient::new(&env, &token_address).mint(&bob, &10_000);
+
+    for _ in 0..3 {
+        client.contribute(&alice, &10_000, &None, &None);
+        // Wait out the per-address cooldown.
+        env.ledger().set_timestamp(env.ledger().timestamp() + 5);
+    }
+    client.contribute(&bob, &10_000, &None, &None);
+
+    assert_eq!(client.contribution_count(&alice), 3);
+    assert_eq!(client.contribution_count(&bob), 1);
+    assert_eq!(client.contribution(&alice), 30_000);
+}
+
+// ── Leaderboard Tests ──────────────────────────────────────────────────────
+
+/// Mints `amount` to a new address and contributes it.
+fn back_campaign(env: &Env, client: &CrowdfundContractClient, amount: i128) -> Address {
+    let contributor = Address::generate(env);
+    token::StellarAssetClient::new(env, &client.token()).mint(&contributor, &amount);
+    client.contribute(&contributor, &amount, &None, &None);
+    contributor
+}
+
+#[test]
+fn test_top_contributors_ranks_interleaved_contributions() {
+    let (env, client, _creator) = setup_links_campaign();
+    let mut backers = std::vec::Vec::new();
+    // Contribute 10k, 20k, ... 150k, in a shuffled order.
+    for step in 0..15 {
+        let rank = (step * 7) % 15;
+        let amount = (rank as i128 + 1) * 10_000;
+        backers.push((rank, back_campaign(&env, &client, amount)));
+    }
+    backers.sort_by_key(|(rank, _)| std::cmp::Reverse(*rank));
+
+    let top = client.top_contributors();
+    assert_eq!(top.len(), 10);
+    for (index, (rank, backer)) in backers.iter().take(10).enumerate() {
+        assert_eq!(
+            top.get(index as u32).unwrap(),
+            (backer.clone(), (*rank as i128 + 1) * 10_000)
+        );
+    }
+}
+
+#[test]
+fn test_top_contributors_top_up_overtakes_and_ties_keep_order() {
+    let (env, client, _creator) = setup_links_campaign();
+    let token_admin = token::StellarAssetClient::new(&env, &client.token());
+    let alice = back_campaign(&env, &client, 50_000);
+    let bob = back_campaign(&env, &client, 60_000);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 5);
+    token_admin.mint(&alice, &20_000);
+    client.contribute(&alice, &20_000, &None, &None);
+    let carol = back_campaign(&env, &client, 60_000);
+
+    assert_eq!(
+        client.top_contributors(),
+        soroban_sdk::Vec::from_array(&env, [(alice, 70_000), (bob, 60_000), (carol, 60_000)],)
     );
+}
 
-    // Verify initial deadline
-    assert_eq!(client.deadline(), deadline);
+#[test]
+fn test_top_contributors_refills_after_full_pledge_withdrawal() {
+    let (env, client, _creator) = setup_links_campaign();
+    let leader = back_campaign(&env, &client, 200_000);
+    for _ in 0..9 {
+        back_campaign(&env, &client, 50_000);
+    }
+    let eleventh = back_campaign(&env, &client, 10_000);
+    assert!(!client
+        .top_contributors()
+        .iter()
+        .any(|(address, _)| address == eleventh));
+
+    client.partial_refund(&leader, &200_000);
+
+    let top = client.top_contributors();
+    assert_eq!(top.len(), 10);
+    assert!(!top.iter().any(|(address, _)| address == leader));
+    assert_eq!(top.last().unwrap(), (eleventh, 10_000));
+}
 
-    // Extend the deadline
-    let new_deadline = deadline + 7200; // 2 more hours
-    client.update_deadline(&new_deadline);
+#[test]
+fn test_top_contributors_partial_withdrawal_drops_rank() {
+    let (env, client, _creator) = setup_links_campaign();
+    let alice = back_campaign(&env, &client, 90_000);
+    let bob = back_campaign(&env, &client, 60_000);
 
-    // Verify the deadline was updated
-    assert_eq!(client.deadline(), new_deadline);
+    client.partial_refund(&alice, &50_000);
+
+    assert_eq!(
+        client.top_contributors(),
+        soroban_sdk::Vec::from_array(&env, [(bob, 60_000), (alice, 40_000)])
+    );
 }
 
+// ── Contribution Stats Tests ───────────────────────────────────────────────
+
 #[test]
-#[should_panic(expected = "new deadline must be after current deadline")]
-fn test_update_deadline_rejects_shortening() {
-    let (env, client, creator, token_address, _admin) = setup_env();
+fn test_stats_follow_top_ups() {
+    let (env, client, _creator) = setup_links_campaign();
+    let alice = back_campaign(&env, &client, 30_000);
+    let bob = back_campaign(&env, &client, 50_000);
 
-    let deadline = env.ledger().timestamp() + 3600;
-    let goal: i128 = 1_000_000;
-    let min_contribution: i128 = 1_000;
-    client.initialize(
-        &creator,
-        &token_address,
-        &goal,
-        &(goal * 2),
-        &deadline,
-        &min_contribution,
-        &None,
+    env.ledger().set_timestamp(env.ledger().timestamp() + 5);
+    token::StellarAssetClient::new(&env, &client.token()).mint(&alice, &40_000);
+    client.contribute(&alice, &40_000, &None, &None);
+
+    assert_eq!(
+        client.stats(),
+        ContributionStats {
+            count: 3,
+            unique_contributors: 2,
+            largest: 70_000,
+            largest_from: Some(alice),
+            average: 60_000,
+        }
     );
+    assert_eq!(client.contribution(&bob), 50_000);
+}
 
-    // Try to shorten the deadline (should panic)
-    let shorter_deadline = deadline - 1800; // 30 minutes earlier
-    client.update_deadline(&shorter_deadline);
+#[test]
+fn test_stats_follow_pledge_withdrawals() {
+    let (env, client, _creator) = setup_links_campaign();
+    let alice = back_campaign(&env, &client, 90_000);
+    let bob = back_campaign(&env, &client, 60_000);
+    let carol = back_campaign(&env, &client, 30_000);
+
+    client.partial_refund(&alice, &90_000);
+    client.partial_refund(&carol, &10_000);
+
+    assert_eq!(
+        client.stats(),
+        ContributionStats {
+            count: 3,
+            unique_contributors: 2,
+            largest: 60_000,
+            largest_from: Some(bob),
+            average: 40_000,
+        }
+    );
 }
 
 #[test]
-#[should_panic(expected = "new deadline must be after current deadline")]
-fn test_update_deadline_rejects_equal_deadline() {
-    let (env, client, creator, token_address, _admin) = setup_env();
+fn test_stats_freeze_at_refund() {
+    let (env, client, _creator) = setup_links_campaign();
+    let alice = back_campaign(&env, &client, 90_000);
+    back_campaign(&env, &client, 30_000);
+    let before = client.stats();
 
-    let deadline = env.ledger().timestamp() + 3600;
-    let goal: i128 = 1_000_000;
-    let min_contribution: i128 = 1_000;
+    env.ledger().set_timestamp(env.ledger().timestamp() + 3601);
+    client.refund();
+
+    assert_eq!(client.total_raised(), 0);
+    assert_eq!(client.stats(), before);
+    assert_eq!(
+        before,
+        ContributionStats {
+            count: 2,
+            unique_contributors: 2,
+            largest: 90_000,
+            largest_from: Some(alice),
+            average: 60_000,
+        }
+    );
+}
+
+#[test]
+fn test_stats_empty_campaign() {
+    let (_env, client, _creator) = setup_links_campaign();
+
+    assert_eq!(
+        client.stats(),
+        ContributionStats {
+            count: 0,
+            unique_contributors: 0,
+            largest: 0,
+            largest_from: None,
+            average: 0,
+        }
+    );
+}
+
+// ── Campaign Identity Getter Tests ─────────────────────────────────────────
+
+#[test]
+fn test_creator_getter_after_initialize() {
+    let (env, client, creator, token_address, _admin) = setup_env();
+    let admin = Address::generate(&env);
     client.initialize(
         &creator,
         &token_address,
-        &goal,
-        &(goal * 2),
-        &deadline,
-        &min_contribution,
+        &1_000_000,
+        &2_000_000,
+        &Deadline::Timestamp(env.ledger().timestamp() + 3600),
+        &1_000,
+        &None,
+        &Some(admin.clone()),
         &None,
     );
 
-    // Try to set deadline to the same value (should panic)
-    client.update_deadline(&deadline);
+    assert_eq!(client.creator(), creator);
+    assert_ne!(client.creator(), admin);
 }
 
 #[test]
-#[should_panic(expected = "campaign is not active")]
-fn test_update_deadline_when_not_active_panics() {
+fn test_token_address_fixed_at_initialize() {
     let (env, client, creator, token_address, _admin) = setup_env();
-
     let deadline = env.ledger().timestamp() + 3600;
-    let goal: i128 = 1_000_000;
-    let min_contribution: i128 = 1_000;
     client.initialize(
         &creator,
         &token_address,
-        &goal,
-        &(goal * 2),
-        &deadline,
-        &min_contribution,
+        &1_000_000,
+        &2_000_000,
+        &Deadline::Timestamp(deadline),
+        &1_000,
+        &None,
+        &None,
         &None,
     );
+    assert_eq!(client.token_address(), token_address);
 
-    // Move past deadline and refund
-    env.ledger().set_timestamp(deadline + 1);
+    client.update_metadata(
+        &creator,
+        &Some(soroban_sdk::String::from_str(&env, "Renamed")),
+        &None,
+        &None,
+    );
+    client.update_deadline(&(deadline + 3600));
+    client.set_category(&CampaignCategory::Art);
 
-    // Refund to change status from Active to Refunded
-    let _ = client.try_refund();
+    assert_eq!(client.token_address(), token_address);
+    assert_eq!(client.token_address(), client.token());
+}
 
-    // Try to update deadline on a non-Active campaign (should panic)
-    let new_deadline = deadline + 7200;
-    client.update_deadline(&new_deadline);
+#[test]
+fn test_token_balance_tracks_total_raised_until_withdraw() {
+    let (env, client, _creator) = setup_links_campaign();
+    assert_eq!(client.token_balance(), 0);
+    back_campaign(&env, &client, 600_000);
+    back_campaign(&env, &client, 400_000);
+    assert_eq!(client.token_balance(), client.total_raised());
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 3601);
+    client.withdraw();
+
+    assert_eq!(client.token_balance(), 0);
 }
 
-// ── Stretch Goal Tests ─────────────────────────────────────────────────────
+#[test]
+fn test_token_balance_zero_after_full_refund() {
+    let (env, client, _creator) = setup_links_campaign();
+    back_campaign(&env, &client, 300_000);
+    assert_eq!(client.token_balance(), 300_000);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 3601);
+    client.refund();
+
+    assert_eq!(client.token_balance(), 0);
+}
 
 #[test]
-fn test_add_single_stretch_goal() {
+fn test_creation_timestamp_fixed_at_initialize() {
     let (env, client, creator, token_address, _admin) = setup_env();
-
-    let deadline = env.ledger().timestamp() + 3600;
-    let goal: i128 = 1_000_000;
-    let min_contribution: i128 = 1_000;
+    env.ledger().set_timestamp(1_700_000_000);
     client.initialize(
         &creator,
         &token_address,
-        &goal,
-        &(goal * 2),
-        &deadline,
-        &min_contribution,
+        &1_000_000,
+        &2_000_000,
+        &Deadline::Timestamp(1_700_000_000 + 3600),
+        &1_000,
+        &None,
+        &None,
         &None,
     );
+    assert_eq!(client.creation_timestamp(), 1_700_000_000);
 
-    let stretch_milestone: i128 = 1_500_000;
-    client.add_stretch_goal(&stretch_milestone);
+    env.ledger().set_timestamp(1_700_000_600);
+    back_campaign(&env, &client, 100_000);
+    client.update_deadline(&(1_700_000_000 + 7200));
 
-    assert_eq!(client.current_milestone(), stretch_milestone);
+    assert_eq!(client.creation_timestamp(), 1_700_000_000);
 }
 
-// ── Property-Based Fuzz Tests with Proptest ────────────────────────────────
-
-/// **Property Test 1: Invariant - Total Raised Equals Sum of Contributions**
-///
-/// For any valid (goal, deadline, contributions[]), the contract invariant holds:
-/// total_raised == sum of all individual contributions
-///
-/// This test generates random valid parameters and multiple contributors with
-/// varying contribution amounts, then verifies the invariant is maintained.
-proptest! {
-    #[test]
-    fn prop_total_raised_equals_sum_of_contributions(
-        goal in 1_000_000i128..100_000_000i128,
-        deadline_offset in 100u64..100_000u64,
-        amount1 in 1_000i128..10_000_000i128,
-        amount2 in 1_000i128..10_000_000i128,
-        amount3 in 1_000i128..10_000_000i128,
-    ) {
-        let (env, client, creator, token_address, admin) = setup_env();
-        let deadline = env.ledger().timestamp() + deadline_offset;
-        let hard_cap = (amount1 + amount2 + amount3).max(goal * 2);
-
-        client.initialize(&creator, &token_address, &goal, &hard_cap, &deadline, &1_000, &None);
+// ── Goal Reached Timestamp Tests ───────────────────────────────────────────
+
+/// Returns the `("goal_reached",)` events published by the last invocation
+/// of `contract`.
+fn goal_reached_events(env: &Env, contract: &Address) -> std::vec::Vec<GoalReachedEvent> {
+    let topics: soroban_sdk::Vec<Val> = (Symbol::new(env, "goal_reached"),).into_val(env);
+    env.events()
+        .all()
+        .iter()
+        .filter(|(id, t, _)| id == contract && *t == topics)
+        .map(|(_, _, data)| GoalReachedEvent::try_from_val(env, &data).unwrap())
+        .collect()
+}
 
-        let alice = Address::generate(&env);
-        let bob = Address::generate(&env);
-        let charlie = Address::generate(&env);
+#[test]
+fn test_goal_reached_at_records_exact_crossing() {
+    let (env, client, _creator) = setup_links_campaign();
+    back_campaign(&env, &client, 600_000);
+    assert_eq!(client.goal_reached_at(), None);
 
-        mint_to(&env, &token_address, &admin, &alice, amount1);
-        mint_to(&env, &token_address, &admin, &bob, amount2);
-        mint_to(&env, &token_address, &admin, &charlie, amount3);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 60);
+    let crossed_at = env.ledger().timestamp();
+    let crosser = back_campaign(&env, &client, 400_000);
 
-        client.contribute(&alice, &amount1, None);
-        client.contribute(&bob, &amount2, None);
-        client.contribute(&charlie, &amount3, None);
+    assert_eq!(
+        goal_reached_events(&env, &client.address),
+        std::vec![GoalReachedEvent {
+            total_raised: 1_000_000,
+            timestamp: crossed_at,
+            contributor: crosser,
+        }]
+    );
+    assert_eq!(client.goal_reached_at(), Some(crossed_at));
 
-        let expected_total = amount1 + amount2 + amount3;
-        let actual_total = client.total_raised();
+    // Later contributions neither move the timestamp nor re-emit the event.
+    env.ledger().set_timestamp(crossed_at + 60);
+    back_campaign(&env, &client, 100_000);
+    assert!(goal_reached_events(&env, &client.address).is_empty());
+    assert_eq!(client.goal_reached_at(), Some(crossed_at));
+}
 
-        // **INVARIANT**: total_raised must equal the sum of all contributions
-        prop_assert_eq!(actual_total, expected_total,
-            "total_raised ({}) != sum of contributions ({})",
-            actual_total, expected_total
-        );
-    }
+#[test]
+fn test_goal_reached_event_emitted_once_across_recrossing() {
+    let (env, client, _creator) = setup_links_campaign();
+    let mut events = std::vec::Vec::new();
+
+    back_campaign(&env, &client, 500_000);
+    events.extend(goal_reached_events(&env, &client.address));
+    let crosser = back_campaign(&env, &client, 600_000);
+    events.extend(goal_reached_events(&env, &client.address));
+
+    // Withdrawing a pledge dips the campaign back under its goal...
+    client.partial_refund(&crosser, &200_000);
+    events.extend(goal_reached_events(&env, &client.address));
+    assert_eq!(client.total_raised(), 900_000);
+
+    // ...and re-crossing it stays silent.
+    env.ledger().set_timestamp(env.ledger().timestamp() + 60);
+    back_campaign(&env, &client, 300_000);
+    events.extend(goal_reached_events(&env, &client.address));
+
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].total_raised, 1_100_000);
+    assert_eq!(events[0].contributor, crosser);
 }
 
-/// **Property Test 2: Invariant - Refund Returns Exact Contributed Amount**
-///
-/// For any valid contribution amount, refund always returns the exact amount
-/// with no remainder or shortfall.
-///
-/// This test verifies that each contributor receives back exactly what they
-/// contributed when the goal is not met and refund is called.
-proptest! {
-    #[test]
-    fn prop_refund_returns_exact_amount(
-        goal in 5_000_000i128..100_000_000i128,
-        deadline_offset in 100u64..100_000u64,
-        contribution in 1_000i128..5_000_000i128,
-    ) {
-        let (env, client, creator, token_address, admin) = setup_env();
-        let deadline = env.ledger().timestamp() + deadline_offset;
+#[test]
+fn test_goal_reached_at_records_over_contribution() {
+    let (env, client, _creator) = setup_links_campaign();
+    back_campaign(&env, &client, 10_000);
 
-        // Ensure contribution is less than goal
-        let safe_contribution = contribution.min(goal - 1);
+    back_campaign(&env, &client, 1_500_000);
 
-        client.initialize(&creator, &token_address, &goal, &(goal * 2), &deadline, &1_000, &None);
+    assert_eq!(client.goal_reached_at(), Some(env.ledger().timestamp()));
+}
 
-        let contributor = Address::generate(&env);
-        mint_to(&env, &token_address, &admin, &contributor, safe_contribution);
-        client.contribute(&contributor, &safe_contribution, None);
+#[test]
+fn test_goal_reached_at_none_when_goal_missed() {
+    let (env, client, _creator) = setup_links_campaign();
+    back_campaign(&env, &client, 999_999);
 
-        // Move past deadline (goal not met)
-        env.ledger().set_timestamp(deadline + 1);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 3601);
 
-        let token_client = token::Client::new(&env, &token_address);
-        let balance_before_refund = token_client.balance(&contributor);
+    assert_eq!(client.goal_reached_at(), None);
+}
 
-        client.refund();
+// ── Allowlist Tests ────────────────────────────────────────────────────────
 
-        let balance_after_refund = token_client.balance(&contributor);
+fn setup_allowlist_campaign() -> (Env, CrowdfundContractClient<'static>, Address) {
+    let (env, client, creator, token_address, _admin) = setup_env();
+    init_with_options(
+        &env,
+        &client,
+        &creator,
+        &token_address,
+        CampaignOptions {
+            allowlist_enabled: true,
+            ..Default::default()
+        },
+    );
+    (env, client, creator)
+}
 
-        // **INVARIANT**: Refund must return exact amount with no remainder
-        prop_assert_eq!(
-            balance_after_refund - balance_before_refund,
-            safe_contribution,
-            "refund amount ({}) != original contribution ({})",
-            balance_after_refund - balance_before_refund,
-            safe_contribution
-        );
-    }
+/// Mints `amount` to a fresh address and returns it, without contributing.
+fn funded_contributor(env: &Env, client: &CrowdfundContractClient, amount: i128) -> Address {
+    let contributor = Address::generate(env);
+    token::StellarAssetClient::new(env, &client.token()).mint(&contributor, &amount);
+    contributor
 }
 
-/// **Property Test 3: Contribute with Amount <= 0 Always Fails**
-///
-/// For any contribution amount <= 0, the contribute function must fail.
-/// This test verifies that zero and negative contributions are rejected.
-proptest! {
-    #[test]
-    fn prop_contribute_zero_or_negative_fails(
-        goal in 1_000_000i128..10_000_000i128,
-        deadline_offset in 100u64..10_000u64,
-        negative_amount in -1_000_000i128..=0i128,
-    ) {
-        let (env, client, creator, token_address, admin) = setup_env();
-        let deadline = env.ledger().timestamp() + deadline_offset;
+#[test]
+fn test_allowlisted_contributor_can_contribute() {
+    let (env, client, _creator) = setup_allowlist_campaign();
+    let alice = funded_contributor(&env, &client, 100_000);
 
-        client.initialize(&creator, &token_address, &goal, &(goal * 2), &deadline, &1_000, &None);
+    client.add_to_allowlist(&soroban_sdk::Vec::from_array(&env, [alice.clone()]));
+    assert!(client.is_allowlisted(&alice));
+    client.contribute(&alice, &100_000, &None, &None);
 
-        let contributor = Address::generate(&env);
-        // Mint enough tokens so the failure is due to amount validation, not balance
-        mint_to(&env, &token_address, &admin, &contributor, 10_000_000);
+    assert_eq!(client.contribution(&alice), 100_000);
+}
 
-        // Attempt to contribute zero or negative amount
-        // This should fail due to minimum contribution check
-        let result = client.try_contribute(&contributor, &negative_amount);
+#[test]
+fn test_non_allowlisted_contributor_rejected() {
+    let (env, client, _creator) = setup_allowlist_campaign();
+    let alice = funded_contributor(&env, &client, 100_000);
+    let mallory = funded_contributor(&env, &client, 100_000);
+    client.add_to_allowlist(&soroban_sdk::Vec::from_array(&env, [alice]));
+
+    assert!(!client.is_allowlisted(&mallory));
+    assert_eq!(
+        client.try_contribute(&mallory, &100_000, &None, &None),
+        Err(Ok(crate::ContractError::NotAllowlisted))
+    );
+}
+
+#[test]
+fn test_allowlist_removal_blocks_future_contributions_only() {
+    let (env, client, _creator) = setup_allowlist_campaign();
+    let alice = funded_contributor(&env, &client, 200_000);
+    let listed = soroban_sdk::Vec::from_array(&env, [alice.clone()]);
+    client.add_to_allowlist(&listed);
+    client.contribute(&alice, &100_000, &None, &None);
+
+    client.remove_from_allowlist(&listed);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 5);
+
+    assert!(!client.is_allowlisted(&alice));
+    assert_eq!(
+        client.try_contribute(&alice, &100_000, &None, &None),
+        Err(Ok(crate::ContractError::NotAllowlisted))
+    );
+    assert_eq!(client.contribution(&alice), 100_000);
+    assert_eq!(client.total_raised(), 100_000);
+}
+
+// ── Merkle Allowlist Tests ─────────────────────────────────────────────────
+
+fn merkle_leaf(env: &Env, address: &Address) -> soroban_sdk::BytesN<32> {
+    use soroban_sdk::xdr::ToXdr;
+    env.crypto().sha256(&address.clone().to_xdr(env)).into()
+}
+
+fn merkle_parent(
+    env: &Env,
+    a: &soroban_sdk::BytesN<32>,
+    b: &soroban_sdk::BytesN<32>,
+) -> soroban_sdk::BytesN<32> {
+    let (left, right) = if a <= b { (a, b) } else { (b, a) };
+    let mut pair = soroban_sdk::Bytes::from_array(env, &left.to_array());
+    pair.append(&soroban_sdk::Bytes::from_array(env, &right.to_array()));
+    env.crypto().sha256(&pair).into()
+}
+
+/// Builds a four-leaf tree over `members` and returns its root and the
+/// proof for each member, in order.
+fn merkle_tree(
+    env: &Env,
+    members: &[Address; 4],
+) -> (
+    soroban_sdk::BytesN<32>,
+    std::vec::Vec<soroban_sdk::Vec<soroban_sdk::BytesN<32>>>,
+) {
+    let leaves: std::vec::Vec<_> = members.iter().map(|m| merkle_leaf(env, m)).collect();
+    let left = merkle_parent(env, &leaves[0], &leaves[1]);
+    let right = merkle_parent(env, &leaves[2], &leaves[3]);
+    let root = merkle_parent(env, &left, &right);
+    let proofs = (0..4)
+        .map(|i| {
+            let uncle = if i < 2 { right.clone() } else { left.clone() };
+            soroban_sdk::Vec::from_array(env, [leaves[i ^ 1].clone(), uncle])
+        })
+        .collect();
+    (root, proofs)
+}
+
+#[test]
+fn test_contribute_with_valid_proof() {
+    let (env, client, _creator) = setup_links_campaign();
+    let members = [(); 4].map(|_| funded_contributor(&env, &client, 100_000));
+    let (root, proofs) = merkle_tree(&env, &members);
+    client.set_allowlist_root(&root);
+
+    client.contribute_with_proof(&members[2], &100_000, &proofs[2]);
+
+    assert_eq!(client.allowlist_root(), Some(root));
+    assert_eq!(client.contribution(&members[2]), 100_000);
+    // Members still need the proof: the root gates plain `contribute`.
+    assert_eq!(
+        client.try_contribute(&members[3], &100_000, &None, &None),
+        Err(Ok(crate::ContractError::NotAllowlisted))
+    );
+}
+
+#[test]
+fn test_contribute_with_proof_for_wrong_leaf_fails() {
+    let (env, client, _creator) = setup_links_campaign();
+    let members = [(); 4].map(|_| funded_contributor(&env, &client, 100_000));
+    let (root, proofs) = merkle_tree(&env, &members);
+    client.set_allowlist_root(&root);
+    let outsider = funded_contributor(&env, &client, 100_000);
+
+    assert_eq!(
+        client.try_contribute_with_proof(&outsider, &100_000, &proofs[0]),
+        Err(Ok(crate::ContractError::InvalidProof))
+    );
+    assert_eq!(
+        client.try_contribute_with_proof(&members[1], &100_000, &proofs[2]),
+        Err(Ok(crate::ContractError::InvalidProof))
+    );
+    assert_eq!(client.total_raised(), 0);
+}
+
+#[test]
+fn test_contribute_with_stale_proof_after_root_rotation_fails() {
+    let (env, client, _creator) = setup_links_campaign();
+    let members = [(); 4].map(|_| funded_contributor(&env, &client, 100_000));
+    let (old_root, old_proofs) = merkle_tree(&env, &members);
+    client.set_allowlist_root(&old_root);
+
+    let rotated = [(); 4].map(|_| funded_contributor(&env, &client, 100_000));
+    let (new_root, new_proofs) = merkle_tree(&env, &rotated);
+    client.set_allowlist_root(&new_root);
+
+    assert_eq!(
+        client.try_contribute_with_proof(&members[0], &100_000, &old_proofs[0]),
+        Err(Ok(crate::ContractError::InvalidProof))
+    );
+    client.contribute_with_proof(&rotated[0], &100_000, &new_proofs[0]);
+    assert_eq!(client.total_raised(), 100_000);
+}
+
+// ── Cancel And Migrate Tests ───────────────────────────────────────────────
+
+/// Registers a second campaign on `token_address`, Active with the given
+/// minimum contribution.
+fn setup_migration_target(
+    env: &Env,
+    token_address: &Address,
+    min_contribution: i128,
+) -> CrowdfundContractClient<'static> {
+    let target = CrowdfundContractClient::new(
+        env,
+        &env.register(CrowdfundContract, (Address::generate(env),)),
+    );
+    target.initialize(
+        &Address::generate(env),
+        token_address,
+        &1_000_000,
+        &2_000_000,
+        &Deadline::Timestamp(env.ledger().timestamp() + 7200),
+        &min_contribution,
+        &None,
+        &None,
+        &None,
+    );
+    target
+}
+
+#[test]
+fn test_cancel_and_migrate_needs_only_creator_auth() {
+    let (env, client, creator) = setup_links_campaign();
+    let token_address = client.token();
+    let target = setup_migration_target(&env, &token_address, 1_000);
+    let alice = back_campaign(&env, &client, 300_000);
+    let bob = back_campaign(&env, &client, 200_000);
+
+    env.set_auths(&[]);
+    client
+        .mock_auths(&[soroban_sdk::testutils::MockAuth {
+            address: &creator,
+            invoke: &soroban_sdk::testutils::MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "cancel_and_migrate",
+                args: (target.address.clone(),).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .cancel_and_migrate(&target.address);
+
+    assert_eq!(client.status(), Status::Cancelled);
+    assert_eq!(client.migration_target(), Some(target.address.clone()));
+    assert_eq!(client.migratable_amount(&alice), 300_000);
+    assert_eq!(client.migratable_amount(&bob), 200_000);
+    // Nothing moves until each contributor signs their own migration.
+    let token = token::Client::new(&env, &token_address);
+    assert_eq!(token.balance(&alice), 300_000);
+    assert_eq!(target.total_raised(), 0);
+}
+
+#[test]
+fn test_migrate_my_contribution_moves_refund() {
+    let (env, client, _creator) = setup_links_campaign();
+    let token_address = client.token();
+    let target = setup_migration_target(&env, &token_address, 1_000);
+    let alice = back_campaign(&env, &client, 300_000);
+    let bob = back_campaign(&env, &client, 200_000);
+    client.cancel_and_migrate(&target.address);
+
+    assert_eq!(client.migrate_my_contribution(&alice), 300_000);
+
+    assert_eq!(target.contribution(&alice), 300_000);
+    assert_eq!(target.contribution(&bob), 0);
+    assert_eq!(client.migratable_amount(&alice), 0);
+    let token = token::Client::new(&env, &token_address);
+    assert_eq!(token.balance(&alice), 0);
+    assert_eq!(token.balance(&bob), 200_000);
+    assert_eq!(token.balance(&target.address), 300_000);
+
+    let again = client.try_migrate_my_contribution(&alice);
+    assert_eq!(again, Err(Ok(crate::ContractError::NothingToClaim)));
+}
+
+#[test]
+fn test_failed_migration_keeps_refund() {
+    let (env, client, _creator) = setup_links_campaign();
+    let token_address = client.token();
+    // Bob's contribution is below the new campaign's minimum.
+    let target = setup_migration_target(&env, &token_address, 100_000);
+    let bob = back_campaign(&env, &client, 50_000);
+    client.cancel_and_migrate(&target.address);
+
+    let result = client.try_migrate_my_contribution(&bob);
+
+    assert!(result.is_err());
+    assert_eq!(target.contribution(&bob), 0);
+    assert_eq!(client.migratable_amount(&bob), 50_000);
+    let token = token::Client::new(&env, &token_address);
+    assert_eq!(token.balance(&bob), 50_000);
+}
+
+#[test]
+fn test_migrate_my_contribution_requires_migration() {
+    let (env, client, _creator) = setup_links_campaign();
+    let alice = back_campaign(&env, &client, 300_000);
+    client.cancel();
+
+    let result = client.try_migrate_my_contribution(&alice);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_cancel_and_migrate_rejects_other_token_without_refunding() {
+    let (env, client, _creator) = setup_links_campaign();
+    let other_token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let target = setup_migration_target(&env, &other_token, 1_000);
+    let alice = back_campaign(&env, &client, 300_000);
+
+    let result = client.try_cancel_and_migrate(&target.address);
+
+    assert!(result.is_err());
+    assert_eq!(client.status(), Status::Active);
+    assert_eq!(client.contribution(&alice), 300_000);
+}
+
+// ── Auto-Close Tests ───────────────────────────────────────────────────────
+
+fn setup_auto_close_campaign() -> (Env, CrowdfundContractClient<'static>, Address) {
+    let (env, client, creator, token_address, _admin) = setup_env();
+    init_with_options(
+        &env,
+        &client,
+        &creator,
+        &token_address,
+        CampaignOptions {
+            auto_close_at_goal: true,
+            ..Default::default()
+        },
+    );
+    (env, client, creator)
+}
+
+#[test]
+fn test_auto_close_trims_closing_contribution_to_goal() {
+    let (env, client, _creator) = setup_auto_close_campaign();
+    back_campaign(&env, &client, 700_000);
+
+    let closer = back_campaign(&env, &client, 500_000);
+
+    assert_eq!(
+        campaign_events::<i128>(&env, &client.address, "closed_at_goal"),
+        std::vec![1_000_000]
+    );
+    assert_eq!(client.contribution(&closer), 300_000);
+    assert_eq!(client.total_raised(), 1_000_000);
+    let token = token::Client::new(&env, &client.token());
+    assert_eq!(token.balance(&closer), 200_000);
+}
+
+#[test]
+fn test_auto_close_rejects_later_contributions() {
+    let (env, client, _creator) = setup_auto_close_campaign();
+    let backer = back_campaign(&env, &client, 1_000_000);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 5);
+
+    let latecomer = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &client.token()).mint(&latecomer, &10_000);
+    assert_eq!(
+        client.try_contribute(&latecomer, &10_000, &None, &None),
+        Err(Ok(crate::ContractError::GoalAlreadyReached))
+    );
+    assert_eq!(
+        client.try_partial_refund(&backer, &100_000),
+        Err(Ok(crate::ContractError::GoalAlreadyReached))
+    );
+    assert_eq!(client.total_raised(), 1_000_000);
+}
+
+#[test]
+fn test_auto_close_allows_withdraw_before_deadline() {
+    let (env, client, creator) = setup_auto_close_campaign();
+    let token = token::Client::new(&env, &client.token());
+    let creator_before = token.balance(&creator);
+    back_campaign(&env, &client, 1_000_000);
+
+    client.withdraw();
+
+    assert_eq!(client.status(), Status::Successful);
+    assert_eq!(token.balance(&creator), creator_before + 1_000_000);
+}
+
+#[test]
+fn test_withdraw_before_deadline_still_rejected_without_auto_close() {
+    let (env, client, _creator) = setup_links_campaign();
+    back_campaign(&env, &client, 1_000_000);
+
+    assert_eq!(
+        client.try_withdraw(),
+        Err(Ok(crate::ContractError::CampaignStillActive))
+    );
+}
+
+fn setup_cap_close_campaign(
+    auto_close_on_cap: bool,
+) -> (Env, CrowdfundContractClient<'static>, Address) {
+    let (env, client, creator, token_address, _admin) = setup_env();
+    init_with_options(
+        &env,
+        &client,
+        &creator,
+        &token_address,
+        CampaignOptions {
+            auto_close_on_cap,
+            ..Default::default()
+        },
+    );
+    (env, client, creator)
+}
+
+#[test]
+fn test_auto_close_on_cap_allows_withdraw_before_deadline() {
+    let (env, client, creator) = setup_cap_close_campaign(true);
+    let token = token::Client::new(&env, &client.token());
+    let creator_before = token.balance(&creator);
+    back_campaign(&env, &client, 1_500_000);
+    assert!(campaign_events::<i128>(&env, &client.address, "closed_at_cap").is_empty());
+    assert_eq!(client.status(), Status::Active);
+
+    back_campaign(&env, &client, 500_000);
+    assert_eq!(
+        campaign_events::<i128>(&env, &client.address, "closed_at_cap"),
+        std::vec![2_000_000]
+    );
+    assert_eq!(client.status(), Status::Successful);
+
+    client.withdraw();
+
+    assert_eq!(client.status(), Status::Successful);
+    assert_eq!(token.balance(&creator), creator_before + 2_000_000);
+    assert!(client.try_withdraw().is_err());
+}
+
+#[test]
+fn test_auto_close_on_cap_holds_post_payout_claims_until_withdraw() {
+    let (env, client, _creator) = setup_cap_close_campaign(true);
+    let contributor = back_campaign(&env, &client, 2_000_000);
+    assert_eq!(client.status(), Status::Successful);
+
+    assert_eq!(
+        client.try_claim_badge(&contributor),
+        Err(Ok(crate::ContractError::CampaignNotSuccessful))
+    );
+
+    client.withdraw();
+    client.claim_badge(&contributor);
+}
+
+#[test]
+#[should_panic(expected = "auto_close_on_cap cannot have a refund window")]
+fn test_auto_close_on_cap_rejects_refund_window() {
+    let (env, client, creator, token_address, _admin) = setup_env();
+    init_with_options(
+        &env,
+        &client,
+        &creator,
+        &token_address,
+        CampaignOptions {
+            auto_close_on_cap: true,
+            refund_window_seconds: 3600,
+            ..Default::default()
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "campaign is fully funded")]
+fn test_auto_close_on_cap_rejects_later_contributions() {
+    let (env, client, _creator) = setup_cap_close_campaign(true);
+    back_campaign(&env, &client, 2_000_000);
+
+    back_campaign(&env, &client, 10_000);
+}
+
+#[test]
+fn test_cap_without_auto_close_keeps_campaign_open() {
+    let (env, client, _creator) = setup_cap_close_campaign(false);
+    back_campaign(&env, &client, 2_000_000);
+    assert_eq!(client.status(), Status::Active);
+
+    let latecomer = funded_contributor(&env, &client, 10_000);
+    assert_eq!(
+        client.try_contribute(&latecomer, &10_000, &None, &None),
+        Err(Ok(crate::ContractError::HardCapExceeded))
+    );
+    assert_eq!(
+        client.try_withdraw(),
+        Err(Ok(crate::ContractError::CampaignStillActive))
+    );
+}
+
+#[test]
+fn test_auto_close_on_cap_when_credit_overshoots() {
+    let (env, client, _creator) = setup_cap_close_campaign(true);
+    let contributor = back_campaign(&env, &client, 1_999_000);
+
+    // A credit landing past the cap still closes the campaign.
+    env.as_contract(&client.address, || {
+        crate::credit_contribution(&env, &contributor, 5_000, 1_999_000, 2_000_000).unwrap();
+    });
+
+    assert_eq!(
+        campaign_events::<i128>(&env, &client.address, "closed_at_cap"),
+        std::vec![2_000_000]
+    );
+    assert_eq!(client.total_raised(), 2_004_000);
+    assert_eq!(client.status(), Status::Successful);
+}
+
+// ── Contribution Share Tests ───────────────────────────────────────────────
+
+#[test]
+fn test_contribution_share_bps_single_contributor() {
+    let (env, client, _creator) = setup_links_campaign();
+    let contributor = back_campaign(&env, &client, 250_000);
+
+    assert_eq!(client.contribution_share_bps(&contributor), 10_000);
+}
+
+#[test]
+fn test_contribution_share_bps_equal_contributors() {
+    let (env, client, _creator) = setup_links_campaign();
+    let first = back_campaign(&env, &client, 300_000);
+    let second = back_campaign(&env, &client, 300_000);
+
+    assert_eq!(client.contribution_share_bps(&first), 5_000);
+    assert_eq!(client.contribution_share_bps(&second), 5_000);
+}
+
+#[test]
+fn test_contribution_share_bps_zero_for_non_contributor() {
+    let (env, client, _creator) = setup_links_campaign();
+    let outsider = Address::generate(&env);
+    assert_eq!(client.contribution_share_bps(&outsider), 0);
+
+    back_campaign(&env, &client, 300_000);
+    assert_eq!(client.contribution_share_bps(&outsider), 0);
+}
+
+// ── Snapshot Tests ─────────────────────────────────────────────────────────
+
+#[test]
+fn test_snapshot_captures_contribution_state() {
+    let (env, client, _creator) = setup_links_campaign();
+    back_campaign(&env, &client, 250_000);
+    back_campaign(&env, &client, 150_000);
+    env.ledger().set_sequence_number(42);
+    let name = soroban_sdk::String::from_str(&env, "round-1");
+
+    client.snapshot(&name);
+
+    assert_eq!(
+        client.get_snapshot(&name),
+        Some(Snapshot {
+            total_raised: 400_000,
+            contributor_count: 2,
+            ledger_sequence: 42,
+        })
+    );
+    assert_eq!(
+        client.get_snapshot(&soroban_sdk::String::from_str(&env, "missing")),
+        None
+    );
+}
+
+#[test]
+fn test_multiple_snapshots_keep_their_own_state() {
+    let (env, client, _creator) = setup_links_campaign();
+    let first = soroban_sdk::String::from_str(&env, "first");
+    let second = soroban_sdk::String::from_str(&env, "second");
+    back_campaign(&env, &client, 100_000);
+    env.ledger().set_sequence_number(10);
+    client.snapshot(&first);
+
+    back_campaign(&env, &client, 300_000);
+    env.ledger().set_sequence_number(20);
+    client.snapshot(&second);
+
+    assert_eq!(
+        client.get_snapshot(&first),
+        Some(Snapshot {
+            total_raised: 100_000,
+            contributor_count: 1,
+            ledger_sequence: 10,
+        })
+    );
+    assert_eq!(
+        client.get_snapshot(&second),
+        Some(Snapshot {
+            total_raised: 400_000,
+            contributor_count: 2,
+            ledger_sequence: 20,
+        })
+    );
+}
+
+#[test]
+#[should_panic(expected = "snapshot name already exists")]
+fn test_snapshot_name_cannot_be_reused() {
+    let (env, client, _creator) = setup_links_campaign();
+    let name = soroban_sdk::String::from_str(&env, "round-1");
+    client.snapshot(&name);
+    back_campaign(&env, &client, 100_000);
+
+    client.snapshot(&name);
+}
+
+// ── Featured Flag Tests ────────────────────────────────────────────────────
+
+#[test]
+fn test_set_featured_active_until_expiry() {
+    let (env, client, _creator) = setup_links_campaign();
+    let expires_at = env.ledger().timestamp() + DAY;
+    assert!(!client.is_featured());
+
+    client.set_featured(&expires_at);
+
+    assert!(client.is_featured());
+    assert_eq!(client.featured_until(), Some(expires_at));
+}
+
+#[test]
+fn test_expired_featured_flag_is_cleared_on_read() {
+    let (env, client, _creator) = setup_links_campaign();
+    let expires_at = env.ledger().timestamp() + DAY;
+    client.set_featured(&expires_at);
+
+    env.ledger().set_timestamp(expires_at);
+
+    assert!(!client.is_featured());
+    assert_eq!(client.featured_until(), None);
+}
+
+#[test]
+fn test_set_featured_rejected_from_non_deployer() {
+    let (env, client, creator) = setup_links_campaign();
+    let expires_at = env.ledger().timestamp() + DAY;
+
+    env.set_auths(&[]);
+    let result = client
+        .mock_auths(&[soroban_sdk::testutils::MockAuth {
+            address: &creator,
+            invoke: &soroban_sdk::testutils::MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "set_featured",
+                args: (expires_at,).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_set_featured(&expires_at);
+
+    assert!(result.is_err());
+    assert!(!client.is_featured());
+}
+
+// ── Campaign Links Tests ───────────────────────────────────────────────────
+
+fn campaign_links(env: &Env, website: &str, twitter: &str, github: &str) -> CampaignLinks {
+    CampaignLinks {
+        website: soroban_sdk::String::from_str(env, website),
+        twitter: soroban_sdk::String::from_str(env, twitter),
+        github: soroban_sdk::String::from_str(env, github),
+    }
+}
+
+fn setup_links_campaign() -> (Env, CrowdfundContractClient<'static>, Address) {
+    let (env, client, creator, token_address, _admin) = setup_env();
+    init_with_options(
+        &env,
+        &client,
+        &creator,
+        &token_address,
+        CampaignOptions::default(),
+    );
+    (env, client, creator)
+}
+
+#[test]
+fn test_set_links_stores_and_emits() {
+    let (env, client, _creator) = setup_links_campaign();
+    assert_eq!(client.links(), None);
+
+    let links = campaign_links(
+        &env,
+        "https://solar.example",
+        "https://twitter.com/solar",
+        "https://github.com/solar",
+    );
+    client.set_links(&links);
+
+    assert_eq!(
+        campaign_events::<LinksUpdatedEvent>(&env, &client.address, "links_updated"),
+        std::vec![LinksUpdatedEvent {
+            links: links.clone()
+        }]
+    );
+    assert_eq!(client.links(), Some(links));
+}
+
+#[test]
+fn test_set_links_update_replaces_previous() {
+    let (env, client, _creator) = setup_links_campaign();
+    client.set_links(&campaign_links(
+        &env,
+        "https://old.example",
+        "https://twitter.com/old",
+        "",
+    ));
+
+    let updated = campaign_links(&env, "https://new.example", "", "https://github.com/new");
+    client.set_links(&updated);
+
+    assert_eq!(client.links(), Some(updated));
+}
+
+#[test]
+fn test_set_links_accepts_empty_fields() {
+    let (env, client, _creator) = setup_links_campaign();
+    let empty = campaign_links(&env, "", "", "");
+
+    client.set_links(&empty);
+
+    assert_eq!(client.links(), Some(empty));
+}
+
+#[test]
+fn test_set_links_rejected_from_non_creator() {
+    let (env, client, _creator) = setup_links_campaign();
+    let outsider = Address::generate(&env);
+    let links = campaign_links(&env, "https://phish.example", "", "");
+
+    env.set_auths(&[]);
+    let result = client
+        .mock_auths(&[soroban_sdk::testutils::MockAuth {
+            address: &outsider,
+            invoke: &soroban_sdk::testutils::MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "set_links",
+                args: (links.clone(),).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_set_links(&links);
+
+    assert!(result.is_err());
+    assert_eq!(client.links(), None);
+}
+
+// ── Category Tests ─────────────────────────────────────────────────────────
+
+#[test]
+fn test_set_category_round_trip() {
+    let (_env, client, _creator) = setup_links_campaign();
+    assert_eq!(client.category(), None);
+
+    client.set_category(&CampaignCategory::Environment);
+    assert_eq!(client.category(), Some(CampaignCategory::Environment));
+
+    client.set_category(&CampaignCategory::Education);
+    assert_eq!(client.category(), Some(CampaignCategory::Education));
+}
+
+#[test]
+fn test_set_category_after_deadline_fails() {
+    let (env, client, _creator) = setup_links_campaign();
+    client.set_category(&CampaignCategory::Art);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 3601);
+    let result = client.try_set_category(&CampaignCategory::Music);
+
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        crate::ContractError::CampaignEnded
+    );
+    assert_eq!(client.category(), Some(CampaignCategory::Art));
+}
+
+// ── Media Hash Tests ───────────────────────────────────────────────────────
+
+#[test]
+fn test_set_media_hash_stores_and_updates() {
+    let (env, client, _creator) = setup_links_campaign();
+    assert_eq!(client.media_hash(), None);
+
+    let banner =
+        soroban_sdk::String::from_str(&env, "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG");
+    client.set_media_hash(&banner);
+    assert_eq!(client.media_hash(), Some(banner));
+
+    let video = soroban_sdk::String::from_str(
+        &env,
+        "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+    );
+    client.set_media_hash(&video);
+    assert_eq!(client.media_hash(), Some(video));
+}
+
+#[test]
+#[should_panic(expected = "invalid media hash")]
+fn test_set_media_hash_too_short_panics() {
+    let (env, client, _creator) = setup_links_campaign();
+
+    client.set_media_hash(&soroban_sdk::String::from_str(&env, "QmTooShort"));
+}
+
+#[test]
+#[should_panic(expected = "invalid media hash")]
+fn test_set_media_hash_too_long_panics() {
+    let (env, client, _creator) = setup_links_campaign();
+
+    client.set_media_hash(&soroban_sdk::String::from_str(
+        &env,
+        "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi0",
+    ));
+}
+
+#[test]
+#[should_panic(expected = "campaign is not active")]
+fn test_set_media_hash_locked_after_campaign_ends() {
+    let (env, client, _creator) = setup_links_campaign();
+    client.cancel();
+
+    client.set_media_hash(&soroban_sdk::String::from_str(
+        &env,
+        "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG",
+    ));
+}
+
+// ── Deadline Update Tests ──────────────────────────────────────────────────
+
+#[test]
+fn test_update_deadline_extends_campaign() {
+    let (env, client, creator, token_address, _admin) = setup_env();
+
+    let deadline = env.ledger().timestamp() + 3600;
+    let goal: i128 = 1_000_000;
+    let min_contribution: i128 = 1_000;
+    client.initialize(
+        &creator,
+        &token_address,
+        &goal,
+        &(goal * 2),
+        &Deadline::Timestamp(deadline),
+        &min_contribution,
+        &None,
+        &None,
+        &None,
+    );
+
+    // Verify initial deadline
+    assert_eq!(client.deadline(), deadline);
+
+    // Extend the deadline
+    let new_deadline = deadline + 7200; // 2 more hours
+    client.update_deadline(&new_deadline);
+
+    // Verify the deadline was updated
+    assert_eq!(client.deadline(), new_deadline);
+}
+
+#[test]
+#[should_panic(expected = "new deadline must be after current deadline")]
+fn test_update_deadline_rejects_shortening() {
+    let (env, client, creator, token_address, _admin) = setup_env();
+
+    let deadline = env.ledger().timestamp() + 3600;
+    let goal: i128 = 1_000_000;
+    let min_contribution: i128 = 1_000;
+    client.initialize(
+        &creator,
+        &token_address,
+        &goal,
+        &(goal * 2),
+        &Deadline::Timestamp(deadline),
+        &min_contribution,
+        &None,
+        &None,
+        &None,
+    );
+
+    // Try to shorten the deadline (should panic)
+    let shorter_deadline = deadline - 1800; // 30 minutes earlier
+    client.update_deadline(&shorter_deadline);
+}
+
+#[test]
+#[should_panic(expected = "new deadline must be after current deadline")]
+fn test_update_deadline_rejects_equal_deadline() {
+    let (env, client, creator, token_address, _admin) = setup_env();
+
+    let deadline = env.ledger().timestamp() + 3600;
+    let goal: i128 = 1_000_000;
+    let min_contribution: i128 = 1_000;
+    client.initialize(
+        &creator,
+        &token_address,
+        &goal,
+        &(goal * 2),
+        &Deadline::Timestamp(deadline),
+        &min_contribution,
+        &None,
+        &None,
+        &None,
+    );
+
+    // Try to set deadline to the same value (should panic)
+    client.update_deadline(&deadline);
+}
+
+#[test]
+#[should_panic(expected = "campaign is not active")]
+fn test_update_deadline_when_not_active_panics() {
+    let (env, client, creator, token_address, _admin) = setup_env();
+
+    let deadline = env.ledger().timestamp() + 3600;
+    let goal: i128 = 1_000_000;
+    let min_contribution: i128 = 1_000;
+    client.initialize(
+        &creator,
+        &token_address,
+        &goal,
+        &(goal * 2),
+        &Deadline::Timestamp(deadline),
+        &min_contribution,
+        &None,
+        &None,
+        &None,
+    );
+
+    // Move past deadline and refund
+    env.ledger().set_timestamp(deadline + 1);
+
+    // Refund to change status from Active to Refunded
+    let _ = client.try_refund();
+
+    // Try to update deadline on a non-Active campaign (should panic)
+    let new_deadline = deadline + 7200;
+    client.update_deadline(&new_deadline);
+}
+
+// ── Stretch Goal Tests ─────────────────────────────────────────────────────
+
+#[test]
+fn test_add_single_stretch_goal() {
+    let (env, client, creator, token_address, _admin) = setup_env();
+
+    let deadline = env.ledger().timestamp() + 3600;
+    let goal: i128 = 1_000_000;
+    let min_contribution: i128 = 1_000;
+    client.initialize(
+        &creator,
+        &token_address,
+        &goal,
+        &(goal * 2),
+        &Deadline::Timestamp(deadline),
+        &min_contribution,
+        &None,
+        &None,
+        &None,
+    );
+
+    let stretch_milestone: i128 = 1_500_000;
+    client.add_stretch_goal(&stretch_milestone);
+
+    assert_eq!(client.current_milestone(), stretch_milestone);
+}
+
+// ── Property-Based Fuzz Tests with Proptest ────────────────────────────────
+
+/// **Property Test 1: Invariant - Total Raised Equals Sum of Contributions**
+///
+/// For any valid (goal, deadline, contributions[]), the contract invariant holds:
+/// total_raised == sum of all individual contributions
+///
+/// This test generates random valid parameters and multiple contributors with
+/// varying contribution amounts, then verifies the invariant is maintained.
+proptest! {
+    #[test]
+    fn prop_total_raised_equals_sum_of_contributions(
+        goal in 1_000_000i128..100_000_000i128,
+        deadline_offset in 3_600u64..100_000u64,
+        amount1 in 1_000i128..10_000_000i128,
+        amount2 in 1_000i128..10_000_000i128,
+        amount3 in 1_000i128..10_000_000i128,
+    ) {
+        let (env, client, creator, token_address, admin) = setup_env();
+        let deadline = env.ledger().timestamp() + deadline_offset;
+        let hard_cap = (amount1 + amount2 + amount3).max(goal * 2);
+
+        client.initialize(&creator, &token_address, &goal, &hard_cap, &Deadline::Timestamp(deadline), &1_000, &None, &None, &None);
+
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        let charlie = Address::generate(&env);
+
+        mint_to(&env, &token_address, &admin, &alice, amount1);
+        mint_to(&env, &token_address, &admin, &bob, amount2);
+        mint_to(&env, &token_address, &admin, &charlie, amount3);
+
+        client.contribute(&alice, &amount1, &None, &None);
+        client.contribute(&bob, &amount2, &None, &None);
+        client.contribute(&charlie, &amount3, &None, &None);
+
+        let expected_total = amount1 + amount2 + amount3;
+        let actual_total = client.total_raised();
+
+        // **INVARIANT**: total_raised must equal the sum of all contributions
+        prop_assert_eq!(actual_total, expected_total,
+            "total_raised ({}) != sum of contributions ({})",
+            actual_total, expected_total
+        );
+    }
+}
+
+/// **Property Test 2: Invariant - Refund Returns Exact Contributed Amount**
+///
+/// For any valid contribution amount, refund always returns the exact amount
+/// with no remainder or shortfall.
+///
+/// This test verifies that each contributor receives back exactly what they
+/// contributed when the goal is not met and refund is called.
+proptest! {
+    #[test]
+    fn prop_refund_returns_exact_amount(
+        goal in 5_000_000i128..100_000_000i128,
+        deadline_offset in 3_600u64..100_000u64,
+        contribution in 1_000i128..5_000_000i128,
+    ) {
+        let (env, client, creator, token_address, admin) = setup_env();
+        let deadline = env.ledger().timestamp() + deadline_offset;
+
+        // Ensure contribution is less than goal
+        let safe_contribution = contribution.min(goal - 1);
+
+        client.initialize(&creator, &token_address, &goal, &(goal * 2), &Deadline::Timestamp(deadline), &1_000, &None, &None, &None);
+
+        let contributor = Address::generate(&env);
+        mint_to(&env, &token_address, &admin, &contributor, safe_contribution);
+        client.contribute(&contributor, &safe_contribution, &None, &None);
+
+        // Move past deadline (goal not met)
+        env.ledger().set_timestamp(deadline + 1);
+
+        let token_client = token::Client::new(&env, &token_address);
+        let balance_before_refund = token_client.balance(&contributor);
+
+        client.refund();
+
+        let balance_after_refund = token_client.balance(&contributor);
+
+        // **INVARIANT**: Refund must return exact amount with no remainder
+        prop_assert_eq!(
+            balance_after_refund - balance_before_refund,
+            safe_contribution,
+            "refund amount ({}) != original contribution ({})",
+            balance_after_refund - balance_before_refund,
+            safe_contribution
+        );
+    }
+}
+
+/// **Property Test 3: Contribute with Amount <= 0 Always Fails**
+///
+/// For any contribution amount <= 0, the contribute function must fail.
+/// This test verifies that zero and negative contributions are rejected.
+proptest! {
+    #[test]
+    fn prop_contribute_zero_or_negative_fails(
+        goal in 1_000_000i128..10_000_000i128,
+        deadline_offset in 3_600u64..10_000u64,
+        negative_amount in -1_000_000i128..=0i128,
+    ) {
+        let (env, client, creator, token_address, admin) = setup_env();
+        let deadline = env.ledger().timestamp() + deadline_offset;
+
+        client.initialize(&creator, &token_address, &goal, &(goal * 2), &Deadline::Timestamp(deadline), &1_000, &None, &None, &None);
+
+        let contributor = Address::generate(&env);
+        // Mint enough tokens so the failure is due to amount validation, not balance
+        mint_to(&env, &token_address, &admin, &contributor, 10_000_000);
+
+        // Attempt to contribute zero or negative amount
+        // This should fail due to minimum contribution check
+        let result = client.try_contribute(&contributor, &negative_amount, &None, &None);
 
         // **INVARIANT**: Contribution <= 0 must fail
         prop_assert!(
             result.is_err(),
-            "contribute with amount {} should fail but succeeded",
-            negative_amount
+            "contribute with amount {} should fail but succeeded",
+            negative_amount
+        );
+    }
+}
+
+/// **Property Test 4: Deadline in the Past Always Fails on Initialize**
+///
+/// For any deadline in the past (relative to current ledger time),
+/// initialization must fail or panic.
+proptest! {
+    #[test]
+    fn prop_initialize_with_past_deadline_fails(
+        goal in 1_000_000i128..10_000_000i128,
+        past_offset in 1u64..10_000u64,
+    ) {
+        let (env, client, creator, token_address, _admin) = setup_env();
+
+        let current_time = env.ledger().timestamp();
+        // Set deadline in the past
+        let past_deadline = current_time.saturating_sub(past_offset);
+
+        // Attempt to initialize with past deadline
+        let result = client.try_initialize(
+            &creator,
+            &token_address,
+            &goal,
+            &(goal * 2),
+            &Deadline::Timestamp(past_deadline),
+            &1_000,
+            &None,
+            &None,
+            &None,
+        );
+
+        // **INVARIANT**: Past deadline should fail or be rejected
+        // Note: The contract may not explicitly validate this, but it's a logical invariant
+        // If the contract allows it, the campaign would already be expired
+        // This test documents the expected behavior
+        if result.is_ok() {
+            // If initialization succeeds with past deadline, verify campaign is immediately expired
+            let deadline = client.deadline();
+            prop_assert!(
+                deadline <= current_time,
+                "deadline {} should be in the past relative to current time {}",
+                deadline,
+                current_time
+            );
+        }
+    }
+}
+
+/// **Property Test 5: Multiple Contributions Accumulate Correctly**
+///
+/// For any sequence of valid contributions from multiple contributors,
+/// the total_raised must equal the sum of all contributions.
+proptest! {
+    #[test]
+    fn prop_multiple_contributions_accumulate(
+        goal in 5_000_000i128..50_000_000i128,
+        deadline_offset in 3_600u64..100_000u64,
+        amount1 in 1_000i128..5_000_000i128,
+        amount2 in 1_000i128..5_000_000i128,
+        amount3 in 1_000i128..5_000_000i128,
+    ) {
+        let (env, client, creator, token_address, admin) = setup_env();
+        let deadline = env.ledger().timestamp() + deadline_offset;
+        let expected_total = amount1 + amount2 + amount3;
+        let hard_cap = expected_total.max(goal);
+
+        client.initialize(&creator, &token_address, &goal, &hard_cap, &Deadline::Timestamp(deadline), &1_000, &None, &None, &None);
+
+        let contributor1 = Address::generate(&env);
+        let contributor2 = Address::generate(&env);
+        let contributor3 = Address::generate(&env);
+
+        mint_to(&env, &token_address, &admin, &contributor1, amount1);
+        mint_to(&env, &token_address, &admin, &contributor2, amount2);
+        mint_to(&env, &token_address, &admin, &contributor3, amount3);
+
+        client.contribute(&contributor1, &amount1, &None, &None);
+        client.contribute(&contributor2, &amount2, &None, &None);
+        client.contribute(&contributor3, &amount3, &None, &None);
+
+        // **INVARIANT**: total_raised must equal sum of all contributions
+        prop_assert_eq!(client.total_raised(), expected_total);
+
+        // **INVARIANT**: Each contributor's balance must be tracked correctly
+        prop_assert_eq!(client.contribution(&contributor1), amount1);
+        prop_assert_eq!(client.contribution(&contributor2), amount2);
+        prop_assert_eq!(client.contribution(&contributor3), amount3);
+    }
+}
+
+/// **Property Test 6: Withdrawal After Goal Met Transfers Correct Amount**
+///
+/// For any valid goal and contributions that meet or exceed the goal,
+/// withdrawal must transfer the exact total_raised amount to the creator.
+proptest! {
+    #[test]
+    fn prop_withdrawal_transfers_exact_amount(
+        goal in 1_000_000i128..10_000_000i128,
+        deadline_offset in 3_600u64..10_000u64,
+    ) {
+        let (env, client, creator, token_address, admin) = setup_env();
+        let deadline = env.ledger().timestamp() + deadline_offset;
+
+        client.initialize(&creator, &token_address, &goal, &(goal * 2), &Deadline::Timestamp(deadline), &1_000, &None, &None, &None);
+
+        let contributor = Address::generate(&env);
+        mint_to(&env, &token_address, &admin, &contributor, goal);
+        client.contribute(&contributor, &goal, &None, &None);
+
+        // Move past deadline
+        env.ledger().set_timestamp(deadline + 1);
+
+        let token_client = token::Client::new(&env, &token_address);
+        let creator_balance_before = token_client.balance(&creator);
+
+        client.withdraw();
+
+        let creator_balance_after = token_client.balance(&creator);
+        let transferred_amount = creator_balance_after - creator_balance_before;
+
+        // **INVARIANT**: Withdrawal must transfer exact total_raised amount
+        prop_assert_eq!(
+            transferred_amount, goal,
+            "withdrawal transferred {} but expected {}",
+            transferred_amount, goal
+        );
+
+        // **INVARIANT**: total_raised must be reset to 0 after withdrawal
+        prop_assert_eq!(client.total_raised(), 0);
+    }
+}
+
+/// **Property Test 7: Contribution Tracking Persists Across Multiple Calls**
+///
+/// For any contributor making multiple contributions, the total tracked
+/// must equal the sum of all their contributions.
+proptest! {
+    #[test]
+    fn prop_contribution_tracking_persists(
+        goal in 5_000_000i128..50_000_000i128,
+        deadline_offset in 3_600u64..100_000u64,
+        amount1 in 1_000i128..2_000_000i128,
+        amount2 in 1_000i128..2_000_000i128,
+        amount3 in 1_000i128..2_000_000i128,
+    ) {
+        let (env, client, creator, token_address, admin) = setup_env();
+        let deadline = env.ledger().timestamp() + deadline_offset;
+
+        client.initialize(&creator, &token_address, &goal, &(goal * 2), &Deadline::Timestamp(deadline), &1_000, &None, &None, &None);
+
+        let contributor = Address::generate(&env);
+        let total_needed = amount1.saturating_add(amount2).saturating_add(amount3);
+        mint_to(&env, &token_address, &admin, &contributor, total_needed);
+
+        // First contribution
+        client.contribute(&contributor, &amount1, &None, &None);
+        prop_assert_eq!(client.contribution(&contributor), amount1);
+
+        // Second contribution (after the per-address cooldown)
+        env.ledger().set_timestamp(env.ledger().timestamp() + 5);
+        client.contribute(&contributor, &amount2, &None, &None);
+        let expected_after_2 = amount1.saturating_add(amount2);
+        prop_assert_eq!(client.contribution(&contributor), expected_after_2);
+
+        // Third contribution
+        env.ledger().set_timestamp(env.ledger().timestamp() + 5);
+        client.contribute(&contributor, &amount3, &None, &None);
+        let expected_total = amount1.saturating_add(amount2).saturating_add(amount3);
+        prop_assert_eq!(client.contribution(&contributor), expected_total);
+
+        // **INVARIANT**: Final total_raised must equal sum of all contributions
+        prop_assert_eq!(client.total_raised(), expected_total);
+    }
+}
+
+/// **Property Test 8: Refund Resets Total Raised to Zero**
+///
+/// For any valid refund scenario (goal not met, deadline passed),
+/// total_raised must be reset to 0 after refund completes.
+proptest! {
+    #[test]
+    fn prop_refund_resets_total_raised(
+        goal in 5_000_000i128..50_000_000i128,
+        deadline_offset in 3_600u64..100_000u64,
+        contribution in 1_000i128..5_000_000i128,
+    ) {
+        let (env, client, creator, token_address, admin) = setup_env();
+        let deadline = env.ledger().timestamp() + deadline_offset;
+
+        let safe_contribution = contribution.min(goal - 1);
+
+        client.initialize(&creator, &token_address, &goal, &(goal * 2), &Deadline::Timestamp(deadline), &1_000, &None, &None, &None);
+
+        let contributor = Address::generate(&env);
+        mint_to(&env, &token_address, &admin, &contributor, safe_contribution);
+        client.contribute(&contributor, &safe_contribution, &None, &None);
+
+        // Verify total_raised is set
+        prop_assert_eq!(client.total_raised(), safe_contribution);
+
+        // Move past deadline (goal not met)
+        env.ledger().set_timestamp(deadline + 1);
+
+        client.refund();
+
+        // **INVARIANT**: total_raised must be 0 after refund
+        prop_assert_eq!(client.total_raised(), 0);
+    }
+}
+
+/// **Property Test 9: Contribution Below Minimum Always Fails**
+///
+/// For any contribution amount below the minimum, the contribute function
+/// must fail or panic.
+proptest! {
+    #[test]
+    fn prop_contribute_below_minimum_fails(
+        goal in 1_000_000i128..10_000_000i128,
+        deadline_offset in 3_600u64..10_000u64,
+        min_contribution in 1_000i128..100_000i128,
+        below_minimum in 1i128..1_000i128,
+    ) {
+        let (env, client, creator, token_address, admin) = setup_env();
+        let deadline = env.ledger().timestamp() + deadline_offset;
+
+        client.initialize(&creator, &token_address, &goal, &(goal * 2), &Deadline::Timestamp(deadline), &min_contribution, &None, &None, &None);
+
+        let contributor = Address::generate(&env);
+        let amount_to_contribute = below_minimum.min(min_contribution - 1);
+        mint_to(&env, &token_address, &admin, &contributor, amount_to_contribute);
+
+        // Attempt to contribute below minimum
+        let result = client.try_contribute(&contributor, &amount_to_contribute, &None, &None);
+
+        // **INVARIANT**: Contribution below minimum must fail
+        prop_assert!(
+            result.is_err(),
+            "contribute with amount {} below minimum {} should fail",
+            amount_to_contribute, min_contribution
+        );
+    }
+}
+
+/// **Property Test 10: Contribution After Deadline Always Fails**
+///
+/// For any contribution attempt after the deadline has passed,
+/// the contribute function must fail.
+proptest! {
+    #[test]
+    fn prop_contribute_after_deadline_fails(
+        goal in 1_000_000i128..10_000_000i128,
+        deadline_offset in 3_600u64..10_000u64,
+        contribution in 1_000i128..10_000_000i128,
+        time_after_deadline in 1u64..100_000u64,
+    ) {
+        let (env, client, creator, token_address, admin) = setup_env();
+        let deadline = env.ledger().timestamp() + deadline_offset;
+
+        client.initialize(&creator, &token_address, &goal, &(goal * 2), &Deadline::Timestamp(deadline), &1_000, &None, &None, &None);
+
+        // Move past deadline
+        env.ledger().set_timestamp(deadline + time_after_deadline);
+
+        let contributor = Address::generate(&env);
+        mint_to(&env, &token_address, &admin, &contributor, contribution);
+
+        // Attempt to contribute after deadline
+        let result = client.try_contribute(&contributor, &contribution, &None, &None);
+
+        // **INVARIANT**: Contribution after deadline must fail
+        prop_assert!(
+            result.is_err(),
+            "contribute after deadline should fail"
+        );
+        prop_assert_eq!(
+            result.unwrap_err().unwrap(),
+            crate::ContractError::CampaignEnded
+        );
+    }
+}
+
+// ── Pause/Unpause Tests ─────────────────────────────────────────────────────
+
+#[test]
+fn test_contribute_rejected_when_paused() {
+    let (env, client, creator, token_address, admin) = setup_env();
+
+    let deadline = env.ledger().timestamp() + 3600;
+    let goal: i128 = 1_000_000;
+    let min_contribution: i128 = 1_000;
+
+    client.initialize(
+        &creator,
+        &token_address,
+        &goal,
+        &(goal * 2),
+        &Deadline::Timestamp(deadline),
+        &min_contribution,
+        &None,
+        &None,
+        &None,
+    );
+
+    // Pause the contract
+    client.set_paused(&true);
+
+    // Try to contribute while paused
+    let contributor = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &contributor, 5_000);
+
+    let result = client.try_contribute(&contributor, &5_000, &None, &None);
+
+    assert!(result.is_err());
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        crate::ContractError::ContractPaused
+    );
+}
+
+#[test]
+fn test_withdraw_rejected_when_paused() {
+    let (env, client, creator, token_address, admin) = setup_env();
+
+    let deadline = env.ledger().timestamp() + 3600;
+    let goal: i128 = 1_000_000;
+    let min_contribution: i128 = 1_000;
+
+    client.initialize(
+        &creator,
+        &token_address,
+        &goal,
+        &(goal * 2),
+        &Deadline::Timestamp(deadline),
+        &min_contribution,
+        &None,
+        &None,
+        &None,
+    );
+
+    // Contribute to meet goal
+    let contributor = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &contributor, goal);
+    client.contribute(&contributor, &goal, &None, &None);
+
+    // Move past deadline
+    env.ledger().set_timestamp(deadline + 1);
+
+    // Pause the contract
+    client.set_paused(&true);
+
+    // Try to withdraw while paused
+    let result = client.try_withdraw();
+
+    assert!(result.is_err());
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        crate::ContractError::ContractPaused
+    );
+}
+
+#[test]
+fn test_refund_rejected_when_paused() {
+    let (env, client, creator, token_address, admin) = setup_env();
+
+    let deadline = env.ledger().timestamp() + 3600;
+    let goal: i128 = 1_000_000;
+    let min_contribution: i128 = 1_000;
+
+    client.initialize(
+        &creator,
+        &token_address,
+        &goal,
+        &(goal * 2),
+        &Deadline::Timestamp(deadline),
+        &min_contribution,
+        &None,
+        &None,
+        &None,
+    );
+
+    // Contribute but don't meet goal
+    let contributor = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &contributor, 500_000);
+    client.contribute(&contributor, &500_000, &None, &None);
+
+    // Move past deadline
+    env.ledger().set_timestamp(deadline + 1);
+
+    // Pause the contract
+    client.set_paused(&true);
+
+    // Try to refund while paused
+    let result = client.try_refund();
+
+    assert!(result.is_err());
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        crate::ContractError::ContractPaused
+    );
+}
+
+#[test]
+fn test_all_interactions_succeed_after_unpause() {
+    let (env, client, creator, token_address, admin) = setup_env();
+
+    let deadline = env.ledger().timestamp() + 3600;
+    let goal: i128 = 1_000_000;
+    let min_contribution: i128 = 1_000;
+
+    client.initialize(
+        &creator,
+        &token_address,
+        &goal,
+        &(goal * 2),
+        &Deadline::Timestamp(deadline),
+        &min_contribution,
+        &None,
+        &None,
+        &None,
+    );
+
+    // Pause the contract
+    client.set_paused(&true);
+
+    // Unpause the contract
+    client.set_paused(&false);
+
+    // Contribute should succeed
+    let contributor = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &contributor, 5_000);
+    client.contribute(&contributor, &5_000, &None, &None);
+
+    assert_eq!(client.total_raised(), 5_000);
+}
+
+#[test]
+#[should_panic]
+fn test_set_paused_rejected_from_non_creator() {
+    let env = Env::default();
+    let contract_id = env.register(CrowdfundContract, (Address::generate(&env),));
+    let client = CrowdfundContractClient::new(&env, &contract_id);
+
+    let token_admin = Address::generate(&env);
+    let token_contract_id = env.register_stellar_asset_contract_v2(token_admin.clone());
+    let token_address = token_contract_id.address();
+
+    let creator = Address::generate(&env);
+    let non_creator = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let deadline = env.ledger().timestamp() + 3600;
+    let goal: i128 = 1_000_000;
+    let min_contribution: i128 = 1_000;
+
+    client.initialize(
+        &creator,
+        &token_address,
+        &goal,
+        &(goal * 2),
+        &Deadline::Timestamp(deadline),
+        &min_contribution,
+        &None,
+        &None,
+        &None,
+    );
+
+    env.mock_all_auths_allowing_non_root_auth();
+    env.set_auths(&[]);
+
+    client.mock_auths(&[soroban_sdk::testutils::MockAuth {
+        address: &non_creator,
+        invoke: &soroban_sdk::testutils::MockAuthInvoke {
+            contract: &contract_id,
+            fn_name: "set_paused",
+            args: soroban_sdk::vec![&env, true.into()],
+            sub_invokes: &[],
+        },
+    }]);
+
+    client.set_paused(&true);
+}
+
+// ── Contributor Count Tests ────────────────────────────────────────────────
+
+#[test]
+fn test_contributor_count_zero_before_contributions() {
+    let (env, client, creator, token_address, _admin) = setup_env();
+
+    let deadline = env.ledger().timestamp() + 3600;
+    let goal: i128 = 1_000_000;
+    let min_contribution: i128 = 1_000;
+
+    client.initialize(
+        &creator,
+        &token_address,
+        &goal,
+        &(goal * 2),
+        &Deadline::Timestamp(deadline),
+        &min_contribution,
+        &None,
+        &None,
+        &None,
+    );
+
+    assert_eq!(client.contributor_count(), 0);
+}
+
+#[test]
+fn test_contributor_count_one_after_single_contribution() {
+    let (env, client, creator, token_address, admin) = setup_env();
+
+    let deadline = env.ledger().timestamp() + 3600;
+    let goal: i128 = 1_000_000;
+    let min_contribution: i128 = 1_000;
+
+    client.initialize(
+        &creator,
+        &token_address,
+        &goal,
+        &(goal * 2),
+        &Deadline::Timestamp(deadline),
+        &min_contribution,
+        &None,
+        &None,
+        &None,
+    );
+
+    let contributor = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &contributor, 500_000);
+    client.contribute(&contributor, &500_000, &None, &None);
+
+    assert_eq!(client.contributor_count(), 1);
+}
+
+#[test]
+fn test_contributor_count_multiple_contributors() {
+    let (env, client, creator, token_address, admin) = setup_env();
+
+    let deadline = env.ledger().timestamp() + 3600;
+    let goal: i128 = 1_000_000;
+    let min_contribution: i128 = 1_000;
+
+    client.initialize(
+        &creator,
+        &token_address,
+        &goal,
+        &(goal * 2),
+        &Deadline::Timestamp(deadline),
+        &min_contribution,
+        &None,
+        &None,
+        &None,
+    );
+
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let charlie = Address::generate(&env);
+
+    mint_to(&env, &token_address, &admin, &alice, 300_000);
+    mint_to(&env, &token_address, &admin, &bob, 200_000);
+    mint_to(&env, &token_address, &admin, &charlie, 100_000);
+
+    client.contribute(&alice, &300_000, &None, &None);
+    assert_eq!(client.contributor_count(), 1);
+
+    client.contribute(&bob, &200_000, &None, &None);
+    assert_eq!(client.contributor_count(), 2);
+
+    client.contribute(&charlie, &100_000, &None, &None);
+    assert_eq!(client.contributor_count(), 3);
+}
+
+// ── Upgrade / Admin Tests ──────────────────────────────────────────────────
+
+/// Smallest module the host accepts as contract code: an empty wasm with only
+/// the `contractenvmetav0` section declaring interface version 22.0.
+const EMPTY_CONTRACT_WASM: &[u8] = &[
+    0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, // magic + version
+    0x00, 0x1e, 0x11, // custom section, size, name length
+    b'c', b'o', b'n', b't', b'r', b'a', b'c', b't', b'e', b'n', b'v', b'm', b'e', b't', b'a', b'v',
+    b'0', // "contractenvmetav0"
+    0x00, 0x00, 0x00, 0x00, // SC_ENV_META_KIND_INTERFACE_VERSION
+    0x00, 0x00, 0x00, 0x16, // protocol 22
+    0x00, 0x00, 0x00, 0x00, // pre-release 0
+];
+
+/// A contract whose only export is `version`, returning 3 — a stand-in for
+/// an upgraded build of the campaign contract.
+const NEXT_VERSION_WASM: &[u8] = &[
+    0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, // magic + version
+    0x00, 0x1e, 0x11, // custom section, size, name length
+    b'c', b'o', b'n', b't', b'r', b'a', b'c', b't', b'e', b'n', b'v', b'm', b'e', b't', b'a', b'v',
+    b'0', // "contractenvmetav0"
+    0x00, 0x00, 0x00, 0x00, // SC_ENV_META_KIND_INTERFACE_VERSION
+    0x00, 0x00, 0x00, 0x16, // protocol 22
+    0x00, 0x00, 0x00, 0x00, // pre-release 0
+    0x01, 0x05, 0x01, 0x60, 0x00, 0x01, 0x7e, // type: () -> i64
+    0x03, 0x02, 0x01, 0x00, // function 0 has type 0
+    0x07, 0x0b, 0x01, 0x07, b'v', b'e', b'r', b's', b'i', b'o', b'n', 0x00,
+    0x00, // export "version"
+    0x0a, 0x0a, 0x01, 0x08, 0x00, 0x42, 0x84, 0x80, 0x80, 0x80, 0x30,
+    0x0b, // i64.const U32Val(3)
+];
+
+#[test]
+fn test_admin_defaults_to_creator() {
+    let (env, client, creator, token_address, _admin) = setup_env();
+
+    let deadline = env.ledger().timestamp() + 3600;
+    client.initialize(
+        &creator,
+        &token_address,
+        &1_000_000,
+        &2_000_000,
+        &Deadline::Timestamp(deadline),
+        &1_000,
+        &None,
+        &None,
+        &None,
+    );
+
+    assert_eq!(client.admin(), creator);
+}
+
+#[test]
+fn test_upgrade_by_admin() {
+    let (env, client, creator, token_address, _admin) = setup_env();
+
+    let admin = Address::generate(&env);
+    let deadline = env.ledger().timestamp() + 3600;
+    client.initialize(
+        &creator,
+        &token_address,
+        &1_000_000,
+        &2_000_000,
+        &Deadline::Timestamp(deadline),
+        &1_000,
+        &None,
+        &Some(admin.clone()),
+        &None,
+    );
+
+    let new_wasm_hash = env.deployer().upload_contract_wasm(EMPTY_CONTRACT_WASM);
+    client.upgrade(&new_wasm_hash);
+
+    assert_eq!(
+        env.auths(),
+        std::vec![(
+            admin.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    client.address.clone(),
+                    Symbol::new(&env, "upgrade"),
+                    (new_wasm_hash.clone(),).into_val(&env),
+                )),
+                sub_invocations: std::vec![],
+            }
+        )]
+    );
+
+    // The new code is live (it exports nothing), but storage survives.
+    assert!(client.try_goal().is_err());
+    let goal: i128 = env.as_contract(&client.address, || {
+        env.storage().instance().get(&DataKey::Goal).unwrap()
+    });
+    assert_eq!(goal, 1_000_000);
+}
+
+#[test]
+#[should_panic]
+fn test_upgrade_rejected_from_non_admin() {
+    let (env, client, creator, token_address, _admin) = setup_env();
+
+    let admin = Address::generate(&env);
+    let deadline = env.ledger().timestamp() + 3600;
+    client.initialize(
+        &creator,
+        &token_address,
+        &1_000_000,
+        &2_000_000,
+        &Deadline::Timestamp(deadline),
+        &1_000,
+        &None,
+        &Some(admin),
+        &None,
+    );
+
+    let new_wasm_hash = env.deployer().upload_contract_wasm(EMPTY_CONTRACT_WASM);
+
+    // The creator is not the designated admin.
+    env.set_auths(&[]);
+    client.mock_auths(&[soroban_sdk::testutils::MockAuth {
+        address: &creator,
+        invoke: &soroban_sdk::testutils::MockAuthInvoke {
+            contract: &client.address,
+            fn_name: "upgrade",
+            args: (new_wasm_hash.clone(),).into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+
+    client.upgrade(&new_wasm_hash);
+}
+
+#[test]
+fn test_set_admin_transfers_upgrade_rights() {
+    let (env, client, creator, token_address, _admin) = setup_env();
+
+    let deadline = env.ledger().timestamp() + 3600;
+    client.initialize(
+        &creator,
+        &token_address,
+        &1_000_000,
+        &2_000_000,
+        &Deadline::Timestamp(deadline),
+        &1_000,
+        &None,
+        &None,
+        &None,
+    );
+
+    let new_admin = Address::generate(&env);
+    client.set_admin(&new_admin);
+    assert_eq!(client.admin(), new_admin);
+
+    // The old admin (the creator) can no longer upgrade.
+    let new_wasm_hash = env.deployer().upload_contract_wasm(EMPTY_CONTRACT_WASM);
+    env.set_auths(&[]);
+    let result = client
+        .mock_auths(&[soroban_sdk::testutils::MockAuth {
+            address: &creator,
+            invoke: &soroban_sdk::testutils::MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "upgrade",
+                args: (new_wasm_hash.clone(),).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_upgrade(&new_wasm_hash);
+    assert!(result.is_err());
+
+    // The new admin can.
+    client
+        .mock_auths(&[soroban_sdk::testutils::MockAuth {
+            address: &new_admin,
+            invoke: &soroban_sdk::testutils::MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "upgrade",
+                args: (new_wasm_hash.clone(),).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .upgrade(&new_wasm_hash);
+}
+
+// ── Event Tests ────────────────────────────────────────────────────────────
+
+/// Returns the data of the `("campaign", action)` events published by the
+/// last contract invocation, decoded as `T`.
+fn campaign_events<T: TryFromVal<Env, Val>>(
+    env: &Env,
+    contract: &Address,
+    action: &str,
+) -> std::vec::Vec<T> {
+    let topics: soroban_sdk::Vec<Val> = ("campaign", action).into_val(env);
+    env.events()
+        .all()
+        .iter()
+        .filter(|(id, t, _)| id == contract && *t == topics)
+        .map(|(_, _, data)| T::try_from_val(env, &data).unwrap())
+        .collect()
+}
+
+#[test]
+fn test_contribute_emits_contribution_event() {
+    let (env, client, creator, token_address, admin) = setup_env();
+
+    let deadline = env.ledger().timestamp() + 3600;
+    client.initialize(
+        &creator,
+        &token_address,
+        &1_000_000,
+        &2_000_000,
+        &Deadline::Timestamp(deadline),
+        &1_000,
+        &None,
+        &None,
+        &None,
+    );
+
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &alice, 300_000);
+    mint_to(&env, &token_address, &admin, &bob, 200_000);
+
+    client.contribute(&alice, &300_000, &None, &None);
+    assert_eq!(
+        campaign_events::<ContributionEvent>(&env, &client.address, "contributed"),
+        std::vec![ContributionEvent {
+            contributor: alice.clone(),
+            amount: 300_000,
+            total_raised_after: 300_000,
+        }]
+    );
+
+    client.contribute(&bob, &200_000, &None, &None);
+    assert_eq!(
+        campaign_events::<ContributionEvent>(&env, &client.address, "contributed"),
+        std::vec![ContributionEvent {
+            contributor: bob,
+            amount: 200_000,
+            total_raised_after: 500_000,
+        }]
+    );
+}
+
+#[test]
+fn test_withdraw_emits_withdraw_and_status_events() {
+    let (env, client, creator, token_address, admin) = setup_env();
+
+    let platform = Address::generate(&env);
+    let deadline = env.ledger().timestamp() + 3600;
+    client.initialize(
+        &creator,
+        &token_address,
+        &1_000_000,
+        &2_000_000,
+        &Deadline::Timestamp(deadline),
+        &1_000,
+        &Some(PlatformConfig {
+            address: platform,
+            fee_bps: 250,
+            fee_timing: FeeTiming::OnWithdraw,
+            tiered_fee: None,
+            min_campaign_duration_seconds: 0,
+            max_campaign_duration_seconds: 0,
+            dust_policy: DustPolicy::Creator,
+        }),
+        &None,
+        &None,
+    );
+
+    let contributor = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &contributor, 1_000_000);
+    client.contribute(&contributor, &1_000_000, &None, &None);
+
+    env.ledger().set_timestamp(deadline + 1);
+    client.withdraw();
+
+    assert_eq!(
+        campaign_events::<WithdrawEvent>(&env, &client.address, "withdrawn"),
+        std::vec![WithdrawEvent {
+            creator: creator.clone(),
+            destination: creator,
+            amount: 975_000,
+            platform_fee: 25_000,
+        }]
+    );
+    assert_eq!(
+        campaign_events::<StatusChangedEvent>(&env, &client.address, "status_changed"),
+        std::vec![StatusChangedEvent {
+            old_status: Status::Active,
+            new_status: Status::Successful,
+        }]
+    );
+}
+
+#[test]
+fn test_refund_emits_refund_and_status_events() {
+    let (env, client, creator, token_address, admin) = setup_env();
+
+    let deadline = env.ledger().timestamp() + 3600;
+    client.initialize(
+        &creator,
+        &token_address,
+        &1_000_000,
+        &2_000_000,
+        &Deadline::Timestamp(deadline),
+        &1_000,
+        &None,
+        &None,
+        &None,
+    );
+
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &alice, 300_000);
+    mint_to(&env, &token_address, &admin, &bob, 200_000);
+    client.contribute(&alice, &300_000, &None, &None);
+    client.contribute(&bob, &200_000, &None, &None);
+
+    env.ledger().set_timestamp(deadline + 1);
+    client.refund();
+
+    assert_eq!(
+        campaign_events::<RefundEvent>(&env, &client.address, "refunded"),
+        std::vec![RefundEvent {
+            total_refunded: 500_000,
+        }]
+    );
+    assert_eq!(
+        campaign_events::<StatusChangedEvent>(&env, &client.address, "status_changed"),
+        std::vec![StatusChangedEvent {
+            old_status: Status::Active,
+            new_status: Status::Refunded,
+        }]
+    );
+}
+
+#[test]
+fn test_cancel_emits_cancel_and_status_events() {
+    let (env, client, creator, token_address, admin) = setup_env();
+
+    let deadline = env.ledger().timestamp() + 3600;
+    client.initialize(
+        &creator,
+        &token_address,
+        &1_000_000,
+        &2_000_000,
+        &Deadline::Timestamp(deadline),
+        &1_000,
+        &None,
+        &None,
+        &None,
+    );
+
+    let contributor = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &contributor, 400_000);
+    client.contribute(&contributor, &400_000, &None, &None);
+
+    client.cancel();
+
+    assert_eq!(
+        campaign_events::<CancelEvent>(&env, &client.address, "cancelled"),
+        std::vec![CancelEvent {
+            creator,
+            total_refunded: 400_000,
+        }]
+    );
+    assert_eq!(
+        campaign_events::<StatusChangedEvent>(&env, &client.address, "status_changed"),
+        std::vec![StatusChangedEvent {
+            old_status: Status::Active,
+            new_status: Status::Cancelled,
+        }]
+    );
+}
+
+#[test]
+fn test_add_roadmap_item_emits_indexed_event() {
+    let (env, client, creator, token_address, _admin) = setup_env();
+
+    let deadline = env.ledger().timestamp() + 3600;
+    client.initialize(
+        &creator,
+        &token_address,
+        &1_000_000,
+        &2_000_000,
+        &Deadline::Timestamp(deadline),
+        &1_000,
+        &None,
+        &None,
+        &None,
+    );
+
+    let first = soroban_sdk::String::from_str(&env, "Prototype");
+    let second = soroban_sdk::String::from_str(&env, "Launch");
+    let now = env.ledger().timestamp();
+
+    client.add_roadmap_item(&(now + 100), &(now + 100), &first);
+    assert_eq!(
+        campaign_events::<RoadmapItemAddedEvent>(&env, &client.address, "roadmap_item_added"),
+        std::vec![RoadmapItemAddedEvent {
+            index: 0,
+            date: now + 100,
+            deadline: now + 100,
+            description: first,
+        }]
+    );
+
+    client.add_roadmap_item(&(now + 200), &(now + 200), &second);
+    assert_eq!(
+        campaign_events::<RoadmapItemAddedEvent>(&env, &client.address, "roadmap_item_added"),
+        std::vec![RoadmapItemAddedEvent {
+            index: 1,
+            date: now + 200,
+            deadline: now + 200,
+            description: second,
+        }]
+    );
+}
+
+// ── Fee Timing Tests ───────────────────────────────────────────────────────
+
+/// Runs the same two-backer campaign under the given fee timing and returns
+/// `(creator_balance, platform_balance, alice_balance, bob_balance)` after
+/// the campaign settles. `succeed` decides whether the goal is reached.
+fn run_fee_timing_scenario(fee_timing: FeeTiming, succeed: bool) -> (i128, i128, i128, i128) {
+    let (env, client, creator, token_address, admin) = setup_env();
+
+    let platform = Address::generate(&env);
+    let deadline = env.ledger().timestamp() + 3600;
+    let goal: i128 = if succeed { 400_000 } else { 1_000_000 };
+    client.initialize(
+        &creator,
+        &token_address,
+        &goal,
+        &(goal * 2),
+        &Deadline::Timestamp(deadline),
+        &1_000,
+        &Some(PlatformConfig {
+            address: platform.clone(),
+            fee_bps: 1_000, // 10%
+            fee_timing,
+            tiered_fee: None,
+            min_campaign_duration_seconds: 0,
+            max_campaign_duration_seconds: 0,
+            dust_policy: DustPolicy::Creator,
+        }),
+        &None,
+        &None,
+    );
+
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &alice, 300_000);
+    mint_to(&env, &token_address, &admin, &bob, 200_000);
+    client.contribute(&alice, &300_000, &None, &None);
+    client.contribute(&bob, &200_000, &None, &None);
+
+    env.ledger().set_timestamp(deadline + 1);
+    if succeed {
+        client.withdraw();
+    } else {
+        client.refund();
+    }
+
+    let token_client = token::Client::new(&env, &token_address);
+    (
+        token_client.balance(&creator),
+        token_client.balance(&platform),
+        token_client.balance(&alice),
+        token_client.balance(&bob),
+    )
+}
+
+#[test]
+fn test_fee_timing_modes_pay_same_totals_on_success() {
+    let on_withdraw = run_fee_timing_scenario(FeeTiming::OnWithdraw, true);
+    let on_contribute = run_fee_timing_scenario(FeeTiming::OnContribute, true);
+
+    // Creator starts with 10_000_000 from setup_env; 10% of 500_000 is the fee.
+    assert_eq!(on_withdraw, (10_450_000, 50_000, 0, 0));
+    assert_eq!(on_contribute, on_withdraw);
+}
+
+#[test]
+fn test_fee_on_contribute_credits_net_amount() {
+    let (env, client, creator, token_address, admin) = setup_env();
+
+    let platform = Address::generate(&env);
+    let deadline = env.ledger().timestamp() + 3600;
+    client.initialize(
+        &creator,
+        &token_address,
+        &1_000_000,
+        &2_000_000,
+        &Deadline::Timestamp(deadline),
+        &1_000,
+        &Some(PlatformConfig {
+            address: platform.clone(),
+            fee_bps: 250,
+            fee_timing: FeeTiming::OnContribute,
+            tiered_fee: None,
+            min_campaign_duration_seconds: 0,
+            max_campaign_duration_seconds: 0,
+            dust_policy: DustPolicy::Creator,
+        }),
+        &None,
+        &None,
+    );
+
+    let contributor = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &contributor, 100_000);
+    client.contribute(&contributor, &100_000, &None, &None);
+
+    let token_client = token::Client::new(&env, &token_address);
+    assert_eq!(token_client.balance(&platform), 2_500);
+    assert_eq!(token_client.balance(&client.address), 97_500);
+    assert_eq!(client.total_raised(), 97_500);
+    assert_eq!(client.contribution(&contributor), 97_500);
+}
+
+#[test]
+fn test_fee_on_contribute_refunds_net_amount() {
+    let on_withdraw = run_fee_timing_scenario(FeeTiming::OnWithdraw, false);
+    let on_contribute = run_fee_timing_scenario(FeeTiming::OnContribute, false);
+
+    // OnWithdraw: failed campaigns never pay a fee.
+    assert_eq!(on_withdraw, (10_000_000, 0, 300_000, 200_000));
+    // OnContribute: the fee was already paid, so only the net comes back.
+    assert_eq!(on_contribute, (10_000_000, 50_000, 270_000, 180_000));
+}
+
+// ── Campaign Stats Tests ───────────────────────────────────────────────────
+
+/// Asserts every `campaign_stats` field agrees with the individual getters.
+fn assert_stats_match_getters(env: &Env, client: &CrowdfundContractClient, fee_bps: u32) {
+    let stats = client.campaign_stats();
+    assert_eq!(stats.total_raised, client.total_raised());
+    assert_eq!(stats.goal, client.goal());
+    assert_eq!(stats.contributor_count, client.contributor_count());
+    assert_eq!(stats.deadline, client.deadline());
+    assert_eq!(
+        stats.time_remaining,
+        client.deadline().saturating_sub(env.ledger().timestamp())
+    );
+    assert_eq!(stats.status, client.status());
+    assert_eq!(stats.platform_fee_bps, fee_bps);
+    assert_eq!(stats.roadmap_item_count, client.roadmap().len());
+}
+
+#[test]
+fn test_campaign_stats_after_initialize() {
+    let (env, client, creator, token_address, _admin) = setup_env();
+
+    let deadline = env.ledger().timestamp() + 3600;
+    client.initialize(
+        &creator,
+        &token_address,
+        &1_000_000,
+        &2_000_000,
+        &Deadline::Timestamp(deadline),
+        &1_000,
+        &None,
+        &None,
+        &None,
+    );
+
+    let stats = client.campaign_stats();
+    assert_eq!(stats.total_raised, 0);
+    assert_eq!(stats.progress_bps, 0);
+    assert_eq!(stats.time_remaining, 3600);
+    assert_eq!(stats.status, Status::Active);
+    assert_eq!(stats.platform_fee_bps, 0);
+    assert_stats_match_getters(&env, &client, 0);
+}
+
+#[test]
+fn test_campaign_stats_track_contributions_and_roadmap() {
+    let (env, client, creator, token_address, admin) = setup_env();
+
+    let deadline = env.ledger().timestamp() + 3600;
+    client.initialize(
+        &creator,
+        &token_address,
+        &1_000_000,
+        &2_000_000,
+        &Deadline::Timestamp(deadline),
+        &1_000,
+        &Some(PlatformConfig {
+            address: Address::generate(&env),
+            fee_bps: 300,
+            fee_timing: FeeTiming::OnWithdraw,
+            tiered_fee: None,
+            min_campaign_duration_seconds: 0,
+            max_campaign_duration_seconds: 0,
+            dust_policy: DustPolicy::Creator,
+        }),
+        &None,
+        &None,
+    );
+
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &alice, 300_000);
+    mint_to(&env, &token_address, &admin, &bob, 100_000);
+    client.contribute(&alice, &300_000, &None, &None);
+    client.contribute(&bob, &100_000, &None, &None);
+    let date = env.ledger().timestamp() + 100;
+    client.add_roadmap_item(&date, &date, &soroban_sdk::String::from_str(&env, "Beta"));
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 600);
+
+    let stats = client.campaign_stats();
+    assert_eq!(stats.progress_bps, 4_000);
+    assert_eq!(stats.average_contribution, 200_000);
+    assert_eq!(stats.largest_contribution, 300_000);
+    assert_eq!(stats.time_remaining, 3_000);
+    assert_eq!(stats.roadmap_item_count, 1);
+    assert_stats_match_getters(&env, &client, 300);
+}
+
+#[test]
+fn test_campaign_stats_after_withdraw() {
+    let (env, client, creator, token_address, admin) = setup_env();
+
+    let deadline = env.ledger().timestamp() + 3600;
+    client.initialize(
+        &creator,
+        &token_address,
+        &1_000_000,
+        &2_000_000,
+        &Deadline::Timestamp(deadline),
+        &1_000,
+        &None,
+        &None,
+        &None,
+    );
+
+    let contributor = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &contributor, 1_200_000);
+    client.contribute(&contributor, &1_200_000, &None, &None);
+    assert_eq!(client.campaign_stats().progress_bps, 10_000);
+
+    env.ledger().set_timestamp(deadline + 50);
+    client.withdraw();
+
+    let stats = client.campaign_stats();
+    assert_eq!(stats.status, Status::Successful);
+    assert_eq!(stats.time_remaining, 0);
+    assert_stats_match_getters(&env, &client, 0);
+    assert_eq!(client.get_stats(), stats);
+}
+
+// ── Withdraw To Tests ──────────────────────────────────────────────────────
+
+#[test]
+fn test_withdraw_to_pays_contract_destination() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let (vault, deadline) = setup_vault_campaign(&env, &client, &creator, &token_address, &admin);
+    let treasury = env.register(hook_recorder::HookRecorderContract, ());
+    let token_client = token::Client::new(&env, &token_address);
+    let creator_before = token_client.balance(&creator);
+
+    env.ledger().set_timestamp(deadline + 1);
+    client.withdraw_to(&treasury);
+
+    assert_eq!(
+        campaign_events::<WithdrawEvent>(&env, &client.address, "withdrawn"),
+        std::vec![WithdrawEvent {
+            creator: creator.clone(),
+            destination: treasury.clone(),
+            amount: 975_000,
+            platform_fee: 25_000,
+        }]
+    );
+    assert_eq!(token_client.balance(&treasury), 975_000);
+    assert_eq!(token_client.balance(&vault.address), 25_000);
+    assert_eq!(token_client.balance(&creator), creator_before);
+    assert_eq!(client.status(), Status::Successful);
+}
+
+#[test]
+fn test_withdraw_to_rejected_from_non_creator() {
+    let (env, client, _creator) = setup_links_campaign();
+    back_campaign(&env, &client, 1_000_000);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 3601);
+    let outsider = Address::generate(&env);
+
+    env.set_auths(&[]);
+    let result = client
+        .mock_auths(&[soroban_sdk::testutils::MockAuth {
+            address: &outsider,
+            invoke: &soroban_sdk::testutils::MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "withdraw_to",
+                args: (outsider.clone(),).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_withdraw_to(&outsider);
+
+    assert!(result.is_err());
+    assert_eq!(client.status(), Status::Active);
+    assert_eq!(client.total_raised(), 1_000_000);
+}
+
+// ── Partial Withdraw Tests ─────────────────────────────────────────────────
+
+#[test]
+fn test_partial_withdrawals_add_up_to_net_total() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let (vault, deadline) = setup_vault_campaign(&env, &client, &creator, &token_address, &admin);
+    let token_client = token::Client::new(&env, &token_address);
+    let creator_before = token_client.balance(&creator);
+    env.ledger().set_timestamp(deadline + 1);
+
+    client.withdraw_amount(&400_000);
+    assert_eq!(client.status(), Status::Successful);
+    assert_eq!(token_client.balance(&vault.address), 25_000);
+    assert_eq!(token_client.balance(&creator), creator_before + 400_000);
+
+    client.withdraw_amount(&575_000);
+    assert_eq!(client.withdrawn_so_far(), 975_000);
+    assert_eq!(client.status(), Status::Successful);
+    assert_eq!(token_client.balance(&creator), creator_before + 975_000);
+    assert_eq!(token_client.balance(&vault.address), 25_000);
+    assert_eq!(token_client.balance(&client.address), 0);
+}
+
+#[test]
+fn test_partial_withdrawal_beyond_balance_rejected() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let (_vault, deadline) = setup_vault_campaign(&env, &client, &creator, &token_address, &admin);
+    env.ledger().set_timestamp(deadline + 1);
+
+    assert_eq!(
+        client.try_withdraw_amount(&975_001),
+        Err(Ok(crate::ContractError::WithdrawalExceedsBalance))
+    );
+    client.withdraw_amount(&900_000);
+    assert_eq!(
+        client.try_withdraw_amount(&75_001),
+        Err(Ok(crate::ContractError::WithdrawalExceedsBalance))
+    );
+    assert_eq!(client.withdrawn_so_far(), 900_000);
+}
+
+#[test]
+fn test_partial_withdrawal_before_deadline_rejected() {
+    let (env, client, _creator) = setup_links_campaign();
+    back_campaign(&env, &client, 1_000_000);
+
+    assert_eq!(
+        client.try_withdraw_amount(&100_000),
+        Err(Ok(crate::ContractError::CampaignStillActive))
+    );
+    assert_eq!(client.status(), Status::Active);
+}
+
+// ── Payout Vesting Tests ───────────────────────────────────────────────────
+
+/// Initializes a 1M-goal campaign paying a 2.5% fee to a fresh vault, with
+/// the creator's share vesting over 10,000s after a 1,000s cliff, and fully
+/// funds it. Returns the vault and the deadline.
+fn setup_vesting_campaign(
+    env: &Env,
+    client: &CrowdfundContractClient,
+    creator: &Address,
+    token_address: &Address,
+) -> (fee_vault::FeeVaultContractClient<'static>, u64) {
+    let vault = setup_fee_vault(env);
+    vault.add_allowed_token(token_address);
+    let deadline = env.ledger().timestamp() + 3600;
+    client.initialize(
+        creator,
+        token_address,
+        &1_000_000,
+        &2_000_000,
+        &Deadline::Timestamp(deadline),
+        &1_000,
+        &Some(vault_platform_config(&vault)),
+        &None,
+        &Some(CampaignOptions {
+            vesting_cliff_secs: 1_000,
+            vesting_duration_secs: 10_000,
+            ..Default::default()
+        }),
+    );
+    back_campaign(env, client, 1_000_000);
+    (vault, deadline)
+}
+
+#[test]
+fn test_claim_vested_before_cliff_releases_nothing() {
+    let (env, client, creator, token_address, _admin) = setup_env();
+    let (vault, deadline) = setup_vesting_campaign(&env, &client, &creator, &token_address);
+    let token_client = token::Client::new(&env, &token_address);
+    let creator_before = token_client.balance(&creator);
+
+    env.ledger().set_timestamp(deadline + 999);
+    assert_eq!(client.claim_vested(), 0);
+
+    assert_eq!(client.status(), Status::Successful);
+    assert_eq!(token_client.balance(&vault.address), 25_000);
+    assert_eq!(token_client.balance(&creator), creator_before);
+    assert_eq!(client.token_balance(), 975_000);
+}
+
+#[test]
+fn test_claim_vested_releases_linearly_then_remainder() {
+    let (env, client, creator, token_address, _admin) = setup_env();
+    let (vault, deadline) = setup_vesting_campaign(&env, &client, &creator, &token_address);
+    let token_client = token::Client::new(&env, &token_address);
+    let creator_before = token_client.balance(&creator);
+    env.ledger().set_timestamp(deadline + 1);
+    client.withdraw();
+    assert_eq!(token_client.balance(&creator), creator_before);
+
+    env.ledger().set_timestamp(deadline + 5_000);
+    assert_eq!(client.vested_amount(), 487_500);
+    assert_eq!(client.claim_vested(), 487_500);
+    assert_eq!(client.claim_vested(), 0);
+    assert_eq!(client.claimed_amount(), 487_500);
+
+    env.ledger().set_timestamp(deadline + 20_000);
+    assert_eq!(client.claim_vested(), 487_500);
+
+    assert_eq!(token_client.balance(&creator), creator_before + 975_000);
+    assert_eq!(token_client.balance(&vault.address), 25_000);
+    assert_eq!(client.token_balance(), 0);
+}
+
+#[test]
+#[should_panic(expected = "creator payout vests; use claim_vested")]
+fn test_withdraw_amount_rejected_under_payout_vesting() {
+    let (env, client, creator, token_address, _admin) = setup_env();
+    let (_vault, deadline) = setup_vesting_campaign(&env, &client, &creator, &token_address);
+    env.ledger().set_timestamp(deadline + 20_000);
+
+    client.withdraw_amount(&975_000);
+}
+
+// ── Early Withdraw Tests ───────────────────────────────────────────────────
+
+#[test]
+fn test_early_withdraw_when_goal_exactly_met() {
+    let (env, client, creator, token_address, admin) = setup_env();
+
+    let platform = Address::generate(&env);
+    let deadline = env.ledger().timestamp() + 3600;
+    let goal: i128 = 1_000_000;
+    client.initialize(
+        &creator,
+        &token_address,
+        &goal,
+        &(goal * 2),
+        &Deadline::Timestamp(deadline),
+        &1_000,
+        &Some(PlatformConfig {
+            address: platform.clone(),
+            fee_bps: 500,
+            fee_timing: FeeTiming::OnWithdraw,
+            tiered_fee: None,
+            min_campaign_duration_seconds: 0,
+            max_campaign_duration_seconds: 0,
+            dust_policy: DustPolicy::Creator,
+        }),
+        &None,
+        &None,
+    );
+
+    let contributor = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &contributor, goal);
+    client.contribute(&contributor, &goal, &None, &None);
+
+    // Still well before the deadline.
+    client.early_withdraw();
+
+    let token_client = token::Client::new(&env, &token_address);
+    assert_eq!(token_client.balance(&platform), 50_000);
+    assert_eq!(token_client.balance(&creator), 10_000_000 + 950_000);
+    assert_eq!(client.status(), Status::Successful);
+    assert_eq!(client.total_raised(), 0);
+}
+
+#[test]
+#[should_panic(expected = "campaign is not active")]
+fn test_contribute_after_early_withdraw_panics() {
+    let (env, client, creator, token_address, admin) = setup_env();
+
+    let deadline = env.ledger().timestamp() + 3600;
+    let goal: i128 = 1_000_000;
+    client.initialize(
+        &creator,
+        &token_address,
+        &goal,
+        &(goal * 2),
+        &Deadline::Timestamp(deadline),
+        &1_000,
+        &None,
+        &None,
+        &None,
+    );
+
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &alice, goal);
+    mint_to(&env, &token_address, &admin, &bob, 10_000);
+    client.contribute(&alice, &goal, &None, &None);
+
+    client.early_withdraw();
+
+    client.contribute(&bob, &10_000, &None, &None);
+}
+
+#[test]
+fn test_early_withdraw_before_goal_met_fails() {
+    let (env, client, creator, token_address, admin) = setup_env();
+
+    let deadline = env.ledger().timestamp() + 3600;
+    let goal: i128 = 1_000_000;
+    client.initialize(
+        &creator,
+        &token_address,
+        &goal,
+        &(goal * 2),
+        &Deadline::Timestamp(deadline),
+        &1_000,
+        &None,
+        &None,
+        &None,
+    );
+
+    let contributor = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &contributor, goal - 1);
+    client.contribute(&contributor, &(goal - 1), &None, &None);
+
+    let result = client.try_early_withdraw();
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        crate::ContractError::GoalNotReached
+    );
+    assert_eq!(client.status(), Status::Active);
+}
+
+/// Initializes a campaign with `min_funding_duration_seconds` and backs it
+/// to its goal straight away.
+fn setup_min_funding_campaign(
+    min_funding_duration_seconds: u64,
+) -> (Env, CrowdfundContractClient<'static>) {
+    let (env, client, creator, token_address, _admin) = setup_env();
+    init_with_options(
+        &env,
+        &client,
+        &creator,
+        &token_address,
+        CampaignOptions {
+            min_funding_duration_seconds,
+            ..Default::default()
+        },
+    );
+    back_campaign(&env, &client, 1_000_000);
+    (env, client)
+}
+
+#[test]
+#[should_panic(expected = "minimum funding duration not elapsed")]
+fn test_early_withdraw_before_min_funding_duration_panics() {
+    let (env, client) = setup_min_funding_campaign(1_800);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1_799);
+
+    client.early_withdraw();
+}
+
+#[test]
+fn test_early_withdraw_at_min_funding_duration() {
+    let (env, client) = setup_min_funding_campaign(1_800);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1_800);
+
+    client.early_withdraw();
+
+    assert_eq!(client.status(), Status::Successful);
+}
+
+#[test]
+fn test_early_withdraw_without_min_funding_duration() {
+    let (_env, client) = setup_min_funding_campaign(0);
+
+    client.early_withdraw();
+
+    assert_eq!(client.status(), Status::Successful);
+}
+
+// ── Referral Fee Tests ─────────────────────────────────────────────────────
+
+/// Initializes a campaign paying `referral_fee_bps` to referrers.
+fn init_with_referral_fee(
+    env: &Env,
+    client: &CrowdfundContractClient,
+    creator: &Address,
+    token_address: &Address,
+    referral_fee_bps: u32,
+) -> u64 {
+    init_with_options(
+        env,
+        client,
+        creator,
+        token_address,
+        CampaignOptions {
+            referral_fee_bps,
+            ..Default::default()
+        },
+    )
+}
+
+#[test]
+fn test_referral_earnings_accrue_across_contributions() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    init_with_referral_fee(&env, &client, &creator, &token_address, 500);
+
+    let referrer = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &alice, 300_000);
+    mint_to(&env, &token_address, &admin, &bob, 100_000);
+
+    client.contribute(&alice, &200_000, &Some(referrer.clone()), &None);
+    assert_eq!(client.referral_earnings(&referrer), 10_000);
+
+    client.contribute(&bob, &100_000, &Some(referrer.clone()), &None);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 5);
+    client.contribute(&alice, &100_000, &Some(referrer.clone()), &None);
+    assert_eq!(client.referral_earnings(&referrer), 20_000);
+
+    // Contributions without a referrer accrue nothing.
+    assert_eq!(client.referral_earnings(&alice), 0);
+}
+
+#[test]
+fn test_claim_referral_after_withdraw() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let deadline = init_with_referral_fee(&env, &client, &creator, &token_address, 1_000);
+
+    let referrer = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &contributor, 1_000_000);
+    client.contribute(&contributor, &1_000_000, &Some(referrer.clone()), &None);
+
+    env.ledger().set_timestamp(deadline + 1);
+    client.withdraw();
+
+    // The referral fee was held back from the creator's payout.
+    let token_client = token::Client::new(&env, &token_address);
+    assert_eq!(token_client.balance(&creator), 10_000_000 + 900_000);
+    assert_eq!(token_client.balance(&client.address), 100_000);
+
+    client.claim_referral(&referrer);
+    assert_eq!(token_client.balance(&referrer), 100_000);
+    assert_eq!(token_client.balance(&client.address), 0);
+    assert_eq!(client.referral_earnings(&referrer), 0);
+
+    let result = client.try_claim_referral(&referrer);
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        crate::ContractError::NothingToClaim
+    );
+}
+
+#[test]
+fn test_claim_referral_blocked_when_campaign_fails() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let deadline = init_with_referral_fee(&env, &client, &creator, &token_address, 1_000);
+
+    let referrer = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &contributor, 500_000);
+    client.contribute(&contributor, &500_000, &Some(referrer.clone()), &None);
+
+    env.ledger().set_timestamp(deadline + 1);
+    client.refund();
+
+    // Refunds are unaffected by referral fees.
+    let token_client = token::Client::new(&env, &token_address);
+    assert_eq!(token_client.balance(&contributor), 500_000);
+
+    let result = client.try_claim_referral(&referrer);
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        crate::ContractError::CampaignNotSuccessful
+    );
+}
+
+#[test]
+fn test_self_referral_rejected() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    init_with_referral_fee(&env, &client, &creator, &token_address, 500);
+
+    let contributor = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &contributor, 100_000);
+
+    let result = client.try_contribute(&contributor, &100_000, &Some(contributor.clone()), &None);
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        crate::ContractError::SelfReferral
+    );
+    assert_eq!(client.total_raised(), 0);
+}
+
+#[test]
+fn test_partial_refund_claws_back_referral_fee() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let deadline = init_with_referral_fee(&env, &client, &creator, &token_address, 1_000);
+
+    let referrer = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &alice, 600_000);
+    mint_to(&env, &token_address, &admin, &bob, 700_000);
+    client.contribute(&alice, &600_000, &Some(referrer.clone()), &None);
+    client.contribute(&bob, &700_000, &None, &None);
+    assert_eq!(client.referral_earnings(&referrer), 60_000);
+
+    client.partial_refund(&alice, &300_000);
+    assert_eq!(client.referral_earnings(&referrer), 30_000);
+
+    env.ledger().set_timestamp(deadline + 1);
+    client.withdraw();
+
+    // Only the fee on the 300_000 that stayed is held back.
+    let token_client = token::Client::new(&env, &token_address);
+    assert_eq!(token_client.balance(&creator), 10_000_000 + 970_000);
+    client.claim_referral(&referrer);
+    assert_eq!(token_client.balance(&referrer), 30_000);
+    assert_eq!(token_client.balance(&client.address), 0);
+}
+
+#[test]
+fn test_referral_fee_follows_transferred_contribution() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    init_with_referral_fee(&env, &client, &creator, &token_address, 1_000);
+
+    let referrer = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &alice, 400_000);
+    client.contribute(&alice, &400_000, &Some(referrer.clone()), &None);
+    client.transfer_contribution(&alice, &bob, &100_000);
+
+    // Alice's opt-out takes back only the fee on what she still held.
+    client.partial_refund(&alice, &300_000);
+    assert_eq!(client.referral_earnings(&referrer), 10_000);
+
+    client.partial_refund(&bob, &100_000);
+    assert_eq!(client.referral_earnings(&referrer), 0);
+}
+
+// ── Partial Refund Tests ───────────────────────────────────────────────────
+
+#[test]
+fn test_partial_refund_reduces_contribution() {
+    let (env, client, creator, token_address, admin) = setup_env();
+
+    let deadline = env.ledger().timestamp() + 3600;
+    client.initialize(
+        &creator,
+        &token_address,
+        &1_000_000,
+        &2_000_000,
+        &Deadline::Timestamp(deadline),
+        &10_000,
+        &None,
+        &None,
+        &None,
+    );
+
+    let contributor = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &contributor, 100_000);
+    client.contribute(&contributor, &100_000, &None, &None);
+
+    client.partial_refund(&contributor, &40_000);
+
+    let token_client = token::Client::new(&env, &token_address);
+    assert_eq!(client.contribution(&contributor), 60_000);
+    assert_eq!(client.total_raised(), 60_000);
+    assert_eq!(token_client.balance(&contributor), 40_000);
+    assert_eq!(client.contributor_count(), 1);
+
+    // A full opt-out drops the contributor entirely.
+    client.partial_refund(&contributor, &60_000);
+    assert_eq!(client.contribution(&contributor), 0);
+    assert_eq!(client.total_raised(), 0);
+    assert_eq!(token_client.balance(&contributor), 100_000);
+    assert_eq!(client.contributor_count(), 0);
+}
+
+#[test]
+#[should_panic(expected = "remaining contribution below minimum")]
+fn test_partial_refund_below_minimum_panics() {
+    let (env, client, creator, token_address, admin) = setup_env();
+
+    let deadline = env.ledger().timestamp() + 3600;
+    client.initialize(
+        &creator,
+        &token_address,
+        &1_000_000,
+        &2_000_000,
+        &Deadline::Timestamp(deadline),
+        &10_000,
+        &None,
+        &None,
+        &None,
+    );
+
+    let contributor = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &contributor, 15_000);
+    client.contribute(&contributor, &15_000, &None, &None);
+
+    client.partial_refund(&contributor, &10_000);
+}
+
+#[test]
+fn test_partial_refund_after_deadline_fails() {
+    let (env, client, creator, token_address, admin) = setup_env();
+
+    let deadline = env.ledger().timestamp() + 3600;
+    client.initialize(
+        &creator,
+        &token_address,
+        &1_000_000,
+        &2_000_000,
+        &Deadline::Timestamp(deadline),
+        &10_000,
+        &None,
+        &None,
+        &None,
+    );
+
+    let contributor = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &contributor, 50_000);
+    client.contribute(&contributor, &50_000, &None, &None);
+
+    env.ledger().set_timestamp(deadline + 1);
+
+    let result = client.try_partial_refund(&contributor, &20_000);
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        crate::ContractError::CampaignEnded
+    );
+    assert_eq!(client.contribution(&contributor), 50_000);
+}
+
+// ── Matching Fund Tests ────────────────────────────────────────────────────
+
+#[test]
+fn test_matching_applied_until_pool_exhausted() {
+    let (env, client, creator, token_address, admin) = setup_env();
+
+    let deadline = env.ledger().timestamp() + 3600;
+    client.initialize(
+        &creator,
+        &token_address,
+        &1_000_000,
+        &2_000_000,
+        &Deadline::Timestamp(deadline),
+        &1_000,
+        &None,
+        &None,
+        &None,
+    );
+
+    let sponsor = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &sponsor, 150_000);
+    // 1:1 match with a pool that covers one and a half contributions.
+    client.fund_match(&sponsor, &150_000, &10_000);
+
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let carol = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &alice, 100_000);
+    mint_to(&env, &token_address, &admin, &bob, 100_000);
+    mint_to(&env, &token_address, &admin, &carol, 100_000);
+
+    client.contribute(&alice, &100_000, &None, &None);
+    assert_eq!(client.contribution(&sponsor), 100_000);
+    assert_eq!(client.total_raised(), 200_000);
+
+    client.contribute(&bob, &100_000, &None, &None);
+    assert_eq!(client.contribution(&sponsor), 150_000);
+    assert_eq!(client.total_raised(), 350_000);
+    assert_eq!(client.matching_pool().unwrap().remaining, 0);
+
+    client.contribute(&carol, &100_000, &None, &None);
+    assert_eq!(client.contribution(&sponsor), 150_000);
+    assert_eq!(client.total_raised(), 450_000);
+    assert_eq!(client.contributor_count(), 4);
+}
+
+#[test]
+fn test_sponsor_refunded_on_failure() {
+    let (env, client, creator, token_address, admin) = setup_env();
+
+    let deadline = env.ledger().timestamp() + 3600;
+    client.initialize(
+        &creator,
+        &token_address,
+        &1_000_000,
+        &2_000_000,
+        &Deadline::Timestamp(deadline),
+        &1_000,
+        &None,
+        &None,
+        &None,
+    );
+
+    let sponsor = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &sponsor, 200_000);
+    // Half match: 50_000 of the 200_000 pool gets used.
+    client.fund_match(&sponsor, &200_000, &5_000);
+
+    let contributor = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &contributor, 100_000);
+    client.contribute(&contributor, &100_000, &None, &None);
+    assert_eq!(client.total_raised(), 150_000);
+
+    env.ledger().set_timestamp(deadline + 1);
+    client.refund();
+
+    let token_client = token::Client::new(&env, &token_address);
+    assert_eq!(token_client.balance(&sponsor), 200_000);
+    assert_eq!(token_client.balance(&contributor), 100_000);
+    assert_eq!(token_client.balance(&client.address), 0);
+}
+
+#[test]
+fn test_withdraw_includes_matched_funds() {
+    let (env, client, creator, token_address, admin) = setup_env();
+
+    let deadline = env.ledger().timestamp() + 3600;
+    client.initialize(
+        &creator,
+        &token_address,
+        &200_000,
+        &1_000_000,
+        &Deadline::Timestamp(deadline),
+        &1_000,
+        &None,
+        &None,
+        &None,
+    );
+
+    let sponsor = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &sponsor, 300_000);
+    client.fund_match(&sponsor, &300_000, &10_000);
+
+    let contributor = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &contributor, 100_000);
+    client.contribute(&contributor, &100_000, &None, &None);
+
+    env.ledger().set_timestamp(deadline + 1);
+    let token_client = token::Client::new(&env, &token_address);
+    let creator_before = token_client.balance(&creator);
+    client.withdraw();
+
+    assert_eq!(token_client.balance(&creator), creator_before + 200_000);
+    // The unused part of the pool goes back to the sponsor.
+    assert_eq!(token_client.balance(&sponsor), 200_000);
+    assert_eq!(token_client.balance(&client.address), 0);
+}
+
+#[test]
+#[should_panic(expected = "matching pool already funded")]
+fn test_fund_match_twice_panics() {
+    let (env, client, creator, token_address, admin) = setup_env();
+
+    let deadline = env.ledger().timestamp() + 3600;
+    client.initialize(
+        &creator,
+        &token_address,
+        &1_000_000,
+        &2_000_000,
+        &Deadline::Timestamp(deadline),
+        &1_000,
+        &None,
+        &None,
+        &None,
+    );
+
+    let sponsor = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &sponsor, 200_000);
+    client.fund_match(&sponsor, &100_000, &10_000);
+    client.fund_match(&sponsor, &100_000, &10_000);
+}
+
+// ── Refund Window Tests ────────────────────────────────────────────────────
+
+/// Initializes a campaign with a 1-day refund window and funds it past the
+/// goal with two contributors. Returns `(deadline, alice, bob)`.
+fn setup_refund_window(
+    env: &Env,
+    client: &CrowdfundContractClient,
+    creator: &Address,
+    token_address: &Address,
+    admin: &Address,
+) -> (u64, Address, Address) {
+    let deadline = init_with_options(
+        env,
+        client,
+        creator,
+        token_address,
+        CampaignOptions {
+            refund_window_seconds: 86_400,
+            ..Default::default()
+        },
+    );
+
+    let alice = Address::generate(env);
+    let bob = Address::generate(env);
+    mint_to(env, token_address, admin, &alice, 1_000_000);
+    mint_to(env, token_address, admin, &bob, 500_000);
+    client.contribute(&alice, &1_000_000, &None, &None);
+    client.contribute(&bob, &500_000, &None, &None);
+
+    (deadline, alice, bob)
+}
+
+#[test]
+fn test_refund_single_within_window_after_goal_met() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let (deadline, _alice, bob) =
+        setup_refund_window(&env, &client, &creator, &token_address, &admin);
+
+    env.ledger().set_timestamp(deadline + 100);
+    client.refund_single(&bob);
+
+    let token_client = token::Client::new(&env, &token_address);
+    assert_eq!(token_client.balance(&bob), 500_000);
+    assert_eq!(client.contribution(&bob), 0);
+    assert_eq!(client.total_raised(), 1_000_000);
+    assert_eq!(client.contributor_count(), 1);
+
+    // Once the window closes the creator gets the remaining funds.
+    env.ledger().set_timestamp(deadline + 86_401);
+    let creator_before = token_client.balance(&creator);
+    client.withdraw();
+    assert_eq!(token_client.balance(&creator), creator_before + 1_000_000);
+}
+
+#[test]
+fn test_refund_single_after_window_fails() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let (deadline, alice, _bob) =
+        setup_refund_window(&env, &client, &creator, &token_address, &admin);
+
+    env.ledger().set_timestamp(deadline + 86_401);
+    let result = client.try_refund_single(&alice);
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        crate::ContractError::RefundWindowClosed
+    );
+    assert_eq!(client.contribution(&alice), 1_000_000);
+}
+
+#[test]
+fn test_refund_single_before_deadline_fails() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let (_deadline, alice, _bob) =
+        setup_refund_window(&env, &client, &creator, &token_address, &admin);
+
+    let result = client.try_refund_single(&alice);
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        crate::ContractError::RefundWindowClosed
+    );
+}
+
+#[test]
+fn test_withdraw_during_refund_window_fails() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let (deadline, _alice, _bob) =
+        setup_refund_window(&env, &client, &creator, &token_address, &admin);
+
+    env.ledger().set_timestamp(deadline + 86_400);
+    let result = client.try_withdraw();
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        crate::ContractError::RefundWindowOpen
+    );
+
+    let result = client.try_early_withdraw();
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        crate::ContractError::RefundWindowOpen
+    );
+}
+
+// ── Early-Bird Tests ───────────────────────────────────────────────────────
+
+/// Initializes a campaign with a +20% window for the first 20 minutes and a
+/// +10% window for the next 20. Returns the campaign start time.
+fn setup_early_bird(
+    env: &Env,
+    client: &CrowdfundContractClient,
+    creator: &Address,
+    token_address: &Address,
+) -> u64 {
+    let start = env.ledger().timestamp();
+    let mut windows = soroban_sdk::Vec::new(env);
+    windows.push_back(EarlyBirdWindow {
+        end_timestamp: start + 1_200,
+        bonus_bps: 2_000,
+    });
+    windows.push_back(EarlyBirdWindow {
+        end_timestamp: start + 2_400,
+        bonus_bps: 1_000,
+    });
+
+    init_with_options(
+        env,
+        client,
+        creator,
+        token_address,
+        CampaignOptions {
+            early_bird_windows: Some(windows),
+            ..Default::default()
+        },
+    );
+
+    start
+}
+
+#[test]
+fn test_early_bird_weights_inside_and_outside_windows() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let start = setup_early_bird(&env, &client, &creator, &token_address);
+
+    let early = Address::generate(&env);
+    let second_day = Address::generate(&env);
+    let late = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &early, 100_000);
+    mint_to(&env, &token_address, &admin, &second_day, 100_000);
+    mint_to(&env, &token_address, &admin, &late, 100_000);
+
+    client.contribute(&early, &100_000, &None, &None);
+
+    env.ledger().set_timestamp(start + 1_500);
+    client.contribute(&second_day, &100_000, &None, &None);
+
+    env.ledger().set_timestamp(start + 3_000);
+    client.contribute(&late, &100_000, &None, &None);
+
+    assert_eq!(client.weighted_contribution(&early), 120_000);
+    assert_eq!(client.weighted_contribution(&second_day), 110_000);
+    assert_eq!(client.weighted_contribution(&late), 100_000);
+    assert_eq!(client.total_weighted(), 330_000);
+
+    // Raw accounting is untouched by the bonus.
+    assert_eq!(client.contribution(&early), 100_000);
+    assert_eq!(client.total_raised(), 300_000);
+}
+
+#[test]
+fn test_early_bird_weights_accumulate_across_windows() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let start = setup_early_bird(&env, &client, &creator, &token_address);
+
+    let contributor = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &contributor, 200_000);
+
+    client.contribute(&contributor, &100_000, &None, &None);
+    env.ledger().set_timestamp(start + 2_400);
+    client.contribute(&contributor, &100_000, &None, &None);
+
+    assert_eq!(client.weighted_contribution(&contributor), 230_000);
+    assert_eq!(client.contribution(&contributor), 200_000);
+}
+
+#[test]
+fn test_early_bird_refund_returns_raw_amount() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let start = setup_early_bird(&env, &client, &creator, &token_address);
+
+    let contributor = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &contributor, 100_000);
+    client.contribute(&contributor, &100_000, &None, &None);
+    assert_eq!(client.weighted_contribution(&contributor), 120_000);
+
+    env.ledger().set_timestamp(start + 3601);
+    client.refund();
+
+    let token_client = token::Client::new(&env, &token_address);
+    assert_eq!(token_client.balance(&contributor), 100_000);
+}
+
+#[test]
+fn test_early_bird_partial_refund_scales_weight() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    setup_early_bird(&env, &client, &creator, &token_address);
+
+    let contributor = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &contributor, 100_000);
+    client.contribute(&contributor, &100_000, &None, &None);
+
+    client.partial_refund(&contributor, &50_000);
+
+    assert_eq!(client.weighted_contribution(&contributor), 60_000);
+    assert_eq!(client.total_weighted(), 60_000);
+}
+
+#[test]
+#[should_panic(expected = "early-bird windows must be in ascending order")]
+fn test_early_bird_windows_out_of_order_panics() {
+    let (env, client, creator, token_address, _admin) = setup_env();
+
+    let start = env.ledger().timestamp();
+    let mut windows = soroban_sdk::Vec::new(&env);
+    windows.push_back(EarlyBirdWindow {
+        end_timestamp: start + 2_400,
+        bonus_bps: 1_000,
+    });
+    windows.push_back(EarlyBirdWindow {
+        end_timestamp: start + 1_200,
+        bonus_bps: 2_000,
+    });
+
+    init_with_options(
+        &env,
+        &client,
+        &creator,
+        &token_address,
+        CampaignOptions {
+            early_bird_windows: Some(windows),
+            ..Default::default()
+        },
+    );
+}
+
+// ── Excess Return Tests ────────────────────────────────────────────────────
+
+fn init_with_return_excess(
+    env: &Env,
+    client: &CrowdfundContractClient,
+    creator: &Address,
+    token_address: &Address,
+) -> u64 {
+    init_with_options(
+        env,
+        client,
+        creator,
+        token_address,
+        CampaignOptions {
+            return_excess: true,
+            ..Default::default()
+        },
+    )
+}
+
+#[test]
+fn test_return_excess_two_contributors_equal_split() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let deadline = init_with_return_excess(&env, &client, &creator, &token_address);
+
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &alice, 750_000);
+    mint_to(&env, &token_address, &admin, &bob, 750_000);
+    client.contribute(&alice, &750_000, &None, &None);
+    client.contribute(&bob, &750_000, &None, &None);
+
+    env.ledger().set_timestamp(deadline + 1);
+    let token_client = token::Client::new(&env, &token_address);
+    let creator_before = token_client.balance(&creator);
+    client.withdraw();
+
+    assert_eq!(token_client.balance(&creator), creator_before + 1_000_000);
+    assert_eq!(client.excess_claim(&alice), 250_000);
+    assert_eq!(client.excess_claim(&bob), 250_000);
+
+    client.claim_excess(&alice);
+    client.claim_excess(&bob);
+    assert_eq!(token_client.balance(&alice), 250_000);
+    assert_eq!(token_client.balance(&bob), 250_000);
+    assert_eq!(token_client.balance(&client.address), 0);
+
+    let result = client.try_claim_excess(&alice);
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        crate::ContractError::NothingToClaim
+    );
+}
+
+#[test]
+fn test_return_excess_three_contributors_unequal() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let deadline = init_with_return_excess(&env, &client, &creator, &token_address);
+
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let carol = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &alice, 1_000_000);
+    mint_to(&env, &token_address, &admin, &bob, 600_000);
+    mint_to(&env, &token_address, &admin, &carol, 400_000);
+    client.contribute(&alice, &1_000_000, &None, &None);
+    client.contribute(&bob, &600_000, &None, &None);
+    client.contribute(&carol, &400_000, &None, &None);
+
+    env.ledger().set_timestamp(deadline + 1);
+    let token_client = token::Client::new(&env, &token_address);
+    let creator_before = token_client.balance(&creator);
+    client.withdraw();
+
+    // 1_000_000 over goal out of 2_000_000 raised: everyone gets half back.
+    assert_eq!(token_client.balance(&creator), creator_before + 1_000_000);
+    assert_eq!(client.excess_claim(&alice), 500_000);
+    assert_eq!(client.excess_claim(&bob), 300_000);
+    assert_eq!(client.excess_claim(&carol), 200_000);
+
+    client.claim_excess(&carol);
+    assert_eq!(token_client.balance(&carol), 200_000);
+}
+
+#[test]
+fn test_claim_excess_before_withdraw_fails() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    init_with_return_excess(&env, &client, &creator, &token_address);
+
+    let alice = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &alice, 1_500_000);
+    client.contribute(&alice, &1_500_000, &None, &None);
+
+    let result = client.try_claim_excess(&alice);
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        crate::ContractError::CampaignNotSuccessful
+    );
+}
+
+// ── Receipt Token Tests ────────────────────────────────────────────────────
+
+/// Deploys a receipt contract minted by the campaign and initializes the
+/// campaign with it. Returns `(receipt client, deadline)`.
+fn setup_with_receipt(
+    env: &Env,
+    client: &CrowdfundContractClient,
+    creator: &Address,
+    token_address: &Address,
+) -> (receipt::ReceiptContractClient<'static>, u64) {
+    let receipt_id = env.register(receipt::ReceiptContract, (client.address.clone(),));
+    let receipt_client = receipt::ReceiptContractClient::new(env, &receipt_id);
+
+    let deadline = init_with_options(
+        env,
+        client,
+        creator,
+        token_address,
+        CampaignOptions {
+            receipt_contract: Some(receipt_id),
+            ..Default::default()
+        },
+    );
+
+    (receipt_client, deadline)
+}
+
+#[test]
+fn test_receipt_minted_on_contribute() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let (receipt_client, _deadline) = setup_with_receipt(&env, &client, &creator, &token_address);
+
+    let contributor = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &contributor, 100_000);
+    client.contribute(&contributor, &60_000, &None, &None);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 5);
+    client.contribute(&contributor, &40_000, &None, &None);
+
+    assert_eq!(
+        client.receipt_contract(),
+        Some(receipt_client.address.clone())
+    );
+    assert_eq!(receipt_client.balance(&contributor), 100_000);
+    assert_eq!(receipt_client.total_supply(), 100_000);
+}
+
+#[test]
+fn test_receipt_burned_on_refund() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let (receipt_client, deadline) = setup_with_receipt(&env, &client, &creator, &token_address);
+
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &alice, 100_000);
+    mint_to(&env, &token_address, &admin, &bob, 50_000);
+    client.contribute(&alice, &100_000, &None, &None);
+    client.contribute(&bob, &50_000, &None, &None);
+
+    client.partial_refund(&alice, &30_000);
+    assert_eq!(receipt_client.balance(&alice), 70_000);
+
+    env.ledger().set_timestamp(deadline + 1);
+    client.refund();
+
+    assert_eq!(receipt_client.balance(&alice), 0);
+    assert_eq!(receipt_client.balance(&bob), 0);
+    assert_eq!(receipt_client.total_supply(), 0);
+}
+
+#[test]
+fn test_no_receipt_contract_keeps_current_behavior() {
+    let (env, client, creator, token_address, admin) = setup_env();
+
+    let deadline = env.ledger().timestamp() + 3600;
+    client.initialize(
+        &creator,
+        &token_address,
+        &1_000_000,
+        &2_000_000,
+        &Deadline::Timestamp(deadline),
+        &1_000,
+        &None,
+        &None,
+        &None,
+    );
+    assert_eq!(client.receipt_contract(), None);
+
+    let contributor = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &contributor, 100_000);
+    client.contribute(&contributor, &100_000, &None, &None);
+
+    env.ledger().set_timestamp(deadline + 1);
+    client.refund();
+
+    let token_client = token::Client::new(&env, &token_address);
+    assert_eq!(token_client.balance(&contributor), 100_000);
+}
+
+// ── Reward Token Tests ─────────────────────────────────────────────────────
+
+/// Initializes a campaign and registers a reward asset administered by it,
+/// with clawback enabled. Returns `(reward token, funded contributor)`.
+fn setup_reward_token(
+    env: &Env,
+    client: &CrowdfundContractClient,
+    creator: &Address,
+    token_address: &Address,
+    admin: &Address,
+) -> (token::Client<'static>, Address) {
+    let contributor = setup_terms(env, client, creator, token_address, admin);
+    let reward = env.register_stellar_asset_contract_v2(client.address.clone());
+    reward
+        .issuer()
+        .set_flag(soroban_sdk::testutils::IssuerFlags::ClawbackEnabledFlag);
+    (token::Client::new(env, &reward.address()), contributor)
+}
+
+#[test]
+fn test_reward_minted_on_contribute() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let (reward, contributor) = setup_reward_token(&env, &client, &creator, &token_address, &admin);
+
+    client.set_reward_token(&reward.address, &10);
+    assert_eq!(client.reward_token(), Some((reward.address.clone(), 10)));
+
+    client.contribute(&contributor, &10_005, &None, &None);
+    assert_eq!(reward.balance(&contributor), 1_000);
+}
+
+#[test]
+fn test_reward_rounding_to_zero_mints_nothing() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let (reward, contributor) = setup_reward_token(&env, &client, &creator, &token_address, &admin);
+
+    client.set_reward_token(&reward.address, &100_000);
+    client.contribute(&contributor, &10_000, &None, &None);
+
+    assert_eq!(reward.balance(&contributor), 0);
+    assert_eq!(client.contribution(&contributor), 10_000);
+}
+
+#[test]
+fn test_reward_minted_only_once_set() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let (reward, contributor) = setup_reward_token(&env, &client, &creator, &token_address, &admin);
+
+    assert_eq!(client.reward_token(), None);
+    client.contribute(&contributor, &10_000, &None, &None);
+    assert_eq!(reward.balance(&contributor), 0);
+
+    client.set_reward_token(&reward.address, &10);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 5);
+    client.contribute(&contributor, &20_000, &None, &None);
+    assert_eq!(reward.balance(&contributor), 2_000);
+}
+
+#[test]
+#[should_panic(expected = "contract is not the reward token admin")]
+fn test_reward_token_must_be_administered_by_campaign() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    setup_terms(&env, &client, &creator, &token_address, &admin);
+
+    let foreign = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.set_reward_token(&foreign, &10);
+}
+
+#[test]
+fn test_transferring_credit_back_and_forth_mints_no_rewards() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let (reward, alice) = setup_reward_token(&env, &client, &creator, &token_address, &admin);
+    client.set_reward_token(&reward.address, &10);
+    client.contribute(&alice, &10_000, &None, &None);
+
+    let bob = Address::generate(&env);
+    for _ in 0..3 {
+        client.transfer_contribution(&alice, &bob, &10_000);
+        assert_eq!(reward.balance(&alice), 0);
+        assert_eq!(reward.balance(&bob), 1_000);
+        client.transfer_contribution(&bob, &alice, &10_000);
+        assert_eq!(reward.balance(&alice), 1_000);
+        assert_eq!(reward.balance(&bob), 0);
+    }
+}
+
+#[test]
+fn test_partial_refund_claws_back_rewards() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let (reward, contributor) = setup_reward_token(&env, &client, &creator, &token_address, &admin);
+    client.set_reward_token(&reward.address, &10);
+    client.contribute(&contributor, &20_000, &None, &None);
+
+    client.partial_refund(&contributor, &5_000);
+
+    assert_eq!(reward.balance(&contributor), 1_500);
+}
+
+#[test]
+fn test_partial_refund_reports_rewards_it_cannot_claw_back() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let (reward, contributor) = setup_reward_token(&env, &client, &creator, &token_address, &admin);
+    client.set_reward_token(&reward.address, &10);
+    client.contribute(&contributor, &20_000, &None, &None);
+    reward.transfer(&contributor, &Address::generate(&env), &2_000);
+
+    client.partial_refund(&contributor, &20_000);
+
+    let failed: std::vec::Vec<(Address, i128)> =
+        campaign_events(&env, &client.address, "reward_clawback_failed");
+    assert_eq!(failed, [(contributor.clone(), 2_000)]);
+    assert_eq!(client.contribution(&contributor), 0);
+}
+
+#[test]
+fn test_refund_burns_locked_rewards() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let (reward, contributor) = setup_reward_token(&env, &client, &creator, &token_address, &admin);
+    client.set_reward_token(&reward.address, &10);
+    client.set_reward_vesting(&DAY, &(10 * DAY));
+    client.contribute(&contributor, &20_000, &None, &None);
+    assert_eq!(reward.balance(&client.address), 2_000);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 3601);
+    client.refund();
+
+    assert_eq!(reward.balance(&client.address), 0);
+    assert_eq!(reward.balance(&contributor), 0);
+}
+
+/// Funds a campaign to its goal with vested rewards (1 per 10 contributed,
+/// one-day cliff, ten-day vesting) and withdraws. Returns the reward token,
+/// the contributor and the payout timestamp.
+fn setup_vested_rewards() -> (
+    Env,
+    CrowdfundContractClient<'static>,
+    token::Client<'static>,
+    Address,
+    u64,
+) {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let (reward, contributor) = setup_reward_token(&env, &client, &creator, &token_address, &admin);
+    client.set_reward_token(&reward.address, &10);
+    client.set_reward_vesting(&DAY, &(10 * DAY));
+    assert_eq!(client.reward_vesting(), Some((DAY, 10 * DAY)));
+
+    mint_to(&env, &token_address, &admin, &contributor, 900_000);
+    client.contribute(&contributor, &1_000_000, &None, &None);
+    assert_eq!(reward.balance(&contributor), 0);
+    assert_eq!(reward.balance(&client.address), 100_000);
+
+    let payout_time = env.ledger().timestamp() + 3601;
+    env.ledger().set_timestamp(payout_time);
+    assert_eq!(client.vested_reward_tokens(&contributor), 0);
+    client.withdraw();
+    (env, client, reward, contributor, payout_time)
+}
+
+#[test]
+fn test_vested_reward_zero_before_cliff() {
+    let (env, client, _reward, contributor, payout_time) = setup_vested_rewards();
+
+    env.ledger().set_timestamp(payout_time + DAY / 2);
+    assert_eq!(client.vested_reward_tokens(&contributor), 0);
+}
+
+#[test]
+fn test_vested_reward_zero_at_cliff() {
+    let (env, client, reward, contributor, payout_time) = setup_vested_rewards();
+
+    env.ledger().set_timestamp(payout_time + DAY);
+    assert_eq!(client.vested_reward_tokens(&contributor), 0);
+    assert_eq!(client.claim_reward_tokens(&contributor), 0);
+    assert_eq!(reward.balance(&contributor), 0);
+}
+
+#[test]
+fn test_vested_reward_partial_mid_vesting() {
+    let (env, client, reward, contributor, payout_time) = setup_vested_rewards();
+
+    env.ledger().set_timestamp(payout_time + DAY + 5 * DAY);
+    assert_eq!(client.vested_reward_tokens(&contributor), 50_000);
+    assert_eq!(client.claim_reward_tokens(&contributor), 50_000);
+    assert_eq!(reward.balance(&contributor), 50_000);
+    assert_eq!(client.vested_reward_tokens(&contributor), 0);
+}
+
+#[test]
+fn test_vested_reward_fully_vested() {
+    let (env, client, reward, contributor, payout_time) = setup_vested_rewards();
+
+    env.ledger().set_timestamp(payout_time + DAY + 3 * DAY);
+    client.claim_reward_tokens(&contributor);
+
+    env.ledger().set_timestamp(payout_time + DAY + 20 * DAY);
+    assert_eq!(client.vested_reward_tokens(&contributor), 70_000);
+    client.claim_reward_tokens(&contributor);
+    assert_eq!(reward.balance(&contributor), 100_000);
+    assert_eq!(reward.balance(&client.address), 0);
+}
+
+// ── Profit Distribution Tests ──────────────────────────────────────────────
+
+/// Funds a campaign past its goal with a 3:1 contribution split and
+/// withdraws. Returns the two contributors and the token client.
+fn setup_profit_campaign() -> (
+    Env,
+    CrowdfundContractClient<'static>,
+    token::Client<'static>,
+    Address,
+    Address,
+) {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let deadline = init_with_options(
+        &env,
+        &client,
+        &creator,
+        &token_address,
+        CampaignOptions::default(),
+    );
+
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &alice, 900_000);
+    mint_to(&env, &token_address, &admin, &bob, 300_000);
+    client.contribute(&alice, &900_000, &None, &None);
+    client.contribute(&bob, &300_000, &None, &None);
+
+    env.ledger().set_timestamp(deadline + 1);
+    client.withdraw();
+    let token_client = token::Client::new(&env, &token_address);
+    (env, client, token_client, alice, bob)
+}
+
+#[test]
+fn test_distribute_profit_splits_proportionally() {
+    let (_env, client, token_client, alice, bob) = setup_profit_campaign();
+
+    client.distribute_profit(&40_000);
+    assert_eq!(client.claimable_profit(&alice), 30_000);
+    assert_eq!(client.claimable_profit(&bob), 10_000);
+
+    assert_eq!(client.claim_profit(&alice), 30_000);
+    assert_eq!(token_client.balance(&alice), 30_000);
+    assert_eq!(client.claimable_profit(&alice), 0);
+    assert_eq!(client.claim_profit(&alice), 0);
+}
+
+#[test]
+fn test_distribute_profit_rounds_accumulate() {
+    let (_env, client, token_client, alice, bob) = setup_profit_campaign();
+
+    client.distribute_profit(&40_000);
+    client.claim_profit(&alice);
+    client.distribute_profit(&80_000);
+
+    assert_eq!(client.claimable_profit(&alice), 60_000);
+    assert_eq!(client.claimable_profit(&bob), 30_000);
+
+    client.claim_profit(&alice);
+    client.claim_profit(&bob);
+    assert_eq!(token_client.balance(&alice), 90_000);
+    assert_eq!(token_client.balance(&bob), 30_000);
+}
+
+#[test]
+#[should_panic(expected = "campaign has not succeeded")]
+fn test_distribute_profit_before_success_panics() {
+    let (env, client, creator, token_address, _admin) = setup_env();
+    let deadline = env.ledger().timestamp() + 3600;
+    client.initialize(
+        &creator,
+        &token_address,
+        &1_000_000,
+        &2_000_000,
+        &Deadline::Timestamp(deadline),
+        &1_000,
+        &None,
+        &None,
+        &None,
+    );
+
+    client.distribute_profit(&10_000);
+}
+
+// ── Fee Vault Tests ────────────────────────────────────────────────────────
+
+fn setup_fee_vault(env: &Env) -> fee_vault::FeeVaultContractClient<'static> {
+    let vault_id = env.register(fee_vault::FeeVaultContract, ());
+    let vault = fee_vault::FeeVaultContractClient::new(env, &vault_id);
+    vault.initialize(&Address::generate(env));
+    vault
+}
+
+fn vault_platform_config(vault: &fee_vault::FeeVaultContractClient) -> PlatformConfig {
+    PlatformConfig {
+        address: vault.address.clone(),
+        fee_bps: 250,
+        fee_timing: FeeTiming::OnWithdraw,
+        tiered_fee: None,
+        min_campaign_duration_seconds: 0,
+        max_campaign_duration_seconds: 0,
+        dust_policy: DustPolicy::Creator,
+    }
+}
+
+#[test]
+fn test_withdraw_fee_lands_in_vault_and_is_recorded() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let vault = setup_fee_vault(&env);
+    let vault_id = vault.address.clone();
+    vault.add_allowed_token(&token_address);
+
+    let deadline = env.ledger().timestamp() + 3600;
+    client.initialize(
+        &creator,
+        &token_address,
+        &1_000_000,
+        &2_000_000,
+        &Deadline::Timestamp(deadline),
+        &1_000,
+        &Some(vault_platform_config(&vault)),
+        &None,
+        &None,
+    );
+    let contributor = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &contributor, 1_000_000);
+    client.contribute(&contributor, &1_000_000, &None, &None);
+
+    env.ledger().set_timestamp(deadline + 1);
+    client.withdraw();
+
+    let token_client = token::Client::new(&env, &token_address);
+    assert_eq!(token_client.balance(&vault_id), 25_000);
+    assert_eq!(vault.campaign_fees(&client.address, &token_address), 25_000);
+    assert_eq!(vault.total_fees(&token_address), 25_000);
+
+    let treasury = Address::generate(&env);
+    vault.sweep(&token_address, &treasury, &25_000);
+    assert_eq!(token_client.balance(&treasury), 25_000);
+    assert_eq!(token_client.balance(&vault_id), 0);
+}
+
+#[test]
+fn test_unlisted_token_rejected_until_platform_allows_it() {
+    let (env, client, creator, token_address, _admin) = setup_env();
+    let vault = setup_fee_vault(&env);
+    let deadline = Deadline::Timestamp(env.ledger().timestamp() + 3600);
+    let config = Some(vault_platform_config(&vault));
+
+    let result = client.try_initialize(
+        &creator,
+        &token_address,
+        &1_000_000,
+        &2_000_000,
+        &deadline,
+        &1_000,
+        &config,
+        &None,
+        &None,
+    );
+    assert!(result.is_err());
+
+    vault.add_allowed_token(&token_address);
+    client.initialize(
+        &creator,
+        &token_address,
+        &1_000_000,
+        &2_000_000,
+        &deadline,
+        &1_000,
+        &config,
+        &None,
+        &None,
+    );
+    assert_eq!(client.token(), token_address);
+}
+
+#[test]
+#[should_panic(expected = "token not allowed by platform")]
+fn test_unlisted_token_panics_with_platform() {
+    let (env, client, creator, token_address, _admin) = setup_env();
+    let vault = setup_fee_vault(&env);
+
+    client.initialize(
+        &creator,
+        &token_address,
+        &1_000_000,
+        &2_000_000,
+        &Deadline::Timestamp(env.ledger().timestamp() + 3600),
+        &1_000,
+        &Some(vault_platform_config(&vault)),
+        &None,
+        &None,
+    );
+}
+
+#[test]
+fn test_token_unrestricted_without_platform_config() {
+    let (env, client, creator, token_address, _admin) = setup_env();
+    let vault = setup_fee_vault(&env);
+    assert!(!vault.is_token_allowed(&token_address));
+
+    client.initialize(
+        &creator,
+        &token_address,
+        &1_000_000,
+        &2_000_000,
+        &Deadline::Timestamp(env.ledger().timestamp() + 3600),
+        &1_000,
+        &None,
+        &None,
+        &None,
+    );
+    assert_eq!(client.token(), token_address);
+}
+
+/// Initializes a campaign on `vault` charging `fee_bps`.
+fn init_with_vault_fee(
+    env: &Env,
+    client: &CrowdfundContractClient,
+    creator: &Address,
+    token_address: &Address,
+    vault: &fee_vault::FeeVaultContractClient,
+    fee_bps: u32,
+) {
+    vault.add_allowed_token(token_address);
+    client.initialize(
+        creator,
+        token_address,
+        &1_000_000,
+        &2_000_000,
+        &Deadline::Timestamp(env.ledger().timestamp() + 3600),
+        &1_000,
+        &Some(PlatformConfig {
+            fee_bps,
+            ..vault_platform_config(vault)
+        }),
+        &None,
+        &None,
+    );
+}
+
+#[test]
+fn test_platform_fee_at_global_cap_allowed() {
+    let (env, client, creator, token_address, _admin) = setup_env();
+    let vault = setup_fee_vault(&env);
+
+    init_with_vault_fee(&env, &client, &creator, &token_address, &vault, 5_000);
+
+    assert_eq!(client.platform_config().unwrap().fee_bps, 5_000);
+}
+
+#[test]
+#[should_panic(expected = "platform fee exceeds global cap")]
+fn test_platform_fee_above_global_cap_panics() {
+    let (env, client, creator, token_address, _admin) = setup_env();
+    let vault = setup_fee_vault(&env);
+
+    init_with_vault_fee(&env, &client, &creator, &token_address, &vault, 5_001);
+}
+
+#[test]
+fn test_platform_fee_follows_updated_global_cap() {
+    let (env, client, creator, token_address, _admin) = setup_env();
+    let vault = setup_fee_vault(&env);
+    vault.set_fee_cap_bps(&8_000);
+
+    init_with_vault_fee(&env, &client, &creator, &token_address, &vault, 6_000);
+    assert_eq!(client.platform_config().unwrap().fee_bps, 6_000);
+
+    vault.set_fee_cap_bps(&1_000);
+    assert!(client.try_override_platform_fee(&2_000).is_err());
+    assert_eq!(client.platform_config().unwrap().fee_bps, 6_000);
+}
+
+/// Initializes a campaign with the vault as platform and funds it to its
+/// goal. Returns `(vault, deadline)`.
+fn setup_vault_campaign(
+    env: &Env,
+    client: &CrowdfundContractClient,
+    creator: &Address,
+    token_address: &Address,
+    admin: &Address,
+) -> (fee_vault::FeeVaultContractClient<'static>, u64) {
+    let vault = setup_fee_vault(env);
+    vault.add_allowed_token(token_address);
+
+    let deadline = env.ledger().timestamp() + 3600;
+    client.initialize(
+        creator,
+        token_address,
+        &1_000_000,
+        &2_000_000,
+        &Deadline::Timestamp(deadline),
+        &1_000,
+        &Some(vault_platform_config(&vault)),
+        &None,
+        &None,
+    );
+    let contributor = Address::generate(env);
+    mint_to(env, token_address, admin, &contributor, 1_000_000);
+    client.contribute(&contributor, &1_000_000, &None, &None);
+    (vault, deadline)
+}
+
+#[test]
+fn test_fee_waived_creator_receives_full_amount() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let (vault, deadline) = setup_vault_campaign(&env, &client, &creator, &token_address, &admin);
+    vault.add_fee_waiver(&creator);
+    let token_client = token::Client::new(&env, &token_address);
+    let before = token_client.balance(&creator);
+
+    env.ledger().set_timestamp(deadline + 1);
+    client.withdraw();
+
+    assert_eq!(token_client.balance(&creator) - before, 1_000_000);
+    assert_eq!(token_client.balance(&vault.address), 0);
+}
+
+#[test]
+fn test_removed_fee_waiver_restores_fee() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let (vault, deadline) = setup_vault_campaign(&env, &client, &creator, &token_address, &admin);
+    vault.add_fee_waiver(&creator);
+    vault.remove_fee_waiver(&creator);
+    let token_client = token::Client::new(&env, &token_address);
+    let before = token_client.balance(&creator);
+
+    env.ledger().set_timestamp(deadline + 1);
+    client.withdraw();
+
+    assert_eq!(token_client.balance(&creator) - before, 975_000);
+    assert_eq!(token_client.balance(&vault.address), 25_000);
+}
+
+#[test]
+fn test_campaign_fee_waiver_pays_creator_full_amount() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let (vault, deadline) = setup_vault_campaign(&env, &client, &creator, &token_address, &admin);
+    assert!(!client.is_fee_waived());
+    vault.waive_fee(&client.address);
+    assert!(client.is_fee_waived());
+    let token_client = token::Client::new(&env, &token_address);
+    let before = token_client.balance(&creator);
+
+    env.ledger().set_timestamp(deadline + 1);
+    client.withdraw();
+
+    assert_eq!(token_client.balance(&creator) - before, 1_000_000);
+    assert_eq!(token_client.balance(&vault.address), 0);
+}
+
+#[test]
+fn test_revoked_campaign_fee_waiver_deducts_fee() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let (vault, deadline) = setup_vault_campaign(&env, &client, &creator, &token_address, &admin);
+    vault.waive_fee(&client.address);
+    vault.revoke_fee_waiver(&client.address);
+    assert!(!client.is_fee_waived());
+    let token_client = token::Client::new(&env, &token_address);
+    let before = token_client.balance(&creator);
+
+    env.ledger().set_timestamp(deadline + 1);
+    client.withdraw();
+
+    assert_eq!(token_client.balance(&creator) - before, 975_000);
+    assert_eq!(token_client.balance(&vault.address), 25_000);
+}
+
+#[test]
+fn test_waive_fee_rejected_from_non_admin() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let (vault, _) = setup_vault_campaign(&env, &client, &creator, &token_address, &admin);
+
+    env.set_auths(&[]);
+    let result = vault
+        .mock_auths(&[soroban_sdk::testutils::MockAuth {
+            address: &creator,
+            invoke: &soroban_sdk::testutils::MockAuthInvoke {
+                contract: &vault.address,
+                fn_name: "waive_fee",
+                args: (client.address.clone(),).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_waive_fee(&client.address);
+
+    assert!(result.is_err());
+    assert!(!client.is_fee_waived());
+}
+
+// ── Campaign Hook Tests ────────────────────────────────────────────────────
+
+/// Initializes a campaign notifying a fresh `hook_recorder` and returns the
+/// hook and the campaign deadline.
+fn setup_hooked_campaign(
+    env: &Env,
+    client: &CrowdfundContractClient,
+    creator: &Address,
+    token_address: &Address,
+) -> (hook_recorder::HookRecorderContractClient<'static>, u64) {
+    let hook_id = env.register(hook_recorder::HookRecorderContract, ());
+    let hook = hook_recorder::HookRecorderContractClient::new(env, &hook_id);
+    let deadline = init_with_options(
+        env,
+        client,
+        creator,
+        token_address,
+        CampaignOptions {
+            hook: Some(hook_id),
+            ..Default::default()
+        },
+    );
+    (hook, deadline)
+}
+
+#[test]
+fn test_hook_receives_contribute_and_finalize_args() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let (hook, deadline) = setup_hooked_campaign(&env, &client, &creator, &token_address);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &alice, 600_000);
+    mint_to(&env, &token_address, &admin, &bob, 400_000);
+
+    client.contribute(&alice, &600_000, &None, &None);
+    client.contribute(&bob, &400_000, &None, &None);
+    env.ledger().set_timestamp(deadline + 1);
+    client.withdraw();
+
+    assert_eq!(
+        hook.contributions(),
+        soroban_sdk::Vec::from_array(
+            &env,
+            [
+                hook_recorder::ContributeCall {
+                    contributor: alice,
+                    amount: 600_000,
+                    total: 600_000,
+                },
+                hook_recorder::ContributeCall {
+                    contributor: bob,
+                    amount: 400_000,
+                    total: 1_000_000,
+                },
+            ],
+        )
+    );
+    assert_eq!(
+        hook.finalizations(),
+        soroban_sdk::Vec::from_array(
+            &env,
+            [hook_recorder::FinalizeCall {
+                status: hook_recorder::CampaignStatus::Successful,
+                total: 1_000_000,
+            }],
+        )
+    );
+}
+
+#[test]
+fn test_failing_hook_does_not_block_contribute() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let (hook, _deadline) = setup_hooked_campaign(&env, &client, &creator, &token_address);
+    hook.set_failing(&true);
+    let contributor = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &contributor, 500_000);
+
+    client.contribute(&contributor, &500_000, &None, &None);
+
+    let failures: std::vec::Vec<(Address, soroban_sdk::Symbol)> =
+        campaign_events(&env, &client.address, "hook_failed");
+    assert_eq!(
+        failures,
+        std::vec![(
+            hook.address.clone(),
+            soroban_sdk::Symbol::new(&env, "on_contribute")
+        )]
+    );
+    assert_eq!(client.total_raised(), 500_000);
+    assert_eq!(hook.contributions().len(), 0);
+}
+
+#[test]
+fn test_failing_hook_does_not_block_cancel() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let (hook, _deadline) = setup_hooked_campaign(&env, &client, &creator, &token_address);
+    let contributor = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &contributor, 500_000);
+    client.contribute(&contributor, &500_000, &None, &None);
+    hook.set_failing(&true);
+
+    client.cancel();
+
+    assert_eq!(client.status(), Status::Cancelled);
+    let token_client = token::Client::new(&env, &token_address);
+    assert_eq!(token_client.balance(&contributor), 500_000);
+    hook.set_failing(&false);
+    assert_eq!(hook.finalizations().len(), 0);
+}
+
+#[test]
+fn test_campaign_without_hook_emits_no_hook_events() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    client.initialize(
+        &creator,
+        &token_address,
+        &1_000_000,
+        &2_000_000,
+        &Deadline::Timestamp(env.ledger().timestamp() + 3600),
+        &1_000,
+        &None,
+        &None,
+        &None,
+    );
+    let contributor = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &contributor, 500_000);
+
+    client.contribute(&contributor, &500_000, &None, &None);
+
+    let failures: std::vec::Vec<(Address, soroban_sdk::Symbol)> =
+        campaign_events(&env, &client.address, "hook_failed");
+    assert!(failures.is_empty());
+    assert_eq!(client.total_raised(), 500_000);
+}
+
+// ── Platform Finalization Callback Tests ───────────────────────────────────
+
+mod recording_platform {
+    use crate::Status;
+    use soroban_sdk::{contract, contractimpl, Address, Env};
+
+    /// Platform contract that records the last `campaign_finalized` call.
+    #[contract]
+    pub struct RecordingPlatform;
+
+    #[contractimpl]
+    impl RecordingPlatform {
+        pub fn campaign_finalized(
+            env: Env,
+            campaign: Address,
+            status: Status,
+            total_raised: i128,
+            fee_paid: i128,
+        ) {
+            env.storage()
+                .instance()
+                .set(&0u32, &(campaign, status, total_raised, fee_paid));
+        }
+
+        pub fn last_call(env: Env) -> Option<(Address, Status, i128, i128)> {
+            env.storage().instance().get(&0u32)
+        }
+    }
+}
+
+/// Initializes a campaign whose platform is a `RecordingPlatform` taking a
+/// 2.5% fee on withdraw, and contributes `amount`. Returns the platform and
+/// the campaign deadline.
+fn setup_recording_platform(
+    env: &Env,
+    client: &CrowdfundContractClient,
+    creator: &Address,
+    token_address: &Address,
+    admin: &Address,
+    amount: i128,
+) -> (recording_platform::RecordingPlatformClient<'static>, u64) {
+    let platform_id = env.register(recording_platform::RecordingPlatform, ());
+    let platform = recording_platform::RecordingPlatformClient::new(env, &platform_id);
+    let deadline = env.ledger().timestamp() + 3600;
+    client.initialize(
+        creator,
+        token_address,
+        &1_000_000,
+        &2_000_000,
+        &Deadline::Timestamp(deadline),
+        &1_000,
+        &Some(PlatformConfig {
+            address: platform_id,
+            fee_bps: 250,
+            fee_timing: FeeTiming::OnWithdraw,
+            tiered_fee: None,
+            dust_policy: DustPolicy::Creator,
+            min_campaign_duration_seconds: 0,
+            max_campaign_duration_seconds: 0,
+        }),
+        &None,
+        &None,
+    );
+    let contributor = Address::generate(env);
+    mint_to(env, token_address, admin, &contributor, amount);
+    client.contribute(&contributor, &amount, &None, &None);
+    (platform, deadline)
+}
+
+#[test]
+fn test_withdraw_notifies_platform() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let (platform, deadline) =
+        setup_recording_platform(&env, &client, &creator, &token_address, &admin, 1_000_000);
+
+    env.ledger().set_timestamp(deadline + 1);
+    client.withdraw();
+
+    assert_eq!(
+        platform.last_call(),
+        Some((
+            client.address.clone(),
+            Status::Successful,
+            1_000_000,
+            25_000
+        ))
+    );
+}
+
+#[test]
+fn test_refund_notifies_platform() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let (platform, deadline) =
+        setup_recording_platform(&env, &client, &creator, &token_address, &admin, 400_000);
+
+    env.ledger().set_timestamp(deadline + 1);
+    client.refund();
+
+    assert_eq!(
+        platform.last_call(),
+        Some((client.address.clone(), Status::Refunded, 400_000, 0))
+    );
+}
+
+#[test]
+fn test_cancel_notifies_platform() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let (platform, _deadline) =
+        setup_recording_platform(&env, &client, &creator, &token_address, &admin, 400_000);
+
+    client.cancel();
+
+    assert_eq!(
+        platform.last_call(),
+        Some((client.address.clone(), Status::Cancelled, 400_000, 0))
+    );
+}
+
+// ── Tiered Fee Tests ───────────────────────────────────────────────────────
+
+/// Runs a successful campaign raising `raised` under brackets of 5% up to
+/// 100k, 3% up to 500k and 1% up to 1M. Returns the platform's fee.
+fn run_tiered_fee_campaign(raised: i128) -> i128 {
+    let (env, client, creator, token_address, admin) = setup_env();
+
+    let mut tiers = soroban_sdk::Vec::new(&env);
+    tiers.push_back((100_000i128, 500u32));
+    tiers.push_back((500_000i128, 300u32));
+    tiers.push_back((1_000_000i128, 100u32));
+
+    let platform = Address::generate(&env);
+    let deadline = env.ledger().timestamp() + 3600;
+    client.initialize(
+        &creator,
+        &token_address,
+        &10_000,
+        &5_000_000,
+        &Deadline::Timestamp(deadline),
+        &1_000,
+        &Some(PlatformConfig {
+            address: platform.clone(),
+            fee_bps: 0,
+            fee_timing: FeeTiming::OnWithdraw,
+            tiered_fee: Some(tiers),
+            min_campaign_duration_seconds: 0,
+            max_campaign_duration_seconds: 0,
+            dust_policy: DustPolicy::Creator,
+        }),
+        &None,
+        &None,
+    );
+
+    let contributor = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &contributor, raised);
+    client.contribute(&contributor, &raised, &None, &None);
+
+    env.ledger().set_timestamp(deadline + 1);
+    client.withdraw();
+
+    token::Client::new(&env, &token_address).balance(&platform)
+}
+
+#[test]
+fn test_tiered_fee_under_first_tier() {
+    assert_eq!(run_tiered_fee_campaign(80_000), 4_000);
+}
+
+#[test]
+fn test_tiered_fee_between_tiers() {
+    assert_eq!(run_tiered_fee_campaign(400_000), 12_000);
+}
+
+#[test]
+fn test_tiered_fee_above_all_tiers() {
+    assert_eq!(run_tiered_fee_campaign(2_000_000), 20_000);
+}
+
+#[test]
+#[should_panic(expected = "fee tiers must be in ascending order")]
+fn test_tiered_fee_rejects_unordered_tiers() {
+    let (env, client, creator, token_address, _admin) = setup_env();
+
+    let mut tiers = soroban_sdk::Vec::new(&env);
+    tiers.push_back((500_000i128, 300u32));
+    tiers.push_back((500_000i128, 100u32));
+
+    client.initialize(
+        &creator,
+        &token_address,
+        &10_000,
+        &5_000_000,
+        &Deadline::Timestamp(env.ledger().timestamp() + 3600),
+        &1_000,
+        &Some(PlatformConfig {
+            address: Address::generate(&env),
+            fee_bps: 0,
+            fee_timing: FeeTiming::OnWithdraw,
+            tiered_fee: Some(tiers),
+            min_campaign_duration_seconds: 0,
+            max_campaign_duration_seconds: 0,
+            dust_policy: DustPolicy::Creator,
+        }),
+        &None,
+        &None,
+    );
+}
+
+// ── Contribution Transfer Tests ────────────────────────────────────────────
+
+#[test]
+fn test_transfer_full_contribution() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    setup_limited_tiers(&env, &client, &creator, &token_address);
+
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &from, 50_000);
+    client.contribute(&from, &50_000, &None, &None);
+
+    client.transfer_contribution(&from, &to, &50_000);
+
+    assert_eq!(client.contribution(&from), 0);
+    assert_eq!(client.contribution(&to), 50_000);
+    assert_eq!(client.total_raised(), 50_000);
+    assert_eq!(client.contributor_count(), 1);
+    assert_eq!(client.contributor_tier(&from), None);
+    assert_eq!(client.contributor_tier(&to), Some(0));
+
+    let token_client = token::Client::new(&env, &token_address);
+    assert_eq!(token_client.balance(&client.address), 50_000);
+}
+
+#[test]
+fn test_transfer_partial_contribution_adds_to_existing_balance() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    setup_limited_tiers(&env, &client, &creator, &token_address);
+
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &from, 80_000);
+    mint_to(&env, &token_address, &admin, &to, 20_000);
+    client.contribute(&from, &80_000, &None, &None);
+    client.contribute(&to, &20_000, &None, &None);
+
+    client.transfer_contribution(&from, &to, &30_000);
+
+    assert_eq!(client.contribution(&from), 50_000);
+    assert_eq!(client.contribution(&to), 50_000);
+    assert_eq!(client.total_raised(), 100_000);
+    assert_eq!(client.contributor_count(), 2);
+}
+
+#[test]
+#[should_panic(expected = "invalid transfer amount")]
+fn test_transfer_more_than_contribution_panics() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    setup_limited_tiers(&env, &client, &creator, &token_address);
+
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &from, 50_000);
+    client.contribute(&from, &50_000, &None, &None);
+
+    client.transfer_contribution(&from, &to, &50_001);
+}
+
+#[test]
+fn test_transfer_contribution_reassigns_tiers() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    setup_limited_tiers(&env, &client, &creator, &token_address);
+
+    let from = Address::generate(&env);
+    let to = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &from, 600_000);
+    mint_to(&env, &token_address, &admin, &to, 10_000);
+    client.contribute(&from, &600_000, &None, &None);
+    client.contribute(&to, &10_000, &None, &None);
+    assert_eq!(client.contributor_tier(&from), Some(2));
+    assert_eq!(client.contributor_tier(&to), Some(0));
+
+    // `from` drops to 100k (Silver), `to` climbs to 510k (Gold).
+    client.transfer_contribution(&from, &to, &500_000);
+
+    assert_eq!(client.contributor_tier(&from), Some(1));
+    assert_eq!(client.contributor_tier(&to), Some(2));
+
+    let tiers = client.reward_tiers();
+    assert_eq!(tiers.get(0).unwrap().claimed, 0);
+    assert_eq!(tiers.get(1).unwrap().claimed, 1);
+    assert_eq!(tiers.get(2).unwrap().claimed, 1);
+}
+
+#[test]
+fn test_transfer_to_blacklisted_address_rejected() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let from = setup_terms(&env, &client, &creator, &token_address, &admin);
+    client.contribute(&from, &50_000, &None, &None);
+
+    let to = Address::generate(&env);
+    client.blacklist_contributor(&to);
+    let result = client.try_transfer_contribution(&from, &to, &10_000);
+    assert_eq!(result, Err(Ok(crate::ContractError::AddressBlocked)));
+}
+
+#[test]
+#[should_panic(expected = "terms not accepted")]
+fn test_transfer_requires_receiver_to_accept_terms() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let from = setup_terms(&env, &client, &creator, &token_address, &admin);
+    client.set_terms_hash(&soroban_sdk::String::from_str(&env, "QmTermsHash"));
+    client.accept_terms(&from);
+    client.contribute(&from, &50_000, &None, &None);
+
+    client.transfer_contribution(&from, &Address::generate(&env), &10_000);
+}
+
+#[test]
+fn test_transfer_rejects_receiver_off_allowlist() {
+    let (env, client, _creator) = setup_allowlist_campaign();
+    let from = funded_contributor(&env, &client, 50_000);
+    client.add_to_allowlist(&soroban_sdk::Vec::from_array(&env, [from.clone()]));
+    client.contribute(&from, &50_000, &None, &None);
+
+    let to = Address::generate(&env);
+    let result = client.try_transfer_contribution(&from, &to, &10_000);
+    assert_eq!(result, Err(Ok(crate::ContractError::NotAllowlisted)));
+    assert_eq!(client.contribution(&from), 50_000);
+}
+
+#[test]
+fn test_transfer_rejected_after_deadline() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let from = setup_terms(&env, &client, &creator, &token_address, &admin);
+    client.contribute(&from, &50_000, &None, &None);
+
+    env.ledger().set_timestamp(client.deadline() + 1);
+    let to = Address::generate(&env);
+    let result = client.try_transfer_contribution(&from, &to, &10_000);
+    assert_eq!(result, Err(Ok(crate::ContractError::CampaignEnded)));
+    assert_eq!(client.contribution(&to), 0);
+}
+
+// ── Backer Badge Tests ─────────────────────────────────────────────────────
+
+#[test]
+fn test_claim_badge_after_success() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    setup_limited_tiers(&env, &client, &creator, &token_address);
+
+    let contributor = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &contributor, 1_000_000);
+    client.contribute(&contributor, &1_000_000, &None, &None);
+
+    env.ledger().set_timestamp(client.deadline() + 1);
+    client.withdraw();
+    client.claim_badge(&contributor);
+
+    let badge = client.badge_of(&contributor).unwrap();
+    assert_eq!(badge.amount, 1_000_000);
+    assert_eq!(badge.timestamp, client.deadline() + 1);
+    assert_eq!(badge.tier, Some(2));
+}
+
+#[test]
+fn test_claim_badge_twice_rejected() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    setup_limited_tiers(&env, &client, &creator, &token_address);
+
+    let contributor = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &contributor, 1_000_000);
+    client.contribute(&contributor, &1_000_000, &None, &None);
+
+    env.ledger().set_timestamp(client.deadline() + 1);
+    client.withdraw();
+    client.claim_badge(&contributor);
+
+    let result = client.try_claim_badge(&contributor);
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        crate::ContractError::BadgeAlreadyClaimed
+    );
+}
+
+#[test]
+fn test_claim_badge_by_non_contributor_rejected() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    setup_limited_tiers(&env, &client, &creator, &token_address);
+
+    let contributor = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &contributor, 1_000_000);
+    client.contribute(&contributor, &1_000_000, &None, &None);
+
+    env.ledger().set_timestamp(client.deadline() + 1);
+    client.withdraw();
+
+    let outsider = Address::generate(&env);
+    let result = client.try_claim_badge(&outsider);
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        crate::ContractError::NotAContributor
+    );
+    assert_eq!(client.badge_of(&outsider), None);
+}
+
+#[test]
+fn test_claim_badge_after_refund_rejected() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    setup_limited_tiers(&env, &client, &creator, &token_address);
+
+    let contributor = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &contributor, 50_000);
+    client.contribute(&contributor, &50_000, &None, &None);
+
+    env.ledger().set_timestamp(client.deadline() + 1);
+    client.refund();
+
+    let result = client.try_claim_badge(&contributor);
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        crate::ContractError::CampaignNotSuccessful
+    );
+}
+
+// ── Receipt Refund Tests ───────────────────────────────────────────────────
+
+#[test]
+fn test_claim_refund_with_soulbound_receipt() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let (receipt_client, deadline) = setup_with_receipt(&env, &client, &creator, &token_address);
+
+    let contributor = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &contributor, 100_000);
+    client.contribute(&contributor, &100_000, &None, &None);
+
+    env.ledger().set_timestamp(deadline + 1);
+    client.claim_refund_with_receipt(&contributor);
+
+    let token_client = token::Client::new(&env, &token_address);
+    assert_eq!(token_client.balance(&contributor), 100_000);
+    assert_eq!(receipt_client.balance(&contributor), 0);
+    assert_eq!(receipt_client.total_supply(), 0);
+    assert_eq!(client.contribution(&contributor), 0);
+    assert_eq!(client.total_raised(), 0);
+    assert_eq!(client.contributor_count(), 0);
+}
+
+#[test]
+fn test_refund_after_receipt_claim_rejected_for_original_address() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let (_receipt_client, deadline) = setup_with_receipt(&env, &client, &creator, &token_address);
+
+    let contributor = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &contributor, 100_000);
+    client.contribute(&contributor, &100_000, &None, &None);
+
+    env.ledger().set_timestamp(deadline + 1);
+    client.claim_refund_with_receipt(&contributor);
+
+    let result = client.try_claim_refund_with_receipt(&contributor);
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        crate::ContractError::NothingToClaim
+    );
+
+    // The bulk refund has nothing left to pay this address.
+    client.refund();
+    let token_client = token::Client::new(&env, &token_address);
+    assert_eq!(token_client.balance(&contributor), 100_000);
+}
+
+#[test]
+fn test_receipt_and_bulk_refunds_never_exceed_total_raised() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let (receipt_client, deadline) = setup_with_receipt(&env, &client, &creator, &token_address);
+
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &alice, 300_000);
+    mint_to(&env, &token_address, &admin, &bob, 200_000);
+    client.contribute(&alice, &300_000, &None, &None);
+    client.contribute(&bob, &200_000, &None, &None);
+    let total_raised = client.total_raised();
+
+    env.ledger().set_timestamp(deadline + 1);
+    client.claim_refund_with_receipt(&alice);
+    client.refund();
+
+    let refunded = campaign_events::<RefundEvent>(&env, &client.address, "refunded");
+    assert_eq!(refunded[0].total_refunded, 200_000);
+    assert_eq!(300_000 + refunded[0].total_refunded, total_raised);
+
+    let token_client = token::Client::new(&env, &token_address);
+    assert_eq!(token_client.balance(&client.address), 0);
+    assert_eq!(receipt_client.total_supply(), 0);
+}
+
+// ── USD Goal Tests ─────────────────────────────────────────────────────────
+
+/// A price oracle stub whose price can be set from the test.
+#[soroban_sdk::contract]
+struct MockOracle;
+
+#[soroban_sdk::contractimpl]
+impl MockOracle {
+    pub fn set_price(env: Env, price: i128) {
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "price"), &price);
+    }
+
+    pub fn price(env: Env, _asset: Address) -> i128 {
+        env.storage()
+            .instance()
+            .get(&Symbol::new(&env, "price"))
+            .unwrap_or(0)
+    }
+}
+
+/// Initializes a campaign with a $1,000 goal priced by a mock oracle.
+/// Returns the oracle client and the deadline.
+fn setup_usd_goal(
+    env: &Env,
+    client: &CrowdfundContractClient,
+    creator: &Address,
+    token_address: &Address,
+    price: i128,
+) -> (MockOracleClient<'static>, u64) {
+    let oracle_id = env.register(MockOracle, ());
+    let oracle = MockOracleClient::new(env, &oracle_id);
+    oracle.set_price(&price);
+
+    let deadline = init_with_options(
+        env,
+        client,
+        creator,
+        token_address,
+        CampaignOptions {
+            usd_goal: Some(100_000),
+            price_oracle: Some(oracle_id),
+            ..Default::default()
+        },
+    );
+
+    (oracle, deadline)
+}
+
+#[test]
+fn test_usd_goal_with_fixed_price() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let (_oracle, deadline) = setup_usd_goal(&env, &client, &creator, &token_address, 5);
+
+    assert_eq!(client.goal(), 500_000);
+
+    let contributor = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &contributor, 500_000);
+    client.contribute(&contributor, &500_000, &None, &None);
+
+    env.ledger().set_timestamp(deadline + 1);
+    client.withdraw();
+    assert_eq!(client.status(), Status::Successful);
+}
+
+#[test]
+fn test_usd_goal_follows_price_change() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let (oracle, deadline) = setup_usd_goal(&env, &client, &creator, &token_address, 5);
+
+    let contributor = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &contributor, 500_000);
+    client.contribute(&contributor, &500_000, &None, &None);
+
+    // The token loses value, so the same USD goal needs more tokens.
+    oracle.set_price(&8);
+    assert_eq!(client.goal(), 800_000);
+
+    env.ledger().set_timestamp(deadline + 1);
+    let result = client.try_withdraw();
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        crate::ContractError::GoalNotReached
+    );
+    client.refund();
+    assert_eq!(client.status(), Status::Refunded);
+}
+
+#[test]
+#[should_panic(expected = "oracle unavailable")]
+fn test_usd_goal_zero_price_panics() {
+    let (env, client, creator, token_address, _admin) = setup_env();
+    let (oracle, _deadline) = setup_usd_goal(&env, &client, &creator, &token_address, 5);
+
+    oracle.set_price(&0);
+    client.goal();
+}
+
+// ── Multi-Token Contribution Tests ─────────────────────────────────────────
+
+/// A swap pool stub paying out the base token at a settable rate.
+#[soroban_sdk::contract]
+struct MockSwapPool;
+
+#[soroban_sdk::contractimpl]
+impl MockSwapPool {
+    pub fn init(env: Env, base: Address, rate_bps: i128) {
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "base"), &base);
+        Self::set_rate(env, rate_bps);
+    }
+
+    pub fn set_rate(env: Env, rate_bps: i128) {
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "rate"), &rate_bps);
+    }
+
+    pub fn swap(env: Env, _token_in: Address, amount_in: i128, to: Address) -> i128 {
+        let base: Address = env
+            .storage()
+            .instance()
+            .get(&Symbol::new(&env, "base"))
+            .unwrap();
+        let rate: i128 = env
+            .storage()
+            .instance()
+            .get(&Symbol::new(&env, "rate"))
+            .unwrap();
+        let out = amount_in * rate / 10_000;
+        token::Client::new(&env, &base).transfer(&env.current_contract_address(), &to, &out);
+        out
+    }
+}
+
+/// Registers a fresh token and a swap pool for it that pays the base token
+/// at `rate_bps`. Returns `(token, pool client)`.
+fn setup_swap_pool(
+    env: &Env,
+    base_token: &Address,
+    base_admin: &Address,
+    rate_bps: i128,
+) -> (Address, MockSwapPoolClient<'static>) {
+    let token_admin = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+
+    let pool_id = env.register(MockSwapPool, ());
+    let pool = MockSwapPoolClient::new(env, &pool_id);
+    pool.init(base_token, &rate_bps);
+    mint_to(env, base_token, base_admin, &pool_id, 10_000_000);
+
+    (token, pool)
+}
+
+fn swapped_token(token: &Address, pool: &MockSwapPoolClient) -> AcceptedToken {
+    AcceptedToken {
+        token: token.clone(),
+        swap_pool: Some(pool.address.clone()),
+    }
+}
+
+#[test]
+fn test_contribute_token_with_two_accepted_tokens() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    init_with_options(
+        &env,
+        &client,
+        &creator,
+        &token_address,
+        CampaignOptions::default(),
+    );
+
+    let (token_a, pool_a) = setup_swap_pool(&env, &token_address, &admin, 20_000);
+    let (token_b, pool_b) = setup_swap_pool(&env, &token_address, &admin, 5_000);
+    let mut accepted = soroban_sdk::Vec::new(&env);
+    accepted.push_back(swapped_token(&token_a, &pool_a));
+    accepted.push_back(swapped_token(&token_b, &pool_b));
+    client.set_accepted_tokens(&accepted);
+    assert_eq!(client.accepted_tokens(), accepted);
+
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token_a).mint(&alice, &50_000);
+    token::StellarAssetClient::new(&env, &token_b).mint(&bob, &50_000);
+
+    client.contribute_token(&alice, &token_a, &50_000);
+    client.contribute_token(&bob, &token_b, &50_000);
+
+    assert_eq!(client.contribution(&alice), 100_000);
+    assert_eq!(client.contribution(&bob), 25_000);
+    assert_eq!(client.total_raised(), 125_000);
+
+    let base = token::Client::new(&env, &token_address);
+    assert_eq!(base.balance(&client.address), 125_000);
+}
+
+#[test]
+#[should_panic(expected = "converted amount below minimum")]
+fn test_contribute_token_slippage_below_minimum_panics() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    init_with_options(
+        &env,
+        &client,
+        &creator,
+        &token_address,
+        CampaignOptions::default(),
+    );
+
+    let (token_a, pool_a) = setup_swap_pool(&env, &token_address, &admin, 10_000);
+    client.set_accepted_tokens(&soroban_sdk::Vec::from_array(
+        &env,
+        [swapped_token(&token_a, &pool_a)],
+    ));
+
+    let contributor = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token_a).mint(&contributor, &1_000);
+
+    // The pool price slips 10% between quote and execution.
+    pool_a.set_rate(&9_000);
+    client.contribute_token(&contributor, &token_a, &1_000);
+}
+
+#[test]
+fn test_contribute_unregistered_token_rejected() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    init_with_options(
+        &env,
+        &client,
+        &creator,
+        &token_address,
+        CampaignOptions::default(),
+    );
+
+    let (token_a, _pool_a) = setup_swap_pool(&env, &token_address, &admin, 10_000);
+    let contributor = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token_a).mint(&contributor, &50_000);
+
+    let result = client.try_contribute_token(&contributor, &token_a, &50_000);
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        crate::ContractError::TokenNotAccepted
+    );
+}
+
+// ── Native Asset Tests ─────────────────────────────────────────────────────
+
+#[test]
+fn test_native_asset_contribute_withdraw_cycle() {
+    let (env, client, creator, token_address, admin) = setup_env();
+
+    let deadline = env.ledger().timestamp() + 3600;
+    client.initialize(
+        &creator,
+        &token_address,
+        &100_000,
+        &200_000,
+        &Deadline::Timestamp(deadline),
+        &1_000,
+        &None,
+        &None,
+        &None,
+    );
+    assert_eq!(client.token_decimals(), 7);
+
+    let contributor = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &contributor, 100_000);
+    client.contribute(&contributor, &100_000, &None, &None);
+
+    env.ledger().set_timestamp(deadline + 1);
+    let token_client = token::Client::new(&env, &token_address);
+    let creator_before = token_client.balance(&creator);
+    client.withdraw();
+
+    assert_eq!(token_client.balance(&creator), creator_before + 100_000);
+    assert_eq!(token_client.balance(&client.address), 0);
+}
+
+#[test]
+fn test_native_asset_contribute_refund_cycle() {
+    let (env, client, creator, token_address, admin) = setup_env();
+
+    let deadline = env.ledger().timestamp() + 3600;
+    client.initialize(
+        &creator,
+        &token_address,
+        &100_000,
+        &200_000,
+        &Deadline::Timestamp(deadline),
+        &1_000,
+        &None,
+        &None,
+        &None,
+    );
+
+    let contributor = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &contributor, 60_000);
+    client.contribute(&contributor, &60_000, &None, &None);
+
+    env.ledger().set_timestamp(deadline + 1);
+    client.refund();
+
+    let token_client = token::Client::new(&env, &token_address);
+    assert_eq!(token_client.balance(&contributor), 60_000);
+    assert_eq!(token_client.balance(&client.address), 0);
+}
+
+#[test]
+fn test_initialize_with_non_token_address_fails() {
+    let (env, client, creator, _token_address, _admin) = setup_env();
+
+    // A contract that does not implement the token interface.
+    let not_a_token = env.register(MockOracle, ());
+    let result = client.try_initialize(
+        &creator,
+        &not_a_token,
+        &100_000,
+        &200_000,
+        &Deadline::Timestamp(env.ledger().timestamp() + 3600),
+        &1_000,
+        &None,
+        &None,
+        &None,
+    );
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        crate::ContractError::InvalidToken
+    );
+
+    // An address with no contract behind it at all.
+    let result = client.try_initialize(
+        &creator,
+        &Address::generate(&env),
+        &100_000,
+        &200_000,
+        &Deadline::Timestamp(env.ledger().timestamp() + 3600),
+        &1_000,
+        &None,
+        &None,
+        &None,
+    );
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        crate::ContractError::InvalidToken
+    );
+
+    // A contract that answers `decimals` but not `symbol`.
+    let decimals_only = env.register(MockPriceFeed, ());
+    let result = client.try_initialize(
+        &creator,
+        &decimals_only,
+        &100_000,
+        &200_000,
+        &Deadline::Timestamp(env.ledger().timestamp() + 3600),
+        &1_000,
+        &None,
+        &None,
+        &None,
+    );
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        crate::ContractError::InvalidToken
+    );
+}
+
+#[test]
+fn test_initialize_caches_token_metadata() {
+    let (env, client, creator, token_address, _admin) = setup_env();
+    client.initialize(
+        &creator,
+        &token_address,
+        &100_000,
+        &200_000,
+        &Deadline::Timestamp(env.ledger().timestamp() + 3600),
+        &1_000,
+        &None,
+        &None,
+        &None,
+    );
+
+    let token_client = token::Client::new(&env, &token_address);
+    let metadata = client.token_metadata();
+    assert_eq!(metadata.symbol, token_client.symbol());
+    assert_eq!(metadata.decimals, token_client.decimals());
+}
+
+// ── Approved Token Tests ───────────────────────────────────────────────────
+
+/// Initializes a campaign (goal 100k in the primary token, 2.5% platform
+/// fee) that also accepts a second token. Returns `(second token, platform,
+/// deadline)`.
+fn setup_approved_token(
+    env: &Env,
+    client: &CrowdfundContractClient,
+    creator: &Address,
+    token_address: &Address,
+) -> (Address, Address, u64) {
+    let second_token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    let mut approved = soroban_sdk::Vec::new(env);
+    approved.push_back(second_token.clone());
+
+    let platform = Address::generate(env);
+    let deadline = env.ledger().timestamp() + 3600;
+    client.initialize(
+        creator,
+        token_address,
+        &100_000,
+        &1_000_000,
+        &Deadline::Timestamp(deadline),
+        &1_000,
+        &Some(PlatformConfig {
+            address: platform.clone(),
+            fee_bps: 250,
+            fee_timing: FeeTiming::OnWithdraw,
+            tiered_fee: None,
+            min_campaign_duration_seconds: 0,
+            max_campaign_duration_seconds: 0,
+            dust_policy: DustPolicy::Creator,
+        }),
+        &None,
+        &Some(CampaignOptions {
+            approved_tokens: Some(approved),
+            ..Default::default()
+        }),
+    );
+
+    (second_token, platform, deadline)
+}
+
+#[test]
+fn test_contribute_in_two_tokens_tracked_separately() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let (second, _platform, _deadline) =
+        setup_approved_token(&env, &client, &creator, &token_address);
+
+    let contributor = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &contributor, 40_000);
+    token::StellarAssetClient::new(&env, &second).mint(&contributor, &70_000);
+
+    client.contribute(&contributor, &40_000, &None, &Some(token_address.clone()));
+    env.ledger().set_timestamp(env.ledger().timestamp() + 5);
+    client.contribute(&contributor, &70_000, &None, &Some(second.clone()));
+
+    assert_eq!(client.contribution(&contributor), 40_000);
+    assert_eq!(client.token_contribution(&contributor, &second), 70_000);
+    assert_eq!(client.token_total(&second), 70_000);
+    assert_eq!(
+        client.accepted_tokens(),
+        soroban_sdk::Vec::from_array(
+            &env,
+            [AcceptedToken {
+                token: second.clone(),
+                swap_pool: None,
+            }]
+        )
+    );
+
+    // Only the primary token counts toward the goal.
+    assert_eq!(client.total_raised(), 40_000);
+}
+
+#[test]
+fn test_secondary_tokens_do_not_meet_goal() {
+    let (env, client, creator, token_address, _admin) = setup_env();
+    let (second, _platform, deadline) =
+        setup_approved_token(&env, &client, &creator, &token_address);
+
+    let contributor = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &second).mint(&contributor, &500_000);
+    client.contribute(&contributor, &500_000, &None, &Some(second.clone()));
+
+    env.ledger().set_timestamp(deadline + 1);
+    let result = client.try_withdraw();
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        crate::ContractError::GoalNotReached
+    );
+}
+
+#[test]
+fn test_withdraw_pays_each_token_with_fee() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let (second, platform, deadline) =
+        setup_approved_token(&env, &client, &creator, &token_address);
+
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &alice, 100_000);
+    token::StellarAssetClient::new(&env, &second).mint(&bob, &40_000);
+    client.contribute(&alice, &100_000, &None, &None);
+    client.contribute(&bob, &40_000, &None, &Some(second.clone()));
+
+    env.ledger().set_timestamp(deadline + 1);
+    let primary = token::Client::new(&env, &token_address);
+    let secondary = token::Client::new(&env, &second);
+    let creator_before = primary.balance(&creator);
+    client.withdraw();
+
+    assert_eq!(primary.balance(&creator), creator_before + 97_500);
+    assert_eq!(primary.balance(&platform), 2_500);
+    assert_eq!(secondary.balance(&creator), 39_000);
+    assert_eq!(secondary.balance(&platform), 1_000);
+    assert_eq!(secondary.balance(&client.address), 0);
+}
+
+#[test]
+fn test_refund_returns_each_token_to_contributors() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let (second, _platform, deadline) =
+        setup_approved_token(&env, &client, &creator, &token_address);
+
+    let contributor = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &contributor, 30_000);
+    token::StellarAssetClient::new(&env, &second).mint(&contributor, &20_000);
+    client.contribute(&contributor, &30_000, &None, &None);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 5);
+    client.contribute(&contributor, &20_000, &None, &Some(second.clone()));
+
+    env.ledger().set_timestamp(deadline + 1);
+    client.refund();
+
+    let primary = token::Client::new(&env, &token_address);
+    let secondary = token::Client::new(&env, &second);
+    assert_eq!(primary.balance(&contributor), 30_000);
+    assert_eq!(secondary.balance(&contributor), 20_000);
+    assert_eq!(secondary.balance(&client.address), 0);
+    assert_eq!(client.token_total(&second), 0);
+}
+
+#[test]
+fn test_contribute_unapproved_token_rejected() {
+    let (env, client, creator, token_address, _admin) = setup_env();
+    setup_approved_token(&env, &client, &creator, &token_address);
+
+    let other = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let contributor = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &other).mint(&contributor, &10_000);
+
+    let result = client.try_contribute(&contributor, &10_000, &None, &Some(other));
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        crate::ContractError::TokenNotAccepted
+    );
+}
+
+#[test]
+fn test_accepted_tokens_mix_swapped_and_tallied() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let (second, _platform, _deadline) =
+        setup_approved_token(&env, &client, &creator, &token_address);
+    let (swapped, pool) = setup_swap_pool(&env, &token_address, &admin, 20_000);
+    let mut accepted = client.accepted_tokens();
+    accepted.push_back(swapped_token(&swapped, &pool));
+    client.set_accepted_tokens(&accepted);
+
+    let contributor = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &second).mint(&contributor, &10_000);
+    token::StellarAssetClient::new(&env, &swapped).mint(&contributor, &10_000);
+    client.contribute(&contributor, &10_000, &None, &Some(second.clone()));
+    env.ledger().set_timestamp(env.ledger().timestamp() + 5);
+    client.contribute(&contributor, &10_000, &None, &Some(swapped.clone()));
+
+    // The swapped token is converted and counts toward the goal; the
+    // tallied one does not.
+    assert_eq!(client.contribution(&contributor), 20_000);
+    assert_eq!(client.token_contribution(&contributor, &second), 10_000);
+    assert_eq!(client.token_total(&swapped), 0);
+}
+
+#[test]
+#[should_panic(expected = "token still holds contributions")]
+fn test_set_accepted_tokens_keeps_tallied_token_with_contributions() {
+    let (env, client, creator, token_address, _admin) = setup_env();
+    let (second, _platform, _deadline) =
+        setup_approved_token(&env, &client, &creator, &token_address);
+    let contributor = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &second).mint(&contributor, &10_000);
+    client.contribute(&contributor, &10_000, &None, &Some(second));
+
+    client.set_accepted_tokens(&soroban_sdk::Vec::new(&env));
+}
+
+// ── Contribution Memo Tests ────────────────────────────────────────────────
+
+#[test]
+fn test_contribute_with_memo_stores_memos_by_index() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    client.initialize(
+        &creator,
+        &token_address,
+        &1_000_000,
+        &2_000_000,
+        &Deadline::Timestamp(env.ledger().timestamp() + 3600),
+        &1_000,
+        &None,
+        &None,
+        &None,
+    );
+
+    let contributor = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &contributor, 30_000);
+
+    let first = soroban_sdk::String::from_str(&env, "Go team!");
+    let third = soroban_sdk::String::from_str(&env, "Topping up");
+    client.contribute_with_memo(&contributor, &10_000, &first);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 5);
+    client.contribute(&contributor, &10_000, &None, &None);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 5);
+    client.contribute_with_memo(&contributor, &10_000, &third);
+
+    assert_eq!(client.contribution(&contributor), 30_000);
+    assert_eq!(client.contribution_memo(&contributor, &0), Some(first));
+    assert_eq!(client.contribution_memo(&contributor, &1), None);
+    assert_eq!(client.contribution_memo(&contributor, &2), Some(third));
+    assert_eq!(client.contribution_memo(&contributor, &3), None);
+}
+
+#[test]
+fn test_contribute_with_memo_at_max_length() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    client.initialize(
+        &creator,
+        &token_address,
+        &1_000_000,
+        &2_000_000,
+        &Deadline::Timestamp(env.ledger().timestamp() + 3600),
+        &1_000,
+        &None,
+        &None,
+        &None,
+    );
+
+    let contributor = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &contributor, 10_000);
+
+    let memo = soroban_sdk::String::from_str(&env, &"a".repeat(256));
+    client.contribute_with_memo(&contributor, &10_000, &memo);
+    assert_eq!(client.contribution_memo(&contributor, &0), Some(memo));
+}
+
+#[test]
+#[should_panic(expected = "memo too long")]
+fn test_contribute_with_memo_too_long_panics() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    client.initialize(
+        &creator,
+        &token_address,
+        &1_000_000,
+        &2_000_000,
+        &Deadline::Timestamp(env.ledger().timestamp() + 3600),
+        &1_000,
+        &None,
+        &None,
+        &None,
+    );
+
+    let contributor = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &contributor, 10_000);
+
+    let memo = soroban_sdk::String::from_str(&env, &"a".repeat(257));
+    client.contribute_with_memo(&contributor, &10_000, &memo);
+}
+
+// ── Suspension Tests ───────────────────────────────────────────────────────
+
+/// Initializes a campaign with a platform, funds it with 50k and suspends
+/// it. Returns `(platform, contributor, deadline)`.
+fn setup_suspended(
+    env: &Env,
+    client: &CrowdfundContractClient,
+    creator: &Address,
+    token_address: &Address,
+    admin: &Address,
+) -> (Address, Address, u64) {
+    let platform = Address::generate(env);
+    let deadline = env.ledger().timestamp() + 3600;
+    client.initialize(
+        creator,
+        token_address,
+        &100_000,
+        &1_000_000,
+        &Deadline::Timestamp(deadline),
+        &1_000,
+        &Some(PlatformConfig {
+            address: platform.clone(),
+            fee_bps: 0,
+            fee_timing: FeeTiming::OnWithdraw,
+            tiered_fee: None,
+            min_campaign_duration_seconds: 0,
+            max_campaign_duration_seconds: 0,
+            dust_policy: DustPolicy::Creator,
+        }),
+        &None,
+        &None,
+    );
+
+    let contributor = Address::generate(env);
+    mint_to(env, token_address, admin, &contributor, 200_000);
+    client.contribute(&contributor, &50_000, &None, &None);
+
+    client.suspend();
+    assert!(client.is_suspended());
+
+    (platform, contributor, deadline)
+}
+
+#[test]
+#[should_panic(expected = "campaign is suspended")]
+fn test_contribute_while_suspended_panics() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let (_platform, contributor, _deadline) =
+        setup_suspended(&env, &client, &creator, &token_address, &admin);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 5);
+    client.contribute(&contributor, &10_000, &None, &None);
+}
+
+#[test]
+#[should_panic(expected = "campaign is suspended")]
+fn test_withdraw_while_suspended_panics() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let (_platform, contributor, deadline) =
+        setup_suspended(&env, &client, &creator, &token_address, &admin);
+
+    client.unsuspend();
+    env.ledger().set_timestamp(env.ledger().timestamp() + 5);
+    client.contribute(&contributor, &50_000, &None, &None);
+    client.suspend();
+
+    env.ledger().set_timestamp(deadline + 1);
+    client.withdraw();
+}
+
+#[test]
+#[should_panic(expected = "campaign is suspended")]
+fn test_cancel_while_suspended_panics() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    setup_suspended(&env, &client, &creator, &token_address, &admin);
+
+    client.cancel();
+}
+
+#[test]
+#[should_panic(expected = "campaign is suspended")]
+fn test_refund_while_suspended_panics() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let (_platform, _contributor, deadline) =
+        setup_suspended(&env, &client, &creator, &token_address, &admin);
+
+    env.ledger().set_timestamp(deadline + 1);
+    client.refund();
+}
+
+#[test]
+fn test_suspended_campaign_still_allows_roadmap_and_reads() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    setup_suspended(&env, &client, &creator, &token_address, &admin);
+
+    let description = soroban_sdk::String::from_str(&env, "Audit");
+    let date = env.ledger().timestamp() + 100;
+    client.add_roadmap_item(&date, &date, &description);
+
+    assert_eq!(client.roadmap().len(), 1);
+    assert_eq!(client.total_raised(), 50_000);
+}
+
+#[test]
+fn test_unsuspend_then_contribute() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let (_platform, contributor, _deadline) =
+        setup_suspended(&env, &client, &creator, &token_address, &admin);
+
+    client.unsuspend();
+    assert!(!client.is_suspended());
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 5);
+    client.contribute(&contributor, &10_000, &None, &None);
+    assert_eq!(client.contribution(&contributor), 60_000);
+}
+
+#[test]
+#[should_panic]
+fn test_suspend_by_non_platform_rejected() {
+    let (env, client, creator, token_address, _admin) = setup_env();
+
+    let platform = Address::generate(&env);
+    client.initialize(
+        &creator,
+        &token_address,
+        &100_000,
+        &1_000_000,
+        &Deadline::Timestamp(env.ledger().timestamp() + 3600),
+        &1_000,
+        &Some(PlatformConfig {
+            address: platform,
+            fee_bps: 0,
+            fee_timing: FeeTiming::OnWithdraw,
+            tiered_fee: None,
+            min_campaign_duration_seconds: 0,
+            max_campaign_duration_seconds: 0,
+            dust_policy: DustPolicy::Creator,
+        }),
+        &None,
+        &None,
+    );
+
+    // Only the creator signs, not the platform.
+    env.mock_auths(&[soroban_sdk::testutils::MockAuth {
+        address: &creator,
+        invoke: &soroban_sdk::testutils::MockAuthInvoke {
+            contract: &client.address,
+            fn_name: "suspend",
+            args: ().into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+    client.suspend();
+}
+
+// ── SEP-40 Goal Oracle Tests ───────────────────────────────────────────────
+
+/// A SEP-40 price feed stub with 7 decimals and a settable last price.
+#[soroban_sdk::contract]
+struct MockPriceFeed;
+
+#[soroban_sdk::contractimpl]
+impl MockPriceFeed {
+    pub fn set_last_price(env: Env, price: i128, timestamp: u64) {
+        env.storage()
+            .instance()
+            .set(&Symbol::new(&env, "last"), &PriceData { price, timestamp });
+    }
+
+    pub fn decimals(_env: Env) -> u32 {
+        7
+    }
+
+    pub fn lastprice(env: Env, _asset: Asset) -> Option<PriceData> {
+        env.storage().instance().get(&Symbol::new(&env, "last"))
+    }
+}
+
+/// Initializes a campaign with a `usd_goal` of 1,000,000 quote units priced
+/// by a SEP-40 feed (max staleness 5 minutes) and contributes 400,000 tokens.
+/// Returns the feed client and the deadline.
+fn setup_goal_oracle(
+    env: &Env,
+    client: &CrowdfundContractClient,
+    creator: &Address,
+    token_address: &Address,
+    admin: &Address,
+) -> (MockPriceFeedClient<'static>, u64) {
+    let feed_id = env.register(MockPriceFeed, ());
+    let feed = MockPriceFeedClient::new(env, &feed_id);
+
+    let deadline = init_with_options(
+        env,
+        client,
+        creator,
+        token_address,
+        CampaignOptions {
+            usd_goal: Some(1_000_000),
+            price_oracle: Some(feed_id),
+            price_asset: Some(Symbol::new(env, "XLM")),
+            max_price_staleness: 300,
+            ..Default::default()
+        },
+    );
+
+    let contributor = Address::generate(env);
+    mint_to(env, token_address, admin, &contributor, 400_000);
+    client.contribute(&contributor, &400_000, &None, &None);
+
+    (feed, deadline)
+}
+
+#[test]
+fn test_goal_oracle_goal_reached_at_price() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let (feed, deadline) = setup_goal_oracle(&env, &client, &creator, &token_address, &admin);
+
+    env.ledger().set_timestamp(deadline + 1);
+    // 400,000 tokens at 2.5 quote units each = 1,000,000.
+    feed.set_last_price(&25_000_000, &deadline);
+    assert_eq!(client.goal(), 400_000);
+
+    let result = client.try_refund();
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        crate::ContractError::GoalReached
+    );
+    client.withdraw();
+    assert_eq!(client.status(), Status::Successful);
+}
+
+#[test]
+fn test_goal_oracle_goal_not_reached_at_price() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let (feed, deadline) = setup_goal_oracle(&env, &client, &creator, &token_address, &admin);
+
+    env.ledger().set_timestamp(deadline + 1);
+    // 400,000 tokens at 2 quote units each = 800,000.
+    feed.set_last_price(&20_000_000, &deadline);
+
+    let result = client.try_withdraw();
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        crate::ContractError::GoalNotReached
+    );
+    client.refund();
+    assert_eq!(client.status(), Status::Refunded);
+}
+
+#[test]
+fn test_goal_oracle_stale_price_fails_closed() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let (feed, deadline) = setup_goal_oracle(&env, &client, &creator, &token_address, &admin);
+
+    feed.set_last_price(&25_000_000, &deadline);
+    env.ledger().set_timestamp(deadline + 301);
+
+    let result = client.try_withdraw();
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        crate::ContractError::StalePrice
+    );
+    let result = client.try_refund();
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        crate::ContractError::StalePrice
+    );
+    assert_eq!(client.status(), Status::Active);
+}
+
+#[test]
+#[should_panic(expected = "price_asset needs usd_goal and price_oracle")]
+fn test_price_asset_without_usd_goal_panics() {
+    let (env, client, creator, token_address, _admin) = setup_env();
+    init_with_options(
+        &env,
+        &client,
+        &creator,
+        &token_address,
+        CampaignOptions {
+            price_asset: Some(Symbol::new(&env, "XLM")),
+            ..Default::default()
+        },
+    );
+}
+
+// ── Fee-on-Transfer Token Tests ─────────────────────────────────────────────
+
+mod fee_on_transfer {
+    use soroban_sdk::{contract, contractimpl, Address, Env, String};
+
+    /// Token that burns 1% of every transfer, so recipients get 99%, and can
+    /// charge holders a fee on what they hold.
+    #[contract]
+    pub struct SkimmingToken;
+
+    #[contractimpl]
+    impl SkimmingToken {
+        pub fn mint(env: Env, to: Address, amount: i128) {
+            let balance = Self::balance(env.clone(), to.clone());
+            env.storage().instance().set(&to, &(balance + amount));
+        }
+
+        pub fn decimals(_env: Env) -> u32 {
+            7
+        }
+
+        pub fn symbol(env: Env) -> String {
+            String::from_str(&env, "MOCK")
+        }
+
+        pub fn balance(env: Env, id: Address) -> i128 {
+            env.storage().instance().get(&id).unwrap_or(0)
+        }
+
+        pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+            from.require_auth();
+            let from_balance = Self::balance(env.clone(), from.clone());
+            if from_balance < amount {
+                panic!("insufficient balance");
+            }
+            env.storage()
+                .instance()
+                .set(&from, &(from_balance - amount));
+            let to_balance = Self::balance(env.clone(), to.clone());
+            env.storage()
+                .instance()
+                .set(&to, &(to_balance + amount - amount / 100));
+        }
+
+        pub fn charge_holder(env: Env, id: Address, amount: i128) {
+            let balance = Self::balance(env.clone(), id.clone());
+            env.storage().instance().set(&id, &(balance - amount));
+        }
+    }
+}
+
+use fee_on_transfer::{SkimmingToken, SkimmingTokenClient};
+
+fn setup_skimming_token() -> (
+    Env,
+    CrowdfundContractClient<'static>,
+    SkimmingTokenClient<'static>,
+) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(CrowdfundContract, (Address::generate(&env),));
+    let client = CrowdfundContractClient::new(&env, &contract_id);
+    let token_id = env.register(SkimmingToken, ());
+    let token = SkimmingTokenClient::new(&env, &token_id);
+
+    let creator = Address::generate(&env);
+    init_with_options(
+        &env,
+        &client,
+        &creator,
+        &token_id,
+        CampaignOptions::default(),
+    );
+
+    (env, client, token)
+}
+
+#[test]
+fn test_fee_on_transfer_contribution_credits_received_amount() {
+    let (env, client, token) = setup_skimming_token();
+
+    let contributor = Address::generate(&env);
+    token.mint(&contributor, &100_000);
+    client.contribute(&contributor, &100_000, &None, &None);
+
+    assert_eq!(client.contribution(&contributor), 99_000);
+    assert_eq!(client.total_raised(), 99_000);
+    assert_eq!(token.balance(&client.address), client.total_raised());
+}
+
+#[test]
+fn test_fee_on_transfer_matching_pool_records_received_amount() {
+    let (env, client, token) = setup_skimming_token();
+
+    let sponsor = Address::generate(&env);
+    token.mint(&sponsor, &50_000);
+    client.fund_match(&sponsor, &50_000, &10_000);
+
+    assert_eq!(client.matching_pool().unwrap().remaining, 49_500);
+    assert_eq!(token.balance(&client.address), 49_500);
+}
+
+#[test]
+fn test_verify_accounting_holds_for_discounted_deposits() {
+    let (env, client, token) = setup_skimming_token();
+
+    let contributor = Address::generate(&env);
+    token.mint(&contributor, &1_100_000);
+    client.contribute(&contributor, &1_100_000, &None, &None);
+
+    assert_eq!(client.total_raised(), 1_089_000);
+    assert!(client.verify_accounting());
+}
+
+#[test]
+fn test_profit_claim_then_withdraw_keeps_accounting() {
+    let (env, client, creator, token_address, _admin) = setup_env();
+    let deadline = init_with_options(
+        &env,
+        &client,
+        &creator,
+        &token_address,
+        CampaignOptions::default(),
+    );
+    let contributor = back_campaign(&env, &client, 1_000_000);
+    env.ledger().set_timestamp(deadline + 1);
+    client.withdraw_amount(&400_000);
+
+    client.distribute_profit(&40_000);
+    assert_eq!(client.claim_profit(&contributor), 40_000);
+    assert!(client.verify_accounting());
+
+    client.withdraw_amount(&600_000);
+    assert_eq!(client.withdrawn_so_far(), 1_000_000);
+    assert!(client.verify_accounting());
+}
+
+#[test]
+fn test_stray_transfer_does_not_block_withdraw() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let deadline = init_with_options(
+        &env,
+        &client,
+        &creator,
+        &token_address,
+        CampaignOptions::default(),
+    );
+    back_campaign(&env, &client, 1_000_000);
+
+    mint_to(&env, &token_address, &admin, &client.address, 1);
+    assert!(client.verify_accounting());
+
+    env.ledger().set_timestamp(deadline + 1);
+    client.withdraw();
+    assert_eq!(client.status(), Status::Successful);
+}
+
+#[test]
+#[should_panic(expected = "accounting invariant violated")]
+fn test_withdraw_rejected_when_balance_short_of_total_raised() {
+    let (env, client, token) = setup_skimming_token();
+    let contributor = Address::generate(&env);
+    token.mint(&contributor, &1_100_000);
+    client.contribute(&contributor, &1_100_000, &None, &None);
+
+    token.charge_holder(&client.address, &1_000);
+    assert!(!client.verify_accounting());
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 3601);
+    client.withdraw();
+}
+
+// ── Platform Fee Override Tests ─────────────────────────────────────────────
+
+fn setup_fee_override(
+    env: &Env,
+    client: &CrowdfundContractClient,
+    creator: &Address,
+    token_address: &Address,
+    admin: &Address,
+) -> (Address, u64) {
+    let platform = Address::generate(env);
+    let deadline = env.ledger().timestamp() + 3600;
+    client.initialize(
+        creator,
+        token_address,
+        &100_000,
+        &1_000_000,
+        &Deadline::Timestamp(deadline),
+        &1_000,
+        &Some(PlatformConfig {
+            address: platform.clone(),
+            fee_bps: 500,
+            fee_timing: FeeTiming::OnWithdraw,
+            tiered_fee: None,
+            min_campaign_duration_seconds: 0,
+            max_campaign_duration_seconds: 0,
+            dust_policy: DustPolicy::Creator,
+        }),
+        &None,
+        &None,
+    );
+
+    let contributor = Address::generate(env);
+    mint_to(env, token_address, admin, &contributor, 200_000);
+    client.contribute(&contributor, &200_000, &None, &None);
+
+    (platform, deadline)
+}
+
+#[test]
+fn test_fee_override_applies_on_next_withdraw() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let (platform, deadline) = setup_fee_override(&env, &client, &creator, &token_address, &admin);
+
+    client.override_platform_fee(&100);
+    let config = client.platform_config().unwrap();
+    assert_eq!(config.fee_bps, 100);
+    assert_eq!(config.address, platform);
+
+    env.ledger().set_timestamp(deadline + 1);
+    client.withdraw();
+
+    // 1% of 200_000 instead of the original 5%.
+    let token_client = token::Client::new(&env, &token_address);
+    assert_eq!(token_client.balance(&platform), 2_000);
+}
+
+#[test]
+#[should_panic]
+fn test_fee_override_rejected_from_non_platform() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    setup_fee_override(&env, &client, &creator, &token_address, &admin);
+
+    env.set_auths(&[]);
+    client.mock_auths(&[soroban_sdk::testutils::MockAuth {
+        address: &creator,
+        invoke: &soroban_sdk::testutils::MockAuthInvoke {
+            contract: &client.address,
+            fn_name: "override_platform_fee",
+            args: (100u32,).into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+
+    client.override_platform_fee(&100);
+}
+
+// ── Endorsement Tests ───────────────────────────────────────────────────────
+
+fn setup_endorser(
+    env: &Env,
+    client: &CrowdfundContractClient,
+    creator: &Address,
+    token_address: &Address,
+) -> Address {
+    init_with_options(
+        env,
+        client,
+        creator,
+        token_address,
+        CampaignOptions::default(),
+    );
+
+    let endorser = Address::generate(env);
+    client.set_verified_endorser(&endorser, &true);
+    endorser
+}
+
+#[test]
+fn test_endorse_campaign() {
+    let (env, client, creator, token_address, _admin) = setup_env();
+    let endorser = setup_endorser(&env, &client, &creator, &token_address);
+
+    client.endorse_campaign(&endorser);
+
+    assert_eq!(client.endorsement_count(), 1);
+    assert_eq!(client.endorsements().get(0).unwrap(), endorser);
+}
+
+#[test]
+#[should_panic(expected = "already endorsed")]
+fn test_endorse_campaign_twice_panics() {
+    let (env, client, creator, token_address, _admin) = setup_env();
+    let endorser = setup_endorser(&env, &client, &creator, &token_address);
+
+    client.endorse_campaign(&endorser);
+    client.endorse_campaign(&endorser);
+}
+
+#[test]
+fn test_retract_endorsement() {
+    let (env, client, creator, token_address, _admin) = setup_env();
+    let endorser = setup_endorser(&env, &client, &creator, &token_address);
+    let other = Address::generate(&env);
+    client.set_verified_endorser(&other, &true);
+
+    client.endorse_campaign(&endorser);
+    client.endorse_campaign(&other);
+    client.retract_endorsement(&endorser);
+
+    assert_eq!(client.endorsement_count(), 1);
+    assert!(!client.endorsements().contains(&endorser));
+
+    // A retracted endorser may endorse again.
+    client.endorse_campaign(&endorser);
+    assert_eq!(client.endorsement_count(), 2);
+}
+
+#[test]
+#[should_panic(expected = "not a verified endorser")]
+fn test_endorse_by_unverified_address_panics() {
+    let (env, client, creator, token_address, _admin) = setup_env();
+    setup_endorser(&env, &client, &creator, &token_address);
+
+    client.endorse_campaign(&Address::generate(&env));
+}
+
+// ── Batch Refund Failure Isolation Tests ────────────────────────────────────
+
+mod freezable {
+    use soroban_sdk::{contract, contractimpl, Address, Env, String};
+
+    /// Token whose admin can freeze an account so it cannot receive transfers.
+    #[contract]
+    pub struct FreezableToken;
+
+    #[contractimpl]
+    impl FreezableToken {
+        pub fn mint(env: Env, to: Address, amount: i128) {
+            let balance = Self::balance(env.clone(), to.clone());
+            env.storage().instance().set(&to, &(balance + amount));
+        }
+
+        pub fn set_frozen(env: Env, id: Address, frozen: bool) {
+            env.storage().instance().set(&(id, true), &frozen);
+        }
+
+        pub fn decimals(_env: Env) -> u32 {
+            7
+        }
+
+        pub fn symbol(env: Env) -> String {
+            String::from_str(&env, "MOCK")
+        }
+
+        pub fn balance(env: Env, id: Address) -> i128 {
+            env.storage().instance().get(&id).unwrap_or(0)
+        }
+
+        pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+            from.require_auth();
+            let frozen: bool = env
+                .storage()
+                .instance()
+                .get(&(to.clone(), true))
+                .unwrap_or(false);
+            if frozen {
+                panic!("account is frozen");
+            }
+            let from_balance = Self::balance(env.clone(), from.clone());
+            env.storage()
+                .instance()
+                .set(&from, &(from_balance - amount));
+            let to_balance = Self::balance(env.clone(), to.clone());
+            env.storage().instance().set(&to, &(to_balance + amount));
+        }
+    }
+}
+
+use freezable::{FreezableToken, FreezableTokenClient};
+
+#[test]
+fn test_refund_isolates_failed_transfer() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(CrowdfundContract, (Address::generate(&env),));
+    let client = CrowdfundContractClient::new(&env, &contract_id);
+    let token_id = env.register(FreezableToken, ());
+    let token = FreezableTokenClient::new(&env, &token_id);
+
+    let creator = Address::generate(&env);
+    let deadline = env.ledger().timestamp() + 3600;
+    client.initialize(
+        &creator,
+        &token_id,
+        &1_000_000,
+        &2_000_000,
+        &Deadline::Timestamp(deadline),
+        &1_000,
+        &None,
+        &None,
+        &None,
+    );
+
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let carol = Address::generate(&env);
+    for contributor in [&alice, &bob, &carol] {
+        token.mint(contributor, &10_000);
+        client.contribute(contributor, &10_000, &None, &None);
+    }
+
+    token.set_frozen(&bob, &true);
+    env.ledger().set_timestamp(deadline + 1);
+    client.refund();
+
+    // Everyone else is made whole; Bob's claim stays recorded.
+    assert_eq!(client.status(), Status::Refunded);
+    assert_eq!(token.balance(&alice), 10_000);
+    assert_eq!(token.balance(&carol), 10_000);
+    assert_eq!(token.balance(&bob), 0);
+    assert_eq!(client.contribution(&bob), 10_000);
+    assert_eq!(client.total_raised(), 10_000);
+    assert_eq!(client.failed_refunds().get(0).unwrap(), bob);
+
+    token.set_frozen(&bob, &false);
+    client.claim_refund(&bob);
+
+    assert_eq!(token.balance(&bob), 10_000);
+    assert_eq!(client.contribution(&bob), 0);
+    assert_eq!(client.total_raised(), 0);
+    assert!(client.failed_refunds().is_empty());
+    assert_eq!(token.balance(&contract_id), 0);
+
+    let result = client.try_claim_refund(&bob);
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        crate::ContractError::NothingToClaim
+    );
+}
+
+// ── Contribution Rate Limit Tests ───────────────────────────────────────────
+
+/// Campaign limiting each contributor to 10_000 per 60-second window.
+fn setup_rate_limit(
+    env: &Env,
+    client: &CrowdfundContractClient,
+    creator: &Address,
+    token_address: &Address,
+    admin: &Address,
+) -> Address {
+    init_with_options(
+        env,
+        client,
+        creator,
+        token_address,
+        CampaignOptions {
+            rate_limit_amount: 10_000,
+            rate_limit_window_seconds: 60,
+            ..Default::default()
+        },
+    );
+
+    let contributor = Address::generate(env);
+    mint_to(env, token_address, admin, &contributor, 100_000);
+    contributor
+}
+
+#[test]
+fn test_rate_limit_within_limit() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let contributor = setup_rate_limit(&env, &client, &creator, &token_address, &admin);
+
+    client.contribute(&contributor, &4_000, &None, &None);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 5);
+    client.contribute(&contributor, &4_000, &None, &None);
+
+    assert_eq!(client.contribution(&contributor), 8_000);
+}
+
+#[test]
+fn test_rate_limit_at_limit() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let contributor = setup_rate_limit(&env, &client, &creator, &token_address, &admin);
+
+    client.contribute(&contributor, &6_000, &None, &None);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 5);
+    client.contribute(&contributor, &4_000, &None, &None);
+
+    assert_eq!(client.contribution(&contributor), 10_000);
+}
+
+#[test]
+#[should_panic(expected = "rate limit exceeded")]
+fn test_rate_limit_over_limit_panics() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let contributor = setup_rate_limit(&env, &client, &creator, &token_address, &admin);
+
+    client.contribute(&contributor, &6_000, &None, &None);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 5);
+    client.contribute(&contributor, &5_000, &None, &None);
+}
+
+#[test]
+fn test_rate_limit_window_resets() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let contributor = setup_rate_limit(&env, &client, &creator, &token_address, &admin);
+
+    client.contribute(&contributor, &10_000, &None, &None);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 61);
+    client.contribute(&contributor, &10_000, &None, &None);
+
+    assert_eq!(client.contribution(&contributor), 20_000);
+}
+
+// ── Unclaimed Refund Sweep Tests ────────────────────────────────────────────
+
+const CLAIM_PERIOD: u64 = 180 * 24 * 60 * 60;
+
+/// Refunds a failed campaign whose token has Bob, Carol and Dave frozen, then
+/// unfreezes Bob to claim. Returns the refund timestamp.
+fn setup_unclaimed_refunds(
+    env: &Env,
+) -> (
+    CrowdfundContractClient<'static>,
+    FreezableTokenClient<'static>,
+    [Address; 4],
+    u64,
+) {
+    let contract_id = env.register(CrowdfundContract, (Address::generate(env),));
+    let client = CrowdfundContractClient::new(env, &contract_id);
+    let token_id = env.register(FreezableToken, ());
+    let token = FreezableTokenClient::new(env, &token_id);
+
+    let creator = Address::generate(env);
+    let deadline = init_with_options(
+        env,
+        &client,
+        &creator,
+        &token_id,
+        CampaignOptions {
+            refund_claim_period: CLAIM_PERIOD,
+            ..Default::default()
+        },
+    );
+
+    let contributors = [
+        Address::generate(env),
+        Address::generate(env),
+        Address::generate(env),
+        Address::generate(env),
+    ];
+    for (i, contributor) in contributors.iter().enumerate() {
+        let amount = 10_000 * (i as i128 + 1);
+        token.mint(contributor, &amount);
+        client.contribute(contributor, &amount, &None, &None);
+        if i > 0 {
+            token.set_frozen(contributor, &true);
+        }
+    }
+
+    env.ledger().set_timestamp(deadline + 1);
+    client.refund();
+
+    token.set_frozen(&contributors[1], &false);
+    client.claim_refund(&contributors[1]);
+
+    (client, token, contributors, deadline + 1)
+}
+
+#[test]
+fn test_sweep_unclaimed_before_period_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _token, _contributors, refunded_at) = setup_unclaimed_refunds(&env);
+
+    env.ledger().set_timestamp(refunded_at + CLAIM_PERIOD);
+    let result = client.try_sweep_unclaimed(&Address::generate(&env));
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        crate::ContractError::ClaimPeriodActive
+    );
+}
+
+#[test]
+fn test_sweep_unclaimed_after_period() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, token, contributors, refunded_at) = setup_unclaimed_refunds(&env);
+    let [alice, bob, carol, dave] = contributors;
+
+    env.ledger().set_timestamp(refunded_at + CLAIM_PERIOD + 1);
+    let destination = Address::generate(&env);
+    let swept = client.sweep_unclaimed(&destination);
+
+    // Carol's 30_000 and Dave's 40_000 were never claimed.
+    assert_eq!(swept, 70_000);
+    assert_eq!(token.balance(&destination), 70_000);
+    assert_eq!(token.balance(&client.address), 0);
+    assert_eq!(client.total_raised(), 0);
+    assert!(client.failed_refunds().is_empty());
+
+    // Contributors refunded before the sweep keep their tokens.
+    assert_eq!(token.balance(&alice), 10_000);
+    assert_eq!(token.balance(&bob), 20_000);
+
+    token.set_frozen(&carol, &false);
+    let result = client.try_claim_refund(&carol);
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        crate::ContractError::RefundExpired
+    );
+    assert_eq!(client.contribution(&dave), 0);
+}
+
+// ── Fee Dust Policy Tests ───────────────────────────────────────────────────
+
+const DUST_POLICIES: [DustPolicy; 3] = [
+    DustPolicy::Creator,
+    DustPolicy::Platform,
+    DustPolicy::CarryForward,
+];
+
+#[test]
+fn test_split_fee_boundaries() {
+    // amount = 1
+    assert_eq!(
+        crate::split_fee(1, 5_000, DustPolicy::Creator, 0).unwrap(),
+        (0, 0)
+    );
+    assert_eq!(
+        crate::split_fee(1, 5_000, DustPolicy::Platform, 0).unwrap(),
+        (1, 0)
+    );
+    assert_eq!(
+        crate::split_fee(1, 5_000, DustPolicy::CarryForward, 0).unwrap(),
+        (0, 5_000)
+    );
+
+    // fee = 1 bps
+    assert_eq!(
+        crate::split_fee(9_999, 1, DustPolicy::Creator, 0).unwrap(),
+        (0, 0)
+    );
+    assert_eq!(
+        crate::split_fee(9_999, 1, DustPolicy::Platform, 0).unwrap(),
+        (1, 0)
+    );
+    assert_eq!(
+        crate::split_fee(10_000, 1, DustPolicy::Platform, 0).unwrap(),
+        (1, 0)
+    );
+
+    // fee = 9_999 bps
+    assert_eq!(
+        crate::split_fee(10_001, 9_999, DustPolicy::Creator, 0).unwrap(),
+        (9_999, 0)
+    );
+    assert_eq!(
+        crate::split_fee(10_001, 9_999, DustPolicy::Platform, 0).unwrap(),
+        (10_000, 0)
+    );
+
+    // The truncated example from the fee docs: 1_000_003 * 333 / 10_000.
+    assert_eq!(
+        crate::split_fee(1_000_003, 333, DustPolicy::Creator, 0).unwrap(),
+        (33_300, 0)
+    );
+    assert_eq!(
+        crate::split_fee(1_000_003, 333, DustPolicy::CarryForward, 0).unwrap(),
+        (33_300, 999)
+    );
+}
+
+#[test]
+fn test_split_fee_carry_forward_pays_out_accumulated_dust() {
+    let (fee, carry) = crate::split_fee(1, 5_000, DustPolicy::CarryForward, 0).unwrap();
+    assert_eq!((fee, carry), (0, 5_000));
+    let (fee, carry) = crate::split_fee(1, 5_000, DustPolicy::CarryForward, carry).unwrap();
+    assert_eq!((fee, carry), (1, 0));
+}
+
+proptest! {
+    #[test]
+    fn prop_split_fee_conserves_total(
+        amount in 1i128..1_000_000_000_000i128,
+        fee_bps in 0u32..=10_000u32,
+        carried in 0i128..10_000i128,
+    ) {
+        for policy in DUST_POLICIES {
+            let (fee, carry) = crate::split_fee(amount, fee_bps, policy, carried).unwrap();
+            let creator_payout = amount - fee;
+            prop_assert_eq!(creator_payout + fee, amount);
+            prop_assert!(fee >= 0 && creator_payout >= 0);
+            prop_assert!((0..10_000).contains(&carry));
+        }
+    }
+}
+
+#[test]
+fn test_platform_dust_policy_rounds_fee_up_at_withdraw() {
+    let (env, client, creator, token_address, admin) = setup_env();
+
+    let platform = Address::generate(&env);
+    let deadline = env.ledger().timestamp() + 3600;
+    client.initialize(
+        &creator,
+        &token_address,
+        &1_000_000,
+        &2_000_000,
+        &Deadline::Timestamp(deadline),
+        &1_000,
+        &Some(PlatformConfig {
+            address: platform.clone(),
+            fee_bps: 333,
+            fee_timing: FeeTiming::OnWithdraw,
+            tiered_fee: None,
+            min_campaign_duration_seconds: 0,
+            max_campaign_duration_seconds: 0,
+            dust_policy: DustPolicy::Platform,
+        }),
+        &None,
+        &None,
+    );
+
+    let contributor = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &contributor, 1_000_003);
+    client.contribute(&contributor, &1_000_003, &None, &None);
+
+    let token_client = token::Client::new(&env, &token_address);
+    let creator_before = token_client.balance(&creator);
+    env.ledger().set_timestamp(deadline + 1);
+    client.withdraw();
+
+    let fee = token_client.balance(&platform);
+    assert_eq!(fee, 33_301);
+    assert_eq!(
+        token_client.balance(&creator) - creator_before + fee,
+        1_000_003
+    );
+}
+
+// ── Campaign Terms Tests ────────────────────────────────────────────────────
+
+fn setup_terms(
+    env: &Env,
+    client: &CrowdfundContractClient,
+    creator: &Address,
+    token_address: &Address,
+    admin: &Address,
+) -> Address {
+    init_with_options(
+        env,
+        client,
+        creator,
+        token_address,
+        CampaignOptions::default(),
+    );
+
+    let contributor = Address::generate(env);
+    mint_to(env, token_address, admin, &contributor, 100_000);
+    contributor
+}
+
+#[test]
+fn test_accept_terms_then_contribute() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let contributor = setup_terms(&env, &client, &creator, &token_address, &admin);
+
+    let hash = soroban_sdk::String::from_str(&env, "QmTermsHash");
+    client.set_terms_hash(&hash);
+    assert_eq!(client.terms_hash(), Some(hash));
+
+    assert!(!client.has_accepted_terms(&contributor));
+    client.accept_terms(&contributor);
+    assert!(client.has_accepted_terms(&contributor));
+
+    client.contribute(&contributor, &10_000, &None, &None);
+    assert_eq!(client.contribution(&contributor), 10_000);
+}
+
+#[test]
+#[should_panic(expected = "terms not accepted")]
+fn test_contribute_without_accepting_terms_panics() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let contributor = setup_terms(&env, &client, &creator, &token_address, &admin);
+
+    client.set_terms_hash(&soroban_sdk::String::from_str(&env, "QmTermsHash"));
+    client.contribute(&contributor, &10_000, &None, &None);
+}
+
+#[test]
+fn test_contribute_without_terms_hash() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let contributor = setup_terms(&env, &client, &creator, &token_address, &admin);
+
+    assert_eq!(client.terms_hash(), None);
+    client.contribute(&contributor, &10_000, &None, &None);
+    assert_eq!(client.contribution(&contributor), 10_000);
+}
+
+// ── Amount Validation and Overflow Tests ────────────────────────────────────
+
+#[test]
+fn test_contribute_rejects_non_positive_amounts() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let deadline = env.ledger().timestamp() + 3600;
+    client.initialize(
+        &creator,
+        &token_address,
+        &1_000_000,
+        &2_000_000,
+        &Deadline::Timestamp(deadline),
+        &1_000,
+        &None,
+        &None,
+        &None,
+    );
+
+    let contributor = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &contributor, 10_000);
+
+    for amount in [0, -1, i128::MIN] {
+        let result = client.try_contribute(&contributor, &amount, &None, &None);
+        assert_eq!(
+            result.unwrap_err().unwrap(),
+            crate::ContractError::InvalidAmount
         );
+        let result = client.try_pledge(&contributor, &amount);
+        assert_eq!(
+            result.unwrap_err().unwrap(),
+            crate::ContractError::InvalidAmount
+        );
+    }
+}
+
+#[test]
+fn test_contribute_near_i128_max_reports_overflow() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let deadline = env.ledger().timestamp() + 3600;
+    client.initialize(
+        &creator,
+        &token_address,
+        &1_000_000,
+        &i128::MAX,
+        &Deadline::Timestamp(deadline),
+        &1_000,
+        &None,
+        &None,
+        &Some(CampaignOptions {
+            referral_fee_bps: 500,
+            ..Default::default()
+        }),
+    );
+
+    let contributor = Address::generate(&env);
+    let referrer = Address::generate(&env);
+    let amount = i128::MAX / 2;
+    mint_to(&env, &token_address, &admin, &contributor, amount);
+
+    // The referral fee on this amount overflows `amount * bps`.
+    let result = client.try_contribute(&contributor, &amount, &Some(referrer), &None);
+    assert_eq!(result.unwrap_err().unwrap(), crate::ContractError::Overflow);
+    assert_eq!(client.total_raised(), 0);
+}
+
+#[test]
+fn test_withdraw_near_i128_max_reports_overflow() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let deadline = env.ledger().timestamp() + 3600;
+    client.initialize(
+        &creator,
+        &token_address,
+        &1_000_000,
+        &i128::MAX,
+        &Deadline::Timestamp(deadline),
+        &1_000,
+        &Some(PlatformConfig {
+            address: Address::generate(&env),
+            fee_bps: 500,
+            fee_timing: FeeTiming::OnWithdraw,
+            tiered_fee: None,
+            min_campaign_duration_seconds: 0,
+            max_campaign_duration_seconds: 0,
+            dust_policy: DustPolicy::Creator,
+        }),
+        &None,
+        &None,
+    );
+
+    // Each contribution fits the weighted math, but the total is too large
+    // for `total * fee_bps`.
+    let amount = i128::MAX / 10_001;
+    for _ in 0..25 {
+        let contributor = Address::generate(&env);
+        mint_to(&env, &token_address, &admin, &contributor, amount);
+        client.contribute(&contributor, &amount, &None, &None);
+    }
+
+    env.ledger().set_timestamp(deadline + 1);
+    let result = client.try_withdraw();
+    assert_eq!(result.unwrap_err().unwrap(), crate::ContractError::Overflow);
+    assert_eq!(client.status(), Status::Active);
+}
+
+// ── Risk Acknowledgment Tests ───────────────────────────────────────────────
+
+#[test]
+#[should_panic(expected = "risk not acknowledged")]
+fn test_contribute_before_acknowledging_risk_panics() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let contributor = setup_terms(&env, &client, &creator, &token_address, &admin);
+
+    client.set_requires_acknowledgment(&true);
+    client.contribute(&contributor, &10_000, &None, &None);
+}
+
+#[test]
+fn test_acknowledge_risk_then_contribute() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let contributor = setup_terms(&env, &client, &creator, &token_address, &admin);
+
+    client.set_requires_acknowledgment(&true);
+    assert!(client.requires_acknowledgment());
+    assert!(!client.has_acknowledged(&contributor));
+
+    client.acknowledge_risk(&contributor);
+    assert!(client.has_acknowledged(&contributor));
+
+    client.contribute(&contributor, &10_000, &None, &None);
+    assert_eq!(client.contribution(&contributor), 10_000);
+}
+
+#[test]
+fn test_risk_acknowledgment_persists_across_contributions() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let contributor = setup_terms(&env, &client, &creator, &token_address, &admin);
+
+    client.set_requires_acknowledgment(&true);
+    client.acknowledge_risk(&contributor);
+
+    client.contribute(&contributor, &10_000, &None, &None);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 5);
+    client.contribute(&contributor, &10_000, &None, &None);
+
+    assert!(client.has_acknowledged(&contributor));
+    assert_eq!(client.contribution(&contributor), 20_000);
+}
+
+// ── Single Contribution Tests ──────────────────────────────────────────────
+
+fn setup_single_contribution_campaign() -> (Env, CrowdfundContractClient<'static>) {
+    let (env, client, creator, token_address, _admin) = setup_env();
+    init_with_options(
+        &env,
+        &client,
+        &creator,
+        &token_address,
+        CampaignOptions {
+            single_contribution: true,
+            ..Default::default()
+        },
+    );
+    (env, client)
+}
+
+#[test]
+fn test_single_contribution_rejects_top_up() {
+    let (env, client) = setup_single_contribution_campaign();
+    let contributor = funded_contributor(&env, &client, 200_000);
+    client.contribute(&contributor, &100_000, &None, &None);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 5);
+
+    assert_eq!(
+        client.try_contribute(&contributor, &100_000, &None, &None),
+        Err(Ok(crate::ContractError::AlreadyContributed))
+    );
+    assert_eq!(client.contribution(&contributor), 100_000);
+}
+
+#[test]
+fn test_single_contribution_allows_recontribution_after_withdrawal() {
+    let (env, client) = setup_single_contribution_campaign();
+    let contributor = funded_contributor(&env, &client, 200_000);
+    client.contribute(&contributor, &100_000, &None, &None);
+
+    // A partial withdrawal keeps the slot taken...
+    client.partial_refund(&contributor, &50_000);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 5);
+    assert_eq!(
+        client.try_contribute(&contributor, &10_000, &None, &None),
+        Err(Ok(crate::ContractError::AlreadyContributed))
+    );
+
+    // ...withdrawing the rest frees it.
+    client.partial_refund(&contributor, &50_000);
+    client.contribute(&contributor, &150_000, &None, &None);
+    assert_eq!(client.contribution(&contributor), 150_000);
+}
+
+#[test]
+fn test_top_ups_allowed_without_single_contribution() {
+    let (env, client, _creator) = setup_links_campaign();
+    let contributor = funded_contributor(&env, &client, 200_000);
+    client.contribute(&contributor, &100_000, &None, &None);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 5);
+
+    client.contribute(&contributor, &100_000, &None, &None);
+
+    assert_eq!(client.contribution(&contributor), 200_000);
+}
+
+// ── Minimum Contributors Tests ─────────────────────────────────────────────
+
+fn setup_min_contributors_campaign(
+    min_contributors: u32,
+) -> (Env, CrowdfundContractClient<'static>, u64) {
+    let (env, client, creator, token_address, _admin) = setup_env();
+    let deadline = init_with_options(
+        &env,
+        &client,
+        &creator,
+        &token_address,
+        CampaignOptions {
+            min_contributors,
+            ..Default::default()
+        },
+    );
+    (env, client, deadline)
+}
+
+#[test]
+fn test_whale_funded_campaign_short_of_min_contributors_refunds() {
+    let (env, client, deadline) = setup_min_contributors_campaign(10);
+    let whale = back_campaign(&env, &client, 1_500_000);
+    env.ledger().set_timestamp(deadline + 1);
+
+    assert_eq!(
+        client.try_withdraw(),
+        Err(Ok(crate::ContractError::NotEnoughContributors))
+    );
+    assert_eq!(client.finalize(), Status::Refunded);
+    let token = token::Client::new(&env, &client.token());
+    assert_eq!(token.balance(&whale), 1_500_000);
+}
+
+#[test]
+fn test_min_contributors_met_by_small_backers_succeeds() {
+    let (env, client, deadline) = setup_min_contributors_campaign(10);
+    for _ in 0..10 {
+        back_campaign(&env, &client, 100_000);
+    }
+    env.ledger().set_timestamp(deadline + 1);
+
+    client.withdraw();
+
+    assert_eq!(client.status(), Status::Successful);
+}
+
+#[test]
+fn test_min_contributors_boundary() {
+    let (env, client, deadline) = setup_min_contributors_campaign(3);
+    back_campaign(&env, &client, 500_000);
+    back_campaign(&env, &client, 500_000);
+    assert_eq!(client.contributor_count(), 2);
+    assert_eq!(
+        client.try_early_withdraw(),
+        Err(Ok(crate::ContractError::NotEnoughContributors))
+    );
+
+    back_campaign(&env, &client, 1_000);
+    assert_eq!(client.contributor_count(), 3);
+    env.ledger().set_timestamp(deadline + 1);
+
+    assert_eq!(client.finalize(), Status::Successful);
+}
+
+// ── Grace Period Tests ─────────────────────────────────────────────────────
+
+fn setup_grace_period_campaign() -> (Env, CrowdfundContractClient<'static>, u64) {
+    let (env, client, creator, token_address, _admin) = setup_env();
+    let deadline = init_with_options(
+        &env,
+        &client,
+        &creator,
+        &token_address,
+        CampaignOptions {
+            grace_period_secs: DAY,
+            ..Default::default()
+        },
+    );
+    (env, client, deadline)
+}
+
+#[test]
+fn test_refund_waits_for_grace_period() {
+    let (env, client, deadline) = setup_grace_period_campaign();
+    let contributor = back_campaign(&env, &client, 400_000);
+
+    env.ledger().set_timestamp(deadline + DAY);
+    assert_eq!(
+        client.try_refund(),
+        Err(Ok(crate::ContractError::GracePeriodActive))
+    );
+    assert_eq!(client.status(), Status::Active);
+
+    env.ledger().set_timestamp(deadline + DAY + 1);
+    client.refund();
+
+    assert_eq!(client.status(), Status::Refunded);
+    let token = token::Client::new(&env, &client.token());
+    assert_eq!(token.balance(&contributor), 400_000);
+}
+
+#[test]
+fn test_withdraw_open_during_grace_period() {
+    let (env, client, deadline) = setup_grace_period_campaign();
+    back_campaign(&env, &client, 1_000_000);
+
+    env.ledger().set_timestamp(deadline + 1);
+    assert_eq!(
+        client.try_refund(),
+        Err(Ok(crate::ContractError::GracePeriodActive))
+    );
+    client.withdraw();
+
+    assert_eq!(client.status(), Status::Successful);
+}
+
+#[test]
+fn test_successful_campaign_refund_after_grace_period_rejected() {
+    let (env, client, deadline) = setup_grace_period_campaign();
+    back_campaign(&env, &client, 1_000_000);
+
+    env.ledger().set_timestamp(deadline + DAY + 1);
+
+    assert_eq!(
+        client.try_refund(),
+        Err(Ok(crate::ContractError::GoalReached))
+    );
+    client.withdraw();
+    assert_eq!(client.status(), Status::Successful);
+}
+
+// ── Blacklist Tests ─────────────────────────────────────────────────────────
+
+#[test]
+fn test_blacklisted_contributor_cannot_contribute() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let contributor = setup_terms(&env, &client, &creator, &token_address, &admin);
+
+    client.blacklist_contributor(&contributor);
+    assert!(client.is_blacklisted(&contributor));
+    assert_eq!(
+        client.try_contribute(&contributor, &10_000, &None, &None),
+        Err(Ok(crate::ContractError::AddressBlocked))
+    );
+}
+
+#[test]
+fn test_blacklisted_address_keeps_refund_on_failure() {
+    let (env, client, _creator) = setup_links_campaign();
+    let contributor = back_campaign(&env, &client, 100_000);
+    client.blacklist_contributor(&contributor);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 3601);
+    client.refund();
+
+    assert_eq!(client.status(), Status::Refunded);
+    let token = token::Client::new(&env, &client.token());
+    assert_eq!(token.balance(&contributor), 100_000);
+}
+
+#[test]
+fn test_blacklist_keeps_existing_contribution() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let contributor = setup_terms(&env, &client, &creator, &token_address, &admin);
+    client.contribute(&contributor, &10_000, &None, &None);
+
+    client.blacklist_contributor(&contributor);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 5);
+    assert!(client
+        .try_contribute(&contributor, &10_000, &None, &None)
+        .is_err());
+
+    assert_eq!(client.contribution(&contributor), 10_000);
+    assert_eq!(client.total_raised(), 10_000);
+}
+
+#[test]
+fn test_removed_from_blacklist_can_contribute_again() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let contributor = setup_terms(&env, &client, &creator, &token_address, &admin);
+
+    client.blacklist_contributor(&contributor);
+    client.remove_from_blacklist(&contributor);
+    assert!(!client.is_blacklisted(&contributor));
+
+    client.contribute(&contributor, &10_000, &None, &None);
+    assert_eq!(client.contribution(&contributor), 10_000);
+}
+
+#[test]
+fn test_blacklist_rejected_from_non_creator() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let contributor = setup_terms(&env, &client, &creator, &token_address, &admin);
+    let outsider = Address::generate(&env);
+
+    env.set_auths(&[]);
+    let result = client
+        .mock_auths(&[soroban_sdk::testutils::MockAuth {
+            address: &outsider,
+            invoke: &soroban_sdk::testutils::MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "blacklist_contributor",
+                args: (contributor.clone(),).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_blacklist_contributor(&contributor);
+
+    assert!(result.is_err());
+    assert!(!client.is_blacklisted(&contributor));
+}
+
+// ── Governance Proposal Tests ───────────────────────────────────────────────
+
+/// Campaign with a 50% quorum where Alice holds 60% and Bob 40% of the raise.
+fn setup_governance(
+    env: &Env,
+    client: &CrowdfundContractClient,
+    creator: &Address,
+    token_address: &Address,
+    admin: &Address,
+) -> (Address, Address) {
+    init_with_options(
+        env,
+        client,
+        creator,
+        token_address,
+        CampaignOptions {
+            quorum_bps: 5_000,
+            ..Default::default()
+        },
+    );
+
+    let alice = Address::generate(env);
+    let bob = Address::generate(env);
+    mint_to(env, token_address, admin, &alice, 60_000);
+    mint_to(env, token_address, admin, &bob, 40_000);
+    client.contribute(&alice, &60_000, &None, &None);
+    client.contribute(&bob, &40_000, &None, &None);
+
+    (alice, bob)
+}
+
+fn deadline_proposal(env: &Env, deadline: u64) -> (soroban_sdk::String, soroban_sdk::Bytes) {
+    (
+        soroban_sdk::String::from_str(env, "deadline"),
+        soroban_sdk::Bytes::from_array(env, &deadline.to_be_bytes()),
+    )
+}
+
+#[test]
+fn test_proposal_quorum_math() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let (alice, bob) = setup_governance(&env, &client, &creator, &token_address, &admin);
+
+    let (parameter, value) = deadline_proposal(&env, 10_000);
+    let id = client.create_proposal(&bob, &parameter, &value);
+    assert_eq!(id, 0);
+
+    // 40% yes is under the 50% quorum.
+    client.vote_proposal(&bob, &id, &true);
+    let proposal = client.proposal(&id).unwrap();
+    assert_eq!(proposal.yes_weight, 40_000);
+    assert!(!proposal.passed);
+
+    // A "no" vote does not count toward the quorum.
+    client.vote_proposal(&alice, &id, &false);
+    let proposal = client.proposal(&id).unwrap();
+    assert_eq!(proposal.no_weight, 60_000);
+    assert!(!proposal.passed);
+
+    // Alice's 60% alone reaches it on a second proposal.
+    let id = client.create_proposal(&alice, &parameter, &value);
+    client.vote_proposal(&alice, &id, &true);
+    assert!(client.proposal(&id).unwrap().passed);
+}
+
+#[test]
+#[should_panic(expected = "already voted")]
+fn test_proposal_double_vote_panics() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let (alice, _bob) = setup_governance(&env, &client, &creator, &token_address, &admin);
+
+    let (parameter, value) = deadline_proposal(&env, 10_000);
+    let id = client.create_proposal(&alice, &parameter, &value);
+    client.vote_proposal(&alice, &id, &false);
+    client.vote_proposal(&alice, &id, &true);
+}
+
+#[test]
+fn test_apply_passed_proposal() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let (alice, _bob) = setup_governance(&env, &client, &creator, &token_address, &admin);
+
+    let (parameter, value) = deadline_proposal(&env, 10_000);
+    let id = client.create_proposal(&alice, &parameter, &value);
+    client.vote_proposal(&alice, &id, &true);
+
+    // Passing alone does not change anything.
+    assert_ne!(client.deadline(), 10_000);
+
+    client.apply_proposal(&id);
+    assert_eq!(client.deadline(), 10_000);
+    assert!(client.proposal(&id).unwrap().applied);
+
+    let goal = soroban_sdk::Bytes::from_array(&env, &500_000i128.to_be_bytes());
+    let id = client.create_proposal(&alice, &soroban_sdk::String::from_str(&env, "goal"), &goal);
+    client.vote_proposal(&alice, &id, &true);
+    client.apply_proposal(&id);
+    assert_eq!(client.goal(), 500_000);
+}
+
+#[test]
+#[should_panic(expected = "proposal has not passed")]
+fn test_apply_unpassed_proposal_panics() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let (_alice, bob) = setup_governance(&env, &client, &creator, &token_address, &admin);
+
+    let (parameter, value) = deadline_proposal(&env, 10_000);
+    let id = client.create_proposal(&bob, &parameter, &value);
+    client.vote_proposal(&bob, &id, &true);
+    client.apply_proposal(&id);
+}
+
+#[test]
+fn test_transferred_credit_cannot_vote_twice() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let (_alice, bob) = setup_governance(&env, &client, &creator, &token_address, &admin);
+
+    let (parameter, value) = deadline_proposal(&env, 10_000);
+    let id = client.create_proposal(&bob, &parameter, &value);
+    client.vote_proposal(&bob, &id, &true);
+
+    let carol = Address::generate(&env);
+    client.transfer_contribution(&bob, &carol, &40_000);
+    assert_eq!(
+        client.try_vote_proposal(&carol, &id, &true),
+        Err(Ok(crate::ContractError::NotAContributor))
+    );
+
+    let proposal = client.proposal(&id).unwrap();
+    assert_eq!(proposal.yes_weight, 40_000);
+    assert!(!proposal.passed);
+}
+
+/// Creates a proposal setting `parameter` to `value` and passes it with
+/// `voter`'s majority. Returns the proposal id.
+fn pass_proposal(
+    env: &Env,
+    client: &CrowdfundContractClient,
+    voter: &Address,
+    parameter: &str,
+    value: soroban_sdk::Bytes,
+) -> u32 {
+    let parameter = soroban_sdk::String::from_str(env, parameter);
+    let id = client.create_proposal(voter, &parameter, &value);
+    client.vote_proposal(voter, &id, &true);
+    id
+}
+
+#[test]
+fn test_apply_proposal_rejects_invalid_values() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let (alice, _bob) = setup_governance(&env, &client, &creator, &token_address, &admin);
+    let amount = |value: i128| soroban_sdk::Bytes::from_array(&env, &value.to_be_bytes());
+
+    let id = pass_proposal(&env, &client, &alice, "goal", amount(0));
+    assert_eq!(
+        client.try_apply_proposal(&id),
+        Err(Ok(crate::ContractError::InvalidGoal))
+    );
+    let id = pass_proposal(&env, &client, &alice, "goal", amount(3_000_000));
+    assert_eq!(
+        client.try_apply_proposal(&id),
+        Err(Ok(crate::ContractError::InvalidHardCap))
+    );
+    let id = pass_proposal(&env, &client, &alice, "min_contribution", amount(1_000_001));
+    assert_eq!(
+        client.try_apply_proposal(&id),
+        Err(Ok(crate::ContractError::InvalidMinContribution))
+    );
+
+    env.ledger().set_timestamp(5_000);
+    let (parameter, value) = deadline_proposal(&env, 4_000);
+    let id = client.create_proposal(&alice, &parameter, &value);
+    client.vote_proposal(&alice, &id, &true);
+    assert_eq!(
+        client.try_apply_proposal(&id),
+        Err(Ok(crate::ContractError::InvalidDeadline))
+    );
+
+    assert_eq!(client.goal(), 1_000_000);
+    assert_eq!(client.min_contribution(), 1_000);
+}
+
+#[test]
+fn test_create_proposal_requires_contribution() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    setup_governance(&env, &client, &creator, &token_address, &admin);
+
+    let (parameter, value) = deadline_proposal(&env, 10_000);
+    let result = client.try_create_proposal(&Address::generate(&env), &parameter, &value);
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        crate::ContractError::NotAContributor
+    );
+}
+
+// ── Initialization Validation Tests ─────────────────────────────────────────
+
+/// Initializes a fresh campaign with the given parameters and returns the
+/// resulting error, if any.
+fn try_init(
+    goal: i128,
+    hard_cap: i128,
+    deadline_offset: i64,
+    min: i128,
+) -> Option<crate::ContractError> {
+    let (env, client, creator, token_address, _admin) = setup_env();
+    env.ledger().set_timestamp(10_000);
+    let deadline = (10_000 + deadline_offset) as u64;
+    client
+        .try_initialize(
+            &creator,
+            &token_address,
+            &goal,
+            &hard_cap,
+            &Deadline::Timestamp(deadline),
+            &min,
+            &None,
+            &None,
+            &None,
+        )
+        .err()
+        .map(|err| err.unwrap())
+}
+
+#[test]
+fn test_initialize_rejects_non_positive_goal() {
+    assert_eq!(
+        try_init(0, 1_000, 3600, 0),
+        Some(crate::ContractError::InvalidGoal)
+    );
+    assert_eq!(
+        try_init(-1, 1_000, 3600, 0),
+        Some(crate::ContractError::InvalidGoal)
+    );
+}
+
+#[test]
+fn test_initialize_rejects_bad_min_contribution() {
+    assert_eq!(
+        try_init(1_000, 2_000, 3600, -1),
+        Some(crate::ContractError::InvalidMinContribution)
+    );
+    assert_eq!(
+        try_init(1_000, 2_000, 3600, 1_001),
+        Some(crate::ContractError::InvalidMinContribution)
+    );
+    assert_eq!(try_init(1_000, 2_000, 3600, 1_000), None);
+    assert_eq!(try_init(1_000, 2_000, 3600, 0), None);
+}
+
+#[test]
+fn test_initialize_rejects_past_deadline() {
+    assert_eq!(
+        try_init(1_000, 2_000, -1, 10),
+        Some(crate::ContractError::InvalidDeadline)
+    );
+}
+
+#[test]
+fn test_initialize_deadline_boundary() {
+    assert_eq!(
+        try_init(1_000, 2_000, 0, 10),
+        Some(crate::ContractError::InvalidDeadline)
+    );
+    // A deadline just after now is still shorter than the minimum duration.
+    assert_eq!(
+        try_init(1_000, 2_000, 1, 10),
+        Some(crate::ContractError::InvalidDuration)
+    );
+}
+
+#[test]
+fn test_initialize_rejects_hard_cap_below_goal() {
+    assert_eq!(
+        try_init(1_000, 999, 3600, 10),
+        Some(crate::ContractError::InvalidHardCap)
+    );
+    assert_eq!(try_init(1_000, 1_000, 3600, 10), None);
+}
+
+// ── Recurring Contribution Tests ────────────────────────────────────────────
+
+/// Schedules 3 pulls of 10_000 an hour apart for a contributor holding
+/// `balance`.
+fn setup_recurring(
+    env: &Env,
+    client: &CrowdfundContractClient,
+    creator: &Address,
+    token_address: &Address,
+    admin: &Address,
+    balance: i128,
+) -> Address {
+    init_with_options(
+        env,
+        client,
+        creator,
+        token_address,
+        CampaignOptions::default(),
+    );
+
+    let contributor = Address::generate(env);
+    mint_to(env, token_address, admin, &contributor, balance);
+    client.schedule_recurring(&contributor, &10_000, &1_000, &3);
+    contributor
+}
+
+#[test]
+fn test_recurring_first_interval() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let contributor = setup_recurring(&env, &client, &creator, &token_address, &admin, 100_000);
+    assert_eq!(client.remaining_intervals(&contributor), 3);
+
+    // Nothing is due until a full interval has elapsed.
+    assert!(!client.process_recurring(&contributor));
+    env.ledger().set_timestamp(env.ledger().timestamp() + 999);
+    assert!(!client.process_recurring(&contributor));
+    assert_eq!(client.contribution(&contributor), 0);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1);
+    assert!(client.process_recurring(&contributor));
+    assert_eq!(client.contribution(&contributor), 10_000);
+    assert_eq!(client.total_raised(), 10_000);
+    assert_eq!(client.remaining_intervals(&contributor), 2);
+
+    // The same interval cannot be pulled twice.
+    assert!(!client.process_recurring(&contributor));
+}
+
+#[test]
+fn test_recurring_second_interval() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let contributor = setup_recurring(&env, &client, &creator, &token_address, &admin, 100_000);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1_000);
+    client.process_recurring(&contributor);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1_000);
+    assert!(client.process_recurring(&contributor));
+
+    assert_eq!(client.contribution(&contributor), 20_000);
+    assert_eq!(client.remaining_intervals(&contributor), 1);
+}
+
+#[test]
+fn test_recurring_schedule_exhausted() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let contributor = setup_recurring(&env, &client, &creator, &token_address, &admin, 100_000);
+
+    for _ in 0..3 {
+        env.ledger().set_timestamp(env.ledger().timestamp() + 1_000);
+        assert!(client.process_recurring(&contributor));
     }
+    assert_eq!(client.remaining_intervals(&contributor), 0);
+    assert_eq!(client.recurring_schedule(&contributor), None);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1_000);
+    assert!(!client.process_recurring(&contributor));
+    assert_eq!(client.contribution(&contributor), 30_000);
 }
 
-/// **Property Test 4: Deadline in the Past Always Fails on Initialize**
-///
-/// For any deadline in the past (relative to current ledger time),
-/// initialization must fail or panic.
-proptest! {
-    #[test]
-    fn prop_initialize_with_past_deadline_fails(
-        goal in 1_000_000i128..10_000_000i128,
-        past_offset in 1u64..10_000u64,
-    ) {
-        let (env, client, creator, token_address, _admin) = setup_env();
+#[test]
+fn test_recurring_cancelled_by_contributor() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let contributor = setup_recurring(&env, &client, &creator, &token_address, &admin, 100_000);
 
-        let current_time = env.ledger().timestamp();
-        // Set deadline in the past
-        let past_deadline = current_time.saturating_sub(past_offset);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1_000);
+    client.process_recurring(&contributor);
+    client.cancel_recurring(&contributor);
+    assert_eq!(client.remaining_intervals(&contributor), 0);
 
-        // Attempt to initialize with past deadline
-        let result = client.try_initialize(
-            &creator,
-            &token_address,
-            &goal,
-            &(goal * 2),
-            &past_deadline,
-            &1_000,
-            &None,
-        );
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1_000);
+    assert!(!client.process_recurring(&contributor));
+    assert_eq!(client.contribution(&contributor), 10_000);
+}
 
-        // **INVARIANT**: Past deadline should fail or be rejected
-        // Note: The contract may not explicitly validate this, but it's a logical invariant
-        // If the contract allows it, the campaign would already be expired
-        // This test documents the expected behavior
-        if result.is_ok() {
-            // If initialization succeeds with past deadline, verify campaign is immediately expired
-            let deadline = client.deadline();
-            prop_assert!(
-                deadline <= current_time,
-                "deadline {} should be in the past relative to current time {}",
-                deadline,
-                current_time
-            );
-        }
+#[test]
+fn test_recurring_cancelled_on_insufficient_balance() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let contributor = setup_recurring(&env, &client, &creator, &token_address, &admin, 15_000);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1_000);
+    assert!(client.process_recurring(&contributor));
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1_000);
+    assert!(!client.process_recurring(&contributor));
+
+    assert_eq!(client.remaining_intervals(&contributor), 0);
+    assert_eq!(client.contribution(&contributor), 10_000);
+}
+
+// ── Campaign Duration Tests ─────────────────────────────────────────────────
+
+const HOUR: i64 = 60 * 60;
+const YEAR: i64 = 365 * 24 * HOUR;
+
+#[test]
+fn test_duration_bounds_getters() {
+    let (_env, client, _creator, _token_address, _admin) = setup_env();
+    assert_eq!(client.min_duration(), HOUR as u64);
+    assert_eq!(client.max_duration(), YEAR as u64);
+}
+
+#[test]
+fn test_initialize_min_duration_is_inclusive() {
+    assert_eq!(
+        try_init(1_000, 2_000, HOUR - 1, 10),
+        Some(crate::ContractError::InvalidDuration)
+    );
+    assert_eq!(try_init(1_000, 2_000, HOUR, 10), None);
+}
+
+#[test]
+fn test_initialize_max_duration_is_inclusive() {
+    assert_eq!(try_init(1_000, 2_000, YEAR, 10), None);
+    assert_eq!(
+        try_init(1_000, 2_000, YEAR + 1, 10),
+        Some(crate::ContractError::InvalidDuration)
+    );
+}
+
+fn setup_duration_campaign() -> (Env, CrowdfundContractClient<'static>) {
+    let (env, client, creator, token_address, _admin) = setup_env();
+    init_with_options(
+        &env,
+        &client,
+        &creator,
+        &token_address,
+        CampaignOptions::default(),
+    );
+    (env, client)
+}
+
+#[test]
+fn test_update_deadline_up_to_max_duration() {
+    let (env, client) = setup_duration_campaign();
+
+    let max_deadline = env.ledger().timestamp() + YEAR as u64;
+    client.update_deadline(&max_deadline);
+    assert_eq!(client.deadline(), max_deadline);
+}
+
+#[test]
+#[should_panic(expected = "deadline exceeds maximum duration")]
+fn test_update_deadline_past_max_duration_panics() {
+    let (env, client) = setup_duration_campaign();
+
+    client.update_deadline(&(env.ledger().timestamp() + YEAR as u64 + 1));
+}
+
+/// Initializes a campaign `duration` seconds long under a platform that hosts
+/// campaigns of 2 to 30 days.
+fn init_with_platform_durations(duration: u64) -> CrowdfundContractClient<'static> {
+    let (env, client, creator, token_address, _admin) = setup_env();
+    client.initialize(
+        &creator,
+        &token_address,
+        &1_000_000,
+        &2_000_000,
+        &Deadline::Timestamp(env.ledger().timestamp() + duration),
+        &1_000,
+        &Some(PlatformConfig {
+            address: Address::generate(&env),
+            fee_bps: 0,
+            fee_timing: FeeTiming::OnWithdraw,
+            tiered_fee: None,
+            min_campaign_duration_seconds: 2 * DAY,
+            max_campaign_duration_seconds: 30 * DAY,
+            dust_policy: DustPolicy::Creator,
+        }),
+        &None,
+        &None,
+    );
+    client
+}
+
+#[test]
+fn test_platform_duration_exactly_at_minimum() {
+    let client = init_with_platform_durations(2 * DAY);
+    assert_eq!(client.deadline(), 2 * DAY);
+}
+
+#[test]
+#[should_panic(expected = "campaign duration too short")]
+fn test_platform_duration_below_minimum_panics() {
+    init_with_platform_durations(2 * DAY - 1);
+}
+
+#[test]
+#[should_panic(expected = "campaign duration too long")]
+fn test_platform_duration_above_maximum_panics() {
+    init_with_platform_durations(30 * DAY + 1);
+}
+
+#[test]
+fn test_platform_duration_between_bounds() {
+    let client = init_with_platform_durations(10 * DAY);
+    assert_eq!(client.deadline(), 10 * DAY);
+    assert_eq!(client.status(), Status::Active);
+}
+
+// ── Contribution Certificate Tests ─────────────────────────────────────────
+
+#[test]
+fn test_contribution_certificate_while_active() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let contributor = setup_terms(&env, &client, &creator, &token_address, &admin);
+    client.contribute(&contributor, &100_000, &None, &None);
+
+    let cert = client.contribution_certificate(&contributor);
+    assert_eq!(cert.contributor, contributor);
+    assert_eq!(cert.campaign_contract, client.address);
+    assert_eq!(cert.total_contributed, 100_000);
+    assert_eq!(cert.campaign_goal, 1_000_000);
+    assert_eq!(cert.timestamp, env.ledger().timestamp());
+    assert_eq!(cert.campaign_status, Status::Active);
+}
+
+#[test]
+fn test_contribution_certificate_after_success() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let contributor = setup_terms(&env, &client, &creator, &token_address, &admin);
+    mint_to(&env, &token_address, &admin, &contributor, 900_000);
+    client.contribute(&contributor, &1_000_000, &None, &None);
+
+    env.ledger().set_timestamp(client.deadline() + 1);
+    client.withdraw();
+
+    let cert = client.contribution_certificate(&contributor);
+    assert_eq!(cert.total_contributed, 1_000_000);
+    assert_eq!(cert.timestamp, client.deadline() + 1);
+    assert_eq!(cert.campaign_status, Status::Successful);
+}
+
+#[test]
+fn test_contribution_certificate_after_refund() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let contributor = setup_terms(&env, &client, &creator, &token_address, &admin);
+    client.contribute(&contributor, &100_000, &None, &None);
+
+    env.ledger().set_timestamp(client.deadline() + 1);
+    client.refund();
+
+    let cert = client.contribution_certificate(&contributor);
+    assert_eq!(cert.total_contributed, 0);
+    assert_eq!(cert.campaign_status, Status::Refunded);
+}
+
+#[test]
+fn test_contribution_certificate_for_non_contributor() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    setup_terms(&env, &client, &creator, &token_address, &admin);
+
+    let outsider = Address::generate(&env);
+    let cert = client.contribution_certificate(&outsider);
+    assert_eq!(cert.contributor, outsider);
+    assert_eq!(cert.total_contributed, 0);
+}
+
+// ── Sequence Deadline Tests ────────────────────────────────────────────────
+
+/// Ledgers between initialization and a sequence deadline in these tests;
+/// about 1.4 hours at five seconds per ledger.
+const DEADLINE_LEDGERS: u32 = 1_000;
+
+fn setup_deadline_campaign(
+    sequence: bool,
+) -> (
+    Env,
+    CrowdfundContractClient<'static>,
+    Address,
+    Address,
+    Address,
+) {
+    let (env, client, creator, token_address, admin) = setup_env();
+    env.ledger().set_sequence_number(100);
+    let deadline = if sequence {
+        Deadline::Sequence(env.ledger().sequence() + DEADLINE_LEDGERS)
+    } else {
+        Deadline::Timestamp(env.ledger().timestamp() + 3600)
+    };
+    client.initialize(
+        &creator,
+        &token_address,
+        &1_000_000,
+        &2_000_000,
+        &deadline,
+        &1_000,
+        &None,
+        &None,
+        &None,
+    );
+    (env, client, creator, token_address, admin)
+}
+
+/// Moves the ledger just past the campaign deadline along the axis the
+/// deadline is measured on.
+fn pass_deadline(env: &Env, client: &CrowdfundContractClient) {
+    match client.deadline_info().deadline {
+        Deadline::Timestamp(timestamp) => env.ledger().set_timestamp(timestamp + 1),
+        Deadline::Sequence(sequence) => env.ledger().set_sequence_number(sequence + 1),
     }
 }
 
-/// **Property Test 5: Multiple Contributions Accumulate Correctly**
-///
-/// For any sequence of valid contributions from multiple contributors,
-/// the total_raised must equal the sum of all contributions.
-proptest! {
-    #[test]
-    fn prop_multiple_contributions_accumulate(
-        goal in 5_000_000i128..50_000_000i128,
-        deadline_offset in 100u64..100_000u64,
-        amount1 in 1_000i128..5_000_000i128,
-        amount2 in 1_000i128..5_000_000i128,
-        amount3 in 1_000i128..5_000_000i128,
-    ) {
-        let (env, client, creator, token_address, admin) = setup_env();
-        let deadline = env.ledger().timestamp() + deadline_offset;
-        let expected_total = amount1 + amount2 + amount3;
-        let hard_cap = expected_total.max(goal);
+fn run_successful_lifecycle(sequence: bool) {
+    let (env, client, _creator, token_address, admin) = setup_deadline_campaign(sequence);
+
+    let contributor = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &contributor, 1_000_000);
+    client.contribute(&contributor, &1_000_000, &None, &None);
+    assert!(!client.deadline_info().expired);
+
+    let result = client.try_withdraw();
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        crate::ContractError::CampaignStillActive
+    );
+
+    pass_deadline(&env, &client);
+    assert!(client.deadline_info().expired);
+
+    let late = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &late, 1_000);
+    let result = client.try_contribute(&late, &1_000, &None, &None);
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        crate::ContractError::CampaignEnded
+    );
+
+    client.withdraw();
+    assert_eq!(client.status(), Status::Successful);
+}
+
+fn run_refunded_lifecycle(sequence: bool) {
+    let (env, client, _creator, token_address, admin) = setup_deadline_campaign(sequence);
+
+    let contributor = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &contributor, 100_000);
+    client.contribute(&contributor, &100_000, &None, &None);
+
+    let result = client.try_refund();
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        crate::ContractError::CampaignStillActive
+    );
+
+    pass_deadline(&env, &client);
+    client.refund();
+
+    assert_eq!(client.status(), Status::Refunded);
+    let token_client = token::Client::new(&env, &token_address);
+    assert_eq!(token_client.balance(&contributor), 100_000);
+}
+
+#[test]
+fn test_timestamp_deadline_lifecycle() {
+    run_successful_lifecycle(false);
+    run_refunded_lifecycle(false);
+}
+
+#[test]
+fn test_sequence_deadline_lifecycle() {
+    run_successful_lifecycle(true);
+    run_refunded_lifecycle(true);
+}
+
+#[test]
+fn test_sequence_deadline_ignores_timestamp() {
+    let (env, client, _creator, token_address, admin) = setup_deadline_campaign(true);
+
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + YEAR as u64);
+    assert!(!client.deadline_info().expired);
+
+    let contributor = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &contributor, 10_000);
+    client.contribute(&contributor, &10_000, &None, &None);
+    assert_eq!(client.total_raised(), 10_000);
+}
+
+#[test]
+fn test_deadline_info_reports_variant() {
+    let (env, client, _creator, _token_address, _admin) = setup_deadline_campaign(true);
+
+    let info = client.deadline_info();
+    assert_eq!(info.deadline, Deadline::Sequence(100 + DEADLINE_LEDGERS));
+    assert!(!info.expired);
+    assert_eq!(client.deadline(), (100 + DEADLINE_LEDGERS) as u64);
+    assert_eq!(
+        client.get_stats().time_remaining,
+        DEADLINE_LEDGERS as u64 * 5
+    );
+
+    env.ledger().set_sequence_number(100 + DEADLINE_LEDGERS + 1);
+    assert!(client.deadline_info().expired);
+}
+
+#[test]
+fn test_sequence_deadline_validated_at_initialize() {
+    let (env, client, creator, token_address, _admin) = setup_env();
+    env.ledger().set_sequence_number(100);
+
+    let init = |deadline: Deadline| {
+        client
+            .try_initialize(
+                &creator,
+                &token_address,
+                &1_000_000,
+                &2_000_000,
+                &deadline,
+                &1_000,
+                &None,
+                &None,
+                &None,
+            )
+            .err()
+            .map(|err| err.unwrap())
+    };
+
+    assert_eq!(
+        init(Deadline::Sequence(100)),
+        Some(crate::ContractError::InvalidDeadline)
+    );
+    // 700 ledgers is under an hour at five seconds per ledger.
+    assert_eq!(
+        init(Deadline::Sequence(800)),
+        Some(crate::ContractError::InvalidDuration)
+    );
+    assert_eq!(init(Deadline::Sequence(820)), None);
+}
+
+#[test]
+#[should_panic(expected = "refund window requires a timestamp deadline")]
+fn test_sequence_deadline_with_refund_window_panics() {
+    let (env, client, creator, token_address, _admin) = setup_env();
+
+    client.initialize(
+        &creator,
+        &token_address,
+        &1_000_000,
+        &2_000_000,
+        &Deadline::Sequence(env.ledger().sequence() + DEADLINE_LEDGERS),
+        &1_000,
+        &None,
+        &None,
+        &Some(CampaignOptions {
+            refund_window_seconds: 3600,
+            ..Default::default()
+        }),
+    );
+}
+
+#[test]
+#[should_panic(expected = "deadline is not a timestamp")]
+fn test_update_deadline_on_sequence_deadline_panics() {
+    let (env, client, _creator, _token_address, _admin) = setup_deadline_campaign(true);
+
+    client.update_deadline(&(env.ledger().timestamp() + 7200));
+}
+
+// ── Archive Tests ──────────────────────────────────────────────────────────
 
-        client.initialize(&creator, &token_address, &goal, &hard_cap, &deadline, &1_000, &None);
+#[test]
+fn test_archive_successful_campaign() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let contributor = setup_terms(&env, &client, &creator, &token_address, &admin);
+    mint_to(&env, &token_address, &admin, &contributor, 900_000);
+    client.contribute_with_memo(
+        &contributor,
+        &1_000_000,
+        &soroban_sdk::String::from_str(&env, "go!"),
+    );
+    let other = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &other, 50_000);
+    client.contribute(&other, &50_000, &None, &None);
 
-        let contributor1 = Address::generate(&env);
-        let contributor2 = Address::generate(&env);
-        let contributor3 = Address::generate(&env);
+    env.ledger().set_timestamp(client.deadline() + 1);
+    client.withdraw();
+    assert!(!client.is_archived());
 
-        mint_to(&env, &token_address, &admin, &contributor1, amount1);
-        mint_to(&env, &token_address, &admin, &contributor2, amount2);
-        mint_to(&env, &token_address, &admin, &contributor3, amount3);
+    client.archive(&creator);
 
-        client.contribute(&contributor1, &amount1, None);
-        client.contribute(&contributor2, &amount2, None);
-        client.contribute(&contributor3, &amount3, None);
+    assert!(client.is_archived());
+    assert_eq!(
+        client.campaign_archive(),
+        Some(crate::CampaignArchive {
+            total_raised_at_close: 1_050_000,
+            contributor_count_at_close: 2,
+            status: Status::Successful,
+            goal: 1_000_000,
+        })
+    );
+    assert_eq!(client.contribution(&contributor), 0);
+    assert_eq!(client.contribution(&other), 0);
+    assert_eq!(client.contribution_memo(&contributor, &0), None);
+    assert_eq!(client.contributor_count(), 0);
+    env.as_contract(&client.address, || {
+        assert!(!env
+            .storage()
+            .persistent()
+            .has(&DataKey::Contribution(contributor.clone())));
+        assert!(!env.storage().persistent().has(&DataKey::Contributors));
+    });
+}
 
-        // **INVARIANT**: total_raised must equal sum of all contributions
-        prop_assert_eq!(client.total_raised(), expected_total);
+#[test]
+fn test_archive_refunded_campaign_by_admin() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let campaign_admin = Address::generate(&env);
+    client.initialize(
+        &creator,
+        &token_address,
+        &1_000_000,
+        &2_000_000,
+        &Deadline::Timestamp(env.ledger().timestamp() + 3600),
+        &1_000,
+        &None,
+        &Some(campaign_admin.clone()),
+        &None,
+    );
+    let contributor = Address::generate(&env);
+    mint_to(&env, &token_address, &admin, &contributor, 100_000);
+    client.contribute(&contributor, &100_000, &None, &None);
 
-        // **INVARIANT**: Each contributor's balance must be tracked correctly
-        prop_assert_eq!(client.contribution(&contributor1), amount1);
-        prop_assert_eq!(client.contribution(&contributor2), amount2);
-        prop_assert_eq!(client.contribution(&contributor3), amount3);
-    }
+    env.ledger().set_timestamp(client.deadline() + 1);
+    client.refund();
+    client.archive(&campaign_admin);
+
+    let archive = client.campaign_archive().unwrap();
+    assert_eq!(archive.total_raised_at_close, 100_000);
+    assert_eq!(archive.contributor_count_at_close, 1);
+    assert_eq!(archive.status, Status::Refunded);
+    assert_eq!(client.contribution(&contributor), 0);
 }
 
-/// **Property Test 6: Withdrawal After Goal Met Transfers Correct Amount**
-///
-/// For any valid goal and contributions that meet or exceed the goal,
-/// withdrawal must transfer the exact total_raised amount to the creator.
-proptest! {
-    #[test]
-    fn prop_withdrawal_transfers_exact_amount(
-        goal in 1_000_000i128..10_000_000i128,
-        deadline_offset in 100u64..10_000u64,
-    ) {
-        let (env, client, creator, token_address, admin) = setup_env();
-        let deadline = env.ledger().timestamp() + deadline_offset;
+#[test]
+#[should_panic(expected = "campaign is not closed")]
+fn test_archive_active_campaign_panics() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    setup_terms(&env, &client, &creator, &token_address, &admin);
 
-        client.initialize(&creator, &token_address, &goal, &(goal * 2), &deadline, &1_000, &None);
+    client.archive(&creator);
+}
 
-        let contributor = Address::generate(&env);
-        mint_to(&env, &token_address, &admin, &contributor, goal);
-        client.contribute(&contributor, &goal, None);
+#[test]
+#[should_panic(expected = "not authorized")]
+fn test_archive_by_stranger_panics() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    setup_terms(&env, &client, &creator, &token_address, &admin);
+    client.cancel();
 
-        // Move past deadline
-        env.ledger().set_timestamp(deadline + 1);
+    client.archive(&Address::generate(&env));
+}
 
-        let token_client = token::Client::new(&env, &token_address);
-        let creator_balance_before = token_client.balance(&creator);
+#[test]
+#[should_panic(expected = "campaign already archived")]
+fn test_archive_twice_panics() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    setup_terms(&env, &client, &creator, &token_address, &admin);
+    client.cancel();
 
-        client.withdraw();
+    client.archive(&creator);
+    client.archive(&creator);
+}
 
-        let creator_balance_after = token_client.balance(&creator);
-        let transferred_amount = creator_balance_after - creator_balance_before;
+// ── Platform Pause Tests ───────────────────────────────────────────────────
 
-        // **INVARIANT**: Withdrawal must transfer exact total_raised amount
-        prop_assert_eq!(
-            transferred_amount, goal,
-            "withdrawal transferred {} but expected {}",
-            transferred_amount, goal
-        );
+fn setup_platform_pause(
+    env: &Env,
+    client: &CrowdfundContractClient,
+    creator: &Address,
+    token_address: &Address,
+    admin: &Address,
+) -> Address {
+    client.initialize(
+        creator,
+        token_address,
+        &1_000_000,
+        &2_000_000,
+        &Deadline::Timestamp(env.ledger().timestamp() + 3600),
+        &1_000,
+        &Some(PlatformConfig {
+            address: Address::generate(env),
+            fee_bps: 500,
+            fee_timing: FeeTiming::OnWithdraw,
+            tiered_fee: None,
+            min_campaign_duration_seconds: 0,
+            max_campaign_duration_seconds: 0,
+            dust_policy: DustPolicy::Creator,
+        }),
+        &None,
+        &None,
+    );
 
-        // **INVARIANT**: total_raised must be reset to 0 after withdrawal
-        prop_assert_eq!(client.total_raised(), 0);
-    }
+    let contributor = Address::generate(env);
+    mint_to(env, token_address, admin, &contributor, 100_000);
+    client.contribute(&contributor, &50_000, &None, &None);
+    contributor
 }
 
-/// **Property Test 7: Contribution Tracking Persists Across Multiple Calls**
-///
-/// For any contributor making multiple contributions, the total tracked
-/// must equal the sum of all their contributions.
-proptest! {
-    #[test]
-    fn prop_contribution_tracking_persists(
-        goal in 5_000_000i128..50_000_000i128,
-        deadline_offset in 100u64..100_000u64,
-        amount1 in 1_000i128..2_000_000i128,
-        amount2 in 1_000i128..2_000_000i128,
-        amount3 in 1_000i128..2_000_000i128,
-    ) {
-        let (env, client, creator, token_address, admin) = setup_env();
-        let deadline = env.ledger().timestamp() + deadline_offset;
+#[test]
+fn test_platform_pause_blocks_contribute() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let contributor = setup_platform_pause(&env, &client, &creator, &token_address, &admin);
 
-        client.initialize(&creator, &token_address, &goal, &(goal * 2), &deadline, &1_000, &None);
+    client.pause();
+    assert!(client.is_paused());
 
-        let contributor = Address::generate(&env);
-        let total_needed = amount1.saturating_add(amount2).saturating_add(amount3);
-        mint_to(&env, &token_address, &admin, &contributor, total_needed);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 5);
+    let result = client.try_contribute(&contributor, &10_000, &None, &None);
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        crate::ContractError::CampaignPaused
+    );
+    assert_eq!(client.total_raised(), 50_000);
+}
 
-        // First contribution
-        client.contribute(&contributor, &amount1, None);
-        prop_assert_eq!(client.contribution(&contributor), amount1);
+#[test]
+fn test_platform_pause_still_allows_refund() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let contributor = setup_platform_pause(&env, &client, &creator, &token_address, &admin);
 
-        // Second contribution
-        client.contribute(&contributor, &amount2, None);
-        let expected_after_2 = amount1.saturating_add(amount2);
-        prop_assert_eq!(client.contribution(&contributor), expected_after_2);
+    client.pause();
+    env.ledger().set_timestamp(client.deadline() + 1);
+    client.refund();
 
-        // Third contribution
-        client.contribute(&contributor, &amount3, None);
-        let expected_total = amount1.saturating_add(amount2).saturating_add(amount3);
-        prop_assert_eq!(client.contribution(&contributor), expected_total);
+    assert_eq!(client.status(), Status::Refunded);
+    let token_client = token::Client::new(&env, &token_address);
+    assert_eq!(token_client.balance(&contributor), 100_000);
+}
 
-        // **INVARIANT**: Final total_raised must equal sum of all contributions
-        prop_assert_eq!(client.total_raised(), expected_total);
-    }
+#[test]
+fn test_platform_unpause_restores_contributions() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let contributor = setup_platform_pause(&env, &client, &creator, &token_address, &admin);
+
+    client.pause();
+    client.unpause();
+    assert!(!client.is_paused());
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 5);
+    client.contribute(&contributor, &10_000, &None, &None);
+    assert_eq!(client.total_raised(), 60_000);
 }
 
-/// **Property Test 8: Refund Resets Total Raised to Zero**
-///
-/// For any valid refund scenario (goal not met, deadline passed),
-/// total_raised must be reset to 0 after refund completes.
-proptest! {
-    #[test]
-    fn prop_refund_resets_total_raised(
-        goal in 5_000_000i128..50_000_000i128,
-        deadline_offset in 100u64..100_000u64,
-        contribution in 1_000i128..5_000_000i128,
-    ) {
-        let (env, client, creator, token_address, admin) = setup_env();
-        let deadline = env.ledger().timestamp() + deadline_offset;
+#[test]
+#[should_panic(expected = "no platform configured")]
+fn test_platform_pause_without_platform_panics() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    setup_terms(&env, &client, &creator, &token_address, &admin);
 
-        let safe_contribution = contribution.min(goal - 1);
+    client.pause();
+}
 
-        client.initialize(&creator, &token_address, &goal, &(goal * 2), &deadline, &1_000, &None);
+// ── Creator Pause Tests ────────────────────────────────────────────────────
 
-        let contributor = Address::generate(&env);
-        mint_to(&env, &token_address, &admin, &contributor, safe_contribution);
-        client.contribute(&contributor, &safe_contribution, None);
+const DAY: u64 = 24 * 60 * 60;
 
-        // Verify total_raised is set
-        prop_assert_eq!(client.total_raised(), safe_contribution);
+fn setup_creator_pause(
+    env: &Env,
+    client: &CrowdfundContractClient,
+    creator: &Address,
+    token_address: &Address,
+    admin: &Address,
+    max_creator_pause_seconds: u64,
+) -> Address {
+    client.initialize(
+        creator,
+        token_address,
+        &1_000_000,
+        &2_000_000,
+        &Deadline::Timestamp(env.ledger().timestamp() + 30 * DAY),
+        &1_000,
+        &Some(PlatformConfig {
+            address: Address::generate(env),
+            fee_bps: 500,
+            fee_timing: FeeTiming::OnWithdraw,
+            tiered_fee: None,
+            min_campaign_duration_seconds: 0,
+            max_campaign_duration_seconds: 0,
+            dust_policy: DustPolicy::Creator,
+        }),
+        &None,
+        &Some(CampaignOptions {
+            max_creator_pause_seconds,
+            ..Default::default()
+        }),
+    );
 
-        // Move past deadline (goal not met)
-        env.ledger().set_timestamp(deadline + 1);
+    let contributor = Address::generate(env);
+    mint_to(env, token_address, admin, &contributor, 100_000);
+    client.contribute(&contributor, &50_000, &None, &None);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 5);
+    contributor
+}
 
-        client.refund();
+#[test]
+fn test_creator_pause_and_resume() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let contributor = setup_creator_pause(&env, &client, &creator, &token_address, &admin, 0);
+    let deadline = client.deadline();
 
-        // **INVARIANT**: total_raised must be 0 after refund
-        prop_assert_eq!(client.total_raised(), 0);
-    }
+    client.creator_pause();
+    assert!(client.is_creator_paused());
+    let result = client.try_contribute(&contributor, &10_000, &None, &None);
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        crate::ContractError::CampaignPaused
+    );
+
+    client.creator_resume();
+    assert!(!client.is_creator_paused());
+    client.contribute(&contributor, &10_000, &None, &None);
+    assert_eq!(client.total_raised(), 60_000);
+    assert_eq!(client.deadline(), deadline);
 }
 
-/// **Property Test 9: Contribution Below Minimum Always Fails**
-///
-/// For any contribution amount below the minimum, the contribute function
-/// must fail or panic.
-proptest! {
-    #[test]
-    fn prop_contribute_below_minimum_fails(
-        goal in 1_000_000i128..10_000_000i128,
-        deadline_offset in 100u64..10_000u64,
-        min_contribution in 1_000i128..100_000i128,
-        below_minimum in 1i128..1_000i128,
-    ) {
-        let (env, client, creator, token_address, admin) = setup_env();
-        let deadline = env.ledger().timestamp() + deadline_offset;
+#[test]
+fn test_creator_pause_expires_after_max_duration() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let contributor = setup_creator_pause(&env, &client, &creator, &token_address, &admin, 0);
 
-        client.initialize(&creator, &token_address, &goal, &(goal * 2), &deadline, &min_contribution, &None);
+    client.creator_pause();
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + 7 * DAY - 1);
+    assert!(client.is_creator_paused());
 
-        let contributor = Address::generate(&env);
-        let amount_to_contribute = below_minimum.min(min_contribution - 1);
-        mint_to(&env, &token_address, &admin, &contributor, amount_to_contribute);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 1);
+    assert!(!client.is_creator_paused());
+    client.contribute(&contributor, &10_000, &None, &None);
+    assert_eq!(client.total_raised(), 60_000);
+}
 
-        // Attempt to contribute below minimum
-        let result = client.try_contribute(&contributor, &amount_to_contribute);
+#[test]
+fn test_creator_pause_uses_configured_max_duration() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    setup_creator_pause(&env, &client, &creator, &token_address, &admin, DAY);
 
-        // **INVARIANT**: Contribution below minimum must fail
-        prop_assert!(
-            result.is_err(),
-            "contribute with amount {} below minimum {} should fail",
-            amount_to_contribute, min_contribution
-        );
-    }
+    client.creator_pause();
+    env.ledger().set_timestamp(env.ledger().timestamp() + DAY);
+    assert!(!client.is_creator_paused());
 }
 
-/// **Property Test 10: Contribution After Deadline Always Fails**
-///
-/// For any contribution attempt after the deadline has passed,
-/// the contribute function must fail.
-proptest! {
-    #[test]
-    fn prop_contribute_after_deadline_fails(
-        goal in 1_000_000i128..10_000_000i128,
-        deadline_offset in 100u64..10_000u64,
-        contribution in 1_000i128..10_000_000i128,
-        time_after_deadline in 1u64..100_000u64,
-    ) {
-        let (env, client, creator, token_address, admin) = setup_env();
-        let deadline = env.ledger().timestamp() + deadline_offset;
+#[test]
+fn test_creator_pause_still_allows_refund() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let contributor =
+        setup_creator_pause(&env, &client, &creator, &token_address, &admin, 60 * DAY);
 
-        client.initialize(&creator, &token_address, &goal, &(goal * 2), &deadline, &1_000, &None);
+    client.creator_pause();
+    env.ledger().set_timestamp(client.deadline() + 1);
+    assert!(client.is_creator_paused());
+    client.refund();
 
-        // Move past deadline
-        env.ledger().set_timestamp(deadline + time_after_deadline);
+    let token_client = token::Client::new(&env, &token_address);
+    assert_eq!(token_client.balance(&contributor), 100_000);
+}
 
-        let contributor = Address::generate(&env);
-        mint_to(&env, &token_address, &admin, &contributor, contribution);
+#[test]
+fn test_creator_resume_keeps_platform_pause() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let contributor = setup_creator_pause(&env, &client, &creator, &token_address, &admin, 0);
 
-        // Attempt to contribute after deadline
-        let result = client.try_contribute(&contributor, &contribution);
+    client.creator_pause();
+    client.pause();
+    client.creator_resume();
 
-        // **INVARIANT**: Contribution after deadline must fail
-        prop_assert!(
-            result.is_err(),
-            "contribute after deadline should fail"
-        );
-        prop_assert_eq!(
-            result.unwrap_err().unwrap(),
-            crate::ContractError::CampaignEnded
-        );
-    }
+    let result = client.try_contribute(&contributor, &10_000, &None, &None);
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        crate::ContractError::CampaignPaused
+    );
+
+    client.unpause();
+    client.contribute(&contributor, &10_000, &None, &None);
+    assert_eq!(client.total_raised(), 60_000);
+}
+
+#[test]
+#[should_panic(expected = "campaign is not paused")]
+fn test_creator_resume_without_pause_panics() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    setup_creator_pause(&env, &client, &creator, &token_address, &admin, 0);
+
+    client.creator_resume();
+}
+
+// ── Multi-Sig Withdraw Tests ───────────────────────────────────────────────
+
+/// Funds a 2-of-3 multi-sig campaign past its goal and deadline and returns
+/// the three signers.
+fn setup_multisig(
+    env: &Env,
+    client: &CrowdfundContractClient,
+    creator: &Address,
+    token_address: &Address,
+    admin: &Address,
+) -> (Address, Address, Address) {
+    let signers = (
+        Address::generate(env),
+        Address::generate(env),
+        Address::generate(env),
+    );
+    init_with_options(
+        env,
+        client,
+        creator,
+        token_address,
+        CampaignOptions {
+            required_withdraw_signatures: 2,
+            withdraw_signers: Some(soroban_sdk::vec![
+                env,
+                signers.0.clone(),
+                signers.1.clone(),
+                signers.2.clone(),
+            ]),
+            ..Default::default()
+        },
+    );
+
+    let contributor = Address::generate(env);
+    mint_to(env, token_address, admin, &contributor, 1_000_000);
+    client.contribute(&contributor, &1_000_000, &None, &None);
+    env.ledger().set_timestamp(client.deadline() + 1);
+    signers
+}
+
+#[test]
+fn test_multisig_withdraw_two_of_three() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let (first, second, _third) = setup_multisig(&env, &client, &creator, &token_address, &admin);
+    let token_client = token::Client::new(&env, &token_address);
+    let creator_before = token_client.balance(&creator);
+
+    client.initiate_withdraw(&first);
+    let pending = client.pending_withdraw().unwrap();
+    assert_eq!(pending.initiator, first);
+    assert_eq!(pending.signatures.len(), 1);
+    assert_eq!(client.status(), Status::Active);
+    assert_eq!(token_client.balance(&creator), creator_before);
+
+    client.sign_withdraw(&second);
+
+    assert_eq!(client.status(), Status::Successful);
+    assert_eq!(token_client.balance(&creator), creator_before + 1_000_000);
+    assert_eq!(client.pending_withdraw(), None);
 }
 
-// ── Pause/Unpause Tests ─────────────────────────────────────────────────────
+#[test]
+#[should_panic(expected = "withdraw requires co-signatures")]
+fn test_multisig_campaign_rejects_plain_withdraw() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    setup_multisig(&env, &client, &creator, &token_address, &admin);
+
+    client.withdraw();
+}
 
 #[test]
-fn test_contribute_rejected_when_paused() {
+#[should_panic(expected = "not a withdraw signer")]
+fn test_multisig_rejects_outside_signer() {
     let (env, client, creator, token_address, admin) = setup_env();
+    let (first, _second, _third) = setup_multisig(&env, &client, &creator, &token_address, &admin);
 
-    let deadline = env.ledger().timestamp() + 3600;
-    let goal: i128 = 1_000_000;
-    let min_contribution: i128 = 1_000;
+    client.initiate_withdraw(&first);
+    client.sign_withdraw(&Address::generate(&env));
+}
 
-    client.initialize(
-        &creator,
-        &token_address,
-        &goal,
-        &(goal * 2),
-        &deadline,
-        &min_contribution,
-        &None,
-    );
+#[test]
+#[should_panic(expected = "already signed")]
+fn test_multisig_rejects_double_signature() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let (first, _second, _third) = setup_multisig(&env, &client, &creator, &token_address, &admin);
 
-    // Pause the contract
-    client.set_paused(&true);
+    client.initiate_withdraw(&first);
+    client.sign_withdraw(&first);
+}
 
-    // Try to contribute while paused
-    let contributor = Address::generate(&env);
-    mint_to(&env, &token_address, &admin, &contributor, 5_000);
+#[test]
+fn test_multisig_pending_withdraw_resets_after_timeout() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let (first, second, third) = setup_multisig(&env, &client, &creator, &token_address, &admin);
 
-    let result = client.try_contribute(&contributor, &5_000, &None);
+    client.initiate_withdraw(&first);
+    let window = 3 * 24 * 60 * 60;
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + window + 1);
 
+    let result = client.try_sign_withdraw(&second);
     assert!(result.is_err());
-    assert_eq!(
-        result.unwrap_err().unwrap(),
-        crate::ContractError::ContractPaused
-    );
+    assert_eq!(client.status(), Status::Active);
+
+    client.initiate_withdraw(&third);
+    let pending = client.pending_withdraw().unwrap();
+    assert_eq!(pending.initiator, third);
+    assert_eq!(pending.signatures.len(), 1);
+
+    client.sign_withdraw(&second);
+    assert_eq!(client.status(), Status::Successful);
 }
 
 #[test]
-fn test_withdraw_rejected_when_paused() {
-    let (env, client, creator, token_address, admin) = setup_env();
-
-    let deadline = env.ledger().timestamp() + 3600;
-    let goal: i128 = 1_000_000;
-    let min_contribution: i128 = 1_000;
+#[should_panic(expected = "not enough withdraw signers")]
+fn test_multisig_threshold_above_signer_count_panics() {
+    let (env, client, creator, token_address, _admin) = setup_env();
 
-    client.initialize(
+    init_with_options(
+        &env,
+        &client,
         &creator,
         &token_address,
-        &goal,
-        &(goal * 2),
-        &deadline,
-        &min_contribution,
+        CampaignOptions {
+            required_withdraw_signatures: 2,
+            withdraw_signers: Some(soroban_sdk::vec![&env, creator.clone()]),
+            ..Default::default()
+        },
+    );
+}
+
+// ── Platform-Gated Upgrade Tests ───────────────────────────────────────────
+
+fn setup_platform_upgrade(
+    env: &Env,
+    client: &CrowdfundContractClient,
+    creator: &Address,
+    token_address: &Address,
+) -> (Address, soroban_sdk::BytesN<32>) {
+    let platform = Address::generate(env);
+    client.initialize(
+        creator,
+        token_address,
+        &1_000_000,
+        &2_000_000,
+        &Deadline::Timestamp(env.ledger().timestamp() + 3600),
+        &1_000,
+        &Some(PlatformConfig {
+            address: platform.clone(),
+            fee_bps: 500,
+            fee_timing: FeeTiming::OnWithdraw,
+            tiered_fee: None,
+            min_campaign_duration_seconds: 0,
+            max_campaign_duration_seconds: 0,
+            dust_policy: DustPolicy::Creator,
+        }),
+        &None,
         &None,
     );
+    let new_wasm_hash = env.deployer().upload_contract_wasm(NEXT_VERSION_WASM);
+    (platform, new_wasm_hash)
+}
 
-    // Contribute to meet goal
+fn upgrade_invoke<'a>(
+    env: &Env,
+    contract: &'a Address,
+    new_wasm_hash: &soroban_sdk::BytesN<32>,
+) -> soroban_sdk::testutils::MockAuthInvoke<'a> {
+    soroban_sdk::testutils::MockAuthInvoke {
+        contract,
+        fn_name: "upgrade",
+        args: (new_wasm_hash.clone(),).into_val(env),
+        sub_invokes: &[],
+    }
+}
+
+#[test]
+fn test_upgrade_with_creator_and_platform() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let (platform, new_wasm_hash) = setup_platform_upgrade(&env, &client, &creator, &token_address);
     let contributor = Address::generate(&env);
-    mint_to(&env, &token_address, &admin, &contributor, goal);
-    client.contribute(&contributor, &goal, None);
+    mint_to(&env, &token_address, &admin, &contributor, 50_000);
+    client.contribute(&contributor, &50_000, &None, &None);
+    assert_eq!(client.version(), 2);
 
-    // Move past deadline
-    env.ledger().set_timestamp(deadline + 1);
+    env.set_auths(&[]);
+    let invoke = upgrade_invoke(&env, &client.address, &new_wasm_hash);
+    client
+        .mock_auths(&[
+            soroban_sdk::testutils::MockAuth {
+                address: &creator,
+                invoke: &invoke,
+            },
+            soroban_sdk::testutils::MockAuth {
+                address: &platform,
+                invoke: &invoke,
+            },
+        ])
+        .upgrade(&new_wasm_hash);
+
+    let (_, _, data) = env.events().all().last().unwrap();
+    let (upgraded_by, old_hash, new_hash): (
+        Address,
+        Option<soroban_sdk::BytesN<32>>,
+        soroban_sdk::BytesN<32>,
+    ) = <_>::try_from_val(&env, &data).unwrap();
+    assert_eq!(upgraded_by, creator);
+    assert_eq!(old_hash, None);
+    assert_eq!(new_hash, new_wasm_hash);
+
+    // The new code answers with its own version, and storage survives.
+    assert_eq!(client.version(), 3);
+    env.as_contract(&client.address, || {
+        let total: i128 = env.storage().instance().get(&DataKey::TotalRaised).unwrap();
+        assert_eq!(total, 50_000);
+        let contribution: i128 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Contribution(contributor.clone()))
+            .unwrap();
+        assert_eq!(contribution, 50_000);
+    });
+}
 
-    // Pause the contract
-    client.set_paused(&true);
+#[test]
+fn test_upgrade_without_platform_auth_rejected() {
+    let (env, client, creator, token_address, _admin) = setup_env();
+    let (_platform, new_wasm_hash) =
+        setup_platform_upgrade(&env, &client, &creator, &token_address);
 
-    // Try to withdraw while paused
-    let result = client.try_withdraw();
+    env.set_auths(&[]);
+    let invoke = upgrade_invoke(&env, &client.address, &new_wasm_hash);
+    let result = client
+        .mock_auths(&[soroban_sdk::testutils::MockAuth {
+            address: &creator,
+            invoke: &invoke,
+        }])
+        .try_upgrade(&new_wasm_hash);
 
     assert!(result.is_err());
-    assert_eq!(
-        result.unwrap_err().unwrap(),
-        crate::ContractError::ContractPaused
-    );
+    assert_eq!(client.version(), 2);
 }
 
 #[test]
-fn test_refund_rejected_when_paused() {
-    let (env, client, creator, token_address, admin) = setup_env();
+fn test_upgrade_without_creator_auth_rejected() {
+    let (env, client, creator, token_address, _admin) = setup_env();
+    let (platform, new_wasm_hash) = setup_platform_upgrade(&env, &client, &creator, &token_address);
 
-    let deadline = env.ledger().timestamp() + 3600;
-    let goal: i128 = 1_000_000;
-    let min_contribution: i128 = 1_000;
+    env.set_auths(&[]);
+    let invoke = upgrade_invoke(&env, &client.address, &new_wasm_hash);
+    let result = client
+        .mock_auths(&[soroban_sdk::testutils::MockAuth {
+            address: &platform,
+            invoke: &invoke,
+        }])
+        .try_upgrade(&new_wasm_hash);
+
+    assert!(result.is_err());
+    assert_eq!(client.version(), 2);
+}
+
+// ── Migration Tests ────────────────────────────────────────────────────────
+
+/// Rewrites the campaign's storage into the version 1 layout: contributions
+/// in one instance-storage map, no per-contributor entries, no recorded
+/// storage version.
+fn write_legacy_layout(env: &Env, client: &CrowdfundContractClient, entries: &[(Address, i128)]) {
+    env.as_contract(&client.address, || {
+        let mut legacy: soroban_sdk::Map<Address, i128> = soroban_sdk::Map::new(env);
+        for (contributor, amount) in entries.iter() {
+            legacy.set(contributor.clone(), *amount);
+            env.storage()
+                .persistent()
+                .remove(&DataKey::Contribution(contributor.clone()));
+        }
+        env.storage().persistent().set(
+            &DataKey::Contributors,
+            &soroban_sdk::Vec::<Address>::new(env),
+        );
+        env.storage()
+            .instance()
+            .set(&LegacyKey::Contributions, &legacy);
+        env.storage()
+            .instance()
+            .remove(&GovernanceKey::StorageVersion);
+    });
+}
 
+#[test]
+fn test_migrate_converts_legacy_contributions() {
+    let (env, client, creator, token_address, _admin) = setup_env();
+    let deadline = env.ledger().timestamp() + 3600;
     client.initialize(
         &creator,
         &token_address,
-        &goal,
-        &(goal * 2),
-        &deadline,
-        &min_contribution,
+        &1_000_000,
+        &1_000_000,
+        &Deadline::Timestamp(deadline),
+        &1_000,
+        &None,
+        &None,
         &None,
     );
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    write_legacy_layout(
+        &env,
+        &client,
+        &[(alice.clone(), 30_000), (bob.clone(), 20_000)],
+    );
+    assert_eq!(client.storage_version(), 1);
+    assert_eq!(client.contribution(&alice), 0);
 
-    // Contribute but don't meet goal
-    let contributor = Address::generate(&env);
-    mint_to(&env, &token_address, &admin, &contributor, 500_000);
-    client.contribute(&contributor, &500_000, &None);
-
-    // Move past deadline
-    env.ledger().set_timestamp(deadline + 1);
-
-    // Pause the contract
-    client.set_paused(&true);
-
-    // Try to refund while paused
-    let result = client.try_refund();
+    client.migrate();
 
-    assert!(result.is_err());
-    assert_eq!(
-        result.unwrap_err().unwrap(),
-        crate::ContractError::ContractPaused
-    );
+    assert_eq!(client.storage_version(), 2);
+    assert_eq!(client.contribution(&alice), 30_000);
+    assert_eq!(client.contribution(&bob), 20_000);
+    assert_eq!(client.contributor_count(), 2);
+    env.as_contract(&client.address, || {
+        assert!(!env.storage().instance().has(&LegacyKey::Contributions));
+    });
 }
 
 #[test]
-fn test_all_interactions_succeed_after_unpause() {
-    let (env, client, creator, token_address, admin) = setup_env();
-
+#[should_panic(expected = "already migrated")]
+fn test_migrate_runs_once_per_version() {
+    let (env, client, creator, token_address, _admin) = setup_env();
     let deadline = env.ledger().timestamp() + 3600;
-    let goal: i128 = 1_000_000;
-    let min_contribution: i128 = 1_000;
-
     client.initialize(
         &creator,
         &token_address,
-        &goal,
-        &(goal * 2),
-        &deadline,
-        &min_contribution,
+        &1_000_000,
+        &1_000_000,
+        &Deadline::Timestamp(deadline),
+        &1_000,
+        &None,
+        &None,
         &None,
     );
+    write_legacy_layout(&env, &client, &[(Address::generate(&env), 5_000)]);
 
-    // Pause the contract
-    client.set_paused(&true);
-
-    // Unpause the contract
-    client.set_paused(&false);
-
-    // Contribute should succeed
-    let contributor = Address::generate(&env);
-    mint_to(&env, &token_address, &admin, &contributor, 5_000);
-    client.contribute(&contributor, &5_000, &None);
-
-    assert_eq!(client.total_raised(), 5_000);
+    client.migrate();
+    client.migrate();
 }
 
 #[test]
-#[should_panic]
-fn test_set_paused_rejected_from_non_creator() {
-    let env = Env::default();
-    let contract_id = env.register(CrowdfundContract, ());
-    let client = CrowdfundContractClient::new(&env, &contract_id);
-
-    let token_admin = Address::generate(&env);
-    let token_contract_id = env.register_stellar_asset_contract_v2(token_admin.clone());
-    let token_address = token_contract_id.address();
-
-    let creator = Address::generate(&env);
-    let non_creator = Address::generate(&env);
+#[should_panic(expected = "already migrated")]
+fn test_migrate_on_fresh_campaign_panics() {
+    let (env, client, creator, token_address, _admin) = setup_env();
+    let deadline = env.ledger().timestamp() + 3600;
+    client.initialize(
+        &creator,
+        &token_address,
+        &1_000_000,
+        &1_000_000,
+        &Deadline::Timestamp(deadline),
+        &1_000,
+        &None,
+        &None,
+        &None,
+    );
 
-    env.mock_all_auths();
+    assert_eq!(client.storage_version(), 2);
+    client.migrate();
+}
 
+#[test]
+fn test_migrate_requires_creator_auth() {
+    let (env, client, creator, token_address, _admin) = setup_env();
     let deadline = env.ledger().timestamp() + 3600;
-    let goal: i128 = 1_000_000;
-    let min_contribution: i128 = 1_000;
-
     client.initialize(
         &creator,
         &token_address,
-        &goal,
-        &(goal * 2),
-        &deadline,
-        &min_contribution,
+        &1_000_000,
+        &1_000_000,
+        &Deadline::Timestamp(deadline),
+        &1_000,
+        &None,
+        &None,
         &None,
     );
+    write_legacy_layout(&env, &client, &[(Address::generate(&env), 5_000)]);
 
-    env.mock_all_auths_allowing_non_root_auth();
     env.set_auths(&[]);
-
-    client.mock_auths(&[soroban_sdk::testutils::MockAuth {
-        address: &non_creator,
-        invoke: &soroban_sdk::testutils::MockAuthInvoke {
-            contract: &contract_id,
-            fn_name: "set_paused",
-            args: soroban_sdk::vec![&env, true.into()],
-            sub_invokes: &[],
-        },
-    }]);
-
-    client.set_paused(&true);
+    assert!(client.try_migrate().is_err());
+    assert_eq!(client.storage_version(), 1);
 }
 
-// ── Contributor Count Tests ────────────────────────────────────────────────
+// ── Finalize Tests ─────────────────────────────────────────────────────────
 
 #[test]
-fn test_contributor_count_zero_before_contributions() {
-    let (env, client, creator, token_address, _admin) = setup_env();
+fn test_finalize_pays_out_when_goal_met() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let (platform, deadline) = setup_fee_override(&env, &client, &creator, &token_address, &admin);
+    let token_client = token::Client::new(&env, &token_address);
+    let creator_before = token_client.balance(&creator);
 
-    let deadline = env.ledger().timestamp() + 3600;
-    let goal: i128 = 1_000_000;
-    let min_contribution: i128 = 1_000;
+    env.ledger().set_timestamp(deadline + 1);
+    // No one's authorization is needed.
+    env.set_auths(&[]);
+    assert_eq!(client.finalize(), Status::Successful);
 
-    client.initialize(&creator, &token_address, &goal, &(goal * 2), &deadline, &min_contribution, &None);
+    assert_eq!(token_client.balance(&platform), 10_000);
+    assert_eq!(token_client.balance(&creator), creator_before + 190_000);
+}
 
-    assert_eq!(client.contributor_count(), 0);
+#[test]
+fn test_finalize_refunds_when_goal_missed() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let contributor = setup_terms(&env, &client, &creator, &token_address, &admin);
+    client.contribute(&contributor, &100_000, &None, &None);
+
+    env.ledger().set_timestamp(client.deadline() + 1);
+    env.set_auths(&[]);
+    assert_eq!(client.finalize(), Status::Refunded);
+
+    let token_client = token::Client::new(&env, &token_address);
+    assert_eq!(token_client.balance(&contributor), 100_000);
 }
 
 #[test]
-fn test_contributor_count_one_after_single_contribution() {
+fn test_finalize_before_deadline_rejected() {
     let (env, client, creator, token_address, admin) = setup_env();
+    let contributor = setup_terms(&env, &client, &creator, &token_address, &admin);
+    client.contribute(&contributor, &100_000, &None, &None);
 
-    let deadline = env.ledger().timestamp() + 3600;
-    let goal: i128 = 1_000_000;
-    let min_contribution: i128 = 1_000;
+    let result = client.try_finalize();
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        crate::ContractError::CampaignStillActive
+    );
+    assert_eq!(client.status(), Status::Active);
+}
 
-    client.initialize(&creator, &token_address, &goal, &(goal * 2), &deadline, &min_contribution, &None);
+#[test]
+fn test_finalize_is_idempotent() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    let (platform, deadline) = setup_fee_override(&env, &client, &creator, &token_address, &admin);
+    let token_client = token::Client::new(&env, &token_address);
 
-    let contributor = Address::generate(&env);
-    mint_to(&env, &token_address, &admin, &contributor, 500_000);
-    client.contribute(&contributor, &500_000);
+    env.ledger().set_timestamp(deadline + 1);
+    client.finalize();
+    let creator_balance = token_client.balance(&creator);
 
-    assert_eq!(client.contributor_count(), 1);
+    assert_eq!(client.finalize(), Status::Successful);
+    assert_eq!(token_client.balance(&creator), creator_balance);
+    assert_eq!(token_client.balance(&platform), 10_000);
 }
 
+// ── Cancellation Reason Tests ──────────────────────────────────────────────
+
 #[test]
-fn test_contributor_count_multiple_contributors() {
+fn test_cancel_with_reason_stores_reason() {
     let (env, client, creator, token_address, admin) = setup_env();
+    let contributor = setup_terms(&env, &client, &creator, &token_address, &admin);
+    client.contribute(&contributor, &100_000, &None, &None);
 
-    let deadline = env.ledger().timestamp() + 3600;
-    let goal: i128 = 1_000_000;
-    let min_contribution: i128 = 1_000;
+    let reason = soroban_sdk::String::from_str(&env, "Supplier fell through");
+    client.cancel_with_reason(&reason);
 
-    client.initialize(&creator, &token_address, &goal, &(goal * 2), &deadline, &min_contribution, &None);
+    let events = campaign_events::<CancelledEvent>(&env, &client.address, "cancel_reason");
+    assert_eq!(
+        events,
+        std::vec![CancelledEvent {
+            reason: reason.clone(),
+            timestamp: env.ledger().timestamp(),
+        }]
+    );
 
-    let alice = Address::generate(&env);
-    let bob = Address::generate(&env);
-    let charlie = Address::generate(&env);
-    
-    mint_to(&env, &token_address, &admin, &alice, 300_000);
-    mint_to(&env, &token_address, &admin, &bob, 200_000);
-    mint_to(&env, &token_address, &admin, &charlie, 100_000);
+    assert_eq!(client.status(), Status::Cancelled);
+    assert_eq!(client.cancellation_reason(), Some(reason));
+    let token_client = token::Client::new(&env, &token_address);
+    assert_eq!(token_client.balance(&contributor), 100_000);
+}
 
-    client.contribute(&alice, &300_000);
-    assert_eq!(client.contributor_count(), 1);
+#[test]
+#[should_panic(expected = "reason must not be empty")]
+fn test_cancel_with_empty_reason_panics() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    setup_terms(&env, &client, &creator, &token_address, &admin);
 
-    client.contribute(&bob, &200_000);
-    assert_eq!(client.contributor_count(), 2);
+    client.cancel_with_reason(&soroban_sdk::String::from_str(&env, ""));
+}
 
-    client.contribute(&charlie, &100_000);
-    assert_eq!(client.contributor_count(), 3);
+#[test]
+fn test_plain_cancel_sets_no_reason() {
+    let (env, client, creator, token_address, admin) = setup_env();
+    setup_terms(&env, &client, &creator, &token_address, &admin);
+
+    client.cancel();
+
+    assert_eq!(client.status(), Status::Cancelled);
+    assert_eq!(client.cancellation_reason(), None);
 }
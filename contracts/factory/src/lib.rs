@@ -1,20 +1,26 @@
 // Factory contract for batch campaign initialization
 // Implements Issue #68 and extends Issue #23
+#![no_std]
 
-use soroban_sdk::{contractimpl, contracttype, BytesN, Address, Env, Symbol, String, Vec};
+use soroban_sdk::{
+    auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation},
+    contract, contractclient, contracterror, contractimpl, contracttype, token, vec, Address,
+    BytesN, Env, IntoVal, String, Symbol, Val, Vec,
+};
 
-// Registry key for storing deployed campaigns
-const REGISTRY_KEY: &str = "campaign_registry";
-
-// The WASM hash for the crowdfund contract (should be set to the correct value in production)
-const CROWDFUND_WASM_HASH: [u8; 32] = [0u8; 32]; // TODO: Replace with actual hash
+/// Most tags a campaign may carry.
+const MAX_TAGS: u32 = 5;
+/// Longest tag accepted, in bytes.
+const MAX_TAG_LEN: u32 = 32;
 
 #[contracttype]
 pub struct BatchCreatedEvent {
     pub count: u32,
     pub addresses: Vec<Address>,
 }
-#[derive(Clone)]
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
 pub struct CampaignConfig {
     pub creator: Address,
     pub token: Address,
@@ -22,20 +28,516 @@ pub struct CampaignConfig {
     pub deadline: u64,
     pub title: String,
     pub description: String,
+    pub category: Symbol,
+    pub tags: Vec<String>,
+}
+
+/// Mirror of the crowdfund contract's campaign category.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum CampaignCategory {
+    Technology,
+    Art,
+    Music,
+    Social,
+    Environment,
+    Health,
+    Education,
+    Other,
+}
+
+/// Mirror of the crowdfund contract's campaign status.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum CampaignStatus {
+    Active,
+    Successful,
+    Refunded,
+    Cancelled,
+}
+
+/// Mirror of the crowdfund contract's deadline.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum CampaignDeadline {
+    Timestamp(u64),
+    Sequence(u32),
+}
+
+/// Mirror of the crowdfund contract's fee timing.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum CampaignFeeTiming {
+    OnWithdraw,
+    OnContribute,
+}
+
+/// Mirror of the crowdfund contract's fee dust policy.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum CampaignDustPolicy {
+    Creator,
+    Platform,
+    CarryForward,
+}
+
+/// Mirror of the crowdfund contract's platform configuration.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct CampaignPlatformConfig {
+    pub address: Address,
+    pub fee_bps: u32,
+    pub fee_timing: CampaignFeeTiming,
+    pub dust_policy: CampaignDustPolicy,
+    pub tiered_fee: Option<Vec<(i128, u32)>>,
+    pub min_campaign_duration_seconds: u64,
+    pub max_campaign_duration_seconds: u64,
+}
+
+/// Goal limits the platform applies to every campaign the factory creates.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct PlatformConstraints {
+    /// Smallest goal accepted.
+    pub min_goal: i128,
+    /// Largest goal accepted; `None` for no upper limit.
+    pub max_goal: Option<i128>,
+}
+
+/// Discovery metadata recorded when the factory creates a campaign.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct CampaignDetails {
+    pub creator: Address,
+    pub category: Symbol,
+    pub tags: Vec<String>,
+    pub created_at: u64,
+}
+
+/// Running totals over every campaign that has reported a successful close.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[contracttype]
+pub struct VolumeStats {
+    pub total_raised: i128,
+    pub contributor_count: u64,
+    pub finalized_campaigns: u32,
 }
 
 #[derive(Clone)]
-pub struct FactoryContract;
+#[contracttype]
+pub enum DataKey {
+    /// Address allowed to change the crowdfund WASM hash.
+    Admin,
+    /// Crowdfund WASM hash used for newly created campaigns.
+    WasmHash,
+    /// Goal limits checked before a campaign is created.
+    PlatformConstraints,
+    /// Platform config injected into every new campaign.
+    DefaultPlatformConfig,
+    /// Whether a caller-supplied platform config is rejected rather than
+    /// replaced by the default.
+    PlatformConfigLocked,
+    /// Number of campaigns deployed by the factory.
+    CampaignCount,
+    /// Campaign deployed at a given registry index.
+    Campaign(u32),
+    /// Number of campaigns deployed for a creator.
+    CreatorCampaignCount(Address),
+    /// A creator's campaign at a given per-creator index.
+    CreatorCampaign(Address, u32),
+    /// Whether an address is a campaign this factory deployed.
+    IsCampaign(Address),
+    /// Whether a campaign has already reported its close.
+    Reported(Address),
+    /// Platform-wide totals.
+    VolumeStats,
+    /// Amount a caller has contributed to a campaign through bundles.
+    BundleShare(Address, Address),
+    /// Categories campaigns may be filed under.
+    Categories,
+    /// Creation metadata of a campaign.
+    Details(Address),
+    /// Number of campaigns filed under a category.
+    CategoryCampaignCount(Symbol),
+    /// A category's campaign at a given per-category index.
+    CategoryCampaign(Symbol, u32),
+    /// Whether a caller-supplied salt has been used.
+    SaltUsed(BytesN<32>),
+    /// `(campaign, expires_at)` for campaigns the admin has featured.
+    Featured,
+}
 
-#[derive(Debug, PartialEq)]
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
 pub enum ContractError {
-    EmptyBatch,
-    InvalidConfig { index: usize },
-    // ...other errors
+    EmptyBatch = 1,
+    InvalidConfig = 2,
+    InvalidAmount = 3,
+    Overflow = 4,
+    PlatformConfigLocked = 5,
+    UnknownCategory = 6,
+    InvalidTags = 7,
+    AlreadyDeployed = 8,
+    NothingToClaim = 9,
+}
+
+/// The subset of the crowdfund interface the factory calls into. It sits in
+/// its own module because `initialize` needs the argument-count allowance,
+/// which has to cover the generated client too.
+mod campaign_interface {
+    #![allow(clippy::too_many_arguments)]
+
+    use super::*;
+
+    #[contractclient(name = "CampaignClient")]
+    pub trait CampaignInterface {
+        /// `options` is always `None`: factory campaigns use the default
+        /// options, so the factory keeps no mirror of the crowdfund's.
+        fn initialize(
+            env: Env,
+            creator: Address,
+            token: Address,
+            goal: i128,
+            hard_cap: i128,
+            deadline: CampaignDeadline,
+            min_contribution: i128,
+            platform_config: Option<CampaignPlatformConfig>,
+            admin: Option<Address>,
+            options: Option<Val>,
+        );
+        fn status(env: Env) -> CampaignStatus;
+        fn category(env: Env) -> Option<CampaignCategory>;
+        fn set_featured(env: Env, expires_at: u64);
+        fn token(env: Env) -> Address;
+        fn refund(env: Env);
+        fn contribute(
+            env: Env,
+            contributor: Address,
+            amount: i128,
+            referral: Option<Address>,
+            token: Option<Address>,
+        );
+    }
+}
+pub use campaign_interface::{CampaignClient, CampaignInterface};
+
+#[contract]
+pub struct FactoryContract;
+
+/// `create_campaign` sits in its own impl block so that the argument-count
+/// allowance it needs also covers the client and argument builder
+/// `#[contractimpl]` generates alongside it.
+mod create_campaign {
+    #![allow(clippy::too_many_arguments)]
+
+    use super::*;
+
+    #[contractimpl]
+    impl FactoryContract {
+        /// Deploy and initialize a single campaign in one invocation, and add it
+        /// to the registry.
+        ///
+        /// The factory is the campaign's deployer, so no one else can initialize
+        /// it. The hard cap equals `goal`; the campaign admin defaults to the
+        /// creator. When the admin has set a default platform config, it replaces
+        /// `platform_config`. `category` and `tags` are kept for discovery; see
+        /// [`campaign_details`](Self::campaign_details).
+        ///
+        /// With a `salt`, the campaign lands at the address
+        /// [`predict_campaign_address`](Self::predict_campaign_address) returns
+        /// for it; otherwise the address follows the registry index.
+        ///
+        /// # Errors
+        /// * `InvalidConfig` if `goal` is not positive.
+        /// * `PlatformConfigLocked` if `platform_config` is supplied while the
+        ///   default is locked.
+        /// * `UnknownCategory` if `category` is not one of the admin's categories.
+        /// * `InvalidTags` if there are more than five tags, or a tag is empty or
+        ///   longer than 32 bytes.
+        /// * `AlreadyDeployed` if `salt` has been used before.
+        ///
+        /// # Panics
+        /// * If the creator has not authorized the call.
+        /// * If `goal` is outside the platform constraints.
+        /// * If the campaign's own `initialize` rejects the settings.
+        pub fn create_campaign(
+            env: Env,
+            creator: Address,
+            token: Address,
+            goal: i128,
+            deadline: CampaignDeadline,
+            min_contribution: i128,
+            platform_config: Option<CampaignPlatformConfig>,
+            category: Symbol,
+            tags: Vec<String>,
+            salt: Option<BytesN<32>>,
+        ) -> Result<Address, ContractError> {
+            creator.require_auth();
+
+            if goal <= 0 {
+                return Err(ContractError::InvalidConfig);
+            }
+            check_goal_constraints(&env, goal);
+            let platform_config = resolve_platform_config(&env, platform_config)?;
+            check_metadata(&env, &category, &tags)?;
+            let deploy_salt = match salt {
+                Some(salt) => {
+                    let used_key = DataKey::SaltUsed(salt.clone());
+                    if env.storage().persistent().has(&used_key) {
+                        return Err(ContractError::AlreadyDeployed);
+                    }
+                    env.storage().persistent().set(&used_key, &true);
+                    caller_salt(&env, &salt)
+                }
+                None => index_salt(&env, Self::campaign_count(env.clone())),
+            };
+
+            let campaign = deploy_campaign(
+                &env,
+                deploy_salt,
+                &creator,
+                &token,
+                goal,
+                &deadline,
+                min_contribution,
+                &platform_config,
+            );
+            register_campaign(&env, &creator, &campaign);
+            record_details(&env, &campaign, &creator, category, tags);
+
+            env.events()
+                .publish(("factory", "campaign_created"), (creator, campaign.clone()));
+            Ok(campaign)
+        }
+    }
 }
 
 #[contractimpl]
 impl FactoryContract {
+    /// Records the factory admin and the crowdfund WASM hash new campaigns are
+    /// deployed from.
+    pub fn __constructor(env: Env, admin: Address, wasm_hash: BytesN<32>) {
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::WasmHash, &wasm_hash);
+    }
+
+    /// Change the crowdfund WASM hash used for future campaigns — admin-only.
+    ///
+    /// Campaigns already deployed keep running the code they were created
+    /// with.
+    pub fn set_wasm_hash(env: Env, wasm_hash: BytesN<32>) {
+        let admin = Self::admin(env.clone());
+        admin.require_auth();
+
+        env.storage().instance().set(&DataKey::WasmHash, &wasm_hash);
+        env.events()
+            .publish(("factory", "wasm_hash_updated"), wasm_hash);
+    }
+
+    /// Replace the goal limits applied to campaigns created from now on —
+    /// admin-only.
+    ///
+    /// Campaigns already deployed are unaffected.
+    ///
+    /// # Errors
+    /// * `InvalidConfig` if `min_goal` is negative or `max_goal` is below it.
+    pub fn set_platform_constraints(
+        env: Env,
+        constraints: PlatformConstraints,
+    ) -> Result<(), ContractError> {
+        let admin = Self::admin(env.clone());
+        admin.require_auth();
+
+        if constraints.min_goal < 0 {
+            return Err(ContractError::InvalidConfig);
+        }
+        if let Some(max_goal) = constraints.max_goal {
+            if max_goal < constraints.min_goal {
+                return Err(ContractError::InvalidConfig);
+            }
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::PlatformConstraints, &constraints);
+        env.events()
+            .publish(("factory", "constraints_updated"), constraints);
+        Ok(())
+    }
+
+    /// Set the platform config injected into campaigns created from now on —
+    /// admin-only. `None` clears it, letting callers pass their own again.
+    ///
+    /// With `locked`, `create_campaign` rejects a caller-supplied config
+    /// instead of silently replacing it. Campaigns already deployed keep the
+    /// config they were created with.
+    ///
+    /// # Errors
+    /// * `InvalidConfig` if the fee exceeds 100%.
+    pub fn set_default_platform_config(
+        env: Env,
+        config: Option<CampaignPlatformConfig>,
+        locked: bool,
+    ) -> Result<(), ContractError> {
+        let admin = Self::admin(env.clone());
+        admin.require_auth();
+
+        let storage = env.storage().instance();
+        match &config {
+            Some(config) => {
+                if config.fee_bps > 10_000 {
+                    return Err(ContractError::InvalidConfig);
+                }
+                storage.set(&DataKey::DefaultPlatformConfig, config);
+            }
+            None => storage.remove(&DataKey::DefaultPlatformConfig),
+        }
+        storage.set(&DataKey::PlatformConfigLocked, &locked);
+
+        env.events().publish(
+            ("factory", "default_platform_config_updated"),
+            (config, locked),
+        );
+        Ok(())
+    }
+
+    /// Returns the platform config injected into new campaigns, if any.
+    pub fn default_platform_config(env: Env) -> Option<CampaignPlatformConfig> {
+        env.storage()
+            .instance()
+            .get(&DataKey::DefaultPlatformConfig)
+    }
+
+    /// Returns true if caller-supplied platform configs are rejected.
+    pub fn is_platform_config_locked(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::PlatformConfigLocked)
+            .unwrap_or(false)
+    }
+
+    /// Returns the goal limits, or `None` if the platform has not set any.
+    pub fn platform_constraints(env: Env) -> Option<PlatformConstraints> {
+        env.storage().instance().get(&DataKey::PlatformConstraints)
+    }
+
+    /// Returns the factory admin.
+    pub fn admin(env: Env) -> Address {
+        env.storage().instance().get(&DataKey::Admin).unwrap()
+    }
+
+    /// Add a category campaigns may be filed under — admin-only.
+    pub fn add_category(env: Env, category: Symbol) {
+        let admin = Self::admin(env.clone());
+        admin.require_auth();
+
+        let mut categories = Self::categories(env.clone());
+        if !categories.contains(&category) {
+            categories.push_back(category.clone());
+            env.storage()
+                .instance()
+                .set(&DataKey::Categories, &categories);
+        }
+        env.events()
+            .publish(("factory", "category_added"), category);
+    }
+
+    /// Stop accepting `category` for new campaigns — admin-only.
+    ///
+    /// Campaigns already filed under it stay listed.
+    pub fn remove_category(env: Env, category: Symbol) {
+        let admin = Self::admin(env.clone());
+        admin.require_auth();
+
+        let mut categories = Self::categories(env.clone());
+        if let Some(index) = categories.first_index_of(&category) {
+            categories.remove(index);
+            env.storage()
+                .instance()
+                .set(&DataKey::Categories, &categories);
+        }
+        env.events()
+            .publish(("factory", "category_removed"), category);
+    }
+
+    /// Returns the categories new campaigns may be filed under.
+    pub fn categories(env: Env) -> Vec<Symbol> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Categories)
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    /// Feature `campaign` until `expires_at` — admin-only. An `expires_at`
+    /// in the past un-features it.
+    ///
+    /// # Panics
+    /// * If `campaign` was not deployed by this factory.
+    pub fn set_featured(env: Env, campaign: Address, expires_at: u64) {
+        let admin = Self::admin(env.clone());
+        admin.require_auth();
+
+        if !env
+            .storage()
+            .persistent()
+            .get(&DataKey::IsCampaign(campaign.clone()))
+            .unwrap_or(false)
+        {
+            panic!("unknown campaign");
+        }
+        CampaignClient::new(&env, &campaign).set_featured(&expires_at);
+
+        let mut featured = live_featured(&env);
+        if let Some(index) = featured.iter().position(|(listed, _)| listed == campaign) {
+            featured.remove(index as u32);
+        }
+        if expires_at > env.ledger().timestamp() {
+            featured.push_back((campaign.clone(), expires_at));
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::Featured, &featured);
+
+        env.events()
+            .publish(("factory", "campaign_featured"), (campaign, expires_at));
+    }
+
+    /// Returns the campaigns currently featured, in the order they were
+    /// featured. Expired entries are dropped here.
+    pub fn featured_campaigns(env: Env) -> Vec<Address> {
+        let featured = live_featured(&env);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Featured, &featured);
+
+        let mut campaigns = Vec::new(&env);
+        for (campaign, _) in featured.iter() {
+            campaigns.push_back(campaign);
+        }
+        campaigns
+    }
+
+    /// Returns the crowdfund WASM hash used for new campaigns.
+    pub fn wasm_hash(env: Env) -> BytesN<32> {
+        env.storage().instance().get(&DataKey::WasmHash).unwrap()
+    }
+
+    /// Deploy several campaigns in one transaction.
+    ///
+    /// Each config is checked the way [`create_campaign`](Self::create_campaign)
+    /// checks its arguments, including the creator's authorization, before
+    /// any campaign is deployed.
+    ///
+    /// # Errors
+    /// * `EmptyBatch` if `configs` is empty.
+    /// * `InvalidConfig` if a goal is not positive or a title or description
+    ///   is empty.
+    /// * `UnknownCategory` or `InvalidTags` if a config's metadata is invalid.
+    ///
+    /// # Panics
+    /// * If any creator has not authorized the call.
+    /// * If a goal is outside the platform constraints.
     pub fn create_campaigns_batch(
         env: Env,
         configs: Vec<CampaignConfig>,
@@ -45,144 +547,1785 @@ impl FactoryContract {
         }
         let mut deployed = Vec::new(&env);
         // Validate all configs first
-        for (i, config) in configs.iter().enumerate() {
+        for config in configs.iter() {
+            config.creator.require_auth();
             if config.goal <= 0 || config.title.is_empty() || config.description.is_empty() {
-                return Err(ContractError::InvalidConfig { index: i });
+                return Err(ContractError::InvalidConfig);
             }
+            check_goal_constraints(&env, config.goal);
+            check_metadata(&env, &config.category, &config.tags)?;
         }
-        // Deploy and initialize all campaigns
+        // Deploy, initialize and register all campaigns
         for config in configs.iter() {
-            let campaign_addr = deploy_and_init_campaign(&env, config);
+            let campaign_addr = deploy_campaign(
+                &env,
+                index_salt(&env, Self::campaign_count(env.clone())),
+                &config.creator,
+                &config.token,
+                config.goal,
+                &CampaignDeadline::Timestamp(config.deadline),
+                1,
+                &Self::default_platform_config(env.clone()),
+            );
+            register_campaign(&env, &config.creator, &campaign_addr);
+            record_details(
+                &env,
+                &campaign_addr,
+                &config.creator,
+                config.category,
+                config.tags,
+            );
             deployed.push_back(campaign_addr);
         }
-        // Store all deployed addresses in the factory registry
-        let mut registry: Vec<Address> = env
-            .storage()
-            .persistent()
-            .get(&REGISTRY_KEY.into())
-            .unwrap_or(Vec::new(&env));
-        for addr in deployed.iter() {
-            registry.push_back(addr.clone());
-        }
-        env.storage().persistent().set(&REGISTRY_KEY.into(), &registry);
         // Emit batch_campaigns_created event
         let event = BatchCreatedEvent {
-            count: deployed.len() as u32,
+            count: deployed.len(),
             addresses: deployed.clone(),
         };
-        env.events().publish(("factory", "batch_campaigns_created"), event);
+        env.events()
+            .publish(("factory", "batch_campaigns_created"), event);
         Ok(deployed)
     }
+
+    /// Contribute to several campaigns in one transaction.
+    ///
+    /// The factory pulls the bundle total from `contributor` once, using an
+    /// allowance the contributor granted the factory beforehand, and then
+    /// calls each campaign's `contribute`. The factory is therefore the
+    /// contributor of record on each campaign; the caller's share is kept in
+    /// `bundle_share`.
+    ///
+    /// Every campaign must be Active and accept the same token, otherwise the
+    /// whole call panics and nothing is transferred.
+    ///
+    /// # Errors
+    /// * `EmptyBatch` if `bundle` is empty.
+    /// * `InvalidAmount` if any amount is zero or negative.
+    /// * `Overflow` if the bundle total would overflow.
+    pub fn contribute_to_bundle(
+        env: Env,
+        contributor: Address,
+        bundle: Vec<(Address, i128)>,
+    ) -> Result<(), ContractError> {
+        contributor.require_auth();
+
+        if bundle.is_empty() {
+            return Err(ContractError::EmptyBatch);
+        }
+
+        let mut token: Option<Address> = None;
+        let mut total: i128 = 0;
+        for (campaign, amount) in bundle.iter() {
+            if amount <= 0 {
+                return Err(ContractError::InvalidAmount);
+            }
+            let client = CampaignClient::new(&env, &campaign);
+            if client.status() != CampaignStatus::Active {
+                panic!("campaign is not active");
+            }
+            let campaign_token = client.token();
+            match &token {
+                Some(expected) if *expected != campaign_token => {
+                    panic!("bundle campaigns must share a token")
+                }
+                _ => token = Some(campaign_token),
+            }
+            total = total.checked_add(amount).ok_or(ContractError::Overflow)?;
+        }
+        let token = token.unwrap();
+
+        let factory = env.current_contract_address();
+        token::Client::new(&env, &token).transfer_from(&factory, &contributor, &factory, &total);
+
+        for (campaign, amount) in bundle.iter() {
+            // The campaign pulls the tokens from the factory, one level below
+            // this call, so the factory has to authorize that transfer itself.
+            let transfer_args: Vec<Val> =
+                (factory.clone(), campaign.clone(), amount).into_val(&env);
+            env.authorize_as_current_contract(vec![
+                &env,
+                InvokerContractAuthEntry::Contract(SubContractInvocation {
+                    context: ContractContext {
+                        contract: token.clone(),
+                        fn_name: Symbol::new(&env, "transfer"),
+                        args: transfer_args,
+                    },
+                    sub_invocations: Vec::new(&env),
+                }),
+            ]);
+            CampaignClient::new(&env, &campaign).contribute(&factory, &amount, &None, &None);
+
+            let share_key = DataKey::BundleShare(campaign, contributor.clone());
+            let share: i128 = env.storage().persistent().get(&share_key).unwrap_or(0);
+            let share = share.checked_add(amount).ok_or(ContractError::Overflow)?;
+            env.storage().persistent().set(&share_key, &share);
+            env.storage().persistent().extend_ttl(&share_key, 100, 100);
+        }
+
+        env.events()
+            .publish(("factory", "bundle_contributed"), (contributor, total));
+        Ok(())
+    }
+
+    /// Return `contributor`'s bundle share of a campaign that was refunded or
+    /// cancelled — callable by anyone.
+    ///
+    /// The campaign pays bundled contributions back to the factory, their
+    /// contributor of record, and this forwards each caller's share. If the
+    /// campaign failed but nobody has run its `refund` yet, this runs it
+    /// first. Returns the amount paid out.
+    ///
+    /// # Errors
+    /// * `NothingToClaim` if `contributor` has no bundle share in `campaign`.
+    ///
+    /// # Panics
+    /// * If the campaign succeeded or has not yet failed.
+    pub fn claim_bundle_refund(
+        env: Env,
+        campaign: Address,
+        contributor: Address,
+    ) -> Result<i128, ContractError> {
+        let share_key = DataKey::BundleShare(campaign.clone(), contributor.clone());
+        let share: i128 = env.storage().persistent().get(&share_key).unwrap_or(0);
+        if share <= 0 {
+            return Err(ContractError::NothingToClaim);
+        }
+
+        let client = CampaignClient::new(&env, &campaign);
+        if client.status() == CampaignStatus::Active {
+            client.refund();
+        }
+        match client.status() {
+            CampaignStatus::Refunded | CampaignStatus::Cancelled => {}
+            _ => panic!("campaign was not refunded"),
+        }
+
+        env.storage().persistent().remove(&share_key);
+        token::Client::new(&env, &client.token()).transfer(
+            &env.current_contract_address(),
+            &contributor,
+            &share,
+        );
+
+        env.events().publish(
+            ("factory", "bundle_refunded"),
+            (campaign, contributor, share),
+        );
+        Ok(share)
+    }
+
+    /// Returns up to `limit` campaigns deployed by the factory, in creation
+    /// order, starting at index `start`.
+    pub fn campaigns(env: Env, start: u32, limit: u32) -> Vec<Address> {
+        let count = Self::campaign_count(env.clone());
+        read_page(&env, start, limit, count, DataKey::Campaign)
+    }
+
+    /// Returns the number of campaigns deployed by the factory.
+    pub fn campaign_count(env: Env) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::CampaignCount)
+            .unwrap_or(0)
+    }
+
+    /// Returns up to `limit` of `creator`'s campaigns, in creation order,
+    /// starting at the creator's `start`-th campaign.
+    pub fn campaigns_by_creator(
+        env: Env,
+        creator: Address,
+        start: u32,
+        limit: u32,
+    ) -> Vec<Address> {
+        let count: u32 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::CreatorCampaignCount(creator.clone()))
+            .unwrap_or(0);
+        read_page(&env, start, limit, count, |index| {
+            DataKey::CreatorCampaign(creator.clone(), index)
+        })
+    }
+
+    /// Returns up to `limit` campaigns filed under `category`, in creation
+    /// order, starting at the category's `start`-th campaign.
+    pub fn campaigns_by_category(
+        env: Env,
+        category: Symbol,
+        start: u32,
+        limit: u32,
+    ) -> Vec<Address> {
+        let count: u32 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::CategoryCampaignCount(category.clone()))
+            .unwrap_or(0);
+        read_page(&env, start, limit, count, |index| {
+            DataKey::CategoryCampaign(category.clone(), index)
+        })
+    }
+
+    /// Returns the address `create_campaign` will deploy to when given
+    /// `salt`, without deploying anything.
+    pub fn predict_campaign_address(env: Env, salt: BytesN<32>) -> Address {
+        env.deployer()
+            .with_current_contract(caller_salt(&env, &salt))
+            .deployed_address()
+    }
+
+    /// Returns the campaigns among registry entries `start..start + limit`
+    /// whose creator has filed them under `category` with the campaign's own
+    /// `set_category`.
+    ///
+    /// Each campaign in the range is asked for its category, so page through
+    /// the registry rather than requesting it all at once. Campaigns that
+    /// cannot answer are skipped.
+    pub fn campaigns_by_taxonomy(
+        env: Env,
+        category: CampaignCategory,
+        start: u32,
+        limit: u32,
+    ) -> Vec<Address> {
+        let mut matches = Vec::new(&env);
+        for campaign in Self::campaigns(env.clone(), start, limit).iter() {
+            if let Ok(Ok(Some(filed))) = CampaignClient::new(&env, &campaign).try_category() {
+                if filed == category {
+                    matches.push_back(campaign);
+                }
+            }
+        }
+        matches
+    }
+
+    /// Returns the creation metadata of a campaign this factory deployed.
+    pub fn campaign_details(env: Env, campaign: Address) -> Option<CampaignDetails> {
+        env.storage().persistent().get(&DataKey::Details(campaign))
+    }
+
+    /// Record a campaign's successful close in the platform-wide totals.
+    ///
+    /// Called by the campaign itself when it pays out. Only campaigns this
+    /// factory deployed are counted, each once.
+    ///
+    /// # Errors
+    /// * `InvalidAmount` if `raised_amount` is negative.
+    /// * `Overflow` if a running total would overflow.
+    ///
+    /// # Panics
+    /// * If the call is not authorized by `campaign`.
+    /// * If `campaign` was not deployed by this factory.
+    /// * If `campaign` has already reported.
+    pub fn on_campaign_finalized(
+        env: Env,
+        campaign: Address,
+        raised_amount: i128,
+        contributor_count: u64,
+    ) -> Result<(), ContractError> {
+        campaign.require_auth();
+
+        let storage = env.storage().persistent();
+        if !storage
+            .get(&DataKey::IsCampaign(campaign.clone()))
+            .unwrap_or(false)
+        {
+            panic!("unknown campaign");
+        }
+        let reported_key = DataKey::Reported(campaign.clone());
+        if storage.has(&reported_key) {
+            panic!("campaign already reported");
+        }
+        if raised_amount < 0 {
+            return Err(ContractError::InvalidAmount);
+        }
+
+        let mut stats = Self::volume_stats(env.clone());
+        stats.total_raised = stats
+            .total_raised
+            .checked_add(raised_amount)
+            .ok_or(ContractError::Overflow)?;
+        stats.contributor_count = stats
+            .contributor_count
+            .checked_add(contributor_count)
+            .ok_or(ContractError::Overflow)?;
+        stats.finalized_campaigns += 1;
+        storage.set(&DataKey::VolumeStats, &stats);
+        storage.set(&reported_key, &true);
+
+        env.events().publish(
+            ("factory", "campaign_finalized"),
+            (campaign, raised_amount, contributor_count),
+        );
+        Ok(())
+    }
+
+    /// Returns the platform-wide totals.
+    pub fn volume_stats(env: Env) -> VolumeStats {
+        env.storage()
+            .persistent()
+            .get(&DataKey::VolumeStats)
+            .unwrap_or_default()
+    }
+
+    /// Returns the total raised by every campaign that closed successfully.
+    pub fn total_raised_all_campaigns(env: Env) -> i128 {
+        Self::volume_stats(env).total_raised
+    }
+
+    /// Returns the number of contributors summed over every campaign that
+    /// closed successfully.
+    pub fn total_contributors_all_campaigns(env: Env) -> u64 {
+        Self::volume_stats(env).contributor_count
+    }
+
+    /// Returns how much `contributor` has put into `campaign` through bundles.
+    pub fn bundle_share(env: Env, campaign: Address, contributor: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::BundleShare(campaign, contributor))
+            .unwrap_or(0)
+    }
+}
+
+/// Appends `campaign` to the global and per-creator registries. Only called
+/// for campaigns this factory has just deployed.
+fn register_campaign(env: &Env, creator: &Address, campaign: &Address) {
+    let storage = env.storage().persistent();
+
+    let index: u32 = storage.get(&DataKey::CampaignCount).unwrap_or(0);
+    storage.set(&DataKey::Campaign(index), campaign);
+    storage.set(&DataKey::CampaignCount, &(index + 1));
+
+    let creator_count_key = DataKey::CreatorCampaignCount(creator.clone());
+    let creator_index: u32 = storage.get(&creator_count_key).unwrap_or(0);
+    storage.set(
+        &DataKey::CreatorCampaign(creator.clone(), creator_index),
+        campaign,
+    );
+    storage.set(&creator_count_key, &(creator_index + 1));
+
+    storage.set(&DataKey::IsCampaign(campaign.clone()), &true);
+}
+
+/// Stores a new campaign's creation metadata and files it under its
+/// category.
+fn record_details(
+    env: &Env,
+    campaign: &Address,
+    creator: &Address,
+    category: Symbol,
+    tags: Vec<String>,
+) {
+    let storage = env.storage().persistent();
+
+    let count_key = DataKey::CategoryCampaignCount(category.clone());
+    let index: u32 = storage.get(&count_key).unwrap_or(0);
+    storage.set(
+        &DataKey::CategoryCampaign(category.clone(), index),
+        campaign,
+    );
+    storage.set(&count_key, &(index + 1));
+
+    let details = CampaignDetails {
+        creator: creator.clone(),
+        category,
+        tags,
+        created_at: env.ledger().timestamp(),
+    };
+    storage.set(&DataKey::Details(campaign.clone()), &details);
+}
+
+/// Returns the featured list without entries that have expired.
+fn live_featured(env: &Env) -> Vec<(Address, u64)> {
+    let featured: Vec<(Address, u64)> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Featured)
+        .unwrap_or_else(|| Vec::new(env));
+    let now = env.ledger().timestamp();
+    let mut live = Vec::new(env);
+    for (campaign, expires_at) in featured.iter() {
+        if now < expires_at {
+            live.push_back((campaign, expires_at));
+        }
+    }
+    live
+}
+
+/// Reads entries `start..start + limit` (clamped to `count`) of an indexed
+/// registry.
+fn read_page(
+    env: &Env,
+    start: u32,
+    limit: u32,
+    count: u32,
+    key: impl Fn(u32) -> DataKey,
+) -> Vec<Address> {
+    let mut page = Vec::new(env);
+    let end = start.saturating_add(limit).min(count);
+    for index in start..end {
+        page.push_back(env.storage().persistent().get(&key(index)).unwrap());
+    }
+    page
+}
+
+/// Returns the platform config a new campaign gets: the factory default when
+/// one is set, otherwise whatever the caller supplied.
+fn resolve_platform_config(
+    env: &Env,
+    supplied: Option<CampaignPlatformConfig>,
+) -> Result<Option<CampaignPlatformConfig>, ContractError> {
+    let default: Option<CampaignPlatformConfig> = env
+        .storage()
+        .instance()
+        .get(&DataKey::DefaultPlatformConfig);
+    if default.is_none() {
+        return Ok(supplied);
+    }
+    let locked: bool = env
+        .storage()
+        .instance()
+        .get(&DataKey::PlatformConfigLocked)
+        .unwrap_or(false);
+    if locked && supplied.is_some() {
+        return Err(ContractError::PlatformConfigLocked);
+    }
+    Ok(default)
+}
+
+/// Panics if `goal` falls outside the platform constraints, when set.
+fn check_goal_constraints(env: &Env, goal: i128) {
+    let constraints: Option<PlatformConstraints> =
+        env.storage().instance().get(&DataKey::PlatformConstraints);
+    let Some(constraints) = constraints else {
+        return;
+    };
+    if goal < constraints.min_goal {
+        panic!("goal below platform minimum");
+    }
+    if let Some(max_goal) = constraints.max_goal {
+        if goal > max_goal {
+            panic!("goal above platform maximum");
+        }
+    }
+}
+
+/// Checks that `category` is one of the admin's categories and that `tags`
+/// are within the count and length limits.
+fn check_metadata(env: &Env, category: &Symbol, tags: &Vec<String>) -> Result<(), ContractError> {
+    let categories: Vec<Symbol> = env
+        .storage()
+        .instance()
+        .get(&DataKey::Categories)
+        .unwrap_or_else(|| Vec::new(env));
+    if !categories.contains(category) {
+        return Err(ContractError::UnknownCategory);
+    }
+    if tags.len() > MAX_TAGS {
+        return Err(ContractError::InvalidTags);
+    }
+    for tag in tags.iter() {
+        if tag.is_empty() || tag.len() > MAX_TAG_LEN {
+            return Err(ContractError::InvalidTags);
+        }
+    }
+    Ok(())
+}
+
+/// Deployment salt for the campaign at registry `index`, when the caller
+/// supplies none.
+fn index_salt(env: &Env, index: u32) -> BytesN<32> {
+    let mut salt = [0u8; 32];
+    salt[28..].copy_from_slice(&index.to_be_bytes());
+    BytesN::from_array(env, &salt)
+}
+
+/// Deployment salt for a caller-supplied `salt`. Hashing keeps it apart from
+/// the index salts, so no caller can claim the address of a future unsalted
+/// campaign.
+fn caller_salt(env: &Env, salt: &BytesN<32>) -> BytesN<32> {
+    env.crypto().sha256(&salt.clone().into()).into()
 }
 
-fn deploy_and_init_campaign(env: &Env, config: &CampaignConfig) -> Address {
+#[allow(clippy::too_many_arguments)]
+fn deploy_campaign(
+    env: &Env,
+    salt: BytesN<32>,
+    creator: &Address,
+    token: &Address,
+    goal: i128,
+    deadline: &CampaignDeadline,
+    min_contribution: i128,
+    platform_config: &Option<CampaignPlatformConfig>,
+) -> Address {
     // Deploy the crowdfund contract
-    let wasm_hash = BytesN::from_array(env, &CROWDFUND_WASM_HASH);
+    let wasm_hash: BytesN<32> = env.storage().instance().get(&DataKey::WasmHash).unwrap();
     let campaign_addr = env
         .deployer()
-        .with_current_contract(env.current_contract_address())
-        .deploy_contract(wasm_hash);
-    // Call initialize on the deployed contract
-    // NOTE: Hard cap, min_contribution, platform_config are set to defaults for this example
-    let hard_cap = config.goal;
-    let min_contribution = 1i128;
-    let platform_config: Option<()> = None;
-    env.invoke_contract(
-        &campaign_addr,
-        &Symbol::short("initialize"),
-        (
-            config.creator.clone(),
-            config.token.clone(),
-            config.goal,
-            hard_cap,
-            config.deadline,
-            min_contribution,
-            platform_config,
-        ),
+        .with_current_contract(salt)
+        .deploy_v2(wasm_hash, (env.current_contract_address(),));
+    // Initialize in the same call; the factory is the deployer, so only it may
+    // do so. The hard cap is the goal, and admin and options use the defaults.
+    CampaignClient::new(env, &campaign_addr).initialize(
+        creator,
+        token,
+        &goal,
+        &goal,
+        deadline,
+        &min_contribution,
+        platform_config,
+        &None,
+        &None,
     );
     campaign_addr
 }
-}
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use soroban_sdk::{testutils::Address as _, Address, Env, Vec};
+    use crowdfund::{CrowdfundContract, CrowdfundContractClient, Deadline, Status};
+    use soroban_sdk::{
+        symbol_short,
+        testutils::{Address as _, Ledger, MockAuth, MockAuthInvoke},
+        token, Address, Bytes, Env, IntoVal, String, Vec,
+    };
+
+    fn register_factory(env: &Env) -> Address {
+        env.register(
+            FactoryContract,
+            (Address::generate(env), BytesN::from_array(env, &[0u8; 32])),
+        )
+    }
+
+    /// Registers a factory that deploys the real crowdfund contract, which
+    /// must have been built first with
+    /// `cargo build --release --target wasm32v1-none -p crowdfund`.
+    fn register_deploying_factory(env: &Env) -> Address {
+        extern crate std;
+
+        let path = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../../target/wasm32v1-none/release/crowdfund.wasm"
+        );
+        let wasm = std::fs::read(path).unwrap_or_else(|_| {
+            panic!("{path} not found; build it with `cargo build --release --target wasm32v1-none -p crowdfund`")
+        });
+        // Uploading and running the full crowdfund wasm exceeds the default
+        // test budget.
+        env.cost_estimate().budget().reset_unlimited();
+        let wasm_hash = env
+            .deployer()
+            .upload_contract_wasm(Bytes::from_slice(env, &wasm));
+        env.register(FactoryContract, (Address::generate(env), wasm_hash))
+    }
+
+    fn setup_campaign(env: &Env, token: &Address) -> CrowdfundContractClient<'static> {
+        let client = CrowdfundContractClient::new(
+            env,
+            &env.register(CrowdfundContract, (Address::generate(env),)),
+        );
+        client.initialize(
+            &Address::generate(env),
+            token,
+            &1_000_000,
+            &2_000_000,
+            &Deadline::Timestamp(env.ledger().timestamp() + 3600),
+            &1_000,
+            &None,
+            &None,
+            &None,
+        );
+        client
+    }
+
+    fn setup_bundle() -> (
+        Env,
+        FactoryContractClient<'static>,
+        Address,
+        Address,
+        CrowdfundContractClient<'static>,
+        CrowdfundContractClient<'static>,
+    ) {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let factory = FactoryContractClient::new(&env, &register_factory(&env));
+        let token = env
+            .register_stellar_asset_contract_v2(Address::generate(&env))
+            .address();
+        let first = setup_campaign(&env, &token);
+        let second = setup_campaign(&env, &token);
+
+        let contributor = Address::generate(&env);
+        token::StellarAssetClient::new(&env, &token).mint(&contributor, &100_000);
+        token::Client::new(&env, &token).approve(
+            &contributor,
+            &factory.address,
+            &100_000,
+            &(env.ledger().max_live_until_ledger() - 1),
+        );
+        (env, factory, token, contributor, first, second)
+    }
 
     #[test]
     fn test_batch_deploys_campaigns() {
         let env = Env::default();
+        // Each campaign's initialize asks for its own creator's signature.
+        env.mock_all_auths_allowing_non_root_auth();
+        let factory = FactoryContractClient::new(&env, &register_deploying_factory(&env));
+        factory.add_category(&symbol_short!("art"));
+        let token = env
+            .register_stellar_asset_contract_v2(Address::generate(&env))
+            .address();
         let configs = Vec::from_array(
             &env,
             [
                 CampaignConfig {
-                    creator: Address::random(&env),
-                    token: Address::random(&env),
+                    creator: Address::generate(&env),
+                    token: token.clone(),
                     goal: 1000,
                     deadline: 123456,
-                    title: "Campaign 1".to_string(),
-                    description: "Desc 1".to_string(),
+                    title: String::from_str(&env, "Campaign 1"),
+                    description: String::from_str(&env, "Desc 1"),
+                    category: symbol_short!("art"),
+                    tags: Vec::new(&env),
                 },
                 CampaignConfig {
-                    creator: Address::random(&env),
-                    token: Address::random(&env),
+                    creator: Address::generate(&env),
+                    token: token.clone(),
                     goal: 2000,
                     deadline: 223456,
-                    title: "Campaign 2".to_string(),
-                    description: "Desc 2".to_string(),
+                    title: String::from_str(&env, "Campaign 2"),
+                    description: String::from_str(&env, "Desc 2"),
+                    category: symbol_short!("art"),
+                    tags: Vec::new(&env),
                 },
                 CampaignConfig {
-                    creator: Address::random(&env),
-                    token: Address::random(&env),
+                    creator: Address::generate(&env),
+                    token: token.clone(),
                     goal: 3000,
                     deadline: 323456,
-                    title: "Campaign 3".to_string(),
-                    description: "Desc 3".to_string(),
+                    title: String::from_str(&env, "Campaign 3"),
+                    description: String::from_str(&env, "Desc 3"),
+                    category: symbol_short!("art"),
+                    tags: Vec::new(&env),
                 },
             ],
         );
-        let result = FactoryContract::create_campaigns_batch(env.clone(), configs.clone());
-        assert!(result.is_ok());
-        let deployed = result.unwrap();
+        let deployed = factory.create_campaigns_batch(&configs);
         assert_eq!(deployed.len(), 3);
-        // TODO: Check registry and returned addresses
+        assert_eq!(factory.campaigns(&0, &10), deployed);
+        let second = CrowdfundContractClient::new(&env, &deployed.get(1).unwrap());
+        assert_eq!(second.goal(), 2000);
+        assert_eq!(second.deadline(), 223456);
+        assert_eq!(second.deployer(), factory.address);
+        assert_eq!(
+            factory.campaigns_by_category(&symbol_short!("art"), &0, &10),
+            deployed
+        );
+    }
+
+    /// Two configs for fresh creators, filed under `category`.
+    fn batch_configs(env: &Env, category: Symbol) -> Vec<CampaignConfig> {
+        let mut configs = Vec::new(env);
+        for goal in [1000, 2000] {
+            configs.push_back(CampaignConfig {
+                creator: Address::generate(env),
+                token: Address::generate(env),
+                goal,
+                deadline: 123456,
+                title: String::from_str(env, "Campaign"),
+                description: String::from_str(env, "Desc"),
+                category: category.clone(),
+                tags: Vec::new(env),
+            });
+        }
+        configs
     }
 
     #[test]
-    fn test_empty_batch_rejected() {
+    fn test_batch_requires_every_creator_auth() {
         let env = Env::default();
-        let configs = Vec::new(&env);
-        let result = FactoryContract::create_campaigns_batch(env, configs);
-        assert_eq!(result, Err(ContractError::EmptyBatch));
+        let factory = FactoryContractClient::new(&env, &register_factory(&env));
+        let configs = batch_configs(&env, symbol_short!("art"));
+        let first = configs.get(0).unwrap();
+
+        // Only the first creator signs.
+        let result = factory
+            .mock_auths(&[MockAuth {
+                address: &first.creator,
+                invoke: &MockAuthInvoke {
+                    contract: &factory.address,
+                    fn_name: "create_campaigns_batch",
+                    args: (configs.clone(),).into_val(&env),
+                    sub_invokes: &[],
+                },
+            }])
+            .try_create_campaigns_batch(&configs);
+        assert!(result.is_err());
+        assert_eq!(factory.campaign_count(), 0);
     }
 
     #[test]
-    fn test_invalid_config_rolls_back_batch() {
+    fn test_batch_rejects_unknown_category() {
         let env = Env::default();
-        let configs = Vec::from_array(
-            &env,
-            [
-                CampaignConfig {
-                    creator: Address::random(&env),
-                    token: Address::random(&env),
-                    goal: 1000,
-                    deadline: 123456,
-                    title: "Valid".to_string(),
-                    description: "Valid".to_string(),
+        env.mock_all_auths();
+        let factory = FactoryContractClient::new(&env, &register_factory(&env));
+        factory.add_category(&symbol_short!("art"));
+
+        let result =
+            factory.try_create_campaigns_batch(&batch_configs(&env, symbol_short!("games")));
+        assert_eq!(result, Err(Ok(ContractError::UnknownCategory)));
+        assert_eq!(factory.campaign_count(), 0);
+    }
+
+    #[test]
+    fn test_empty_batch_rejected() {
+        let env = Env::default();
+        let factory = FactoryContractClient::new(&env, &register_factory(&env));
+        let result = factory.try_create_campaigns_batch(&Vec::new(&env));
+        assert_eq!(result, Err(Ok(ContractError::EmptyBatch)));
+    }
+
+    #[test]
+    fn test_invalid_config_rolls_back_batch() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let factory = FactoryContractClient::new(&env, &register_factory(&env));
+        factory.add_category(&symbol_short!("art"));
+        let configs = Vec::from_array(
+            &env,
+            [
+                CampaignConfig {
+                    creator: Address::generate(&env),
+                    token: Address::generate(&env),
+                    goal: 1000,
+                    deadline: 123456,
+                    title: String::from_str(&env, "Valid"),
+                    description: String::from_str(&env, "Valid"),
+                    category: symbol_short!("art"),
+                    tags: Vec::new(&env),
                 },
                 CampaignConfig {
-                    creator: Address::random(&env),
-                    token: Address::random(&env),
+                    creator: Address::generate(&env),
+                    token: Address::generate(&env),
                     goal: -1, // Invalid goal
                     deadline: 223456,
-                    title: "Invalid".to_string(),
-                    description: "Invalid".to_string(),
+                    title: String::from_str(&env, "Invalid"),
+                    description: String::from_str(&env, "Invalid"),
+                    category: symbol_short!("art"),
+                    tags: Vec::new(&env),
                 },
             ],
         );
-        let result = FactoryContract::create_campaigns_batch(env, configs);
-        assert_eq!(result, Err(ContractError::InvalidConfig { index: 1 }));
+        let result = factory.try_create_campaigns_batch(&configs);
+        assert_eq!(result, Err(Ok(ContractError::InvalidConfig)));
+        assert_eq!(factory.campaign_count(), 0);
     }
-}
 
-// TODO: Add tests for batch deployment and error handling
+    #[test]
+    fn test_bundle_contributes_to_two_campaigns() {
+        let (env, factory, _token, contributor, first, second) = setup_bundle();
+
+        let bundle = Vec::from_array(
+            &env,
+            [
+                (first.address.clone(), 30_000),
+                (second.address.clone(), 50_000),
+            ],
+        );
+        factory.contribute_to_bundle(&contributor, &bundle);
+
+        assert_eq!(first.total_raised(), 30_000);
+        assert_eq!(second.total_raised(), 50_000);
+        assert_eq!(first.contribution(&factory.address), 30_000);
+        assert_eq!(factory.bundle_share(&first.address, &contributor), 30_000);
+        assert_eq!(factory.bundle_share(&second.address, &contributor), 50_000);
+    }
+
+    #[test]
+    fn test_bundle_transfers_total_once() {
+        let (env, factory, token, contributor, first, second) = setup_bundle();
+        let token_client = token::Client::new(&env, &token);
+
+        let bundle = Vec::from_array(
+            &env,
+            [
+                (first.address.clone(), 30_000),
+                (second.address.clone(), 50_000),
+            ],
+        );
+        factory.contribute_to_bundle(&contributor, &bundle);
+
+        assert_eq!(token_client.balance(&contributor), 20_000);
+        assert_eq!(
+            token_client.allowance(&contributor, &factory.address),
+            20_000
+        );
+        assert_eq!(token_client.balance(&factory.address), 0);
+        assert_eq!(token_client.balance(&first.address), 30_000);
+        assert_eq!(token_client.balance(&second.address), 50_000);
+    }
+
+    #[test]
+    fn test_bundle_needs_only_contributor_auth() {
+        let (env, factory, _token, contributor, first, second) = setup_bundle();
+
+        let bundle = Vec::from_array(
+            &env,
+            [
+                (first.address.clone(), 30_000),
+                (second.address.clone(), 50_000),
+            ],
+        );
+        env.mock_auths(&[MockAuth {
+            address: &contributor,
+            invoke: &MockAuthInvoke {
+                contract: &factory.address,
+                fn_name: "contribute_to_bundle",
+                args: (contributor.clone(), bundle.clone()).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]);
+        factory.contribute_to_bundle(&contributor, &bundle);
+
+        assert_eq!(first.total_raised(), 30_000);
+        assert_eq!(second.total_raised(), 50_000);
+    }
+
+    #[test]
+    #[should_panic(expected = "campaign is not active")]
+    fn test_bundle_with_inactive_campaign_panics() {
+        let (env, factory, _token, contributor, first, second) = setup_bundle();
+        second.cancel();
+
+        let bundle = Vec::from_array(
+            &env,
+            [
+                (first.address.clone(), 30_000),
+                (second.address.clone(), 50_000),
+            ],
+        );
+        factory.contribute_to_bundle(&contributor, &bundle);
+    }
+
+    #[test]
+    fn test_failed_bundle_campaign_refunds_contributor() {
+        let (env, factory, token, contributor, first, second) = setup_bundle();
+        let token_client = token::Client::new(&env, &token);
+        let bundle = Vec::from_array(
+            &env,
+            [
+                (first.address.clone(), 30_000),
+                (second.address.clone(), 50_000),
+            ],
+        );
+        factory.contribute_to_bundle(&contributor, &bundle);
+
+        env.ledger().set_timestamp(env.ledger().timestamp() + 3601);
+        assert_eq!(
+            factory.claim_bundle_refund(&first.address, &contributor),
+            30_000
+        );
+        assert_eq!(first.status(), Status::Refunded);
+        assert_eq!(token_client.balance(&contributor), 50_000);
+        assert_eq!(factory.bundle_share(&first.address, &contributor), 0);
+
+        let again = factory.try_claim_bundle_refund(&first.address, &contributor);
+        assert_eq!(again, Err(Ok(ContractError::NothingToClaim)));
+    }
+
+    #[test]
+    fn test_cancelled_bundle_campaign_refunds_contributor() {
+        let (env, factory, token, contributor, first, second) = setup_bundle();
+        let bundle = Vec::from_array(
+            &env,
+            [
+                (first.address.clone(), 30_000),
+                (second.address.clone(), 50_000),
+            ],
+        );
+        factory.contribute_to_bundle(&contributor, &bundle);
+        second.cancel();
+
+        factory.claim_bundle_refund(&second.address, &contributor);
+        assert_eq!(
+            token::Client::new(&env, &token).balance(&contributor),
+            70_000
+        );
+        assert_eq!(factory.bundle_share(&first.address, &contributor), 30_000);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_bundle_refund_before_deadline_panics() {
+        let (env, factory, _token, contributor, first, second) = setup_bundle();
+        let bundle = Vec::from_array(
+            &env,
+            [
+                (first.address.clone(), 30_000),
+                (second.address.clone(), 50_000),
+            ],
+        );
+        factory.contribute_to_bundle(&contributor, &bundle);
+
+        factory.claim_bundle_refund(&first.address, &contributor);
+    }
+
+    #[test]
+    fn test_empty_bundle_rejected() {
+        let (env, factory, _token, contributor, _first, _second) = setup_bundle();
+
+        let result = factory.try_contribute_to_bundle(&contributor, &Vec::new(&env));
+        assert_eq!(result, Err(Ok(ContractError::EmptyBatch)));
+    }
+
+    #[test]
+    fn test_create_campaign_deploys_independent_campaigns() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let factory = FactoryContractClient::new(&env, &register_deploying_factory(&env));
+        let token = env
+            .register_stellar_asset_contract_v2(Address::generate(&env))
+            .address();
+        let deadline = CampaignDeadline::Timestamp(env.ledger().timestamp() + 3600);
+        factory.add_category(&symbol_short!("art"));
+
+        let first = factory.create_campaign(
+            &Address::generate(&env),
+            &token,
+            &1_000_000,
+            &deadline,
+            &1_000,
+            &None,
+            &symbol_short!("art"),
+            &Vec::new(&env),
+            &None,
+        );
+        let second = factory.create_campaign(
+            &Address::generate(&env),
+            &token,
+            &2_000_000,
+            &deadline,
+            &1_000,
+            &None,
+            &symbol_short!("art"),
+            &Vec::new(&env),
+            &None,
+        );
+        assert_ne!(first, second);
+        assert_eq!(
+            factory.campaigns(&0, &10),
+            Vec::from_array(&env, [first.clone(), second.clone()])
+        );
+
+        let first = CrowdfundContractClient::new(&env, &first);
+        let second = CrowdfundContractClient::new(&env, &second);
+        let contributor = Address::generate(&env);
+        token::StellarAssetClient::new(&env, &token).mint(&contributor, &10_000);
+        first.contribute(&contributor, &10_000, &None, &None);
+
+        assert_eq!(first.goal(), 1_000_000);
+        assert_eq!(second.goal(), 2_000_000);
+        assert_eq!(first.total_raised(), 10_000);
+        assert_eq!(second.total_raised(), 0);
+        assert_eq!(first.deployer(), factory.address);
+    }
+
+    #[test]
+    fn test_create_campaign_requires_creator_auth() {
+        let env = Env::default();
+        let factory = FactoryContractClient::new(&env, &register_factory(&env));
+
+        let result = factory.try_create_campaign(
+            &Address::generate(&env),
+            &Address::generate(&env),
+            &1_000_000,
+            &CampaignDeadline::Timestamp(3600),
+            &1_000,
+            &None,
+            &symbol_short!("art"),
+            &Vec::new(&env),
+            &None,
+        );
+        assert!(result.is_err());
+        assert_eq!(factory.campaign_count(), 0);
+    }
+
+    #[test]
+    fn test_create_campaign_rejects_invalid_goal() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let factory = FactoryContractClient::new(&env, &register_factory(&env));
+
+        let result = factory.try_create_campaign(
+            &Address::generate(&env),
+            &Address::generate(&env),
+            &0,
+            &CampaignDeadline::Timestamp(3600),
+            &1_000,
+            &None,
+            &symbol_short!("art"),
+            &Vec::new(&env),
+            &None,
+        );
+        assert_eq!(result, Err(Ok(ContractError::InvalidConfig)));
+    }
+
+    #[test]
+    fn test_admin_updates_wasm_hash() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let factory = FactoryContractClient::new(&env, &register_factory(&env));
+
+        let new_hash = BytesN::from_array(&env, &[7u8; 32]);
+        factory.set_wasm_hash(&new_hash);
+        assert_eq!(factory.wasm_hash(), new_hash);
+    }
+
+    #[test]
+    fn test_set_wasm_hash_rejected_from_non_admin() {
+        let env = Env::default();
+        let factory = FactoryContractClient::new(&env, &register_factory(&env));
+        let outsider = Address::generate(&env);
+
+        let new_hash = BytesN::from_array(&env, &[7u8; 32]);
+        let result = factory
+            .mock_auths(&[MockAuth {
+                address: &outsider,
+                invoke: &MockAuthInvoke {
+                    contract: &factory.address,
+                    fn_name: "set_wasm_hash",
+                    args: (new_hash.clone(),).into_val(&env),
+                    sub_invokes: &[],
+                },
+            }])
+            .try_set_wasm_hash(&new_hash);
+        assert!(result.is_err());
+        assert_eq!(factory.wasm_hash(), BytesN::from_array(&env, &[0u8; 32]));
+    }
+
+    fn setup_constrained_factory() -> (Env, FactoryContractClient<'static>) {
+        let env = Env::default();
+        env.mock_all_auths();
+        let factory = FactoryContractClient::new(&env, &register_factory(&env));
+        factory.set_platform_constraints(&PlatformConstraints {
+            min_goal: 1_000,
+            max_goal: Some(1_000_000),
+        });
+        (env, factory)
+    }
+
+    #[test]
+    #[should_panic(expected = "goal below platform minimum")]
+    fn test_create_campaign_goal_below_minimum_panics() {
+        let (env, factory) = setup_constrained_factory();
+        factory.create_campaign(
+            &Address::generate(&env),
+            &Address::generate(&env),
+            &999,
+            &CampaignDeadline::Timestamp(3600),
+            &1,
+            &None,
+            &symbol_short!("art"),
+            &Vec::new(&env),
+            &None,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "goal above platform maximum")]
+    fn test_create_campaign_goal_above_maximum_panics() {
+        let (env, factory) = setup_constrained_factory();
+        factory.create_campaign(
+            &Address::generate(&env),
+            &Address::generate(&env),
+            &1_000_001,
+            &CampaignDeadline::Timestamp(3600),
+            &1,
+            &None,
+            &symbol_short!("art"),
+            &Vec::new(&env),
+            &None,
+        );
+    }
+
+    #[test]
+    fn test_goal_within_constraints_accepted() {
+        let (env, factory) = setup_constrained_factory();
+        env.as_contract(&factory.address, || {
+            check_goal_constraints(&env, 1_000);
+            check_goal_constraints(&env, 500_000);
+            check_goal_constraints(&env, 1_000_000);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "goal above platform maximum")]
+    fn test_updated_constraints_apply_to_new_campaigns() {
+        let (env, factory) = setup_constrained_factory();
+        factory.set_platform_constraints(&PlatformConstraints {
+            min_goal: 1_000,
+            max_goal: Some(100_000),
+        });
+        assert_eq!(
+            factory.platform_constraints(),
+            Some(PlatformConstraints {
+                min_goal: 1_000,
+                max_goal: Some(100_000),
+            })
+        );
+
+        env.as_contract(&factory.address, || check_goal_constraints(&env, 500_000));
+    }
+
+    #[test]
+    fn test_invalid_constraints_rejected() {
+        let (_env, factory) = setup_constrained_factory();
+        let result = factory.try_set_platform_constraints(&PlatformConstraints {
+            min_goal: 5_000,
+            max_goal: Some(1_000),
+        });
+        assert_eq!(result, Err(Ok(ContractError::InvalidConfig)));
+    }
+
+    #[test]
+    fn test_registry_pages_and_filters_by_creator() {
+        let env = Env::default();
+        let factory = FactoryContractClient::new(&env, &register_factory(&env));
+        let creators = [
+            Address::generate(&env),
+            Address::generate(&env),
+            Address::generate(&env),
+        ];
+        let mut all = Vec::new(&env);
+        env.as_contract(&factory.address, || {
+            for index in 0..25 {
+                let campaign = Address::generate(&env);
+                register_campaign(&env, &creators[index % 3], &campaign);
+                all.push_back(campaign);
+            }
+        });
+
+        assert_eq!(factory.campaign_count(), 25);
+        assert_eq!(factory.campaigns(&0, &10), all.slice(0..10));
+        assert_eq!(factory.campaigns(&10, &10), all.slice(10..20));
+        assert_eq!(factory.campaigns(&20, &10), all.slice(20..25));
+        assert_eq!(factory.campaigns(&25, &10).len(), 0);
+        assert_eq!(factory.campaigns(&24, &u32::MAX), all.slice(24..25));
+
+        // Creator 0 owns indices 0, 3, ..., 24; creators 1 and 2 own eight each.
+        let first_creator = factory.campaigns_by_creator(&creators[0], &0, &100);
+        assert_eq!(first_creator.len(), 9);
+        assert_eq!(first_creator.get(1), all.get(3));
+        assert_eq!(
+            factory.campaigns_by_creator(&creators[1], &0, &100).len(),
+            8
+        );
+        assert_eq!(
+            factory.campaigns_by_creator(&creators[2], &6, &5),
+            Vec::from_array(&env, [all.get(20).unwrap(), all.get(23).unwrap()])
+        );
+        assert_eq!(
+            factory
+                .campaigns_by_creator(&Address::generate(&env), &0, &10)
+                .len(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_campaigns_filter_by_category() {
+        let env = Env::default();
+        let factory = FactoryContractClient::new(&env, &register_factory(&env));
+        let categories = [
+            symbol_short!("art"),
+            symbol_short!("games"),
+            symbol_short!("tech"),
+        ];
+        let creator = Address::generate(&env);
+        let mut all = Vec::new(&env);
+        env.as_contract(&factory.address, || {
+            for index in 0..12 {
+                let campaign = Address::generate(&env);
+                register_campaign(&env, &creator, &campaign);
+                record_details(
+                    &env,
+                    &campaign,
+                    &creator,
+                    categories[index % 3].clone(),
+                    Vec::new(&env),
+                );
+                all.push_back(campaign);
+            }
+        });
+
+        let tech = factory.campaigns_by_category(&symbol_short!("tech"), &0, &10);
+        assert_eq!(
+            tech,
+            Vec::from_array(
+                &env,
+                [
+                    all.get_unchecked(2),
+                    all.get_unchecked(5),
+                    all.get_unchecked(8),
+                    all.get_unchecked(11),
+                ],
+            )
+        );
+        assert_eq!(
+            factory.campaigns_by_category(&symbol_short!("art"), &3, &10),
+            Vec::from_array(&env, [all.get_unchecked(9)])
+        );
+        assert_eq!(
+            factory
+                .campaigns_by_category(&symbol_short!("music"), &0, &10)
+                .len(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_campaign_details_record_creation_metadata() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_000);
+        let factory = FactoryContractClient::new(&env, &register_factory(&env));
+        let creator = Address::generate(&env);
+        let campaign = Address::generate(&env);
+        let tags = Vec::from_array(&env, [String::from_str(&env, "solar")]);
+
+        env.as_contract(&factory.address, || {
+            record_details(
+                &env,
+                &campaign,
+                &creator,
+                symbol_short!("tech"),
+                tags.clone(),
+            )
+        });
+
+        assert_eq!(
+            factory.campaign_details(&campaign),
+            Some(CampaignDetails {
+                creator,
+                category: symbol_short!("tech"),
+                tags,
+                created_at: 1_000,
+            })
+        );
+        assert_eq!(factory.campaign_details(&Address::generate(&env)), None);
+    }
+
+    #[test]
+    fn test_create_campaign_rejects_unknown_category() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let factory = FactoryContractClient::new(&env, &register_factory(&env));
+        factory.add_category(&symbol_short!("art"));
+        factory.add_category(&symbol_short!("music"));
+        factory.remove_category(&symbol_short!("music"));
+        assert_eq!(
+            factory.categories(),
+            Vec::from_array(&env, [symbol_short!("art")])
+        );
+
+        let result = factory.try_create_campaign(
+            &Address::generate(&env),
+            &Address::generate(&env),
+            &1_000_000,
+            &CampaignDeadline::Timestamp(3600),
+            &1_000,
+            &None,
+            &symbol_short!("music"),
+            &Vec::new(&env),
+            &None,
+        );
+        assert_eq!(result, Err(Ok(ContractError::UnknownCategory)));
+        assert_eq!(factory.campaign_count(), 0);
+    }
+
+    #[test]
+    fn test_create_campaign_enforces_tag_limits() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let factory = FactoryContractClient::new(&env, &register_factory(&env));
+        factory.add_category(&symbol_short!("art"));
+        let tag = String::from_str(&env, "mural");
+        let too_many = Vec::from_array(
+            &env,
+            [
+                tag.clone(),
+                tag.clone(),
+                tag.clone(),
+                tag.clone(),
+                tag.clone(),
+                tag.clone(),
+            ],
+        );
+        let too_long = Vec::from_array(
+            &env,
+            [String::from_str(
+                &env,
+                "a-tag-well-over-thirty-two-bytes-long",
+            )],
+        );
+
+        for tags in [too_many, too_long] {
+            let result = factory.try_create_campaign(
+                &Address::generate(&env),
+                &Address::generate(&env),
+                &1_000_000,
+                &CampaignDeadline::Timestamp(3600),
+                &1_000,
+                &None,
+                &symbol_short!("art"),
+                &tags,
+                &None,
+            );
+            assert_eq!(result, Err(Ok(ContractError::InvalidTags)));
+        }
+    }
+
+    #[test]
+    fn test_salted_campaign_lands_at_predicted_address() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let factory = FactoryContractClient::new(&env, &register_deploying_factory(&env));
+        factory.add_category(&symbol_short!("art"));
+        let token = env
+            .register_stellar_asset_contract_v2(Address::generate(&env))
+            .address();
+        let salt = BytesN::from_array(&env, &[7u8; 32]);
+        let predicted = factory.predict_campaign_address(&salt);
+
+        let campaign = factory.create_campaign(
+            &Address::generate(&env),
+            &token,
+            &1_000_000,
+            &CampaignDeadline::Timestamp(env.ledger().timestamp() + 3600),
+            &1_000,
+            &None,
+            &symbol_short!("art"),
+            &Vec::new(&env),
+            &Some(salt),
+        );
+        assert_eq!(campaign, predicted);
+    }
+
+    #[test]
+    fn test_predicted_addresses_differ_per_salt() {
+        let env = Env::default();
+        let factory = FactoryContractClient::new(&env, &register_factory(&env));
+        let first = BytesN::from_array(&env, &[1u8; 32]);
+        let second = BytesN::from_array(&env, &[2u8; 32]);
+
+        assert_eq!(
+            factory.predict_campaign_address(&first),
+            factory.predict_campaign_address(&first)
+        );
+        assert_ne!(
+            factory.predict_campaign_address(&first),
+            factory.predict_campaign_address(&second)
+        );
+    }
+
+    #[test]
+    fn test_create_campaign_rejects_reused_salt() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let factory = FactoryContractClient::new(&env, &register_deploying_factory(&env));
+        factory.add_category(&symbol_short!("art"));
+        let token = env
+            .register_stellar_asset_contract_v2(Address::generate(&env))
+            .address();
+        let salt = BytesN::from_array(&env, &[7u8; 32]);
+        let create = |salt: &BytesN<32>| {
+            factory.try_create_campaign(
+                &Address::generate(&env),
+                &token,
+                &1_000_000,
+                &CampaignDeadline::Timestamp(env.ledger().timestamp() + 3600),
+                &1_000,
+                &None,
+                &symbol_short!("art"),
+                &Vec::new(&env),
+                &Some(salt.clone()),
+            )
+        };
+
+        assert!(create(&salt).is_ok());
+        assert_eq!(create(&salt), Err(Ok(ContractError::AlreadyDeployed)));
+        assert_eq!(factory.campaign_count(), 1);
+    }
+
+    #[test]
+    fn test_campaigns_by_taxonomy_filters_on_campaign_category() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let factory = FactoryContractClient::new(&env, &register_factory(&env));
+        let token = env
+            .register_stellar_asset_contract_v2(Address::generate(&env))
+            .address();
+        let categories = [
+            crowdfund::CampaignCategory::Health,
+            crowdfund::CampaignCategory::Art,
+            crowdfund::CampaignCategory::Health,
+        ];
+        let mut campaigns = Vec::new(&env);
+        for category in categories {
+            let campaign = setup_campaign(&env, &token);
+            campaign.set_category(&category);
+            campaigns.push_back(campaign.address);
+        }
+        // One campaign never sets a category.
+        campaigns.push_back(setup_campaign(&env, &token).address);
+        env.as_contract(&factory.address, || {
+            for campaign in campaigns.iter() {
+                register_campaign(&env, &Address::generate(&env), &campaign);
+            }
+        });
+
+        assert_eq!(
+            factory.campaigns_by_taxonomy(&CampaignCategory::Health, &0, &10),
+            Vec::from_array(
+                &env,
+                [campaigns.get_unchecked(0), campaigns.get_unchecked(2)]
+            )
+        );
+        assert_eq!(
+            factory.campaigns_by_taxonomy(&CampaignCategory::Health, &1, &1),
+            Vec::new(&env)
+        );
+        assert_eq!(
+            factory.campaigns_by_taxonomy(&CampaignCategory::Art, &0, &10),
+            Vec::from_array(&env, [campaigns.get_unchecked(1)])
+        );
+    }
+
+    /// Registers a campaign deployed (natively) with the factory as its
+    /// deployer, as `create_campaign` would.
+    fn setup_factory_campaign(
+        env: &Env,
+        factory: &FactoryContractClient,
+    ) -> CrowdfundContractClient<'static> {
+        let token = env
+            .register_stellar_asset_contract_v2(Address::generate(env))
+            .address();
+        let creator = Address::generate(env);
+        let client = CrowdfundContractClient::new(
+            env,
+            &env.register(CrowdfundContract, (factory.address.clone(),)),
+        );
+        client.initialize(
+            &creator,
+            &token,
+            &1_000_000,
+            &2_000_000,
+            &Deadline::Timestamp(env.ledger().timestamp() + 3600),
+            &1_000,
+            &None,
+            &None,
+            &None,
+        );
+        env.as_contract(&factory.address, || {
+            register_campaign(env, &creator, &client.address)
+        });
+        client
+    }
+
+    #[test]
+    fn test_featured_campaigns_lists_active_features() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let factory = FactoryContractClient::new(&env, &register_factory(&env));
+        let first = setup_factory_campaign(&env, &factory);
+        let second = setup_factory_campaign(&env, &factory);
+        let now = env.ledger().timestamp();
+
+        factory.set_featured(&first.address, &(now + 100));
+        factory.set_featured(&second.address, &(now + 200));
+
+        assert!(first.is_featured());
+        assert_eq!(
+            factory.featured_campaigns(),
+            Vec::from_array(&env, [first.address.clone(), second.address.clone()])
+        );
+
+        // Un-featuring drops the campaign straight away.
+        factory.set_featured(&second.address, &0);
+        assert!(!second.is_featured());
+        assert_eq!(
+            factory.featured_campaigns(),
+            Vec::from_array(&env, [first.address.clone()])
+        );
+    }
+
+    #[test]
+    fn test_expired_features_drop_out() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let factory = FactoryContractClient::new(&env, &register_factory(&env));
+        let first = setup_factory_campaign(&env, &factory);
+        let second = setup_factory_campaign(&env, &factory);
+        let now = env.ledger().timestamp();
+        factory.set_featured(&first.address, &(now + 100));
+        factory.set_featured(&second.address, &(now + 200));
+
+        env.ledger().set_timestamp(now + 100);
+
+        assert!(!first.is_featured());
+        assert!(second.is_featured());
+        assert_eq!(
+            factory.featured_campaigns(),
+            Vec::from_array(&env, [second.address.clone()])
+        );
+    }
+
+    #[test]
+    fn test_set_featured_rejected_from_non_admin() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let factory = FactoryContractClient::new(&env, &register_factory(&env));
+        let campaign = setup_factory_campaign(&env, &factory);
+        let outsider = Address::generate(&env);
+        let expires_at = env.ledger().timestamp() + 100;
+
+        env.set_auths(&[]);
+        let result = factory
+            .mock_auths(&[MockAuth {
+                address: &outsider,
+                invoke: &MockAuthInvoke {
+                    contract: &factory.address,
+                    fn_name: "set_featured",
+                    args: (campaign.address.clone(), expires_at).into_val(&env),
+                    sub_invokes: &[],
+                },
+            }])
+            .try_set_featured(&campaign.address, &expires_at);
+
+        assert!(result.is_err());
+        assert!(!campaign.is_featured());
+        assert_eq!(factory.featured_campaigns().len(), 0);
+    }
+
+    fn platform_config(platform: &Address, fee_bps: u32) -> CampaignPlatformConfig {
+        CampaignPlatformConfig {
+            address: platform.clone(),
+            fee_bps,
+            fee_timing: CampaignFeeTiming::OnWithdraw,
+            dust_policy: CampaignDustPolicy::Creator,
+            tiered_fee: None,
+            min_campaign_duration_seconds: 0,
+            max_campaign_duration_seconds: 0,
+        }
+    }
+
+    #[test]
+    fn test_default_platform_config_replaces_caller_config() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let factory = FactoryContractClient::new(&env, &register_factory(&env));
+        let platform = Address::generate(&env);
+        let default = platform_config(&platform, 250);
+        factory.set_default_platform_config(&Some(default.clone()), &false);
+
+        let supplied = platform_config(&Address::generate(&env), 0);
+        env.as_contract(&factory.address, || {
+            assert_eq!(
+                resolve_platform_config(&env, Some(supplied)),
+                Ok(Some(default.clone()))
+            );
+            assert_eq!(resolve_platform_config(&env, None), Ok(Some(default)));
+        });
+    }
+
+    #[test]
+    fn test_default_platform_config_change_applies_to_later_campaigns() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let factory = FactoryContractClient::new(&env, &register_factory(&env));
+        let platform = Address::generate(&env);
+        factory.set_default_platform_config(&Some(platform_config(&platform, 250)), &false);
+        factory.set_default_platform_config(&Some(platform_config(&platform, 500)), &false);
+
+        assert_eq!(factory.default_platform_config().unwrap().fee_bps, 500);
+        env.as_contract(&factory.address, || {
+            assert_eq!(
+                resolve_platform_config(&env, None)
+                    .unwrap()
+                    .unwrap()
+                    .fee_bps,
+                500
+            );
+        });
+    }
+
+    #[test]
+    fn test_caller_platform_config_rejected_when_locked() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let factory = FactoryContractClient::new(&env, &register_factory(&env));
+        let platform = Address::generate(&env);
+        factory.set_default_platform_config(&Some(platform_config(&platform, 250)), &true);
+        assert!(factory.is_platform_config_locked());
+
+        let result = factory.try_create_campaign(
+            &Address::generate(&env),
+            &Address::generate(&env),
+            &1_000_000,
+            &CampaignDeadline::Timestamp(3600),
+            &1_000,
+            &Some(platform_config(&Address::generate(&env), 0)),
+            &symbol_short!("art"),
+            &Vec::new(&env),
+            &None,
+        );
+        assert_eq!(result, Err(Ok(ContractError::PlatformConfigLocked)));
+    }
+
+    #[test]
+    fn test_set_default_platform_config_rejects_excess_fee() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let factory = FactoryContractClient::new(&env, &register_factory(&env));
+
+        let result = factory.try_set_default_platform_config(
+            &Some(platform_config(&Address::generate(&env), 10_001)),
+            &false,
+        );
+        assert_eq!(result, Err(Ok(ContractError::InvalidConfig)));
+    }
+
+    #[test]
+    fn test_factory_campaign_pays_default_platform_fee() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let factory = FactoryContractClient::new(&env, &register_deploying_factory(&env));
+        let platform = Address::generate(&env);
+        factory.set_default_platform_config(&Some(platform_config(&platform, 250)), &true);
+        factory.add_category(&symbol_short!("art"));
+        let token = env
+            .register_stellar_asset_contract_v2(Address::generate(&env))
+            .address();
+        let deadline = env.ledger().timestamp() + 3600;
+
+        let campaign = CrowdfundContractClient::new(
+            &env,
+            &factory.create_campaign(
+                &Address::generate(&env),
+                &token,
+                &100_000,
+                &CampaignDeadline::Timestamp(deadline),
+                &1_000,
+                &None,
+                &symbol_short!("art"),
+                &Vec::new(&env),
+                &None,
+            ),
+        );
+        let contributor = Address::generate(&env);
+        token::StellarAssetClient::new(&env, &token).mint(&contributor, &100_000);
+        campaign.contribute(&contributor, &100_000, &None, &None);
+
+        env.ledger().set_timestamp(deadline + 1);
+        campaign.withdraw();
+        assert_eq!(token::Client::new(&env, &token).balance(&platform), 2_500);
+    }
+
+    /// A campaign deployed with the factory as its deployer, registered as if
+    /// `create_campaign` had created it when `registered` is set.
+    fn setup_reporting_campaign(
+        env: &Env,
+        factory: &FactoryContractClient,
+        token: &Address,
+        registered: bool,
+    ) -> CrowdfundContractClient<'static> {
+        let creator = Address::generate(env);
+        let client = CrowdfundContractClient::new(
+            env,
+            &env.register(CrowdfundContract, (factory.address.clone(),)),
+        );
+        if registered {
+            env.as_contract(&factory.address, || {
+                register_campaign(env, &creator, &client.address)
+            });
+        }
+        client.initialize(
+            &creator,
+            token,
+            &10_000,
+            &100_000,
+            &Deadline::Timestamp(env.ledger().timestamp() + 3600),
+            &1_000,
+            &None,
+            &None,
+            &None,
+        );
+        client
+    }
+
+    fn contribute(env: &Env, campaign: &CrowdfundContractClient, token: &Address, amount: i128) {
+        let contributor = Address::generate(env);
+        token::StellarAssetClient::new(env, token).mint(&contributor, &amount);
+        campaign.contribute(&contributor, &amount, &None, &None);
+    }
+
+    #[test]
+    fn test_volume_stats_sum_successful_campaigns() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let factory = FactoryContractClient::new(&env, &register_factory(&env));
+        let token = env
+            .register_stellar_asset_contract_v2(Address::generate(&env))
+            .address();
+        let first = setup_reporting_campaign(&env, &factory, &token, true);
+        let second = setup_reporting_campaign(&env, &factory, &token, true);
+        contribute(&env, &first, &token, 30_000);
+        contribute(&env, &second, &token, 20_000);
+        contribute(&env, &second, &token, 25_000);
+
+        env.ledger().set_timestamp(env.ledger().timestamp() + 3601);
+        first.withdraw();
+        second.withdraw();
+
+        assert_eq!(factory.total_raised_all_campaigns(), 75_000);
+        assert_eq!(factory.total_contributors_all_campaigns(), 3);
+        assert_eq!(factory.volume_stats().finalized_campaigns, 2);
+    }
+
+    #[test]
+    fn test_rejected_report_rolls_back_without_blocking_withdraw() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let factory = FactoryContractClient::new(&env, &register_factory(&env));
+        let token = env
+            .register_stellar_asset_contract_v2(Address::generate(&env))
+            .address();
+        let counted = setup_reporting_campaign(&env, &factory, &token, true);
+        let unknown = setup_reporting_campaign(&env, &factory, &token, false);
+        contribute(&env, &counted, &token, 30_000);
+        contribute(&env, &unknown, &token, 40_000);
+
+        env.ledger().set_timestamp(env.ledger().timestamp() + 3601);
+        counted.withdraw();
+        unknown.withdraw();
+
+        // The unregistered campaign still paid out, but was not counted.
+        assert_eq!(
+            token::Client::new(&env, &token).balance(&unknown.address),
+            0
+        );
+        assert_eq!(factory.total_raised_all_campaigns(), 30_000);
+
+        // A second report for the same campaign is rejected as a whole.
+        let result = factory.try_on_campaign_finalized(&counted.address, &30_000, &1);
+        assert!(result.is_err());
+        assert_eq!(
+            factory.volume_stats(),
+            VolumeStats {
+                total_raised: 30_000,
+                contributor_count: 1,
+                finalized_campaigns: 1,
+            }
+        );
+    }
+}
@@ -0,0 +1,273 @@
+#![no_std]
+#![allow(missing_docs)]
+
+use soroban_sdk::{contract, contractimpl, contracttype, token, Address, Env};
+
+#[cfg(test)]
+mod test;
+
+// ── Data Types ──────────────────────────────────────────────────────────────
+
+/// Highest platform fee, in basis points, campaigns may be initialized with
+/// until the admin sets a cap of their own.
+pub const DEFAULT_FEE_CAP_BPS: u32 = 5_000;
+
+/// Storage keys for the fee vault.
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    /// The address allowed to sweep fees out of the vault.
+    Admin,
+    /// Cumulative fees a campaign has recorded, per token.
+    CampaignFees(Address, Address),
+    /// Cumulative fees recorded across all campaigns, per token.
+    TotalFees(Address),
+    /// Whether campaigns using this vault may be denominated in a token.
+    AllowedToken(Address),
+    /// Whether the platform fee is waived for a creator's campaigns.
+    FeeWaiver(Address),
+    /// Highest platform fee, in basis points, campaigns may charge.
+    FeeCapBps,
+    /// Whether the platform fee is waived for a single campaign.
+    CampaignFeeWaiver(Address),
+}
+
+// ── Contract ────────────────────────────────────────────────────────────────
+
+/// Custody contract for platform fees.
+///
+/// Set as a campaign's `PlatformConfig.address`, the vault receives fee
+/// transfers like any other holder. The campaign then calls `record_fee` so
+/// fees can be attributed per campaign, and the admin (typically a multi-sig
+/// account) moves funds out with `sweep`. The admin also vets the tokens
+/// campaigns may use: a campaign naming the vault as its platform can only
+/// be initialized with a token on the allowlist.
+#[contract]
+pub struct FeeVaultContract;
+
+#[contractimpl]
+impl FeeVaultContract {
+    /// Set the vault admin.
+    ///
+    /// # Panics
+    /// * If already initialized.
+    pub fn initialize(env: Env, admin: Address) {
+        if env.storage().instance().has(&DataKey::Admin) {
+            panic!("already initialized");
+        }
+        env.storage().instance().set(&DataKey::Admin, &admin);
+    }
+
+    /// Record that `campaign` has sent `amount` of `token` to the vault —
+    /// callable by the campaign itself.
+    ///
+    /// # Panics
+    /// * If `amount` is not positive.
+    pub fn record_fee(env: Env, campaign: Address, token: Address, amount: i128) {
+        campaign.require_auth();
+
+        if amount <= 0 {
+            panic!("amount must be positive");
+        }
+
+        let campaign_key = DataKey::CampaignFees(campaign.clone(), token.clone());
+        let recorded: i128 = env.storage().persistent().get(&campaign_key).unwrap_or(0);
+        env.storage()
+            .persistent()
+            .set(&campaign_key, &(recorded + amount));
+        env.storage()
+            .persistent()
+            .extend_ttl(&campaign_key, 100, 100);
+
+        let total_key = DataKey::TotalFees(token.clone());
+        let total: i128 = env.storage().persistent().get(&total_key).unwrap_or(0);
+        env.storage()
+            .persistent()
+            .set(&total_key, &(total + amount));
+        env.storage().persistent().extend_ttl(&total_key, 100, 100);
+
+        env.events()
+            .publish(("fee_vault", "fee_recorded"), (campaign, token, amount));
+    }
+
+    /// Send `amount` of `token` from the vault to `to` — admin-only.
+    ///
+    /// # Panics
+    /// * If `amount` is not positive.
+    /// * If the vault holds less than `amount`.
+    pub fn sweep(env: Env, token: Address, to: Address, amount: i128) {
+        let admin = Self::admin(env.clone());
+        admin.require_auth();
+
+        if amount <= 0 {
+            panic!("amount must be positive");
+        }
+
+        token::Client::new(&env, &token).transfer(&env.current_contract_address(), &to, &amount);
+
+        env.events()
+            .publish(("fee_vault", "swept"), (token, to, amount));
+    }
+
+    /// Add `token` to the allowlist — admin-only.
+    pub fn add_allowed_token(env: Env, token: Address) {
+        let admin = Self::admin(env.clone());
+        admin.require_auth();
+
+        let key = DataKey::AllowedToken(token.clone());
+        env.storage().persistent().set(&key, &true);
+        env.storage().persistent().extend_ttl(&key, 100, 100);
+
+        env.events().publish(("fee_vault", "token_allowed"), token);
+    }
+
+    /// Remove `token` from the allowlist — admin-only. Campaigns already
+    /// initialized with it are unaffected.
+    pub fn remove_allowed_token(env: Env, token: Address) {
+        let admin = Self::admin(env.clone());
+        admin.require_auth();
+
+        env.storage()
+            .persistent()
+            .remove(&DataKey::AllowedToken(token.clone()));
+
+        env.events().publish(("fee_vault", "token_removed"), token);
+    }
+
+    /// Returns true if `token` is on the allowlist.
+    pub fn is_token_allowed(env: Env, token: Address) -> bool {
+        env.storage()
+            .persistent()
+            .get(&DataKey::AllowedToken(token))
+            .unwrap_or(false)
+    }
+
+    /// Waive the platform fee for `creator`'s campaigns — admin-only.
+    ///
+    /// Campaigns check the waiver when they pay out, so it covers every
+    /// withdraw that happens while it is in place.
+    pub fn add_fee_waiver(env: Env, creator: Address) {
+        let admin = Self::admin(env.clone());
+        admin.require_auth();
+
+        let key = DataKey::FeeWaiver(creator.clone());
+        env.storage().persistent().set(&key, &true);
+        env.storage().persistent().extend_ttl(&key, 100, 100);
+
+        env.events()
+            .publish(("fee_vault", "fee_waiver_added"), creator);
+    }
+
+    /// Withdraw `creator`'s fee waiver — admin-only.
+    pub fn remove_fee_waiver(env: Env, creator: Address) {
+        let admin = Self::admin(env.clone());
+        admin.require_auth();
+
+        env.storage()
+            .persistent()
+            .remove(&DataKey::FeeWaiver(creator.clone()));
+
+        env.events()
+            .publish(("fee_vault", "fee_waiver_removed"), creator);
+    }
+
+    /// Returns true if the platform fee is waived for `creator`.
+    pub fn is_fee_waived(env: Env, creator: Address) -> bool {
+        env.storage()
+            .persistent()
+            .get(&DataKey::FeeWaiver(creator))
+            .unwrap_or(false)
+    }
+
+    /// Waive the platform fee for `campaign` alone, e.g. a non-profit
+    /// campaign — admin-only. Like creator waivers, it covers any withdraw
+    /// made while it is in place.
+    pub fn waive_fee(env: Env, campaign: Address) {
+        let admin = Self::admin(env.clone());
+        admin.require_auth();
+
+        let key = DataKey::CampaignFeeWaiver(campaign.clone());
+        env.storage().persistent().set(&key, &true);
+        env.storage().persistent().extend_ttl(&key, 100, 100);
+
+        env.events()
+            .publish(("fee_vault", "campaign_fee_waived"), campaign);
+    }
+
+    /// Revoke `campaign`'s fee waiver — admin-only. Has no effect on a
+    /// campaign that has already paid out.
+    pub fn revoke_fee_waiver(env: Env, campaign: Address) {
+        let admin = Self::admin(env.clone());
+        admin.require_auth();
+
+        env.storage()
+            .persistent()
+            .remove(&DataKey::CampaignFeeWaiver(campaign.clone()));
+
+        env.events()
+            .publish(("fee_vault", "campaign_fee_waiver_revoked"), campaign);
+    }
+
+    /// Returns true if the platform fee is waived for `campaign`.
+    pub fn is_campaign_fee_waived(env: Env, campaign: Address) -> bool {
+        env.storage()
+            .persistent()
+            .get(&DataKey::CampaignFeeWaiver(campaign))
+            .unwrap_or(false)
+    }
+
+    /// Set the highest platform fee campaigns using the vault may be
+    /// initialized with — admin-only. Campaigns already initialized keep
+    /// their fee.
+    ///
+    /// # Panics
+    /// * If `cap_bps` exceeds 10,000 (100%).
+    pub fn set_fee_cap_bps(env: Env, cap_bps: u32) {
+        let admin = Self::admin(env.clone());
+        admin.require_auth();
+
+        if cap_bps > 10_000 {
+            panic!("fee cap cannot exceed 100%");
+        }
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::FeeCapBps, &cap_bps);
+        env.storage()
+            .persistent()
+            .extend_ttl(&DataKey::FeeCapBps, 100, 100);
+
+        env.events()
+            .publish(("fee_vault", "fee_cap_updated"), cap_bps);
+    }
+
+    /// Returns the platform fee cap in basis points; `DEFAULT_FEE_CAP_BPS`
+    /// until the admin sets one.
+    pub fn fee_cap_bps(env: Env) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::FeeCapBps)
+            .unwrap_or(DEFAULT_FEE_CAP_BPS)
+    }
+
+    /// Returns the vault admin.
+    pub fn admin(env: Env) -> Address {
+        env.storage().instance().get(&DataKey::Admin).unwrap()
+    }
+
+    /// Returns the fees in `token` recorded by `campaign`.
+    pub fn campaign_fees(env: Env, campaign: Address, token: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::CampaignFees(campaign, token))
+            .unwrap_or(0)
+    }
+
+    /// Returns the fees in `token` recorded across all campaigns.
+    pub fn total_fees(env: Env, token: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::TotalFees(token))
+            .unwrap_or(0)
+    }
+}
@@ -0,0 +1,198 @@
+extern crate std;
+
+use soroban_sdk::{
+    testutils::{Address as _, MockAuth, MockAuthInvoke},
+    token, Address, Env, IntoVal,
+};
+
+use crate::{FeeVaultContract, FeeVaultContractClient, DEFAULT_FEE_CAP_BPS};
+
+fn setup_env() -> (Env, FeeVaultContractClient<'static>, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(FeeVaultContract, ());
+    let client = FeeVaultContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+
+    (env, client, admin, token_address)
+}
+
+#[test]
+fn test_record_fee_accumulates_per_campaign() {
+    let (env, client, _admin, token_address) = setup_env();
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+
+    client.record_fee(&first, &token_address, &100);
+    client.record_fee(&first, &token_address, &50);
+    client.record_fee(&second, &token_address, &25);
+
+    assert_eq!(client.campaign_fees(&first, &token_address), 150);
+    assert_eq!(client.campaign_fees(&second, &token_address), 25);
+    assert_eq!(client.total_fees(&token_address), 175);
+}
+
+#[test]
+fn test_sweep_sends_funds() {
+    let (env, client, _admin, token_address) = setup_env();
+    token::StellarAssetClient::new(&env, &token_address).mint(&client.address, &1_000);
+    let treasury = Address::generate(&env);
+
+    client.sweep(&token_address, &treasury, &600);
+
+    let token_client = token::Client::new(&env, &token_address);
+    assert_eq!(token_client.balance(&treasury), 600);
+    assert_eq!(token_client.balance(&client.address), 400);
+}
+
+#[test]
+fn test_sweep_rejected_from_non_admin() {
+    let (env, client, _admin, token_address) = setup_env();
+    token::StellarAssetClient::new(&env, &token_address).mint(&client.address, &1_000);
+    let outsider = Address::generate(&env);
+
+    env.set_auths(&[]);
+    let result = client
+        .mock_auths(&[MockAuth {
+            address: &outsider,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "sweep",
+                args: (token_address.clone(), outsider.clone(), 1_000i128).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_sweep(&token_address, &outsider, &1_000);
+
+    assert!(result.is_err());
+    assert_eq!(
+        token::Client::new(&env, &token_address).balance(&client.address),
+        1_000
+    );
+}
+
+#[test]
+fn test_allowlist_add_and_remove() {
+    let (_env, client, _admin, token_address) = setup_env();
+    assert!(!client.is_token_allowed(&token_address));
+
+    client.add_allowed_token(&token_address);
+    assert!(client.is_token_allowed(&token_address));
+
+    client.remove_allowed_token(&token_address);
+    assert!(!client.is_token_allowed(&token_address));
+}
+
+#[test]
+fn test_allowlist_rejected_from_non_admin() {
+    let (env, client, _admin, token_address) = setup_env();
+    let outsider = Address::generate(&env);
+
+    env.set_auths(&[]);
+    let result = client
+        .mock_auths(&[MockAuth {
+            address: &outsider,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "add_allowed_token",
+                args: (token_address.clone(),).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_add_allowed_token(&token_address);
+
+    assert!(result.is_err());
+    assert!(!client.is_token_allowed(&token_address));
+}
+
+#[test]
+fn test_fee_waiver_add_and_remove() {
+    let (env, client, _admin, _token_address) = setup_env();
+    let creator = Address::generate(&env);
+
+    client.add_fee_waiver(&creator);
+    assert!(client.is_fee_waived(&creator));
+
+    client.remove_fee_waiver(&creator);
+    assert!(!client.is_fee_waived(&creator));
+}
+
+#[test]
+fn test_fee_waiver_rejected_from_non_admin() {
+    let (env, client, _admin, _token_address) = setup_env();
+    let creator = Address::generate(&env);
+
+    env.set_auths(&[]);
+    let result = client
+        .mock_auths(&[MockAuth {
+            address: &creator,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "add_fee_waiver",
+                args: (creator.clone(),).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_add_fee_waiver(&creator);
+
+    assert!(result.is_err());
+    assert!(!client.is_fee_waived(&creator));
+}
+
+#[test]
+fn test_campaign_fee_waiver_can_be_revoked() {
+    let (env, client, _admin, _token_address) = setup_env();
+    let campaign = Address::generate(&env);
+    assert!(!client.is_campaign_fee_waived(&campaign));
+
+    client.waive_fee(&campaign);
+    assert!(client.is_campaign_fee_waived(&campaign));
+
+    client.revoke_fee_waiver(&campaign);
+    assert!(!client.is_campaign_fee_waived(&campaign));
+}
+
+#[test]
+fn test_fee_cap_defaults_and_updates() {
+    let (_env, client, _admin, _token_address) = setup_env();
+    assert_eq!(client.fee_cap_bps(), DEFAULT_FEE_CAP_BPS);
+
+    client.set_fee_cap_bps(&1_000);
+    assert_eq!(client.fee_cap_bps(), 1_000);
+}
+
+#[test]
+fn test_fee_cap_rejected_from_non_admin() {
+    let (env, client, _admin, _token_address) = setup_env();
+    let outsider = Address::generate(&env);
+
+    env.set_auths(&[]);
+    let result = client
+        .mock_auths(&[MockAuth {
+            address: &outsider,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "set_fee_cap_bps",
+                args: (1_000u32,).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_set_fee_cap_bps(&1_000);
+
+    assert!(result.is_err());
+    assert_eq!(client.fee_cap_bps(), DEFAULT_FEE_CAP_BPS);
+}
+
+#[test]
+#[should_panic(expected = "already initialized")]
+fn test_double_initialize_panics() {
+    let (env, client, _admin, _token_address) = setup_env();
+    client.initialize(&Address::generate(&env));
+}
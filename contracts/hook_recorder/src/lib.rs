@@ -0,0 +1,122 @@
+#![no_std]
+#![allow(missing_docs)]
+
+use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, Vec};
+
+#[cfg(test)]
+mod test;
+
+// ── Data Types ──────────────────────────────────────────────────────────────
+
+/// Mirror of the crowdfund contract's campaign status.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum CampaignStatus {
+    Active,
+    Successful,
+    Refunded,
+    Cancelled,
+}
+
+/// Arguments of one `on_contribute` call.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ContributeCall {
+    pub contributor: Address,
+    pub amount: i128,
+    pub total: i128,
+}
+
+/// Arguments of one `on_finalize` call.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct FinalizeCall {
+    pub status: CampaignStatus,
+    pub total: i128,
+}
+
+/// Storage keys for the hook recorder.
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    /// Every `on_contribute` call received, oldest first.
+    Contributions,
+    /// Every `on_finalize` call received, oldest first.
+    Finalizations,
+    /// Whether the callbacks currently trap.
+    Failing,
+}
+
+// ── Contract ────────────────────────────────────────────────────────────────
+
+/// Reference campaign hook.
+///
+/// Set as a campaign's `CampaignOptions.hook`, it records every callback so
+/// tests can check what the campaign reported. `set_failing` makes the
+/// callbacks trap, to exercise the campaign's handling of a broken hook. It
+/// is a fixture, not a production hook: none of its entry points check
+/// authorization.
+#[contract]
+pub struct HookRecorderContract;
+
+#[contractimpl]
+impl HookRecorderContract {
+    /// Called by a campaign after each contribution, with the total raised
+    /// after it.
+    pub fn on_contribute(env: Env, contributor: Address, amount: i128, total: i128) {
+        fail_if_requested(&env);
+
+        let mut calls = Self::contributions(env.clone());
+        calls.push_back(ContributeCall {
+            contributor,
+            amount,
+            total,
+        });
+        env.storage()
+            .instance()
+            .set(&DataKey::Contributions, &calls);
+    }
+
+    /// Called by a campaign when it closes, with the total raised at close.
+    pub fn on_finalize(env: Env, status: CampaignStatus, total: i128) {
+        fail_if_requested(&env);
+
+        let mut calls = Self::finalizations(env.clone());
+        calls.push_back(FinalizeCall { status, total });
+        env.storage()
+            .instance()
+            .set(&DataKey::Finalizations, &calls);
+    }
+
+    /// Make the callbacks trap (`true`) or record again (`false`).
+    pub fn set_failing(env: Env, failing: bool) {
+        env.storage().instance().set(&DataKey::Failing, &failing);
+    }
+
+    /// Returns the `on_contribute` calls received, oldest first.
+    pub fn contributions(env: Env) -> Vec<ContributeCall> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Contributions)
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    /// Returns the `on_finalize` calls received, oldest first.
+    pub fn finalizations(env: Env) -> Vec<FinalizeCall> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Finalizations)
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+}
+
+fn fail_if_requested(env: &Env) {
+    if env
+        .storage()
+        .instance()
+        .get(&DataKey::Failing)
+        .unwrap_or(false)
+    {
+        panic!("hook failure requested");
+    }
+}
@@ -0,0 +1,67 @@
+extern crate std;
+
+use soroban_sdk::{testutils::Address as _, Address, Env, Vec};
+
+use crate::{
+    CampaignStatus, ContributeCall, FinalizeCall, HookRecorderContract, HookRecorderContractClient,
+};
+
+fn setup_env() -> (Env, HookRecorderContractClient<'static>) {
+    let env = Env::default();
+    let contract_id = env.register(HookRecorderContract, ());
+    let client = HookRecorderContractClient::new(&env, &contract_id);
+    (env, client)
+}
+
+#[test]
+fn test_records_callbacks_in_order() {
+    let (env, client) = setup_env();
+    let contributor = Address::generate(&env);
+
+    client.on_contribute(&contributor, &100, &100);
+    client.on_contribute(&contributor, &50, &150);
+    client.on_finalize(&CampaignStatus::Successful, &150);
+
+    assert_eq!(
+        client.contributions(),
+        Vec::from_array(
+            &env,
+            [
+                ContributeCall {
+                    contributor: contributor.clone(),
+                    amount: 100,
+                    total: 100,
+                },
+                ContributeCall {
+                    contributor,
+                    amount: 50,
+                    total: 150,
+                },
+            ],
+        )
+    );
+    assert_eq!(
+        client.finalizations(),
+        Vec::from_array(
+            &env,
+            [FinalizeCall {
+                status: CampaignStatus::Successful,
+                total: 150,
+            }],
+        )
+    );
+}
+
+#[test]
+fn test_failing_hook_traps() {
+    let (env, client) = setup_env();
+    client.set_failing(&true);
+
+    assert!(client
+        .try_on_contribute(&Address::generate(&env), &100, &100)
+        .is_err());
+    assert!(client
+        .try_on_finalize(&CampaignStatus::Refunded, &0)
+        .is_err());
+    assert_eq!(client.contributions().len(), 0);
+}
@@ -0,0 +1,119 @@
+#![no_std]
+#![allow(missing_docs)]
+
+use soroban_sdk::{contract, contractimpl, contracttype, Address, Env};
+
+#[cfg(test)]
+mod test;
+
+// ── Data Types ──────────────────────────────────────────────────────────────
+
+/// Storage keys for the receipt contract.
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    /// The address allowed to mint and burn (the crowdfund campaign).
+    Minter,
+    /// Receipt balance of each holder.
+    Balance(Address),
+    /// Total receipts outstanding.
+    TotalSupply,
+}
+
+// ── Contract ────────────────────────────────────────────────────────────────
+
+/// A minimal, non-transferable receipt token.
+///
+/// The crowdfund contract mints receipts to contributors for the amount
+/// they contributed and burns them when the contribution is refunded, giving
+/// backers on-chain proof of their support. There is no `transfer`.
+#[contract]
+pub struct ReceiptContract;
+
+#[contractimpl]
+impl ReceiptContract {
+    /// Records the minter — normally the crowdfund contract address. Set at
+    /// deployment so no one can claim the role afterwards.
+    pub fn __constructor(env: Env, minter: Address) {
+        env.storage().instance().set(&DataKey::Minter, &minter);
+        env.storage().instance().set(&DataKey::TotalSupply, &0i128);
+    }
+
+    /// Mint `amount` receipts to `to` — minter-only.
+    ///
+    /// # Panics
+    /// * If `amount` is not positive.
+    pub fn mint(env: Env, to: Address, amount: i128) {
+        let minter: Address = env.storage().instance().get(&DataKey::Minter).unwrap();
+        minter.require_auth();
+
+        if amount <= 0 {
+            panic!("amount must be positive");
+        }
+
+        let balance_key = DataKey::Balance(to.clone());
+        let balance: i128 = env.storage().persistent().get(&balance_key).unwrap_or(0);
+        env.storage()
+            .persistent()
+            .set(&balance_key, &(balance + amount));
+        env.storage()
+            .persistent()
+            .extend_ttl(&balance_key, 100, 100);
+
+        let supply: i128 = env.storage().instance().get(&DataKey::TotalSupply).unwrap();
+        env.storage()
+            .instance()
+            .set(&DataKey::TotalSupply, &(supply + amount));
+
+        env.events().publish(("receipt", "minted"), (to, amount));
+    }
+
+    /// Burn `amount` receipts from `from` — minter-only.
+    ///
+    /// # Panics
+    /// * If `amount` is not positive or exceeds the holder's balance.
+    pub fn burn(env: Env, from: Address, amount: i128) {
+        let minter: Address = env.storage().instance().get(&DataKey::Minter).unwrap();
+        minter.require_auth();
+
+        let balance_key = DataKey::Balance(from.clone());
+        let balance: i128 = env.storage().persistent().get(&balance_key).unwrap_or(0);
+        if amount <= 0 || amount > balance {
+            panic!("invalid burn amount");
+        }
+        env.storage()
+            .persistent()
+            .set(&balance_key, &(balance - amount));
+        env.storage()
+            .persistent()
+            .extend_ttl(&balance_key, 100, 100);
+
+        let supply: i128 = env.storage().instance().get(&DataKey::TotalSupply).unwrap();
+        env.storage()
+            .instance()
+            .set(&DataKey::TotalSupply, &(supply - amount));
+
+        env.events().publish(("receipt", "burned"), (from, amount));
+    }
+
+    /// Returns the receipt balance of `id`.
+    pub fn balance(env: Env, id: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Balance(id))
+            .unwrap_or(0)
+    }
+
+    /// Returns the total receipts outstanding.
+    pub fn total_supply(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::TotalSupply)
+            .unwrap_or(0)
+    }
+
+    /// Returns the minter address.
+    pub fn minter(env: Env) -> Address {
+        env.storage().instance().get(&DataKey::Minter).unwrap()
+    }
+}
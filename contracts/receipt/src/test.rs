@@ -0,0 +1,58 @@
+extern crate std;
+
+use soroban_sdk::{testutils::Address as _, Address, Env};
+
+use crate::{ReceiptContract, ReceiptContractClient};
+
+fn setup_env() -> (Env, ReceiptContractClient<'static>, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let minter = Address::generate(&env);
+    let contract_id = env.register(ReceiptContract, (minter.clone(),));
+    let client = ReceiptContractClient::new(&env, &contract_id);
+
+    (env, client, minter)
+}
+
+#[test]
+fn test_mint_and_burn() {
+    let (env, client, minter) = setup_env();
+    let holder = Address::generate(&env);
+
+    client.mint(&holder, &500);
+    assert_eq!(client.balance(&holder), 500);
+    assert_eq!(client.total_supply(), 500);
+    assert_eq!(client.minter(), minter);
+
+    client.burn(&holder, &200);
+    assert_eq!(client.balance(&holder), 300);
+    assert_eq!(client.total_supply(), 300);
+}
+
+#[test]
+#[should_panic(expected = "invalid burn amount")]
+fn test_burn_more_than_balance_panics() {
+    let (env, client, _minter) = setup_env();
+    let holder = Address::generate(&env);
+
+    client.mint(&holder, &100);
+    client.burn(&holder, &101);
+}
+
+#[test]
+fn test_minter_set_at_deployment() {
+    let (_env, client, minter) = setup_env();
+    assert_eq!(client.minter(), minter);
+    assert_eq!(client.total_supply(), 0);
+}
+
+#[test]
+#[should_panic]
+fn test_mint_requires_minter_auth() {
+    let env = Env::default();
+    let contract_id = env.register(ReceiptContract, (Address::generate(&env),));
+    let client = ReceiptContractClient::new(&env, &contract_id);
+
+    client.mint(&Address::generate(&env), &100);
+}
@@ -23,7 +23,9 @@ echo "Deploying contract to $NETWORK..."
 CONTRACT_ID=$(soroban contract deploy \
   --wasm "$CONTRACT_WASM" \
   --network "$NETWORK" \
-  --source "$CREATOR")
+  --source "$CREATOR" \
+  -- \
+  --deployer "$CREATOR")
 
 echo "Contract deployed: $CONTRACT_ID"
 
@@ -37,7 +39,7 @@ soroban contract invoke \
   --creator "$CREATOR" \
   --token "$TOKEN" \
   --goal "$GOAL" \
-  --deadline "$DEADLINE" \
+  --deadline "{\"Timestamp\":$DEADLINE}" \
   --min_contribution "$MIN_CONTRIBUTION"
 
 echo "Campaign initialized successfully."